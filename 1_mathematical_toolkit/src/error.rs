@@ -0,0 +1,67 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ToolkitError {
+    /// The modulus passed to `PrimeField::new` is not prime.
+    NonPrimeModulus(u64),
+    /// Attempted to invert (or divide by) the zero element of the field.
+    ZeroInverse,
+    /// Attempted to divide a polynomial by the zero polynomial.
+    DivisionByZeroPolynomial,
+    /// The modulus is not a safe prime `p = 2q + 1` with `q` prime.
+    NotSafePrime(u64),
+    /// The element does not generate the prime-order subgroup used for commitments.
+    InvalidSubgroupGenerator(u64),
+    /// The point is not a root of the polynomial it was supposed to be divided out of.
+    PointNotARoot(u64),
+    /// The extension-field modulus is not monic, has degree 0, or is reducible.
+    InvalidExtensionModulus,
+    /// The string could not be parsed as a polynomial.
+    InvalidPolynomialString(String),
+    /// The requested subgroup order does not divide the group order `p - 1`.
+    InvalidSubgroupOrder(u64),
+    /// Input dimensions (matrix shapes, vector lengths, or variable counts) are incompatible with
+    /// the operation.
+    DimensionMismatch,
+    /// The operands are defined over different fields.
+    FieldMismatch,
+    /// The matrix is not invertible.
+    SingularMatrix,
+    /// The linear system has no solution.
+    InconsistentSystem,
+}
+
+impl fmt::Display for ToolkitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ToolkitError::NonPrimeModulus(p) => write!(f, "Modulus {} is not prime", p),
+            ToolkitError::ZeroInverse => write!(f, "Zero has no multiplicative inverse"),
+            ToolkitError::DivisionByZeroPolynomial => {
+                write!(f, "Division by the zero polynomial")
+            }
+            ToolkitError::NotSafePrime(p) => write!(f, "Modulus {} is not a safe prime", p),
+            ToolkitError::InvalidSubgroupGenerator(g) => {
+                write!(f, "{} does not generate the prime-order subgroup", g)
+            }
+            ToolkitError::PointNotARoot(a) => {
+                write!(f, "{} is not a root of the polynomial being divided", a)
+            }
+            ToolkitError::InvalidExtensionModulus => {
+                write!(
+                    f,
+                    "Extension modulus must be monic, irreducible, and non-constant"
+                )
+            }
+            ToolkitError::InvalidPolynomialString(s) => {
+                write!(f, "Cannot parse '{}' as a polynomial", s)
+            }
+            ToolkitError::InvalidSubgroupOrder(n) => {
+                write!(f, "{} does not divide the multiplicative group order", n)
+            }
+            ToolkitError::DimensionMismatch => write!(f, "Incompatible dimensions"),
+            ToolkitError::FieldMismatch => write!(f, "Operands are over different fields"),
+            ToolkitError::SingularMatrix => write!(f, "Matrix is singular"),
+            ToolkitError::InconsistentSystem => write!(f, "Linear system has no solution"),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Extension fields GF(p^k) = GF(p)[x] / (m(x)).
+//!
+//! An element of the extension is a polynomial of degree below `k` with coefficients in GF(p),
+//! represented by its `k` coefficients in ascending order of power. Addition is coefficient-wise
+//! and multiplication is polynomial multiplication followed by reduction modulo the irreducible
+//! polynomial `m(x)`.
+//!
+//! The **Frobenius map** `phi(a) = a^p` is the fundamental automorphism of GF(p^k): it preserves
+//! addition and multiplication, fixes exactly the base field GF(p), and applying it `k` times
+//! gives the identity. Extension-field soundness arguments (e.g. for challenges drawn from an
+//! extension of a small field) rely on these properties.
+
+use rand::Rng;
+
+use crate::error::ToolkitError;
+use crate::field::PrimeField;
+use crate::polynomial::Polynomial;
+
+/// The extension field GF(p^k) defined by a monic irreducible polynomial of degree `k`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtensionField {
+    /// The base field GF(p).
+    pub base: PrimeField,
+    /// The monic modulus `m(x)`, coefficients in ascending order of power.
+    modulus: Vec<u64>,
+}
+
+impl ExtensionField {
+    /// Creates GF(p^k) from the coefficients (constant term first) of a monic polynomial of
+    /// degree `k >= 1`.
+    ///
+    /// The modulus must be irreducible over GF(p) for the result to be a field; this is not
+    /// checked here. Use [`ExtensionField::quadratic`] for a validated construction.
+    ///
+    /// Returns [`ToolkitError::InvalidExtensionModulus`] if the modulus is not monic of degree at
+    /// least 1.
+    pub fn new(base: PrimeField, modulus: Vec<u64>) -> Result<Self, ToolkitError> {
+        let modulus = Polynomial::new(modulus, base.clone());
+        let degree = modulus.degree();
+        if degree == 0 || modulus.coeffs()[degree] != 1 {
+            return Err(ToolkitError::InvalidExtensionModulus);
+        }
+        Ok(Self {
+            modulus: modulus.coeffs()[..=degree].to_vec(),
+            base,
+        })
+    }
+
+    /// Creates the quadratic extension GF(p^2) = GF(p)[x] / (x^2 - non_residue).
+    ///
+    /// `x^2 - beta` is irreducible iff `beta` has no square root in GF(p), which Euler's
+    /// criterion checks as `beta^((p - 1) / 2) = -1`.
+    ///
+    /// Returns [`ToolkitError::InvalidExtensionModulus`] if `non_residue` is a square.
+    pub fn quadratic(base: PrimeField, non_residue: u64) -> Result<Self, ToolkitError> {
+        let p = base.p;
+        if p == 2 || PrimeField::mod_pow(non_residue, (p - 1) / 2, p) != p - 1 {
+            return Err(ToolkitError::InvalidExtensionModulus);
+        }
+        let modulus = vec![base.neg(non_residue), 0, 1];
+        Self::new(base, modulus)
+    }
+
+    /// Returns the extension degree `k`.
+    pub fn degree(&self) -> usize {
+        self.modulus.len() - 1
+    }
+
+    /// Returns the number of elements `p^k`, or `None` if it does not fit in a `u128`.
+    pub fn order(&self) -> Option<u128> {
+        (self.base.p as u128).checked_pow(self.degree() as u32)
+    }
+
+    /// Samples a uniformly random element of the extension.
+    pub fn random<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u64> {
+        (0..self.degree()).map(|_| self.base.random(rng)).collect()
+    }
+
+    /// Embeds a base-field element into the extension.
+    pub fn from_base(&self, a: u64) -> Vec<u64> {
+        let mut element = vec![0; self.degree()];
+        element[0] = a % self.base.p;
+        element
+    }
+
+    /// Returns the additive identity.
+    pub fn zero(&self) -> Vec<u64> {
+        vec![0; self.degree()]
+    }
+
+    /// Returns the multiplicative identity.
+    pub fn one(&self) -> Vec<u64> {
+        self.from_base(1)
+    }
+
+    /// Returns `a + b`.
+    pub fn add(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        (0..self.degree())
+            .map(|i| self.base.add(coeff(a, i), coeff(b, i)))
+            .collect()
+    }
+
+    /// Returns `a - b`.
+    pub fn sub(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        (0..self.degree())
+            .map(|i| self.base.sub(coeff(a, i), coeff(b, i)))
+            .collect()
+    }
+
+    /// Returns `-a`.
+    pub fn neg(&self, a: &[u64]) -> Vec<u64> {
+        self.sub(&self.zero(), a)
+    }
+
+    /// Returns `a * b mod m(x)`.
+    pub fn mul(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let k = self.degree();
+        let mut product = vec![0; 2 * k - 1];
+        for i in 0..k {
+            for j in 0..k {
+                let term = self.base.mul(coeff(a, i), coeff(b, j));
+                product[i + j] = self.base.add(product[i + j], term);
+            }
+        }
+        let product = Polynomial::new(product, self.base.clone());
+        let modulus = Polynomial::new(self.modulus.clone(), self.base.clone());
+        let (_, remainder) = product
+            .div_rem(&modulus)
+            .expect("the modulus is monic, hence non-zero");
+        (0..k).map(|i| coeff(remainder.coeffs(), i)).collect()
+    }
+
+    /// The power map `a -> a^exp`, computed by square-and-multiply.
+    pub fn pow(&self, a: &[u64], mut exp: u128) -> Vec<u64> {
+        let mut result = self.one();
+        let mut base = self.mul(a, &self.one());
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = self.mul(&result, &base);
+            }
+            base = self.mul(&base, &base);
+            exp /= 2;
+        }
+        result
+    }
+
+    /// Returns `a^-1`, computed as `a^(p^k - 2)`.
+    ///
+    /// Returns [`ToolkitError::ZeroInverse`] if `a` is zero.
+    ///
+    /// # Panics
+    /// Panics if `p^k` does not fit in a `u128`.
+    pub fn inv(&self, a: &[u64]) -> Result<Vec<u64>, ToolkitError> {
+        if (0..self.degree()).all(|i| coeff(a, i) % self.base.p == 0) {
+            return Err(ToolkitError::ZeroInverse);
+        }
+        let order = self.order().expect("field order must fit in a u128");
+        Ok(self.pow(a, order - 2))
+    }
+
+    /// The Frobenius automorphism `phi(a) = a^p`.
+    pub fn frobenius(&self, a: &[u64]) -> Vec<u64> {
+        self.pow(a, self.base.p as u128)
+    }
+
+    /// The `i`-th iterate of the Frobenius map, `phi^i(a) = a^(p^i)`.
+    ///
+    /// Iterating avoids computing `p^i` directly, which can overflow for large `i`.
+    pub fn frobenius_power(&self, a: &[u64], i: usize) -> Vec<u64> {
+        (0..i).fold(self.mul(a, &self.one()), |acc, _| self.frobenius(&acc))
+    }
+}
+
+/// Returns coefficient `i` of an element, treating missing high coefficients as zero.
+fn coeff(a: &[u64], i: usize) -> u64 {
+    a.get(i).copied().unwrap_or(0)
+}
@@ -0,0 +1,126 @@
+//! Integer factorization for group-order computations.
+//!
+//! The multiplicative group GF(p)* is cyclic of order `p - 1`. The order of every element divides
+//! `p - 1`, and `g` generates the whole group iff `g^((p - 1) / q) != 1` for every prime `q`
+//! dividing `p - 1`. Finding generators and element orders therefore starts from the prime
+//! factorization of `p - 1`.
+//!
+//! Small factors are removed by trial division. Whatever remains is split by **Pollard's rho**:
+//! iterating `x -> x^2 + c mod n` eventually cycles modulo every prime factor `q` of `n`, after
+//! roughly `sqrt(q)` steps, and `gcd(x_i - x_2i, n)` exposes `q` once the tortoise and hare meet
+//! modulo `q`. Cofactors are recognized as prime with a deterministic Miller–Rabin test.
+
+use crate::field::PrimeField;
+
+/// Factors below this bound are found by trial division before Pollard's rho is used.
+const TRIAL_DIVISION_BOUND: u64 = 1 << 10;
+
+/// Miller–Rabin bases that correctly classify every `n < 2^64`.
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Returns the prime factorization of `n` as `(prime, exponent)` pairs in increasing order of
+/// prime. The factorization of `0` and `1` is empty.
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut primes = Vec::new();
+    let mut n = n;
+    let mut d = 2;
+    while d < TRIAL_DIVISION_BOUND && d <= n / d {
+        while n % d == 0 {
+            primes.push(d);
+            n /= d;
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        split(n, &mut primes);
+    }
+
+    primes.sort_unstable();
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for q in primes {
+        match factors.last_mut() {
+            Some((last, e)) if *last == q => *e += 1,
+            _ => factors.push((q, 1)),
+        }
+    }
+    factors
+}
+
+/// Returns a non-trivial factor of the composite number `n`.
+///
+/// The result is only meaningful when `n` is composite and odd; for a prime `n` there is no
+/// non-trivial factor and the search does not terminate.
+pub fn pollard_rho(n: u64) -> u64 {
+    if n % 2 == 0 {
+        return 2;
+    }
+    let step = |x: u64, c: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+    // A failed attempt (gcd = n) means both walks collided modulo every factor at once; retry
+    // with a different polynomial.
+    for c in 1.. {
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = step(x, c);
+            y = step(step(y, c), c);
+            d = gcd(x.abs_diff(y), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!("the retry loop only exits by returning a factor")
+}
+
+/// Checks whether `n` is prime with the Miller–Rabin test, using a base set that is
+/// deterministic for all 64-bit inputs. It runs in time polynomial in the bit length of `n`, so
+/// even 64-bit moduli are checked at once.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for q in MILLER_RABIN_BASES {
+        if n % q == 0 {
+            return n == q;
+        }
+    }
+
+    // Write n - 1 = d * 2^s with d odd.
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'bases: for a in MILLER_RABIN_BASES {
+        let mut x = PrimeField::mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = PrimeField::mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Recursively splits `n > 1` into prime factors, appending them to `primes`.
+fn split(n: u64, primes: &mut Vec<u64>) {
+    if is_prime(n) {
+        primes.push(n);
+        return;
+    }
+    let d = pollard_rho(n);
+    split(d, primes);
+    split(n / d, primes);
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
@@ -0,0 +1,189 @@
+//! Finite field arithmetic over GF(p).
+//!
+//! Elements are represented as plain `u64` values in the range `[0, p)`, and all operations are
+//! methods on [`PrimeField`], which carries the modulus. Intermediate results are computed in
+//! `u128`, so any prime below 2^64 (including Goldilocks) is supported.
+//!
+//! Ready-made fields used throughout the workshops live in [`presets`].
+//!
+//! With the `constant-time` feature enabled, addition, subtraction, multiplication,
+//! exponentiation, and inversion run through the branch-free implementations in
+//! [`constant_time`] instead.
+
+use rand::Rng;
+
+use crate::error::ToolkitError;
+use crate::factor::{self, factorize};
+
+pub mod constant_time;
+pub mod presets;
+
+/// A prime field GF(p), the set `{0, 1, ..., p - 1}` with arithmetic modulo `p`.
+///
+/// With the `serde` feature enabled the field serializes as `{"p": ...}`. Deserialization does
+/// not re-check primality, so only load fields from trusted sources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrimeField {
+    /// The prime modulus of the field.
+    pub p: u64,
+}
+
+impl PrimeField {
+    /// Creates the field GF(p).
+    ///
+    /// Returns [`ToolkitError::NonPrimeModulus`] if `p` is not prime.
+    pub fn new(p: u64) -> Result<Self, ToolkitError> {
+        if !Self::is_prime(p) {
+            return Err(ToolkitError::NonPrimeModulus(p));
+        }
+        Ok(Self { p })
+    }
+
+    /// Returns `a + b mod p`.
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        if cfg!(feature = "constant-time") {
+            let p = self.p;
+            return constant_time::add(constant_time::reduce(a, p), constant_time::reduce(b, p), p);
+        }
+        ((a as u128 + b as u128) % self.p as u128) as u64
+    }
+
+    /// Returns `a - b mod p`.
+    pub fn sub(&self, a: u64, b: u64) -> u64 {
+        if cfg!(feature = "constant-time") {
+            let p = self.p;
+            return constant_time::sub(constant_time::reduce(a, p), constant_time::reduce(b, p), p);
+        }
+        ((a as u128 + self.p as u128 - b as u128) % self.p as u128) as u64
+    }
+
+    /// Returns `a * b mod p`.
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        Self::mul_mod(a, b, self.p)
+    }
+
+    /// Returns the additive inverse `-a mod p`.
+    pub fn neg(&self, a: u64) -> u64 {
+        if cfg!(feature = "constant-time") {
+            return constant_time::sub(0, constant_time::reduce(a, self.p), self.p);
+        }
+        (self.p - a % self.p) % self.p
+    }
+
+    /// Returns the multiplicative inverse `a^-1 mod p`, computed via Fermat's little theorem as
+    /// `a^(p-2)`.
+    ///
+    /// Returns [`ToolkitError::ZeroInverse`] if `a` is zero.
+    ///
+    /// In `constant-time` mode only the zero check branches; zero is never a valid secret to
+    /// invert, so rejecting it reveals nothing about non-zero inputs.
+    pub fn inv(&self, a: u64) -> Result<u64, ToolkitError> {
+        if cfg!(feature = "constant-time") {
+            let a = constant_time::reduce(a, self.p);
+            if a == 0 {
+                return Err(ToolkitError::ZeroInverse);
+            }
+            return Ok(constant_time::pow(a, self.p - 2, self.p));
+        }
+        if a % self.p == 0 {
+            return Err(ToolkitError::ZeroInverse);
+        }
+        Ok(Self::mod_pow(a, self.p - 2, self.p))
+    }
+
+    /// Returns `a / b mod p`, i.e. `a * b^-1`.
+    ///
+    /// Returns [`ToolkitError::ZeroInverse`] if `b` is zero.
+    pub fn div(&self, a: u64, b: u64) -> Result<u64, ToolkitError> {
+        Ok(self.mul(a, self.inv(b)?))
+    }
+
+    /// Returns the multiplicative order of `a`: the smallest `k > 0` with `a^k = 1`.
+    ///
+    /// Starting from the group order `p - 1`, each prime factor `q` is divided out for as long as
+    /// `a^(order / q)` is still `1`.
+    ///
+    /// Returns [`ToolkitError::ZeroInverse`] if `a` is zero, which is not in the group.
+    pub fn element_order(&self, a: u64) -> Result<u64, ToolkitError> {
+        let a = a % self.p;
+        if a == 0 {
+            return Err(ToolkitError::ZeroInverse);
+        }
+        let mut order = self.p - 1;
+        for (q, _) in factorize(self.p - 1) {
+            while order % q == 0 && Self::mod_pow(a, order / q, self.p) == 1 {
+                order /= q;
+            }
+        }
+        Ok(order)
+    }
+
+    /// Checks whether `a` generates the whole multiplicative group GF(p)*.
+    pub fn is_generator(&self, a: u64) -> bool {
+        self.element_order(a) == Ok(self.p - 1)
+    }
+
+    /// Returns the smallest primitive root modulo `p`, i.e. the smallest generator of GF(p)*.
+    pub fn primitive_root(&self) -> u64 {
+        let factors = factorize(self.p - 1);
+        (1..self.p)
+            .find(|&g| {
+                factors
+                    .iter()
+                    .all(|&(q, _)| Self::mod_pow(g, (self.p - 1) / q, self.p) != 1)
+            })
+            .expect("GF(p)* is cyclic, so a primitive root exists")
+    }
+
+    /// Returns a generator of the unique multiplicative subgroup of order `n`, obtained as
+    /// `g^((p - 1) / n)` for the primitive root `g`.
+    ///
+    /// Returns [`ToolkitError::InvalidSubgroupOrder`] if `n` does not divide `p - 1`.
+    pub fn subgroup_generator(&self, n: u64) -> Result<u64, ToolkitError> {
+        if n == 0 || (self.p - 1) % n != 0 {
+            return Err(ToolkitError::InvalidSubgroupOrder(n));
+        }
+        Ok(Self::mod_pow(
+            self.primitive_root(),
+            (self.p - 1) / n,
+            self.p,
+        ))
+    }
+
+    /// Samples a uniformly random field element.
+    pub fn random<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        rng.gen_range(0..self.p)
+    }
+
+    /// Checks whether `n` is prime, with the deterministic Miller–Rabin test of
+    /// [`factor::is_prime`].
+    pub fn is_prime(n: u64) -> bool {
+        factor::is_prime(n)
+    }
+
+    /// Computes `base^exp mod modulus` by square-and-multiply.
+    pub fn mod_pow(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+        if cfg!(feature = "constant-time") {
+            return constant_time::pow(constant_time::reduce(base, modulus), exp, modulus);
+        }
+        let mut result = 1;
+        base %= modulus;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = Self::mul_mod(result, base, modulus);
+            }
+            base = Self::mul_mod(base, base, modulus);
+            exp /= 2;
+        }
+        result
+    }
+
+    /// Computes `a * b mod modulus` without overflow by widening to `u128`.
+    pub(crate) fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+        if cfg!(feature = "constant-time") {
+            return constant_time::mul(constant_time::reduce(a, modulus), b, modulus);
+        }
+        ((a as u128 * b as u128) % modulus as u128) as u64
+    }
+}
@@ -0,0 +1,66 @@
+//! Branch-free modular arithmetic for the `constant-time` feature.
+//!
+//! The default [`PrimeField`](super::PrimeField) operations leak timing information about their
+//! operands: `%` compiles to a hardware division whose latency depends on its inputs, and
+//! square-and-multiply only multiplies on the `1` bits of the exponent, so the running time of an
+//! inversion or exponentiation reveals the Hamming weight of a secret exponent.
+//!
+//! The functions here avoid both. Every reduction is a subtraction of `p` masked in or out by the
+//! borrow bit, choices between two values use [`select`] instead of `if`, multiplication is a
+//! fixed 64-step double-and-add, and exponentiation squares *and* multiplies on every bit. The
+//! sequence of instructions executed depends only on the bit width, never on the values.
+//!
+//! This is a teaching implementation: it is far slower than the default path, and a compiler is
+//! not obliged to preserve branch-freedom, so production code should rely on audited libraries.
+
+/// Returns `a` if `choice == 1` and `b` if `choice == 0`, without branching.
+pub fn select(choice: u64, a: u64, b: u64) -> u64 {
+    let mask = choice.wrapping_neg();
+    b ^ (mask & (a ^ b))
+}
+
+/// Reduces `x` in `[0, 2p)` to `[0, p)` by a masked subtraction of `p`.
+fn conditional_sub(x: u128, p: u64) -> u64 {
+    let t = x.wrapping_sub(p as u128);
+    // The top bit of `t` is set exactly when the subtraction borrowed, i.e. `x < p`.
+    let borrowed = (t >> 127) as u64;
+    select(borrowed, x as u64, t as u64)
+}
+
+/// Reduces an arbitrary `a` modulo `p`, one bit at a time from the most significant end.
+pub fn reduce(a: u64, p: u64) -> u64 {
+    (0..u64::BITS).rev().fold(0, |acc, i| {
+        conditional_sub(((acc as u128) << 1) | ((a >> i) & 1) as u128, p)
+    })
+}
+
+/// Returns `a + b mod p` for reduced `a, b < p`.
+pub fn add(a: u64, b: u64, p: u64) -> u64 {
+    conditional_sub(a as u128 + b as u128, p)
+}
+
+/// Returns `a - b mod p` for reduced `a, b < p`.
+pub fn sub(a: u64, b: u64, p: u64) -> u64 {
+    let t = (a as u128).wrapping_sub(b as u128);
+    let borrowed = (t >> 127) as u64;
+    (t as u64).wrapping_add(p & borrowed.wrapping_neg())
+}
+
+/// Returns `a * b mod p` for reduced `a < p` and any `b`, by double-and-add over all 64 bits of
+/// `b`.
+pub fn mul(a: u64, b: u64, p: u64) -> u64 {
+    (0..u64::BITS).rev().fold(0, |acc, i| {
+        let acc = add(acc, acc, p);
+        add(acc, select((b >> i) & 1, a, 0), p)
+    })
+}
+
+/// Returns `base^exp mod p` for reduced `base < p`, squaring and multiplying on every bit of
+/// `exp` and keeping the product only where the bit is set.
+pub fn pow(base: u64, exp: u64, p: u64) -> u64 {
+    (0..u64::BITS).rev().fold(reduce(1, p), |acc, i| {
+        let squared = mul(acc, acc, p);
+        let multiplied = mul(squared, base, p);
+        select((exp >> i) & 1, multiplied, squared)
+    })
+}
@@ -0,0 +1,62 @@
+//! A catalogue of prime fields used by modern proof systems.
+//!
+//! Each preset records the field's **two-adicity**: the largest `s` such that `2^s` divides
+//! `p - 1`. A field contains a multiplicative subgroup of order `2^k` (and therefore supports
+//! radix-2 FFTs over domains of that size) exactly when `k <= s`.
+//!
+//! The BN254 and BLS12-381 scalar fields have ~255-bit moduli, which do not fit in the `u64`
+//! representation used by [`PrimeField`]; their moduli and two-adicity are recorded for reference.
+
+use crate::field::PrimeField;
+
+/// A named prime field together with its two-adicity.
+#[derive(Debug, Clone)]
+pub struct FieldPreset {
+    /// Human-readable name of the field.
+    pub name: &'static str,
+    /// The field itself.
+    pub field: PrimeField,
+    /// The largest `s` such that `2^s` divides `p - 1`.
+    pub two_adicity: u32,
+}
+
+/// Babybear, `p = 15 * 2^27 + 1`, used by RISC Zero and SP1.
+pub const BABYBEAR: FieldPreset = FieldPreset {
+    name: "Babybear",
+    field: PrimeField { p: 2_013_265_921 },
+    two_adicity: 27,
+};
+
+/// Goldilocks, `p = 2^64 - 2^32 + 1`, used by Plonky2.
+pub const GOLDILOCKS: FieldPreset = FieldPreset {
+    name: "Goldilocks",
+    field: PrimeField {
+        p: 18_446_744_069_414_584_321,
+    },
+    two_adicity: 32,
+};
+
+/// Mersenne31, `p = 2^31 - 1`, used by Circle STARKs (Stwo).
+///
+/// Its multiplicative group has two-adicity 1, which is why it needs the circle group instead of
+/// a multiplicative subgroup for FFTs.
+pub const MERSENNE31: FieldPreset = FieldPreset {
+    name: "Mersenne31",
+    field: PrimeField { p: (1 << 31) - 1 },
+    two_adicity: 1,
+};
+
+/// All presets representable as a [`PrimeField`].
+pub const ALL: [FieldPreset; 3] = [BABYBEAR, GOLDILOCKS, MERSENNE31];
+
+/// The scalar field modulus of BN254 (the curve behind Ethereum's pairing precompiles).
+pub const BN254_SCALAR_MODULUS: &str =
+    "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+/// Two-adicity of the BN254 scalar field.
+pub const BN254_SCALAR_TWO_ADICITY: u32 = 28;
+
+/// The scalar field modulus of BLS12-381.
+pub const BLS12_381_SCALAR_MODULUS: &str =
+    "52435875175126190479447740508185965837690552500527637822603658699938581184513";
+/// Two-adicity of the BLS12-381 scalar field.
+pub const BLS12_381_SCALAR_TWO_ADICITY: u32 = 32;
@@ -0,0 +1,61 @@
+//! Hashing arbitrary bytes to field and group elements.
+//!
+//! Fiat–Shamir challenges and "nothing-up-my-sleeve" commitment generators must be derived
+//! deterministically from public data while looking uniformly random. Both functions here hash
+//! the input with Keccak256 and use **rejection sampling**: a candidate that falls outside the
+//! target set is discarded and the input is re-hashed with an incremented counter, so the output
+//! is uniform instead of biased towards small values (as a plain `digest mod p` would be).
+
+use sha3::{Digest, Keccak256};
+
+use crate::field::PrimeField;
+use crate::pedersen::PedersenParams;
+
+/// Domain-separation tag prepended to every hash, so these outputs never collide with other
+/// Keccak256 uses of the same bytes.
+const HASH_TO_FIELD_DST: &[u8] = b"mathematical_toolkit/hash_to_field";
+
+/// Hashes `bytes` to a uniformly distributed element of GF(p).
+///
+/// Each attempt takes the first 8 bytes of `Keccak256(DST || counter || bytes)`, masks them to the
+/// bit length of `p`, and accepts the result if it is below `p`. Since `p > 2^(bits - 1)`, every
+/// attempt succeeds with probability greater than 1/2.
+pub fn hash_to_field(field: &PrimeField, bytes: &[u8]) -> u64 {
+    let bits = 64 - field.p.leading_zeros();
+    let mask = if bits == 64 {
+        u64::MAX
+    } else {
+        (1 << bits) - 1
+    };
+
+    (0u32..)
+        .find_map(|counter| {
+            let digest = Keccak256::new()
+                .chain_update(HASH_TO_FIELD_DST)
+                .chain_update(counter.to_be_bytes())
+                .chain_update(bytes)
+                .finalize();
+            let candidate = u64::from_be_bytes(digest[..8].try_into().unwrap()) & mask;
+            (candidate < field.p).then_some(candidate)
+        })
+        .expect("rejection sampling terminates with overwhelming probability")
+}
+
+/// Hashes `bytes` to a generator of the prime-order subgroup used by [`PedersenParams`].
+///
+/// This plays the role of `hash_to_curve` for the multiplicative group: squaring a field element
+/// maps it into the subgroup of quadratic residues, and since that subgroup has prime order every
+/// element other than `1` generates it. Candidates that square to `1` (i.e. `x = ±1`) or to `0`
+/// are rejected by re-hashing with a counter suffix. Nobody knows the discrete logarithm of the
+/// result with respect to any other generator, which is exactly what Pedersen binding needs.
+pub fn hash_to_subgroup(params: &PedersenParams, bytes: &[u8]) -> u64 {
+    let field = &params.field;
+    (0u32..)
+        .find_map(|counter| {
+            let input = [bytes, &counter.to_be_bytes()].concat();
+            let x = hash_to_field(field, &input);
+            let candidate = field.mul(x, x);
+            (candidate > 1).then_some(candidate)
+        })
+        .expect("rejection sampling terminates with overwhelming probability")
+}
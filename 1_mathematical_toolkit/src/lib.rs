@@ -0,0 +1,56 @@
+//! # Mathematical Toolkit for Verifiable Computation
+//!
+//! A small, dependency-light library implementing the algebraic building blocks introduced in
+//! Chapter 1 of the workshop series:
+//!
+//! - [`field`]: arithmetic in a prime field GF(p).
+//! - [`polynomial`]: univariate polynomials with coefficients in GF(p).
+//! - [`multivariate`]: sparse multivariate polynomials with partial evaluation.
+//! - [`extension`]: extension fields GF(p^k) and the Frobenius automorphism.
+//! - [`factor`]: integer factorization used for group orders and primitive roots.
+//! - [`matrix`]: dense matrices over GF(p) with Gaussian elimination.
+//! - [`pedersen`]: Pedersen commitments in a prime-order subgroup of GF(p)*.
+//! - [`hash`]: hashing bytes to field and subgroup elements.
+//! - [`zerofier`]: vanishing polynomials for point sets and multiplicative subgroups.
+//!
+//! Fallible operations (non-prime moduli, inverting zero, dividing by the zero polynomial) return
+//! a [`ToolkitError`] instead of panicking.
+//!
+//! Enabling the `serde` feature derives `Serialize`/`Deserialize` for [`PrimeField`],
+//! [`Polynomial`], and [`extension::ExtensionField`], so exercises can save and load them as JSON.
+//!
+//! Enabling the `constant-time` feature switches [`PrimeField`] arithmetic to branch-free
+//! implementations (see [`field::constant_time`]) for discussing side channels.
+//!
+//! The implementation favors readability over performance and is intended to be reused by the
+//! other workshop crates and by exercises.
+//!
+//! ```
+//! use mathematical_toolkit::{Polynomial, PrimeField};
+//!
+//! let field = PrimeField::new(7).unwrap();
+//! assert_eq!(field.add(5, 3), 1);
+//!
+//! // P(x) = x^2 + 1
+//! let poly = Polynomial::new(vec![1, 0, 1], field);
+//! assert_eq!(poly.evaluate(2), 5);
+//! ```
+
+pub mod error;
+pub mod extension;
+pub mod factor;
+pub mod field;
+pub mod hash;
+pub mod matrix;
+pub mod multivariate;
+pub mod pedersen;
+pub mod polynomial;
+pub mod strategies;
+#[cfg(test)]
+mod tests;
+pub mod zerofier;
+
+pub use error::ToolkitError;
+pub use extension::ExtensionField;
+pub use field::PrimeField;
+pub use polynomial::Polynomial;
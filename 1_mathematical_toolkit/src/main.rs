@@ -1,106 +1,89 @@
-pub mod tests;
+//! Demo binary for the mathematical toolkit: basic field arithmetic, polynomial evaluation, and
+//! a micro-benchmark comparing Horner's scheme with term-by-term evaluation.
 
-// Finite Field Arithmetic over GF(p)
-#[derive(Debug, Clone)]
-pub struct PrimeField {
-    pub p: u64,
-}
-
-impl PrimeField {
-    pub fn new(p: u64) -> Self {
-        assert!(Self::is_prime(p), "p must be prime");
-        Self { p }
-    }
+use std::time::Instant;
 
-    pub fn add(&self, a: u64, b: u64) -> u64 {
-        (a + b) % self.p
-    }
-
-    pub fn sub(&self, a: u64, b: u64) -> u64 {
-        (a + self.p - b) % self.p
-    }
+use mathematical_toolkit::field::presets::MERSENNE31;
+use mathematical_toolkit::pedersen::{PedersenParams, SAFE_PRIME};
+use mathematical_toolkit::{Polynomial, PrimeField, ToolkitError};
 
-    pub fn mul(&self, a: u64, b: u64) -> u64 {
-        (a * b) % self.p
-    }
+fn main() -> Result<(), ToolkitError> {
+    let f = PrimeField::new(7)?;
+    println!("5 + 3 mod 7 = {}", f.add(5, 3)); // Output: 1
+    println!("3 / 4 mod 7 = {}", f.div(3, 4)?); // Output: 6
 
-    pub fn neg(&self, a: u64) -> u64 {
-        (self.p - a % self.p) % self.p
-    }
+    let poly = Polynomial::new(vec![1, 0, 1], f.clone()); // x^2 + 1
+    println!("P(0) = {}", poly.evaluate(0)); // 1
+    println!("P(2) = {}", poly.evaluate(2)); // 2^2 + 1 = 5
 
-    pub fn inv(&self, a: u64) -> u64 {
-        assert!(a != 0, "No inverse for 0");
-        Self::mod_pow(a, self.p - 2, self.p)
+    // Polynomials can also be read from the command line, e.g. `cargo run -- "3x^2 + 2x + 1"`.
+    if let Some(input) = std::env::args().nth(1) {
+        let parsed = Polynomial::from_str(&input, MERSENNE31.field)?;
+        println!("\nParsed Q(x) = {} over {}", parsed, MERSENNE31.name);
+        println!("Q(2) = {}", parsed.evaluate(2));
     }
 
-    pub fn div(&self, a: u64, b: u64) -> u64 {
-        self.mul(a, self.inv(b))
-    }
+    // Micro-benchmark: Horner's scheme vs. term-by-term evaluation.
+    let field = MERSENNE31.field;
+    let degree = 1 << 12;
+    let coeffs = (0..=degree as u64)
+        .map(|i| i.wrapping_mul(0x9e37_79b9))
+        .collect();
+    let big_poly = Polynomial::new(coeffs, field);
+    let points: Vec<u64> = (1..=64).collect();
 
-    pub fn is_prime(n: u64) -> bool {
-        if n <= 1 {
-            return false;
-        }
-        if n <= 3 {
-            return true;
-        }
-        if n % 2 == 0 || n % 3 == 0 {
-            return false;
-        }
-        let mut i = 5;
-        while i * i <= n {
-            if n % i == 0 || n % (i + 2) == 0 {
-                return false;
-            }
-            i += 6;
-        }
-        true
-    }
+    let start = Instant::now();
+    let horner = big_poly.evaluate_slice(&points);
+    let horner_time = start.elapsed();
 
-    pub fn mod_pow(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
-        let mut result = 1;
-        base %= modulus;
-        while exp > 0 {
-            if exp % 2 == 1 {
-                result = (result * base) % modulus;
-            }
-            base = (base * base) % modulus;
-            exp /= 2;
-        }
-        result
-    }
-}
+    let start = Instant::now();
+    let naive: Vec<u64> = points.iter().map(|&x| big_poly.evaluate_naive(x)).collect();
+    let naive_time = start.elapsed();
 
-// Polynomial over GF(p)
-#[derive(Debug, Clone)]
-pub struct Polynomial {
-    coeffs: Vec<u64>,
-    field: PrimeField,
-}
+    assert_eq!(horner, naive, "Horner and naive evaluation disagree!");
+    println!(
+        "\nEvaluating a degree {} polynomial at {} points:",
+        degree,
+        points.len()
+    );
+    println!("  Horner: {:?}", horner_time);
+    println!("  Naive:  {:?}", naive_time);
 
-impl Polynomial {
-    pub fn new(coeffs: Vec<u64>, field: PrimeField) -> Self {
-        let coeffs = coeffs.into_iter().map(|c| c % field.p).collect();
-        Self { coeffs, field }
-    }
+    // Pedersen commitments: hiding, binding, and additively homomorphic.
+    let pedersen = PedersenParams::new(PrimeField::new(SAFE_PRIME)?, 4, 9)?;
+    let c1 = pedersen.commit(20, 1234);
+    let c2 = pedersen.commit(22, 5678);
+    let sum = pedersen.add(&c1, &c2);
+    println!("\nC(20, 1234) = {}", c1.0);
+    println!("C(22, 5678) = {}", c2.0);
+    println!(
+        "C1 * C2 opens to 42 with blinding 6912: {}",
+        pedersen.verify(&sum, 42, 6912)
+    );
 
-    pub fn evaluate(&self, x: u64) -> u64 {
-        let mut result = 0;
-        for (power, &coeff) in self.coeffs.iter().enumerate() {
-            let x_pow = PrimeField::mod_pow(x, power as u64, self.field.p);
-            let term = self.field.mul(coeff, x_pow);
-            result = self.field.add(result, term);
-        }
-        result
-    }
-}
+    // Fresh randomness instead of hand-picked constants.
+    let mut rng = rand::thread_rng();
+    let value = pedersen.field.random(&mut rng) % pedersen.order;
+    let blinding = pedersen.random_blinding(&mut rng);
+    let commitment = pedersen.commit(value, blinding);
+    println!(
+        "C({}, {}) = {} opens correctly: {}",
+        value,
+        blinding,
+        commitment.0,
+        pedersen.verify(&commitment, value, blinding)
+    );
+    println!(
+        "Random degree-3 polynomial: {}",
+        Polynomial::random(3, f.clone(), &mut rng)
+    );
 
-fn main() {
-    let f = PrimeField::new(7);
-    println!("5 + 3 mod 7 = {}", f.add(5, 3)); // Output: 1
-    println!("3 / 4 mod 7 = {}", f.div(3, 4)); // Output: 6
+    // Failure modes are reported as errors rather than panics.
+    println!(
+        "\nPrimeField::new(8) -> {}",
+        PrimeField::new(8).unwrap_err()
+    );
+    println!("0^-1 mod 7 -> {}", f.inv(0).unwrap_err());
 
-    let poly = Polynomial::new(vec![1, 0, 1], f.clone()); // x^2 + 1
-    println!("P(0) = {}", poly.evaluate(0)); // 1
-    println!("P(2) = {}", poly.evaluate(2)); // 2^2 + 1 = 5
+    Ok(())
 }
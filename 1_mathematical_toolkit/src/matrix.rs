@@ -0,0 +1,234 @@
+//! Dense matrices over GF(p) and Gaussian elimination.
+//!
+//! Linear algebra shows up throughout proof systems: R1CS constraints are matrix–vector products
+//! `(A z) * (B z) = C z`, and polynomial interpolation is the solution of a Vandermonde system.
+
+use crate::error::ToolkitError;
+use crate::field::PrimeField;
+
+/// A `rows x cols` matrix with entries in a [`PrimeField`], stored in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    entries: Vec<u64>,
+    field: PrimeField,
+}
+
+impl Matrix {
+    /// Creates a matrix from its rows, reducing every entry modulo `p`.
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if the rows have different lengths.
+    pub fn new(rows: Vec<Vec<u64>>, field: PrimeField) -> Result<Self, ToolkitError> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            return Err(ToolkitError::DimensionMismatch);
+        }
+        Ok(Self {
+            rows: rows.len(),
+            cols,
+            entries: rows.into_iter().flatten().map(|x| x % field.p).collect(),
+            field,
+        })
+    }
+
+    /// Creates the `rows x cols` zero matrix.
+    pub fn zeros(rows: usize, cols: usize, field: PrimeField) -> Self {
+        Self {
+            rows,
+            cols,
+            entries: vec![0; rows * cols],
+            field,
+        }
+    }
+
+    /// Creates the `n x n` identity matrix.
+    pub fn identity(n: usize, field: PrimeField) -> Self {
+        let mut matrix = Self::zeros(n, n, field);
+        for i in 0..n {
+            matrix.set(i, i, 1);
+        }
+        matrix
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the entry at row `i`, column `j`.
+    pub fn get(&self, i: usize, j: usize) -> u64 {
+        self.entries[i * self.cols + j]
+    }
+
+    /// Sets the entry at row `i`, column `j` to `value mod p`.
+    pub fn set(&mut self, i: usize, j: usize, value: u64) {
+        self.entries[i * self.cols + j] = value % self.field.p;
+    }
+
+    /// Returns row `i` as a slice.
+    pub fn row(&self, i: usize) -> &[u64] {
+        &self.entries[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns the matrix product `self * other`.
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if `self.cols() != other.rows()`.
+    pub fn mul(&self, other: &Matrix) -> Result<Matrix, ToolkitError> {
+        if self.cols != other.rows {
+            return Err(ToolkitError::DimensionMismatch);
+        }
+        let f = &self.field;
+        let mut product = Self::zeros(self.rows, other.cols, f.clone());
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                let dot = (0..self.cols).fold(0, |acc, k| {
+                    f.add(acc, f.mul(self.get(i, k), other.get(k, j)))
+                });
+                product.set(i, j, dot);
+            }
+        }
+        Ok(product)
+    }
+
+    /// Returns the matrix–vector product `self * v`.
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if `v.len() != self.cols()`.
+    pub fn mul_vec(&self, v: &[u64]) -> Result<Vec<u64>, ToolkitError> {
+        if v.len() != self.cols {
+            return Err(ToolkitError::DimensionMismatch);
+        }
+        let f = &self.field;
+        Ok((0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(v)
+                    .fold(0, |acc, (&a, &x)| f.add(acc, f.mul(a, x)))
+            })
+            .collect())
+    }
+
+    /// Computes the reduced row echelon form by Gauss–Jordan elimination, returning it together
+    /// with the indices of the pivot columns.
+    ///
+    /// Every pivot is scaled to `1`, and all other entries in a pivot column are `0`. Over a
+    /// field any non-zero entry can serve as a pivot, since it is always invertible.
+    pub fn row_reduce(&self) -> (Matrix, Vec<usize>) {
+        let f = &self.field;
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+
+        for col in 0..m.cols {
+            let pivot_row = pivots.len();
+            if pivot_row == m.rows {
+                break;
+            }
+            // Find a row at or below `pivot_row` with a non-zero entry in this column.
+            let Some(found) = (pivot_row..m.rows).find(|&r| m.get(r, col) != 0) else {
+                continue;
+            };
+            m.swap_rows(pivot_row, found);
+
+            // Scale the pivot row so the pivot becomes 1.
+            let inv = f.inv(m.get(pivot_row, col)).expect("pivot is non-zero");
+            for j in 0..m.cols {
+                m.set(pivot_row, j, f.mul(m.get(pivot_row, j), inv));
+            }
+
+            // Eliminate this column from every other row.
+            for r in (0..m.rows).filter(|&r| r != pivot_row) {
+                let factor = m.get(r, col);
+                if factor == 0 {
+                    continue;
+                }
+                for j in 0..m.cols {
+                    let value = f.sub(m.get(r, j), f.mul(factor, m.get(pivot_row, j)));
+                    m.set(r, j, value);
+                }
+            }
+            pivots.push(col);
+        }
+
+        (m, pivots)
+    }
+
+    /// Returns the rank: the number of pivots in the row echelon form.
+    pub fn rank(&self) -> usize {
+        self.row_reduce().1.len()
+    }
+
+    /// Returns the inverse matrix, computed by row-reducing `[self | I]` to `[I | self^-1]`.
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if the matrix is not square, and
+    /// [`ToolkitError::SingularMatrix`] if it is not invertible.
+    pub fn inverse(&self) -> Result<Matrix, ToolkitError> {
+        if self.rows != self.cols {
+            return Err(ToolkitError::DimensionMismatch);
+        }
+        let n = self.rows;
+        let identity = Self::identity(n, self.field.clone());
+        let (reduced, pivots) = self.augment(&identity).row_reduce();
+        // The empty matrix is its own inverse; otherwise the last pivot must be in the last column.
+        if pivots.len() < n || pivots.last() != n.checked_sub(1).as_ref() {
+            return Err(ToolkitError::SingularMatrix);
+        }
+
+        let mut inverse = Self::zeros(n, n, self.field.clone());
+        for i in 0..n {
+            for j in 0..n {
+                inverse.set(i, j, reduced.get(i, n + j));
+            }
+        }
+        Ok(inverse)
+    }
+
+    /// Solves `self * x = b`, returning one solution (free variables are set to `0`).
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if `b.len() != self.rows()`, and
+    /// [`ToolkitError::InconsistentSystem`] if no solution exists.
+    pub fn solve(&self, b: &[u64]) -> Result<Vec<u64>, ToolkitError> {
+        if b.len() != self.rows {
+            return Err(ToolkitError::DimensionMismatch);
+        }
+        let column = Self::new(b.iter().map(|&x| vec![x]).collect(), self.field.clone())?;
+        let (reduced, pivots) = self.augment(&column).row_reduce();
+
+        // A pivot in the augmented column means a row reads `0 = 1`.
+        if pivots.last() == Some(&self.cols) {
+            return Err(ToolkitError::InconsistentSystem);
+        }
+        let mut x = vec![0; self.cols];
+        for (row, &col) in pivots.iter().enumerate() {
+            x[col] = reduced.get(row, self.cols);
+        }
+        Ok(x)
+    }
+
+    /// Returns the horizontal concatenation `[self | other]`.
+    fn augment(&self, other: &Matrix) -> Matrix {
+        let mut m = Self::zeros(self.rows, self.cols + other.cols, self.field.clone());
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                m.set(i, j, self.get(i, j));
+            }
+            for j in 0..other.cols {
+                m.set(i, self.cols + j, other.get(i, j));
+            }
+        }
+        m
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for j in 0..self.cols {
+                self.entries.swap(a * self.cols + j, b * self.cols + j);
+            }
+        }
+    }
+}
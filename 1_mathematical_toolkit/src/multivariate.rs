@@ -0,0 +1,181 @@
+//! Sparse multivariate polynomials over GF(p).
+//!
+//! A polynomial in the variables `x_0, ..., x_{n-1}` is a sum of terms `c * x_0^e_0 * ... *
+//! x_{n-1}^e_{n-1}`, stored as a map from exponent vectors to non-zero coefficients. Only the
+//! terms that are present cost memory, which matters for the multilinear polynomials used by
+//! sumcheck: a dense representation of a polynomial in `n` variables needs `2^n` entries even
+//! when only a handful are non-zero.
+//!
+//! **Partial evaluation** fixes the leading variables to field elements and returns a polynomial
+//! in the remaining ones. Sumcheck proceeds exactly this way, binding one variable per round
+//! until a univariate polynomial is left.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+use crate::error::ToolkitError;
+use crate::field::PrimeField;
+use crate::polynomial::Polynomial;
+
+/// A multivariate polynomial in `num_vars` variables, as a sparse list of terms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultivariatePolynomial {
+    num_vars: usize,
+    /// Exponent vector -> coefficient. Coefficients are reduced and never zero.
+    terms: BTreeMap<Vec<u32>, u64>,
+    field: PrimeField,
+}
+
+impl MultivariatePolynomial {
+    /// Creates a polynomial from `(coefficient, exponents)` pairs, where `exponents[i]` is the
+    /// power of `x_i`. Like terms are combined and zero terms dropped.
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if some exponent vector does not have length
+    /// `num_vars`.
+    pub fn new(
+        num_vars: usize,
+        terms: Vec<(u64, Vec<u32>)>,
+        field: PrimeField,
+    ) -> Result<Self, ToolkitError> {
+        let mut poly = Self {
+            num_vars,
+            terms: BTreeMap::new(),
+            field,
+        };
+        for (coeff, exponents) in terms {
+            if exponents.len() != num_vars {
+                return Err(ToolkitError::DimensionMismatch);
+            }
+            poly.add_term(coeff, exponents);
+        }
+        Ok(poly)
+    }
+
+    /// Returns the number of variables.
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the field the coefficients live in.
+    pub fn field(&self) -> &PrimeField {
+        &self.field
+    }
+
+    /// Returns the non-zero terms as `(coefficient, exponents)` pairs.
+    pub fn terms(&self) -> impl Iterator<Item = (u64, &[u32])> {
+        self.terms
+            .iter()
+            .map(|(exponents, &coeff)| (coeff, exponents.as_slice()))
+    }
+
+    /// Returns `true` if this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns the total degree: the largest sum of exponents over all terms (0 for the zero
+    /// polynomial).
+    pub fn total_degree(&self) -> u32 {
+        self.terms
+            .keys()
+            .map(|exponents| exponents.iter().sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the degree in the single variable `x_var`. A polynomial is multilinear when this
+    /// is at most 1 for every variable.
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if `var >= num_vars`.
+    pub fn degree_in(&self, var: usize) -> Result<u32, ToolkitError> {
+        if var >= self.num_vars {
+            return Err(ToolkitError::DimensionMismatch);
+        }
+        Ok(self
+            .terms
+            .keys()
+            .map(|exponents| exponents[var])
+            .max()
+            .unwrap_or(0))
+    }
+
+    /// Evaluates the polynomial at `point`, which assigns a value to every variable.
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if `point.len() != num_vars`.
+    pub fn evaluate(&self, point: &[u64]) -> Result<u64, ToolkitError> {
+        if point.len() != self.num_vars {
+            return Err(ToolkitError::DimensionMismatch);
+        }
+        let f = &self.field;
+        Ok(self.terms.iter().fold(0, |acc, (exponents, &coeff)| {
+            f.add(acc, f.mul(coeff, self.monomial(exponents, point)))
+        }))
+    }
+
+    /// Fixes the first `values.len()` variables to `values`, returning a polynomial in the
+    /// remaining `num_vars - values.len()` variables.
+    ///
+    /// Fixing every variable yields a constant polynomial in zero variables whose only term is
+    /// [`MultivariatePolynomial::evaluate`] at that point.
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if more values than variables are given.
+    pub fn partial_evaluate(&self, values: &[u64]) -> Result<Self, ToolkitError> {
+        let k = values.len();
+        if k > self.num_vars {
+            return Err(ToolkitError::DimensionMismatch);
+        }
+        let mut result = Self {
+            num_vars: self.num_vars - k,
+            terms: BTreeMap::new(),
+            field: self.field.clone(),
+        };
+        for (exponents, &coeff) in &self.terms {
+            let (fixed, free) = exponents.split_at(k);
+            let scale = self.monomial(fixed, values);
+            result.add_term(self.field.mul(coeff, scale), free.to_vec());
+        }
+        Ok(result)
+    }
+
+    /// Converts a polynomial in a single variable into a [`Polynomial`].
+    ///
+    /// Returns [`ToolkitError::DimensionMismatch`] if `num_vars != 1`.
+    pub fn to_univariate(&self) -> Result<Polynomial, ToolkitError> {
+        if self.num_vars != 1 {
+            return Err(ToolkitError::DimensionMismatch);
+        }
+        let mut coeffs = vec![0; self.degree_in(0)? as usize + 1];
+        for (exponents, &coeff) in &self.terms {
+            coeffs[exponents[0] as usize] = coeff;
+        }
+        Ok(Polynomial::new(coeffs, self.field.clone()))
+    }
+
+    /// Adds `coeff * x^exponents` to the polynomial, removing the term if it cancels.
+    fn add_term(&mut self, coeff: u64, exponents: Vec<u32>) {
+        let coeff = coeff % self.field.p;
+        match self.terms.entry(exponents) {
+            Entry::Vacant(entry) => {
+                if coeff != 0 {
+                    entry.insert(coeff);
+                }
+            }
+            Entry::Occupied(mut entry) => {
+                let sum = self.field.add(*entry.get(), coeff);
+                if sum == 0 {
+                    entry.remove();
+                } else {
+                    *entry.get_mut() = sum;
+                }
+            }
+        }
+    }
+
+    /// Returns `prod_i values[i]^exponents[i]`.
+    fn monomial(&self, exponents: &[u32], values: &[u64]) -> u64 {
+        exponents.iter().zip(values).fold(1, |acc, (&e, &x)| {
+            self.field
+                .mul(acc, PrimeField::mod_pow(x, e as u64, self.field.p))
+        })
+    }
+}
@@ -0,0 +1,311 @@
+//! Univariate polynomials over GF(p).
+
+use std::fmt;
+
+use rand::Rng;
+
+use crate::error::ToolkitError;
+use crate::field::PrimeField;
+
+/// A polynomial `a_0 + a_1 x + ... + a_n x^n` with coefficients in a [`PrimeField`].
+///
+/// Coefficients are stored in ascending order of power.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polynomial {
+    coeffs: Vec<u64>,
+    field: PrimeField,
+}
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients (constant term first), reducing each one
+    /// modulo `p`.
+    pub fn new(coeffs: Vec<u64>, field: PrimeField) -> Self {
+        let coeffs = coeffs.into_iter().map(|c| c % field.p).collect();
+        Self { coeffs, field }
+    }
+
+    /// Samples a random polynomial of exactly the given degree: the lower coefficients are uniform
+    /// and the leading coefficient is uniform among the non-zero elements.
+    pub fn random<R: Rng + ?Sized>(degree: usize, field: PrimeField, rng: &mut R) -> Self {
+        let mut coeffs: Vec<u64> = (0..degree).map(|_| field.random(rng)).collect();
+        coeffs.push(rng.gen_range(1..field.p));
+        Self { coeffs, field }
+    }
+
+    /// Returns the coefficients in ascending order of power.
+    pub fn coeffs(&self) -> &[u64] {
+        &self.coeffs
+    }
+
+    /// Returns the field the coefficients live in.
+    pub fn field(&self) -> &PrimeField {
+        &self.field
+    }
+
+    /// Returns `true` if every coefficient is zero.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|&c| c == 0)
+    }
+
+    /// Returns the degree of the polynomial, ignoring trailing zero coefficients.
+    ///
+    /// By convention the zero polynomial has degree 0.
+    pub fn degree(&self) -> usize {
+        self.coeffs.iter().rposition(|&c| c != 0).unwrap_or(0)
+    }
+
+    /// Divides `self` by `divisor` using schoolbook long division, returning the quotient and
+    /// remainder `(q, r)` such that `self = q * divisor + r` and `deg(r) < deg(divisor)`.
+    ///
+    /// Returns [`ToolkitError::DivisionByZeroPolynomial`] if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Polynomial) -> Result<(Polynomial, Polynomial), ToolkitError> {
+        if divisor.is_zero() {
+            return Err(ToolkitError::DivisionByZeroPolynomial);
+        }
+        let field = &self.field;
+        let divisor_degree = divisor.degree();
+        let degree = self.degree();
+        if self.is_zero() || degree < divisor_degree {
+            return Ok((Polynomial::new(vec![], field.clone()), self.clone()));
+        }
+
+        let leading_inv = field.inv(divisor.coeffs[divisor_degree])?;
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![0; degree - divisor_degree + 1];
+
+        // Eliminate the leading term of the remainder one power at a time, from the top down.
+        for i in (0..quotient.len()).rev() {
+            let factor = field.mul(remainder[i + divisor_degree], leading_inv);
+            quotient[i] = factor;
+            for (j, &c) in divisor.coeffs[..=divisor_degree].iter().enumerate() {
+                remainder[i + j] = field.sub(remainder[i + j], field.mul(factor, c));
+            }
+        }
+        remainder.truncate(divisor_degree);
+
+        Ok((
+            Polynomial::new(quotient, field.clone()),
+            Polynomial::new(remainder, field.clone()),
+        ))
+    }
+
+    /// Divides `self` by the linear factor `(x - a)` using synthetic division (Ruffini's rule),
+    /// returning the quotient and the remainder, which equals `self(a)`.
+    ///
+    /// This takes O(n) operations, compared to O(n * m) for dividing by a degree `m` polynomial.
+    pub fn ruffini_division(&self, a: u64) -> (Polynomial, u64) {
+        let a = a % self.field.p;
+        let mut quotient = vec![0; self.coeffs.len().saturating_sub(1)];
+        let mut carry = 0;
+        // Walk the coefficients from the top: each quotient coefficient is the running
+        // Horner value, and the final value is the remainder.
+        for (i, &c) in self.coeffs.iter().enumerate().rev() {
+            carry = self.field.add(self.field.mul(carry, a), c);
+            if i > 0 {
+                quotient[i - 1] = carry;
+            }
+        }
+        (Polynomial::new(quotient, self.field.clone()), carry)
+    }
+
+    /// Returns the formal derivative `a_1 + 2 a_2 x + ... + n a_n x^(n-1)`.
+    ///
+    /// In characteristic `p` the integer factor `i` is reduced modulo `p`, so every term whose
+    /// exponent is a multiple of `p` vanishes: for instance `(x^p)' = p x^(p-1) = 0`. The
+    /// derivative therefore no longer determines a polynomial up to a constant, unlike over the
+    /// reals.
+    pub fn derivative(&self) -> Polynomial {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| self.field.mul(i as u64 % self.field.p, c))
+            .collect();
+        Polynomial::new(coeffs, self.field.clone())
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's scheme:
+    /// `a_0 + x(a_1 + x(a_2 + ... + x(a_n)))`.
+    ///
+    /// This needs only `n` multiplications and `n` additions, with no exponentiation.
+    pub fn evaluate(&self, x: u64) -> u64 {
+        let x = x % self.field.p;
+        self.coeffs.iter().rev().fold(0, |acc, &coeff| {
+            self.field.add(self.field.mul(acc, x), coeff)
+        })
+    }
+
+    /// Evaluates the polynomial at every point of `points`, returning the values in the same order.
+    pub fn evaluate_slice(&self, points: &[u64]) -> Vec<u64> {
+        points.iter().map(|&x| self.evaluate(x)).collect()
+    }
+
+    /// Evaluates every polynomial in `polys` at every point of `points`, so that `result[i][j]` is
+    /// `polys[i](points[j])`.
+    ///
+    /// The powers `1, x, x^2, ...` of each point are computed once, by repeated multiplication, and
+    /// shared by all polynomials; each evaluation is then a plain dot product with the
+    /// coefficients. This is worthwhile when many polynomials are opened at the same points, as
+    /// with the columns of an execution trace.
+    ///
+    /// Returns [`ToolkitError::FieldMismatch`] if the polynomials are not all defined over the
+    /// same field.
+    pub fn evaluate_batch(
+        polys: &[Polynomial],
+        points: &[u64],
+    ) -> Result<Vec<Vec<u64>>, ToolkitError> {
+        let Some(first) = polys.first() else {
+            return Ok(Vec::new());
+        };
+        let f = &first.field;
+        if polys.iter().any(|poly| poly.field != *f) {
+            return Err(ToolkitError::FieldMismatch);
+        }
+
+        let num_powers = polys
+            .iter()
+            .map(|poly| poly.coeffs.len())
+            .max()
+            .unwrap_or(0);
+        let powers: Vec<Vec<u64>> = points
+            .iter()
+            .map(|&x| {
+                let x = x % f.p;
+                std::iter::successors(Some(1 % f.p), |&power| Some(f.mul(power, x)))
+                    .take(num_powers)
+                    .collect()
+            })
+            .collect();
+
+        Ok(polys
+            .iter()
+            .map(|poly| {
+                powers
+                    .iter()
+                    .map(|x_powers| {
+                        poly.coeffs
+                            .iter()
+                            .zip(x_powers)
+                            .fold(0, |acc, (&c, &power)| f.add(acc, f.mul(c, power)))
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Evaluates the polynomial at `x` term by term, computing every power `x^i` from scratch.
+    ///
+    /// Kept for teaching purposes: each term calls `mod_pow`, so the total cost is
+    /// O(n log n) multiplications instead of the O(n) of [`Polynomial::evaluate`].
+    pub fn evaluate_naive(&self, x: u64) -> u64 {
+        let mut result = 0;
+        for (power, &coeff) in self.coeffs.iter().enumerate() {
+            let x_pow = PrimeField::mod_pow(x, power as u64, self.field.p);
+            let term = self.field.mul(coeff, x_pow);
+            result = self.field.add(result, term);
+        }
+        result
+    }
+}
+
+/// Formats the polynomial in descending powers, e.g. `3x^2 + 2x + 1`.
+///
+/// Zero terms are skipped, unit coefficients are omitted on non-constant terms, and the zero
+/// polynomial prints as `0`. The output can be read back with [`Polynomial::from_str`].
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<String> = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, &c)| c != 0)
+            .map(|(power, &c)| match (power, c) {
+                (0, c) => c.to_string(),
+                (1, 1) => "x".to_string(),
+                (1, c) => format!("{}x", c),
+                (power, 1) => format!("x^{}", power),
+                (power, c) => format!("{}x^{}", c, power),
+            })
+            .collect();
+
+        if terms.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", terms.join(" + "))
+        }
+    }
+}
+
+impl Polynomial {
+    /// The highest power of `x` that [`Polynomial::from_str`] accepts, so that a typed exponent
+    /// cannot make it allocate more than a few megabytes of coefficients.
+    pub const MAX_PARSED_DEGREE: usize = 1 << 20;
+
+    /// Parses a polynomial in the variable `x` written as a sum of terms, as produced by the
+    /// `Display` implementation: `"3x^2 + 2x + 1"`.
+    ///
+    /// Each term is `c`, `x`, `x^k`, `cx`, or `cx^k`, optionally with `*` between the coefficient
+    /// and `x`. Terms are separated by `+` or `-` (a `-` negates the next term modulo `p`), may
+    /// appear in any order, and repeated powers are added together. Whitespace is ignored.
+    ///
+    /// Returns [`ToolkitError::InvalidPolynomialString`] if the input is malformed or has a power
+    /// above [`Polynomial::MAX_PARSED_DEGREE`].
+    pub fn from_str(s: &str, field: PrimeField) -> Result<Self, ToolkitError> {
+        let invalid = || ToolkitError::InvalidPolynomialString(s.to_string());
+        let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if compact.is_empty() {
+            return Err(invalid());
+        }
+
+        // Split into signed terms, keeping each `+`/`-` with the term that follows it.
+        let mut terms = Vec::new();
+        let mut start = 0;
+        for (i, c) in compact.char_indices() {
+            if (c == '+' || c == '-') && i > 0 {
+                terms.push(&compact[start..i]);
+                start = i;
+            }
+        }
+        terms.push(&compact[start..]);
+
+        let mut coeffs: Vec<u64> = Vec::new();
+        for term in terms {
+            let (negative, body) = match term.strip_prefix('-') {
+                Some(body) => (true, body),
+                None => (false, term.strip_prefix('+').unwrap_or(term)),
+            };
+            let (coeff, power) = match body.split_once('x') {
+                None => (body.parse::<u64>().map_err(|_| invalid())?, 0),
+                Some((coeff, power)) => {
+                    let coeff = coeff.strip_suffix('*').unwrap_or(coeff);
+                    let coeff = if coeff.is_empty() {
+                        1
+                    } else {
+                        coeff.parse::<u64>().map_err(|_| invalid())?
+                    };
+                    let power = match power {
+                        "" => 1,
+                        power => power
+                            .strip_prefix('^')
+                            .and_then(|k| k.parse::<usize>().ok())
+                            .filter(|&k| k <= Self::MAX_PARSED_DEGREE)
+                            .ok_or_else(invalid)?,
+                    };
+                    (coeff, power)
+                }
+            };
+
+            let coeff = coeff % field.p;
+            let coeff = if negative { field.neg(coeff) } else { coeff };
+            if coeffs.len() <= power {
+                coeffs.resize(power + 1, 0);
+            }
+            coeffs[power] = field.add(coeffs[power], coeff);
+        }
+
+        Ok(Polynomial::new(coeffs, field))
+    }
+}
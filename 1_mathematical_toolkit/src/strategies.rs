@@ -0,0 +1,55 @@
+//! Proptest strategies for the toolkit's algebraic types, mirroring the `strategies` module of
+//! workshop 2 so every feature can be property-tested uniformly.
+
+use proptest::collection::vec;
+use proptest::prelude::Strategy;
+
+use crate::extension::ExtensionField;
+use crate::field::PrimeField;
+use crate::multivariate::MultivariatePolynomial;
+use crate::pedersen::PedersenParams;
+use crate::polynomial::Polynomial;
+
+/// Generates a uniformly random element of `field`.
+pub fn arb_field_element(field: &PrimeField) -> impl Strategy<Value = u64> {
+    0..field.p
+}
+
+/// Generates a uniformly random element of the extension field `ext`.
+pub fn arb_extension_element(ext: &ExtensionField) -> impl Strategy<Value = Vec<u64>> {
+    vec(0..ext.base.p, ext.degree())
+}
+
+/// Generates a polynomial over `field` with degree up to `max_degree`.
+pub fn arb_polynomial(field: &PrimeField, max_degree: usize) -> impl Strategy<Value = Polynomial> {
+    let field = field.clone();
+    // At least one coefficient, so the polynomial always has a constant term.
+    vec(0..field.p, 1..=max_degree + 1)
+        .prop_map(move |coeffs| Polynomial::new(coeffs, field.clone()))
+}
+
+/// Generates a sparse polynomial in `num_vars` variables with up to `max_terms` terms, each
+/// variable raised to a power of at most `max_degree`.
+pub fn arb_multivariate_polynomial(
+    field: &PrimeField,
+    num_vars: usize,
+    max_terms: usize,
+    max_degree: u32,
+) -> impl Strategy<Value = MultivariatePolynomial> {
+    let field = field.clone();
+    let term = (0..field.p, vec(0..=max_degree, num_vars));
+    vec(term, 0..=max_terms).prop_map(move |terms| {
+        MultivariatePolynomial::new(num_vars, terms, field.clone())
+            .expect("every exponent vector has num_vars entries")
+    })
+}
+
+/// Generates a uniformly random element of the prime-order commitment group of `params`, as
+/// `g^k` for a random exponent `k`.
+///
+/// The toolkit has no elliptic-curve group; this subgroup of GF(p)* plays the role of the curve
+/// in [`crate::pedersen`].
+pub fn arb_subgroup_element(params: &PedersenParams) -> impl Strategy<Value = u64> {
+    let (g, p) = (params.g, params.field.p);
+    (0..params.order).prop_map(move |k| PrimeField::mod_pow(g, k, p))
+}
@@ -1,11 +1,31 @@
 use proptest::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
-use crate::PrimeField;
+use crate::extension::ExtensionField;
+use crate::factor::{self, factorize, pollard_rho};
+use crate::field::constant_time;
+use crate::field::presets::{self, BABYBEAR, GOLDILOCKS, MERSENNE31};
+use crate::hash::{hash_to_field, hash_to_subgroup};
+use crate::matrix::Matrix;
+use crate::multivariate::MultivariatePolynomial;
+use crate::pedersen::{PedersenParams, SAFE_PRIME};
+use crate::strategies::{
+    arb_extension_element, arb_field_element, arb_multivariate_polynomial, arb_polynomial,
+    arb_subgroup_element,
+};
+use crate::zerofier::{vanishing_except, vanishing_polynomial, zerofier};
+use crate::{Polynomial, PrimeField, ToolkitError};
 
-// Define the field GF(7)
-pub const PRIME: u64 = (1 << 31) - 1;
+// Define the field GF(2^31 - 1)
+pub const PRIME: u64 = MERSENNE31.field.p;
 pub const FIELD: PrimeField = PrimeField { p: PRIME };
 
+// Pedersen parameters over the 31-bit safe prime, with generators 4 = 2^2 and 9 = 3^2.
+fn pedersen_params() -> PedersenParams {
+    PedersenParams::new(PrimeField { p: SAFE_PRIME }, 4, 9).unwrap()
+}
+
 // Closure property for addition and multiplication:
 // The result of a + b and a * b must remain in the field (i.e., less than p)
 proptest! {
@@ -65,7 +85,7 @@ proptest! {
 proptest! {
     #[test]
     fn multiplicative_inverse(a in 1..FIELD.p) {
-        let inv = FIELD.inv(a);
+        let inv = FIELD.inv(a).unwrap();
         prop_assert_eq!(FIELD.mul(a, inv), 1);
     }
 }
@@ -96,3 +116,806 @@ proptest! {
         prop_assert_eq!((a * b) * c, a * (b * c));
     }
 }
+
+// Horner's scheme must agree with term-by-term evaluation:
+// a_0 + x(a_1 + x(a_2 + ...)) == sum(a_i * x^i)
+proptest! {
+    #[test]
+    fn horner_matches_naive_evaluation(
+        poly in arb_polynomial(&FIELD, 63),
+        x in arb_field_element(&FIELD),
+    ) {
+        prop_assert_eq!(poly.evaluate(x), poly.evaluate_naive(x));
+    }
+}
+
+// Remainder theorem: dividing P(x) by (x - r) leaves the constant remainder P(r),
+// and the quotient reconstructs P via P(x) = q(x) * (x - r) + P(r).
+proptest! {
+    #[test]
+    fn division_by_linear_factor(
+        poly in arb_polynomial(&FIELD, 31),
+        r in arb_field_element(&FIELD),
+        x in arb_field_element(&FIELD),
+    ) {
+        let divisor = Polynomial::new(vec![FIELD.neg(r), 1], FIELD);
+        let (quotient, remainder) = poly.div_rem(&divisor).unwrap();
+        prop_assert_eq!(remainder.evaluate(0), poly.evaluate(r));
+        prop_assert_eq!(remainder.degree(), 0);
+        let reconstructed = FIELD.add(
+            FIELD.mul(quotient.evaluate(x), FIELD.sub(x, r)),
+            remainder.evaluate(x),
+        );
+        prop_assert_eq!(reconstructed, poly.evaluate(x));
+    }
+}
+
+// Error paths: non-prime moduli, inverting zero, and dividing by the zero polynomial
+// are reported as errors instead of panics.
+#[test]
+fn error_paths() {
+    assert_eq!(
+        PrimeField::new(1).unwrap_err(),
+        ToolkitError::NonPrimeModulus(1)
+    );
+    assert_eq!(
+        PrimeField::new(91).unwrap_err(),
+        ToolkitError::NonPrimeModulus(91)
+    );
+    assert_eq!(FIELD.inv(0), Err(ToolkitError::ZeroInverse));
+    assert_eq!(FIELD.div(1, 0), Err(ToolkitError::ZeroInverse));
+
+    let poly = Polynomial::new(vec![1, 2, 3], FIELD);
+    let zero = Polynomial::new(vec![0, 0], FIELD);
+    assert_eq!(
+        poly.div_rem(&zero).unwrap_err(),
+        ToolkitError::DivisionByZeroPolynomial
+    );
+}
+
+// Serde round-trip: a polynomial saved as JSON loads back with the same field and coefficients.
+#[cfg(feature = "serde")]
+#[test]
+fn serde_json_round_trip() {
+    let poly = Polynomial::new(vec![1, 0, 3, PRIME - 1], FIELD);
+    let json = serde_json::to_string(&poly).unwrap();
+    let loaded: Polynomial = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.coeffs(), poly.coeffs());
+    assert_eq!(loaded.field().p, PRIME);
+
+    let field: PrimeField = serde_json::from_str(&serde_json::to_string(&FIELD).unwrap()).unwrap();
+    assert_eq!(field.p, FIELD.p);
+}
+
+// Pedersen homomorphism:
+// C(v1, r1) * C(v2, r2) == C(v1 + v2, r1 + r2)
+proptest! {
+    #[test]
+    fn pedersen_homomorphic_addition(
+        v1 in 0..SAFE_PRIME / 2,
+        r1 in 0..SAFE_PRIME / 2,
+        v2 in 0..SAFE_PRIME / 2,
+        r2 in 0..SAFE_PRIME / 2,
+    ) {
+        let params = pedersen_params();
+        let sum = params.add(&params.commit(v1, r1), &params.commit(v2, r2));
+        prop_assert_eq!(sum, params.commit(v1 + v2, r1 + r2));
+        prop_assert!(params.verify(&sum, v1 + v2, r1 + r2));
+    }
+}
+
+// Pedersen binding (for honest parameters): a commitment does not open to a different value
+// under the same blinding factor.
+proptest! {
+    #[test]
+    fn pedersen_rejects_wrong_opening(v in 0..SAFE_PRIME / 2, r in 0..SAFE_PRIME / 2) {
+        let params = pedersen_params();
+        let commitment = params.commit(v, r);
+        prop_assert!(params.verify(&commitment, v, r));
+        prop_assert!(!params.verify(&commitment, v + 1, r));
+    }
+}
+
+#[test]
+fn pedersen_parameter_validation() {
+    // 2^31 - 1 is prime, but (p - 1) / 2 = 3 * 7 * 11 * 31 * 151 * 331 is not.
+    assert_eq!(
+        PedersenParams::new(FIELD, 4, 9).unwrap_err(),
+        ToolkitError::NotSafePrime(PRIME)
+    );
+    let field = PrimeField { p: SAFE_PRIME };
+    // 1 has order 1, and -1 has order 2.
+    assert_eq!(
+        PedersenParams::new(field.clone(), 1, 9).unwrap_err(),
+        ToolkitError::InvalidSubgroupGenerator(1)
+    );
+    assert_eq!(
+        PedersenParams::new(field.clone(), 4, SAFE_PRIME - 1).unwrap_err(),
+        ToolkitError::InvalidSubgroupGenerator(SAFE_PRIME - 1)
+    );
+    assert_eq!(
+        PedersenParams::new(field, 4, 4).unwrap_err(),
+        ToolkitError::InvalidSubgroupGenerator(4)
+    );
+}
+
+// Hash-to-field is deterministic and always lands inside the field.
+proptest! {
+    #[test]
+    fn hash_to_field_in_range(bytes in proptest::collection::vec(any::<u8>(), 0..64)) {
+        let x = hash_to_field(&FIELD, &bytes);
+        prop_assert!(x < FIELD.p);
+        prop_assert_eq!(x, hash_to_field(&FIELD, &bytes));
+        // Tiny fields exercise the rejection loop on almost every call.
+        prop_assert!(hash_to_field(&PrimeField { p: 5 }, &bytes) < 5);
+    }
+}
+
+// Hash-to-subgroup yields generators accepted by the Pedersen parameters.
+proptest! {
+    #[test]
+    fn hash_to_subgroup_yields_generators(bytes in proptest::collection::vec(any::<u8>(), 0..64)) {
+        let params = pedersen_params();
+        let h = hash_to_subgroup(&params, &bytes);
+        let accepted = PedersenParams::new(params.field.clone(), params.g, h).is_ok();
+        prop_assert!(accepted || h == params.g);
+    }
+}
+
+// Random subgroup elements are annihilated by the group order: x^q == 1.
+proptest! {
+    #[test]
+    fn subgroup_elements_have_prime_order(
+        x in arb_subgroup_element(&pedersen_params()),
+    ) {
+        prop_assert_eq!(PrimeField::mod_pow(x, SAFE_PRIME / 2, SAFE_PRIME), 1);
+    }
+}
+
+// Multiplies the coefficient vector of P(x) by (x - r).
+fn mul_by_linear(coeffs: &[u64], r: u64) -> Vec<u64> {
+    let mut result = vec![0; coeffs.len() + 1];
+    for (i, &c) in coeffs.iter().enumerate() {
+        result[i + 1] = FIELD.add(result[i + 1], c);
+        result[i] = FIELD.sub(result[i], FIELD.mul(r, c));
+    }
+    result
+}
+
+// Multiplicity check: if r is a double root of P, i.e. P(x) = Q(x) * (x - r)^2,
+// then r is also a root of the derivative P'(x).
+proptest! {
+    #[test]
+    fn derivative_vanishes_at_double_root(
+        q in arb_polynomial(&FIELD, 31),
+        r in arb_field_element(&FIELD),
+    ) {
+        let coeffs = mul_by_linear(&mul_by_linear(q.coeffs(), r), r);
+        let poly = Polynomial::new(coeffs, FIELD);
+        prop_assert_eq!(poly.evaluate(r), 0);
+        prop_assert_eq!(poly.derivative().evaluate(r), 0);
+    }
+}
+
+// Characteristic-p quirk: terms whose exponent is a multiple of p vanish.
+#[test]
+fn derivative_in_characteristic_p() {
+    let field = PrimeField::new(5).unwrap();
+    // P(x) = x^10 + x^5 + 3x^2 + 4x + 1  =>  P'(x) = 10x^9 + 5x^4 + 6x + 4 = x + 4 (mod 5)
+    let poly = Polynomial::new(vec![1, 4, 3, 0, 0, 1, 0, 0, 0, 0, 1], field.clone());
+    let derivative = poly.derivative();
+    assert_eq!(derivative.degree(), 1);
+    assert_eq!(&derivative.coeffs()[..2], &[4, 1]);
+    assert!(derivative.coeffs()[2..].iter().all(|&c| c == 0));
+
+    // Constants (and the zero polynomial) differentiate to zero.
+    assert!(Polynomial::new(vec![3], field.clone())
+        .derivative()
+        .is_zero());
+    assert!(Polynomial::new(vec![], field).derivative().is_zero());
+}
+
+// GF(17) has a multiplicative subgroup of order 8 generated by 9 = 3^2.
+const SMALL_FIELD: PrimeField = PrimeField { p: 17 };
+const SUBGROUP_GENERATOR: u64 = 9;
+const SUBGROUP_ORDER: usize = 8;
+
+fn subgroup() -> Vec<u64> {
+    (0..SUBGROUP_ORDER as u64)
+        .map(|k| PrimeField::mod_pow(SUBGROUP_GENERATOR, k, SMALL_FIELD.p))
+        .collect()
+}
+
+// A zerofier vanishes on its points and has degree equal to their number.
+proptest! {
+    #[test]
+    fn zerofier_vanishes_on_points(
+        points in proptest::collection::vec(arb_field_element(&FIELD), 0..16),
+        x in arb_field_element(&FIELD),
+    ) {
+        let z = zerofier(&FIELD, &points);
+        prop_assert_eq!(z.degree(), points.len());
+        for &a in &points {
+            prop_assert_eq!(z.evaluate(a), 0);
+        }
+        let expected = points.iter().fold(1, |acc, &a| FIELD.mul(acc, FIELD.sub(x, a)));
+        prop_assert_eq!(z.evaluate(x), expected);
+    }
+}
+
+// Ruffini division by (x - a) agrees with general long division.
+proptest! {
+    #[test]
+    fn ruffini_matches_long_division(
+        poly in arb_polynomial(&FIELD, 31),
+        a in arb_field_element(&FIELD),
+        x in arb_field_element(&FIELD),
+    ) {
+        let (quotient, remainder) = poly.ruffini_division(a);
+        let (expected_q, expected_r) = poly.div_rem(&zerofier(&FIELD, &[a])).unwrap();
+        prop_assert_eq!(remainder, expected_r.evaluate(0));
+        prop_assert_eq!(quotient.evaluate(x), expected_q.evaluate(x));
+    }
+}
+
+#[test]
+fn vanishing_polynomial_matches_subgroup_zerofier() {
+    let h = subgroup();
+    let vanishing = vanishing_polynomial(&SMALL_FIELD, SUBGROUP_ORDER);
+    let z = zerofier(&SMALL_FIELD, &h);
+    assert_eq!(vanishing.coeffs(), z.coeffs());
+}
+
+#[test]
+fn vanishing_except_skips_exempted_points() {
+    let h = subgroup();
+    let exempt = [h[SUBGROUP_ORDER - 2], h[SUBGROUP_ORDER - 1]];
+    let poly = vanishing_except(&SMALL_FIELD, SUBGROUP_ORDER, &exempt).unwrap();
+    assert_eq!(poly.degree(), SUBGROUP_ORDER - exempt.len());
+    for (i, &x) in h.iter().enumerate() {
+        assert_eq!(poly.evaluate(x) == 0, i < SUBGROUP_ORDER - 2);
+    }
+    // (x^n - 1) / Z_exempt(x) * Z_exempt(x) == x^n - 1 everywhere.
+    let exempt_zerofier = zerofier(&SMALL_FIELD, &exempt);
+    for x in 0..SMALL_FIELD.p {
+        assert_eq!(
+            SMALL_FIELD.mul(poly.evaluate(x), exempt_zerofier.evaluate(x)),
+            SMALL_FIELD.sub(
+                PrimeField::mod_pow(x, SUBGROUP_ORDER as u64, SMALL_FIELD.p),
+                1
+            )
+        );
+    }
+
+    // 3 generates all of GF(17)*, so it is not in the order-8 subgroup, and a point cannot be
+    // divided out twice.
+    assert_eq!(
+        vanishing_except(&SMALL_FIELD, SUBGROUP_ORDER, &[3]).unwrap_err(),
+        ToolkitError::PointNotARoot(3)
+    );
+    assert_eq!(
+        vanishing_except(&SMALL_FIELD, SUBGROUP_ORDER, &[h[1], h[1]]).unwrap_err(),
+        ToolkitError::PointNotARoot(h[1])
+    );
+}
+
+// GF(p^2) = GF(p)[i] / (i^2 + 1): -1 is a non-residue since p = 2^31 - 1 = 3 (mod 4).
+fn quadratic_extension() -> ExtensionField {
+    ExtensionField::quadratic(FIELD, PRIME - 1).unwrap()
+}
+
+// GF(p^4) = GF(p)[x] / (x^4 - 11), the Babybear-style quartic extension.
+fn quartic_extension() -> ExtensionField {
+    ExtensionField::new(
+        PrimeField { p: 2013265921 },
+        vec![2013265921 - 11, 0, 0, 0, 1],
+    )
+    .unwrap()
+}
+
+// Frobenius is a field automorphism:
+// phi(a + b) == phi(a) + phi(b), phi(a * b) == phi(a) * phi(b), phi^k == id
+proptest! {
+    #[test]
+    fn frobenius_is_automorphism(
+        a in arb_extension_element(&quartic_extension()),
+        b in arb_extension_element(&quartic_extension()),
+    ) {
+        let ext = quartic_extension();
+        let (phi_a, phi_b) = (ext.frobenius(&a), ext.frobenius(&b));
+        prop_assert_eq!(ext.frobenius(&ext.add(&a, &b)), ext.add(&phi_a, &phi_b));
+        prop_assert_eq!(ext.frobenius(&ext.mul(&a, &b)), ext.mul(&phi_a, &phi_b));
+        prop_assert_eq!(ext.frobenius_power(&a, ext.degree()), a);
+    }
+}
+
+// Frobenius fixes the base field: phi(c) == c for c in GF(p).
+proptest! {
+    #[test]
+    fn frobenius_fixes_base_field(c in arb_field_element(&FIELD)) {
+        let ext = quadratic_extension();
+        let embedded = ext.from_base(c);
+        prop_assert_eq!(ext.frobenius(&embedded), embedded);
+    }
+}
+
+// In GF(p^2) = GF(p)[i], Frobenius is complex conjugation: phi(a + bi) == a - bi.
+proptest! {
+    #[test]
+    fn quadratic_frobenius_is_conjugation(a in arb_extension_element(&quadratic_extension())) {
+        let ext = quadratic_extension();
+        prop_assert_eq!(ext.frobenius(&a), vec![a[0], FIELD.neg(a[1])]);
+    }
+}
+
+// Power maps are multiplicative, and inverses exist for non-zero elements:
+// (a * b)^k == a^k * b^k and a * a^-1 == 1
+proptest! {
+    #[test]
+    fn power_map_and_inverse(
+        a in arb_extension_element(&quadratic_extension()),
+        b in arb_extension_element(&quadratic_extension()),
+        k in 0..1_000_000u64,
+    ) {
+        let ext = quadratic_extension();
+        let k = k as u128;
+        prop_assert_eq!(ext.pow(&ext.mul(&a, &b), k), ext.mul(&ext.pow(&a, k), &ext.pow(&b, k)));
+        if a != ext.zero() {
+            prop_assert_eq!(ext.mul(&a, &ext.inv(&a).unwrap()), ext.one());
+        }
+    }
+}
+
+#[test]
+fn extension_field_validation() {
+    // 4 = 2^2 is a square, so x^2 - 4 = (x - 2)(x + 2) is reducible.
+    assert_eq!(
+        ExtensionField::quadratic(FIELD, 4).unwrap_err(),
+        ToolkitError::InvalidExtensionModulus
+    );
+    assert_eq!(
+        ExtensionField::new(FIELD, vec![1, 2, 3]).unwrap_err(),
+        ToolkitError::InvalidExtensionModulus
+    );
+    assert_eq!(
+        ExtensionField::new(FIELD, vec![5]).unwrap_err(),
+        ToolkitError::InvalidExtensionModulus
+    );
+    let ext = quadratic_extension();
+    assert_eq!(ext.inv(&ext.zero()), Err(ToolkitError::ZeroInverse));
+}
+
+// Printing and parsing round-trip: from_str(p.to_string()) == p.
+proptest! {
+    #[test]
+    fn display_parse_round_trip(
+        poly in arb_polynomial(&FIELD, 16),
+        x in arb_field_element(&FIELD),
+    ) {
+        let parsed = Polynomial::from_str(&poly.to_string(), FIELD).unwrap();
+        prop_assert_eq!(parsed.degree(), poly.degree());
+        prop_assert_eq!(parsed.evaluate(x), poly.evaluate(x));
+    }
+}
+
+#[test]
+fn polynomial_display_and_parsing() {
+    let field = PrimeField::new(7).unwrap();
+    let poly = Polynomial::new(vec![1, 2, 3], field.clone());
+    assert_eq!(poly.to_string(), "3x^2 + 2x + 1");
+    assert_eq!(
+        Polynomial::new(vec![0, 1, 0, 1], field.clone()).to_string(),
+        "x^3 + x"
+    );
+    assert_eq!(Polynomial::new(vec![0, 0], field.clone()).to_string(), "0");
+
+    // Whitespace, `*`, subtraction, term order, and repeated powers are all accepted.
+    let parsed = Polynomial::from_str(" 2 * x - 1 + 3x^2 +x", field.clone()).unwrap();
+    assert_eq!(parsed.coeffs(), &[6, 3, 3]);
+    assert_eq!(
+        Polynomial::from_str("-x^2", field.clone())
+            .unwrap()
+            .coeffs(),
+        &[0, 0, 6]
+    );
+
+    // Powers too large to allocate, or to index, are rejected before any allocation.
+    let too_high = format!("x^{}", Polynomial::MAX_PARSED_DEGREE + 1);
+    for bad in [
+        "",
+        "3y^2",
+        "x^",
+        "2x^-1",
+        "1 + + x",
+        "x^2x",
+        "x^4000000000",
+        "x^18446744073709551615",
+        &too_high,
+    ] {
+        assert_eq!(
+            Polynomial::from_str(bad, field.clone()).unwrap_err(),
+            ToolkitError::InvalidPolynomialString(bad.to_string())
+        );
+    }
+}
+
+// Field axioms hold for the 64-bit Goldilocks prime, whose products overflow u64.
+proptest! {
+    #[test]
+    fn goldilocks_arithmetic(
+        a in arb_field_element(&GOLDILOCKS.field),
+        b in arb_field_element(&GOLDILOCKS.field),
+        c in arb_field_element(&GOLDILOCKS.field),
+    ) {
+        let f = GOLDILOCKS.field;
+        prop_assert!(f.add(a, b) < f.p && f.mul(a, b) < f.p);
+        prop_assert_eq!(f.sub(f.add(a, b), b), a);
+        prop_assert_eq!(f.mul(a, f.add(b, c)), f.add(f.mul(a, b), f.mul(a, c)));
+        if a != 0 {
+            prop_assert_eq!(f.mul(a, f.inv(a).unwrap()), 1);
+        }
+    }
+}
+
+// The recorded two-adicity matches the factorization of p - 1.
+#[test]
+fn preset_two_adicity() {
+    for preset in presets::ALL {
+        let p_minus_one = preset.field.p - 1;
+        assert_eq!(
+            p_minus_one.trailing_zeros(),
+            preset.two_adicity,
+            "{}",
+            preset.name
+        );
+    }
+    assert!(PrimeField::is_prime(BABYBEAR.field.p));
+}
+
+// Every preset modulus, including the 64-bit Goldilocks prime, passes `PrimeField::new`.
+#[test]
+fn presets_are_accepted_as_fields() {
+    for preset in presets::ALL {
+        assert_eq!(
+            PrimeField::new(preset.field.p),
+            Ok(preset.field),
+            "{}",
+            preset.name
+        );
+    }
+}
+
+// Interpolation as linear algebra: solving the Vandermonde system V * c = y for distinct points
+// recovers the unique polynomial of degree < n with P(x_i) = y_i.
+proptest! {
+    #[test]
+    fn vandermonde_solve_interpolates(poly in arb_polynomial(&FIELD, 7), offset in 0..FIELD.p - 8) {
+        let n = poly.coeffs().len();
+        let xs: Vec<u64> = (0..n as u64).map(|i| offset + i).collect();
+        let vandermonde = Matrix::new(
+            xs.iter()
+                .map(|&x| (0..n as u64).map(|k| PrimeField::mod_pow(x, k, PRIME)).collect())
+                .collect(),
+            FIELD,
+        )
+        .unwrap();
+        let ys: Vec<u64> = xs.iter().map(|&x| poly.evaluate(x)).collect();
+        prop_assert_eq!(vandermonde.rank(), n);
+        prop_assert_eq!(vandermonde.solve(&ys).unwrap(), poly.coeffs().to_vec());
+        let inverse = vandermonde.inverse().unwrap();
+        prop_assert_eq!(vandermonde.mul(&inverse).unwrap(), Matrix::identity(n, FIELD));
+    }
+}
+
+// Random square matrices: A * A^-1 == I when invertible, and solutions satisfy A x = b.
+proptest! {
+    #[test]
+    fn matrix_inverse_and_solve(
+        entries in proptest::collection::vec(arb_field_element(&FIELD), 9),
+        b in proptest::collection::vec(arb_field_element(&FIELD), 3),
+    ) {
+        let a = Matrix::new(entries.chunks(3).map(|row| row.to_vec()).collect(), FIELD).unwrap();
+        match a.inverse() {
+            Ok(inverse) => {
+                prop_assert_eq!(a.rank(), 3);
+                prop_assert_eq!(a.mul(&inverse).unwrap(), Matrix::identity(3, FIELD));
+                prop_assert_eq!(inverse.mul(&a).unwrap(), Matrix::identity(3, FIELD));
+                prop_assert_eq!(a.mul_vec(&a.solve(&b).unwrap()).unwrap(), b);
+            }
+            Err(e) => {
+                prop_assert_eq!(e, ToolkitError::SingularMatrix);
+                prop_assert!(a.rank() < 3);
+            }
+        }
+    }
+}
+
+#[test]
+fn matrix_rank_and_degenerate_systems() {
+    let field = PrimeField::new(7).unwrap();
+    // The second row is twice the first, so the rank is 2.
+    let a = Matrix::new(
+        vec![vec![1, 2, 3], vec![2, 4, 6], vec![0, 1, 1]],
+        field.clone(),
+    )
+    .unwrap();
+    assert_eq!(a.rank(), 2);
+    assert_eq!(a.inverse().unwrap_err(), ToolkitError::SingularMatrix);
+
+    // Consistent underdetermined system: free variables are set to zero.
+    let x = a.solve(&[1, 2, 3]).unwrap();
+    assert_eq!(a.mul_vec(&x).unwrap(), vec![1, 2, 3]);
+    // Inconsistent: row 2 = 2 * row 1 but 2 * 1 != 5.
+    assert_eq!(
+        a.solve(&[1, 5, 3]).unwrap_err(),
+        ToolkitError::InconsistentSystem
+    );
+
+    // Dimension checks.
+    assert_eq!(
+        Matrix::new(vec![vec![1, 2], vec![3]], field.clone()).unwrap_err(),
+        ToolkitError::DimensionMismatch
+    );
+    let b = Matrix::zeros(2, 3, field.clone());
+    assert_eq!(b.mul(&b).unwrap_err(), ToolkitError::DimensionMismatch);
+    assert_eq!(b.inverse().unwrap_err(), ToolkitError::DimensionMismatch);
+    assert_eq!(b.solve(&[1]).unwrap_err(), ToolkitError::DimensionMismatch);
+
+    // The 0x0 matrix is invertible, and its inverse is itself.
+    let empty = Matrix::new(vec![], field).unwrap();
+    assert_eq!(empty.inverse().unwrap(), empty);
+}
+
+// Random sampling: sampled elements are canonical, random polynomials have exactly the requested
+// degree, random subgroup elements are commitment generators, and a fixed seed is reproducible.
+proptest! {
+    #[test]
+    fn random_sampling_is_well_formed(seed in any::<u64>(), degree in 0usize..16) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        prop_assert!(FIELD.random(&mut rng) < PRIME);
+
+        let poly = Polynomial::random(degree, FIELD, &mut rng);
+        prop_assert_eq!(poly.coeffs().len(), degree + 1);
+        prop_assert_eq!(poly.degree(), degree);
+        prop_assert_ne!(poly.coeffs()[degree], 0);
+
+        let ext = quartic_extension();
+        let a = ext.random(&mut rng);
+        prop_assert_eq!(a.len(), 4);
+        prop_assert!(a.iter().all(|&c| c < ext.base.p));
+
+        let params = pedersen_params();
+        let g = params.random_element(&mut rng);
+        prop_assert!(g > 1);
+        prop_assert_eq!(PrimeField::mod_pow(g, params.order, SAFE_PRIME), 1);
+        prop_assert!(params.random_blinding(&mut rng) < params.order);
+
+        let first = Polynomial::random(degree, FIELD, &mut StdRng::seed_from_u64(seed));
+        let second = Polynomial::random(degree, FIELD, &mut StdRng::seed_from_u64(seed));
+        prop_assert_eq!(first.coeffs(), second.coeffs());
+    }
+}
+
+// Checks whether `n` is prime by trial division, as a reference for Miller–Rabin.
+fn is_prime_by_trial_division(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|&i| i <= n / i).all(|i| n % i != 0)
+}
+
+// Factorization: the factors multiply back to n, are prime, and appear in increasing order.
+// Miller–Rabin agrees with trial division on every input small enough to check exhaustively.
+proptest! {
+    #[test]
+    fn factorize_recomposes(n in 1u64..u64::MAX) {
+        let factors = factorize(n);
+        let product = factors
+            .iter()
+            .fold(1u64, |acc, &(q, e)| acc * q.pow(e));
+        prop_assert_eq!(product, n);
+        prop_assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+        prop_assert!(factors.iter().all(|&(q, _)| factor::is_prime(q)));
+    }
+
+    #[test]
+    fn miller_rabin_matches_trial_division(n in 0u64..1 << 24) {
+        prop_assert_eq!(factor::is_prime(n), is_prime_by_trial_division(n));
+    }
+}
+
+#[test]
+fn factorize_known_group_orders() {
+    assert_eq!(
+        factorize(BABYBEAR.field.p - 1),
+        vec![(2, 27), (3, 1), (5, 1)]
+    );
+    assert_eq!(
+        factorize(GOLDILOCKS.field.p - 1),
+        vec![(2, 32), (3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)]
+    );
+    // A semiprime with two 32-bit factors is out of reach for trial division.
+    let (a, b) = (4_294_967_279, 4_294_967_291);
+    assert_eq!(factorize(a * b), vec![(a, 1), (b, 1)]);
+    let d = pollard_rho(a * b);
+    assert!(d == a || d == b);
+    assert!(factorize(1).is_empty());
+}
+
+// Element orders divide p - 1 and are minimal; subgroup generators have exactly the requested
+// order.
+proptest! {
+    #[test]
+    fn element_order_is_minimal(a in 1..PRIME) {
+        let order = FIELD.element_order(a).unwrap();
+        prop_assert_eq!((PRIME - 1) % order, 0);
+        prop_assert_eq!(PrimeField::mod_pow(a, order, PRIME), 1);
+        for (q, _) in factorize(order) {
+            prop_assert_ne!(PrimeField::mod_pow(a, order / q, PRIME), 1);
+        }
+    }
+}
+
+#[test]
+fn primitive_roots_and_subgroups() {
+    assert_eq!(SMALL_FIELD.primitive_root(), 3);
+    assert_eq!(MERSENNE31.field.primitive_root(), 7);
+    assert_eq!(BABYBEAR.field.primitive_root(), 31);
+    assert!(GOLDILOCKS
+        .field
+        .is_generator(GOLDILOCKS.field.primitive_root()));
+    assert_eq!(FIELD.element_order(0), Err(ToolkitError::ZeroInverse));
+
+    for preset in presets::ALL {
+        let field = &preset.field;
+        for log_n in [0, 1, preset.two_adicity] {
+            let g = field.subgroup_generator(1 << log_n).unwrap();
+            assert_eq!(field.element_order(g), Ok(1 << log_n));
+        }
+        assert_eq!(
+            field.subgroup_generator(1 << (preset.two_adicity + 1)),
+            Err(ToolkitError::InvalidSubgroupOrder(
+                1 << (preset.two_adicity + 1)
+            ))
+        );
+    }
+}
+
+// Partial evaluation commutes with full evaluation: fixing the first k variables and then
+// evaluating at the rest gives the same value as evaluating at the whole point.
+proptest! {
+    #[test]
+    fn partial_evaluation_matches_full_evaluation(
+        poly in arb_multivariate_polynomial(&FIELD, 4, 8, 3),
+        point in proptest::collection::vec(arb_field_element(&FIELD), 4),
+        k in 0usize..=4,
+    ) {
+        let partial = poly.partial_evaluate(&point[..k]).unwrap();
+        prop_assert_eq!(partial.num_vars(), 4 - k);
+        prop_assert!(partial.total_degree() <= poly.total_degree());
+        prop_assert_eq!(partial.evaluate(&point[k..]).unwrap(), poly.evaluate(&point).unwrap());
+    }
+}
+
+#[test]
+fn multivariate_terms_evaluation_and_univariate_restriction() {
+    let field = PrimeField::new(17).unwrap();
+    // P(x, y) = 3x^2y + 5xy + 2 + 16xy: the xy terms combine to 21 = 4 mod 17.
+    let poly = MultivariatePolynomial::new(
+        2,
+        vec![
+            (3, vec![2, 1]),
+            (5, vec![1, 1]),
+            (2, vec![0, 0]),
+            (16, vec![1, 1]),
+        ],
+        field.clone(),
+    )
+    .unwrap();
+    assert_eq!(poly.terms().count(), 3);
+    assert_eq!(poly.total_degree(), 3);
+    assert_eq!((poly.degree_in(0), poly.degree_in(1)), (Ok(2), Ok(1)));
+    assert_eq!(poly.degree_in(2), Err(ToolkitError::DimensionMismatch));
+    // P(2, 3) = 3*4*3 + 4*2*3 + 2 = 62 = 11 mod 17.
+    assert_eq!(poly.evaluate(&[2, 3]), Ok(11));
+
+    // Fixing x = 2 leaves P(2, y) = 20y + 2 = 3y + 2.
+    let restricted = poly
+        .partial_evaluate(&[2])
+        .unwrap()
+        .to_univariate()
+        .unwrap();
+    assert_eq!(restricted.coeffs(), &[2, 3]);
+
+    // Terms that cancel disappear entirely.
+    let cancelled =
+        MultivariatePolynomial::new(1, vec![(1, vec![4]), (16, vec![4])], field).unwrap();
+    assert!(cancelled.is_zero());
+
+    assert_eq!(poly.evaluate(&[1]), Err(ToolkitError::DimensionMismatch));
+    assert_eq!(
+        poly.partial_evaluate(&[1, 2, 3]),
+        Err(ToolkitError::DimensionMismatch)
+    );
+    assert_eq!(
+        poly.to_univariate().unwrap_err(),
+        ToolkitError::DimensionMismatch
+    );
+}
+
+// Constant-time arithmetic agrees with plain u128 arithmetic on every operation. These run with or
+// without the `constant-time` feature; with it enabled, every other test in this module also
+// exercises the branch-free path through `PrimeField`. Goldilocks is used so that sums and
+// products overflow 64 bits.
+proptest! {
+    #[test]
+    fn constant_time_matches_reference(
+        a in arb_field_element(&GOLDILOCKS.field),
+        b in arb_field_element(&GOLDILOCKS.field),
+        x in any::<u64>(),
+        exp in any::<u64>(),
+    ) {
+        let p = GOLDILOCKS.field.p;
+        let reference = |v: u128| (v % p as u128) as u64;
+        prop_assert_eq!(constant_time::reduce(x, p), x % p);
+        prop_assert_eq!(constant_time::add(a, b, p), reference(a as u128 + b as u128));
+        prop_assert_eq!(constant_time::sub(a, b, p), reference(a as u128 + p as u128 - b as u128));
+        prop_assert_eq!(constant_time::mul(a, x, p), reference(a as u128 * x as u128));
+
+        let mut expected = 1;
+        for _ in 0..exp % 64 {
+            expected = reference(expected as u128 * a as u128);
+        }
+        prop_assert_eq!(constant_time::pow(a, exp % 64, p), expected);
+    }
+
+    // Fermat: a^(p-1) = 1, so the constant-time inversion exponent really yields a^-1.
+    #[test]
+    fn constant_time_inverse(a in 1..PRIME) {
+        let inv = constant_time::pow(a, PRIME - 2, PRIME);
+        prop_assert_eq!(constant_time::mul(a, inv, PRIME), 1);
+        prop_assert_eq!(inv, FIELD.inv(a).unwrap());
+    }
+}
+
+#[test]
+fn constant_time_select_and_edges() {
+    assert_eq!(constant_time::select(1, 7, 9), 7);
+    assert_eq!(constant_time::select(0, 7, 9), 9);
+    assert_eq!(constant_time::select(1, u64::MAX, 0), u64::MAX);
+
+    let p = GOLDILOCKS.field.p;
+    assert_eq!(constant_time::reduce(u64::MAX, p), u64::MAX % p);
+    assert_eq!(constant_time::add(p - 1, p - 1, p), p - 2);
+    assert_eq!(constant_time::sub(0, 1, p), p - 1);
+    assert_eq!(constant_time::pow(5, 0, p), 1);
+    assert_eq!(constant_time::pow(0, 0, p), 1);
+}
+
+// Multi-point evaluation agrees with evaluating one point at a time, whether through
+// `evaluate_slice` or through the shared powers of `evaluate_batch`.
+proptest! {
+    #[test]
+    fn multi_point_evaluation_matches_pointwise(
+        polys in proptest::collection::vec(arb_polynomial(&FIELD, 12), 0..5),
+        points in proptest::collection::vec(any::<u64>(), 0..8),
+    ) {
+        let batch = Polynomial::evaluate_batch(&polys, &points).unwrap();
+        prop_assert_eq!(batch.len(), polys.len());
+        for (poly, values) in polys.iter().zip(&batch) {
+            let pointwise: Vec<u64> = points.iter().map(|&x| poly.evaluate(x)).collect();
+            prop_assert_eq!(&poly.evaluate_slice(&points), &pointwise);
+            prop_assert_eq!(values, &pointwise);
+        }
+    }
+}
+
+// Batch evaluation needs every polynomial over one field, since the powers are shared.
+#[test]
+fn batch_evaluation_rejects_mixed_fields() {
+    let polys = [
+        Polynomial::new(vec![1, 2], FIELD),
+        Polynomial::new(vec![1, 2], PrimeField::new(7).unwrap()),
+    ];
+    assert_eq!(
+        Polynomial::evaluate_batch(&polys, &[3]).unwrap_err(),
+        ToolkitError::FieldMismatch
+    );
+}
@@ -0,0 +1,64 @@
+//! Zerofiers (vanishing polynomials).
+//!
+//! A zerofier for a set of points `{a_0, ..., a_k}` is the lowest-degree monic polynomial that
+//! vanishes on all of them, `Z(x) = (x - a_0)(x - a_1)...(x - a_k)`. Constraint systems divide by
+//! zerofiers to prove that a constraint holds on a set: `C(x) / Z(x)` is a polynomial iff `C`
+//! vanishes on every `a_i`.
+//!
+//! For a multiplicative subgroup `H = {1, w, ..., w^(n-1)}` of order `n` the zerofier has the
+//! sparse closed form `x^n - 1`. Constraints that must hold on all of `H` *except* a few points
+//! (for example the last rows of a trace) use `(x^n - 1) / ((x - a_0)...(x - a_k))`, built by
+//! [`vanishing_except`].
+
+use crate::error::ToolkitError;
+use crate::field::PrimeField;
+use crate::polynomial::Polynomial;
+
+/// Builds `Z(x) = (x - a_0)(x - a_1)...(x - a_k)` for the given points.
+///
+/// The empty product is the constant polynomial `1`.
+pub fn zerofier(field: &PrimeField, points: &[u64]) -> Polynomial {
+    // Multiply by one linear factor at a time: (c_0 + c_1 x + ...) * (x - a).
+    let coeffs = points.iter().fold(vec![1], |coeffs, &a| {
+        let a = a % field.p;
+        let mut next = vec![0; coeffs.len() + 1];
+        for (i, &c) in coeffs.iter().enumerate() {
+            next[i + 1] = field.add(next[i + 1], c);
+            next[i] = field.sub(next[i], field.mul(a, c));
+        }
+        next
+    });
+    Polynomial::new(coeffs, field.clone())
+}
+
+/// Builds the vanishing polynomial `x^n - 1` of the multiplicative subgroup of order `n`.
+pub fn vanishing_polynomial(field: &PrimeField, n: usize) -> Polynomial {
+    let mut coeffs = vec![0; n + 1];
+    coeffs[0] = field.neg(1);
+    coeffs[n] = field.add(coeffs[n], 1);
+    Polynomial::new(coeffs, field.clone())
+}
+
+/// Builds `(x^n - 1) / ((x - a_0)...(x - a_k))`, the polynomial vanishing on the order-`n`
+/// subgroup everywhere except at the given points.
+///
+/// Instead of building the full zerofier and running a general long division, each linear factor
+/// is divided out with synthetic division in O(n), for O(n * k) total.
+///
+/// Returns [`ToolkitError::PointNotARoot`] if a point is not a root of what remains to be
+/// divided, i.e. it is not in the subgroup or appears more than once.
+pub fn vanishing_except(
+    field: &PrimeField,
+    n: usize,
+    points: &[u64],
+) -> Result<Polynomial, ToolkitError> {
+    points
+        .iter()
+        .try_fold(vanishing_polynomial(field, n), |poly, &a| {
+            let (quotient, remainder) = poly.ruffini_division(a);
+            if remainder != 0 {
+                return Err(ToolkitError::PointNotARoot(a));
+            }
+            Ok(quotient)
+        })
+}
@@ -0,0 +1,100 @@
+//! This module contains benchmarks running the same multiplication algorithms over the 31-bit
+//! Babybear field and the 64-bit Goldilocks field, to compare their throughput:
+//!
+//! ```text
+//! cargo bench --bench field_comparison
+//! ```
+//!
+//! Benchmarks are implemented using the `criterion` crate.
+
+use criterion::measurement::WallTime;
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion,
+};
+use fast_polynomial_arithmetic::{
+    multiply_polynomials_fft_cached, multiply_polynomials_karatsuba, multiply_polynomials_naive,
+    strategies, GoldilocksF, TwiddleCache, F,
+};
+use lambdaworks_math::field::traits::IsFFTField;
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+/// The naive algorithm is only run up to this degree, past which it dwarfs the others.
+const NAIVE_MAX_DEGREE: usize = 1024;
+
+/// Benchmarks the naive, Karatsuba and FFT multiplications of degree `deg` polynomials over `Fld`,
+/// with the benchmark names prefixed by `field`.
+fn bench_field<Fld: IsFFTField>(
+    group: &mut BenchmarkGroup<WallTime>,
+    runner: &mut TestRunner,
+    field: &str,
+    deg: usize,
+) {
+    let strategy = (
+        strategies::arb_polynomial::<Fld>(deg),
+        strategies::arb_polynomial::<Fld>(deg),
+    );
+    // The twiddles are shared across iterations, so only the multiplication itself is timed.
+    let mut cache = TwiddleCache::<Fld>::new();
+
+    if deg <= NAIVE_MAX_DEGREE {
+        group.bench_with_input(
+            BenchmarkId::new(format!("{field} Naive"), deg),
+            &deg,
+            |b, _| {
+                b.iter_batched(
+                    || strategy.new_tree(runner).unwrap().current(),
+                    |(p1, p2)| black_box(multiply_polynomials_naive(&p1, &p2)),
+                    criterion::BatchSize::SmallInput,
+                );
+            },
+        );
+    }
+
+    group.bench_with_input(
+        BenchmarkId::new(format!("{field} Karatsuba"), deg),
+        &deg,
+        |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply_polynomials_karatsuba(&p1, &p2)),
+                criterion::BatchSize::SmallInput,
+            );
+        },
+    );
+
+    group.bench_with_input(
+        BenchmarkId::new(format!("{field} FFT"), deg),
+        &deg,
+        |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(runner).unwrap().current(),
+                |(p1, p2)| {
+                    black_box(multiply_polynomials_fft_cached(&p1, &p2, &mut cache).unwrap())
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        },
+    );
+}
+
+// --- Field Comparison Benchmarks ---
+
+fn field_comparison_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Babybear vs Goldilocks Multiplication");
+
+    let mut runner = TestRunner::default();
+
+    let degrees = [64, 256, 1024, 4096, 16384];
+
+    for &deg in degrees.iter() {
+        bench_field::<F>(&mut group, &mut runner, "Babybear", deg);
+        bench_field::<GoldilocksF>(&mut group, &mut runner, "Goldilocks", deg);
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, field_comparison_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+//! This module contains benchmarks for GPU FFT multiplication against the CPU FFT on the large
+//! domains where the GPU is expected to win. It only builds with the `gpu` feature enabled:
+//!
+//! ```text
+//! cargo bench --features gpu --bench gpu_multiplication
+//! ```
+//!
+//! Benchmarks are implemented using the `criterion` crate.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fast_polynomial_arithmetic::{multiply_polynomials_fft_auto, strategies, GpuMultiplier};
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+fn gpu_multiplication_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("GPU FFT Multiplication");
+    group.sample_size(10);
+
+    let gpu = GpuMultiplier::new().expect("The GPU benchmarks need a GPU adapter.");
+    let mut runner = TestRunner::default();
+
+    // Products of 2^18 to 2^23 coefficients, i.e. domains of 2^18 to 2^23 points.
+    let degrees = [1 << 17, 1 << 19, 1 << 21, 1 << 22];
+
+    for &deg in degrees.iter() {
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+        );
+
+        group.bench_with_input(BenchmarkId::new("GPU", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(gpu.multiply(&p1, &p2).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("CPU FFT", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply_polynomials_fft_auto(&p1, &p2).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default();
+    targets = gpu_multiplication_benchmark
+}
+criterion_main!(benches);
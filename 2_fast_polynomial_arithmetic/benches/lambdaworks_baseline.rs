@@ -0,0 +1,177 @@
+//! This module contains benchmarks comparing the crate's multiplication algorithms with the
+//! `lambdaworks_math` baselines, the `Polynomial` `*` operator and `mul_with_ref`. After the
+//! measurements it writes their medians as CSV, so regressions against upstream show up in a diff:
+//!
+//! ```text
+//! cargo bench --bench lambdaworks_baseline
+//! # -> target/criterion/lambdaworks_baseline.csv
+//! function,degree,median_ns,mean_ns
+//! lambdaworks *,64,...
+//! ```
+//!
+//! The CSV is read back from criterion's own output, which lives in `$CRITERION_HOME`, or in
+//! `criterion/` under `$CARGO_TARGET_DIR` or the workspace `target/` directory.
+//!
+//! Benchmarks are implemented using the `criterion` crate.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
+use fast_polynomial_arithmetic::{
+    multiply, multiply_polynomials_fft_cached, multiply_polynomials_karatsuba, strategies,
+    TwiddleCache,
+};
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+/// The criterion group holding the comparison, whose results the CSV summary collects.
+const GROUP_NAME: &str = "Lambdaworks Baseline Comparison";
+
+// --- Baseline Benchmarks ---
+
+fn lambdaworks_baseline_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group(GROUP_NAME);
+
+    let mut runner = TestRunner::default();
+    let mut cache = TwiddleCache::<Babybear31PrimeField>::new();
+
+    let degrees = [16, 64, 256, 1024, 4096];
+
+    for &deg in degrees.iter() {
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+        );
+
+        group.bench_with_input(BenchmarkId::new("lambdaworks *", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(p1 * p2),
+                criterion::BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("lambdaworks mul_with_ref", deg),
+            &deg,
+            |b, _| {
+                b.iter_batched(
+                    || strategy.new_tree(&mut runner).unwrap().current(),
+                    |(p1, p2)| black_box(p1.mul_with_ref(&p2)),
+                    criterion::BatchSize::SmallInput,
+                );
+            },
+        );
+
+        group.bench_with_input(BenchmarkId::new("Karatsuba", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply_polynomials_karatsuba(&p1, &p2)),
+                criterion::BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("FFT", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| {
+                    black_box(multiply_polynomials_fft_cached(&p1, &p2, &mut cache).unwrap())
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("Adaptive", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply(&p1, &p2)),
+                criterion::BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+// --- CSV Summary ---
+
+/// Returns the directory criterion writes its measurements to, resolved as criterion does.
+fn criterion_home() -> PathBuf {
+    if let Some(home) = std::env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../target"));
+    target.join("criterion")
+}
+
+/// Returns one `(function, degree, median_ns, mean_ns)` row for every benchmark of `GROUP_NAME`
+/// found below `dir`, from the `benchmark.json` and `estimates.json` of each latest run.
+fn collect_rows(dir: &Path, rows: &mut Vec<(String, usize, f64, f64)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.file_name().is_some_and(|name| name == "new") {
+            let read = |file: &str| -> Option<serde_json::Value> {
+                serde_json::from_str(&fs::read_to_string(path.join(file)).ok()?).ok()
+            };
+            let (Some(benchmark), Some(estimates)) =
+                (read("benchmark.json"), read("estimates.json"))
+            else {
+                continue;
+            };
+            if benchmark["group_id"].as_str() != Some(GROUP_NAME) {
+                continue;
+            }
+            let function = benchmark["function_id"].as_str().unwrap_or_default();
+            let degree = benchmark["value_str"].as_str().and_then(|v| v.parse().ok());
+            let median = estimates["median"]["point_estimate"].as_f64();
+            let mean = estimates["mean"]["point_estimate"].as_f64();
+            if let (Some(degree), Some(median), Some(mean)) = (degree, median, mean) {
+                rows.push((function.to_string(), degree, median, mean));
+            }
+        } else {
+            collect_rows(&path, rows);
+        }
+    }
+}
+
+/// Writes the medians of the comparison to `lambdaworks_baseline.csv` in the criterion directory.
+fn write_csv_summary() {
+    let home = criterion_home();
+    let mut rows = Vec::new();
+    collect_rows(&home, &mut rows);
+    if rows.is_empty() {
+        // Nothing was measured, e.g. when the benchmarks only ran as tests.
+        return;
+    }
+    rows.sort_by(|a, b| (&a.0, a.1).cmp(&(&b.0, b.1)));
+
+    let mut csv = String::from("function,degree,median_ns,mean_ns\n");
+    for (function, degree, median, mean) in &rows {
+        csv.push_str(&format!("{function},{degree},{median:.1},{mean:.1}\n"));
+    }
+    let path = home.join("lambdaworks_baseline.csv");
+    match fs::write(&path, csv) {
+        Ok(()) => println!("Wrote {} results to {}", rows.len(), path.display()),
+        Err(e) => eprintln!("Could not write {}: {}", path.display(), e),
+    }
+}
+
+criterion_group!(benches, lambdaworks_baseline_benchmark);
+
+// `criterion_main!` with the CSV summary added once every benchmark has run.
+fn main() {
+    benches();
+    Criterion::default().configure_from_args().final_summary();
+    write_csv_summary();
+}
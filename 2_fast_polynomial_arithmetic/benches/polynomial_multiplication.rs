@@ -1,15 +1,25 @@
 //! This module contains benchmarks for polynomial multiplication
-//! using both FFT-based and naive algorithms.
+//! using the FFT-based, Toom-Cook-3, Karatsuba, and naive algorithms, and for the specialized
+//! squaring routines against generic multiplication, the radix-4 FFT against radix-2, scratch
+//! buffer reuse against allocating multiplication, the inverse FFT with `1/N` folded into its
+//! twiddles against a separate scaling pass, the bit-reversal-free FFT pipeline against the one
+//! with a permutation after every transform, batched multiplication against one call per pair,
+//! sparse-dense multiplication against the FFT, and fast multipoint evaluation against repeated
+//! Horner evaluation.
 //!
 //! Benchmarks are implemented using the `criterion` crate.
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use fast_polynomial_arithmetic::{
-    multiply_polynomials_fft, multiply_polynomials_naive, strategies,
+    evaluate_at_points_fast, multiply_batch, multiply_polynomials_fft,
+    multiply_polynomials_fft_auto, multiply_polynomials_fft_into, multiply_polynomials_fft_scaled,
+    multiply_polynomials_fft_with_permutes, multiply_polynomials_fft_with_radix,
+    multiply_polynomials_karatsuba, multiply_polynomials_naive, multiply_polynomials_toom3,
+    multiply_sparse_dense, required_domain_size, square_polynomial_fft, square_polynomial_naive,
+    strategies, FftPlan, FftRadix, FftScratch, SparsePoly,
 };
-use lambdaworks_math::fft::cpu::roots_of_unity::get_twiddles;
+use lambdaworks_math::field::element::FieldElement;
 use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
-use lambdaworks_math::field::traits::RootsConfig;
 use proptest::prelude::*;
 use proptest::strategy::ValueTree;
 use proptest::test_runner::TestRunner;
@@ -35,35 +45,49 @@ fn polynomial_multiplication_benchmark(c: &mut Criterion) {
         // Benchmark FFT multiplication
         group.bench_with_input(BenchmarkId::new("FFT", deg), &deg, |b, &deg_val| {
             let strategy = (
-                strategies::arb_polynomial(deg_val),
-                strategies::arb_polynomial(deg_val),
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
             );
             b.iter_batched(
                 || {
                     let (p1, p2) = strategy.new_tree(&mut runner).unwrap().current();
-                    let min_domain_size = p1.degree() + p2.degree() + 1;
-                    let n = strategies::next_power_of_2(min_domain_size);
-                    let twiddles = get_twiddles::<Babybear31PrimeField>(
-                        n.trailing_zeros() as u64,
-                        RootsConfig::BitReverse,
-                    )
-                    .unwrap();
-                    let inv_twiddles = get_twiddles::<Babybear31PrimeField>(
-                        n.trailing_zeros() as u64,
-                        RootsConfig::BitReverseInversed,
-                    )
-                    .unwrap();
-                    (p1, p2, n, twiddles, inv_twiddles)
+                    let n = required_domain_size(&p1, &p2);
+                    let plan = FftPlan::new(n.trailing_zeros() as u64).unwrap();
+                    (p1, p2, plan)
                 },
-                |(p1, p2, n, twiddles, inv_twiddles)| {
-                    black_box(multiply_polynomials_fft(
-                        &p1,
-                        &p2,
-                        n,
-                        &twiddles,
-                        &inv_twiddles,
-                    ))
+                |(p1, p2, plan)| black_box(multiply_polynomials_fft(&p1, &p2, &plan).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+
+        // Benchmark Toom-Cook-3 multiplication
+        group.bench_with_input(BenchmarkId::new("Toom-3", deg), &deg, |b, &deg_val| {
+            let strategy = (
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
+            );
+            b.iter_batched(
+                || {
+                    let (p1, p2) = strategy.new_tree(&mut runner).unwrap().current();
+                    (p1, p2)
                 },
+                |(p1, p2)| black_box(multiply_polynomials_toom3(&p1, &p2)),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+
+        // Benchmark Karatsuba multiplication
+        group.bench_with_input(BenchmarkId::new("Karatsuba", deg), &deg, |b, &deg_val| {
+            let strategy = (
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
+            );
+            b.iter_batched(
+                || {
+                    let (p1, p2) = strategy.new_tree(&mut runner).unwrap().current();
+                    (p1, p2)
+                },
+                |(p1, p2)| black_box(multiply_polynomials_karatsuba(&p1, &p2)),
                 criterion::BatchSize::LargeInput,
             );
         });
@@ -71,8 +95,8 @@ fn polynomial_multiplication_benchmark(c: &mut Criterion) {
         // Benchmark Naive multiplication
         group.bench_with_input(BenchmarkId::new("Naive", deg), &deg, |b, &deg_val| {
             let strategy = (
-                strategies::arb_polynomial(deg_val),
-                strategies::arb_polynomial(deg_val),
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
             );
             b.iter_batched(
                 || {
@@ -83,6 +107,372 @@ fn polynomial_multiplication_benchmark(c: &mut Criterion) {
                 criterion::BatchSize::LargeInput,
             );
         });
+
+        // Benchmark Naive multiplication over all cores
+        #[cfg(feature = "parallel")]
+        group.bench_with_input(
+            BenchmarkId::new("Naive (parallel)", deg),
+            &deg,
+            |b, &deg_val| {
+                let strategy = (
+                    strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
+                    strategies::arb_polynomial::<Babybear31PrimeField>(deg_val),
+                );
+                b.iter_batched(
+                    || strategy.new_tree(&mut runner).unwrap().current(),
+                    |(p1, p2)| {
+                        black_box(
+                            fast_polynomial_arithmetic::multiply_polynomials_naive_parallel(
+                                &p1, &p2,
+                            ),
+                        )
+                    },
+                    criterion::BatchSize::LargeInput,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+// --- Squaring Benchmarks ---
+
+fn polynomial_squaring_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Polynomial Squaring Comparison");
+
+    let mut runner = TestRunner::default();
+
+    let degrees = [10, 50, 100, 250, 500, 1000, 2000, 3000];
+
+    for &deg in degrees.iter() {
+        // Benchmark FFT multiplication of a polynomial by itself against FFT squaring; both use
+        // the same domain and twiddles.
+        let setup = |runner: &mut TestRunner, deg_val: usize| {
+            let p = strategies::arb_polynomial::<Babybear31PrimeField>(deg_val)
+                .new_tree(runner)
+                .unwrap()
+                .current();
+            let plan = FftPlan::for_product(&p, &p).unwrap();
+            (p, plan)
+        };
+
+        group.bench_with_input(
+            BenchmarkId::new("FFT multiply", deg),
+            &deg,
+            |b, &deg_val| {
+                b.iter_batched(
+                    || setup(&mut runner, deg_val),
+                    |(p, plan)| black_box(multiply_polynomials_fft(&p, &p, &plan).unwrap()),
+                    criterion::BatchSize::LargeInput,
+                );
+            },
+        );
+
+        group.bench_with_input(BenchmarkId::new("FFT square", deg), &deg, |b, &deg_val| {
+            b.iter_batched(
+                || setup(&mut runner, deg_val),
+                |(p, plan)| black_box(square_polynomial_fft(&p, &plan).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+
+        // Benchmark naive multiplication of a polynomial by itself against naive squaring.
+        group.bench_with_input(
+            BenchmarkId::new("Naive multiply", deg),
+            &deg,
+            |b, &deg_val| {
+                let strategy = strategies::arb_polynomial::<Babybear31PrimeField>(deg_val);
+                b.iter_batched(
+                    || strategy.new_tree(&mut runner).unwrap().current(),
+                    |p| black_box(multiply_polynomials_naive(&p, &p)),
+                    criterion::BatchSize::LargeInput,
+                );
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("Naive square", deg),
+            &deg,
+            |b, &deg_val| {
+                let strategy = strategies::arb_polynomial::<Babybear31PrimeField>(deg_val);
+                b.iter_batched(
+                    || strategy.new_tree(&mut runner).unwrap().current(),
+                    |p| black_box(square_polynomial_naive(&p)),
+                    criterion::BatchSize::LargeInput,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+// --- FFT Radix Benchmarks ---
+
+fn fft_radix_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FFT Radix Comparison");
+
+    let mut runner = TestRunner::default();
+
+    // Large domains, with both even and odd log2(n), where the saved twiddle multiplications
+    // matter most.
+    let degrees = [1000, 2000, 4000, 8000, 16000, 32000];
+
+    for &deg in degrees.iter() {
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+        );
+
+        for (name, radix) in [("Radix-2", FftRadix::Radix2), ("Radix-4", FftRadix::Radix4)] {
+            group.bench_with_input(BenchmarkId::new(name, deg), &deg, |b, _| {
+                b.iter_batched(
+                    || strategy.new_tree(&mut runner).unwrap().current(),
+                    |(p1, p2)| {
+                        black_box(multiply_polynomials_fft_with_radix(&p1, &p2, radix).unwrap())
+                    },
+                    criterion::BatchSize::LargeInput,
+                );
+            });
+        }
+    }
+
+    group.finish();
+}
+
+// --- Buffer Reuse Benchmarks ---
+
+fn fft_buffer_reuse_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FFT Buffer Reuse Comparison");
+
+    let mut runner = TestRunner::default();
+
+    // Small and medium sizes, where allocation is a large share of the cost.
+    let degrees = [8, 16, 32, 64, 128, 256, 512, 1024];
+
+    for &deg in degrees.iter() {
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+        );
+
+        group.bench_with_input(BenchmarkId::new("Allocating", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply_polynomials_fft_auto(&p1, &p2).unwrap()),
+                criterion::BatchSize::SmallInput,
+            );
+        });
+
+        let mut scratch = FftScratch::new();
+        let mut out = Vec::new();
+        group.bench_with_input(BenchmarkId::new("Scratch reuse", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| {
+                    multiply_polynomials_fft_into(&p1, &p2, &mut scratch, &mut out).unwrap();
+                    black_box(out.len())
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+// --- Inverse Scaling Benchmarks ---
+
+fn fft_inverse_scaling_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FFT Inverse Scaling Comparison");
+
+    let mut runner = TestRunner::default();
+
+    let degrees = [64, 256, 1024, 4096, 16384];
+
+    for &deg in degrees.iter() {
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+        );
+        // Both variants share the domain and the precomputed tables, so only the inverse differs.
+        let log_n = (2 * (deg + 1).next_power_of_two()).trailing_zeros() as u64;
+        let plan = FftPlan::<Babybear31PrimeField>::new(log_n).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("Separate 1/N pass", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply_polynomials_fft(&p1, &p2, &plan).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("Scaled twiddles", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply_polynomials_fft_scaled(&p1, &p2, &plan).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+// --- Bit-Reversal Benchmarks ---
+
+fn fft_bit_reversal_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FFT Bit-Reversal Comparison");
+
+    let mut runner = TestRunner::default();
+
+    let degrees = [64, 256, 1024, 4096, 16384];
+
+    for &deg in degrees.iter() {
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+        );
+        // Both pipelines share the domain and the twiddles, so only the permutations differ.
+        let log_n = (2 * (deg + 1).next_power_of_two()).trailing_zeros() as u64;
+        let plan = FftPlan::<Babybear31PrimeField>::new(log_n).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("With permutations", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| {
+                    black_box(multiply_polynomials_fft_with_permutes(&p1, &p2, &plan).unwrap())
+                },
+                criterion::BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("Bit-reversal free", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply_polynomials_fft(&p1, &p2, &plan).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+// --- Batch Multiplication Benchmarks ---
+
+fn batch_multiplication_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Batch Multiplication Comparison");
+
+    let mut runner = TestRunner::default();
+
+    // A prover-like workload: many quotients over the same domain.
+    let batch_size = 64;
+    let degrees = [64, 256, 1024, 4096];
+
+    for &deg in degrees.iter() {
+        let strategy = proptest::collection::vec(
+            (
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+                strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+            ),
+            batch_size,
+        );
+
+        group.bench_with_input(BenchmarkId::new("FFT per pair", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |pairs| {
+                    for (p1, p2) in &pairs {
+                        black_box(multiply_polynomials_fft_auto(p1, p2).unwrap());
+                    }
+                },
+                criterion::BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("Batched FFT", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |pairs| black_box(multiply_batch(&pairs).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+// --- Sparse Multiplication Benchmarks ---
+
+fn sparse_multiplication_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Sparse Multiplication Comparison");
+
+    let mut runner = TestRunner::default();
+
+    let degrees = [64, 256, 1024, 4096, 16384];
+
+    for &deg in degrees.iter() {
+        let strategy = strategies::arb_polynomial::<Babybear31PrimeField>(deg);
+        // The vanishing polynomial x^n - 1 of a subgroup as large as the dense operand.
+        let vanishing = SparsePoly::new(&[
+            (0, -FieldElement::<Babybear31PrimeField>::one()),
+            (deg, FieldElement::one()),
+        ]);
+        let vanishing_dense = vanishing.to_dense();
+
+        group.bench_with_input(BenchmarkId::new("FFT", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |p| black_box(multiply_polynomials_fft_auto(&vanishing_dense, &p).unwrap()),
+                criterion::BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("Sparse", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |p| black_box(multiply_sparse_dense(&vanishing, &p).unwrap()),
+                criterion::BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+// --- Multipoint Evaluation Benchmarks ---
+
+fn multipoint_evaluation_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Multipoint Evaluation Comparison");
+
+    let mut runner = TestRunner::default();
+
+    // A polynomial of degree `n - 1` evaluated at `n` points.
+    let sizes = [256, 512, 1024, 2048, 4096, 8192];
+
+    for &n in sizes.iter() {
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(n - 1),
+            prop::collection::vec(any::<u64>().prop_map(FieldElement::from), n),
+        );
+
+        group.bench_with_input(BenchmarkId::new("Remainder tree", n), &n, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(poly, points)| black_box(evaluate_at_points_fast(&poly, &points)),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("Horner", n), &n, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(poly, points)| black_box(poly.evaluate_slice(&points)),
+                criterion::BatchSize::LargeInput,
+            );
+        });
     }
 
     group.finish();
@@ -91,6 +481,15 @@ fn polynomial_multiplication_benchmark(c: &mut Criterion) {
 criterion_group! {
     name = benches;
     config = Criterion::default();
-    targets = polynomial_multiplication_benchmark
+    targets =
+        polynomial_multiplication_benchmark,
+        polynomial_squaring_benchmark,
+        fft_radix_benchmark,
+        fft_buffer_reuse_benchmark,
+        fft_inverse_scaling_benchmark,
+        fft_bit_reversal_benchmark,
+        batch_multiplication_benchmark,
+        sparse_multiplication_benchmark,
+        multipoint_evaluation_benchmark
 }
 criterion_main!(benches);
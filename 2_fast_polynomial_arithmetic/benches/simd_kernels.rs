@@ -0,0 +1,118 @@
+//! This module contains benchmarks for the SIMD Babybear kernels against their scalar
+//! counterparts, and for the SIMD NTT multiplication against the lambdaworks FFT path. It only
+//! builds with the `simd` feature enabled:
+//!
+//! ```text
+//! cargo bench --features simd --bench simd_kernels
+//! ```
+//!
+//! Benchmarks are implemented using the `criterion` crate.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fast_polynomial_arithmetic::simd::{
+    butterfly, butterfly_scalar, pointwise_mul, pointwise_mul_scalar, P,
+};
+use fast_polynomial_arithmetic::{
+    multiply_polynomials_fft_auto, multiply_polynomials_simd, strategies,
+};
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+// --- Kernel Benchmarks ---
+
+fn kernel_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("SIMD Kernel Comparison");
+
+    let mut runner = TestRunner::default();
+
+    let sizes = [1 << 10, 1 << 14, 1 << 18];
+
+    for &n in sizes.iter() {
+        let strategy = (
+            prop::collection::vec(0..P, n),
+            prop::collection::vec(0..P, n),
+            prop::collection::vec(0..P, n),
+        );
+
+        for (name, kernel) in [
+            (
+                "Butterfly (SIMD)",
+                butterfly as fn(&mut [u32], &mut [u32], &[u32]),
+            ),
+            ("Butterfly (scalar)", butterfly_scalar),
+        ] {
+            group.bench_with_input(BenchmarkId::new(name, n), &n, |b, _| {
+                b.iter_batched(
+                    || strategy.new_tree(&mut runner).unwrap().current(),
+                    |(mut lo, mut hi, twiddles)| {
+                        kernel(&mut lo, &mut hi, &twiddles);
+                        black_box((lo, hi))
+                    },
+                    criterion::BatchSize::LargeInput,
+                );
+            });
+        }
+
+        for (name, kernel) in [
+            ("Pointwise (SIMD)", pointwise_mul as fn(&mut [u32], &[u32])),
+            ("Pointwise (scalar)", pointwise_mul_scalar),
+        ] {
+            group.bench_with_input(BenchmarkId::new(name, n), &n, |b, _| {
+                b.iter_batched(
+                    || strategy.new_tree(&mut runner).unwrap().current(),
+                    |(mut a, b, _)| {
+                        kernel(&mut a, &b);
+                        black_box(a)
+                    },
+                    criterion::BatchSize::LargeInput,
+                );
+            });
+        }
+    }
+
+    group.finish();
+}
+
+// --- Multiplication Benchmarks ---
+
+fn simd_multiplication_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("SIMD NTT Multiplication");
+
+    let mut runner = TestRunner::default();
+
+    let degrees = [1000, 4000, 16000, 64000];
+
+    for &deg in degrees.iter() {
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+        );
+
+        group.bench_with_input(BenchmarkId::new("SIMD NTT", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply_polynomials_simd(&p1, &p2).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("lambdaworks FFT", deg), &deg, |b, _| {
+            b.iter_batched(
+                || strategy.new_tree(&mut runner).unwrap().current(),
+                |(p1, p2)| black_box(multiply_polynomials_fft_auto(&p1, &p2).unwrap()),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default();
+    targets = kernel_benchmark, simd_multiplication_benchmark
+}
+criterion_main!(benches);
@@ -0,0 +1,59 @@
+//! Fuzzes the FFT and Karatsuba multiplications against the naive one.
+//!
+//! The input bytes are decoded into two coefficient vectors, so the fuzzer reaches the shapes the
+//! uniform proptest strategies rarely produce: empty operands, trailing zero coefficients, and
+//! products whose length lands exactly on, or one past, a power of two.
+//!
+//! ```text
+//! cargo +nightly fuzz run multiplication_equivalence
+//! ```
+
+#![no_main]
+
+use fast_polynomial_arithmetic::{
+    multiply_polynomials_fft_auto, multiply_polynomials_karatsuba, multiply_polynomials_naive, FE,
+};
+use lambdaworks_math::polynomial::Polynomial;
+use libfuzzer_sys::fuzz_target;
+
+/// Bytes decoded into each coefficient, reduced modulo the field order.
+const COEFF_BYTES: usize = 4;
+
+/// Splits `data` into two coefficient vectors. The first byte gives the number of coefficients of
+/// the first operand, capped by what remains, and every other coefficient goes to the second.
+fn decode(data: &[u8]) -> (Vec<FE>, Vec<FE>) {
+    let Some((&len, rest)) = data.split_first() else {
+        return (Vec::new(), Vec::new());
+    };
+    let coeffs: Vec<FE> = rest
+        .chunks_exact(COEFF_BYTES)
+        .map(|chunk| {
+            let bytes: [u8; COEFF_BYTES] = chunk.try_into().unwrap();
+            FE::from(u32::from_le_bytes(bytes) as u64)
+        })
+        .collect();
+    let split = (len as usize).min(coeffs.len());
+    (coeffs[..split].to_vec(), coeffs[split..].to_vec())
+}
+
+fuzz_target!(|data: &[u8]| {
+    let (a, b) = decode(data);
+    // The operands are built directly, so trailing zeros are not trimmed away.
+    let p1 = Polynomial { coefficients: a };
+    let p2 = Polynomial { coefficients: b };
+
+    let expected_poly = Polynomial::new(&multiply_polynomials_naive(&p1, &p2).coefficients);
+    let karatsuba_poly = multiply_polynomials_karatsuba(&p1, &p2);
+    let fft_poly = multiply_polynomials_fft_auto(&p1, &p2).unwrap();
+
+    assert_eq!(
+        Polynomial::new(&karatsuba_poly.coefficients).coefficients,
+        expected_poly.coefficients,
+        "Karatsuba and Naive results differ!"
+    );
+    assert_eq!(
+        Polynomial::new(&fft_poly.coefficients).coefficients,
+        expected_poly.coefficients,
+        "FFT and Naive results differ!"
+    );
+});
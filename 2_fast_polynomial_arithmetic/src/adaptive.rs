@@ -0,0 +1,90 @@
+//! Adaptive multiplication that picks an algorithm from the operand sizes.
+//!
+//! Each algorithm wins in a different size range: the naive method has no overhead and is fastest
+//! for short operands, Karatsuba takes over once its recursion pays for itself, and the FFT wins
+//! for long operands despite the cost of the transforms. [`multiply`] dispatches on the shorter
+//! operand's length, since that bounds how much any of the fast algorithms can save.
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::karatsuba::KARATSUBA_THRESHOLD;
+use crate::{
+    multiply_polynomials_fft_cached, multiply_polynomials_karatsuba, multiply_polynomials_naive,
+    DomainPlan, TwiddleCache,
+};
+
+/// The multiplication algorithms [`multiply`] chooses between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Naive,
+    Karatsuba,
+    Fft,
+}
+
+/// Crossover points, in coefficients of the shorter operand, between the algorithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThresholdTable {
+    /// Operands shorter than this use the naive method.
+    pub karatsuba: usize,
+    /// Operands at least this long use the FFT.
+    pub fft: usize,
+}
+
+impl Default for ThresholdTable {
+    /// Conservative defaults for Babybear; use [`tune`](crate::tune::tune) or run the
+    /// `polynomial_multiplication` benchmark to find the crossovers on a given machine.
+    fn default() -> Self {
+        Self {
+            karatsuba: KARATSUBA_THRESHOLD,
+            fft: 256,
+        }
+    }
+}
+
+impl ThresholdTable {
+    /// Returns the algorithm to use for operands with `len1` and `len2` coefficients.
+    pub fn select(&self, len1: usize, len2: usize) -> Algorithm {
+        let shorter = len1.min(len2);
+        if shorter < self.karatsuba {
+            Algorithm::Naive
+        } else if shorter < self.fft {
+            Algorithm::Karatsuba
+        } else {
+            Algorithm::Fft
+        }
+    }
+}
+
+/// Multiplies two polynomials with the algorithm [`ThresholdTable::default`] selects for their
+/// sizes.
+pub fn multiply<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    multiply_with_thresholds(p1, p2, &ThresholdTable::default())
+}
+
+/// Multiplies two polynomials with the algorithm `thresholds` selects for their sizes.
+///
+/// If the FFT is selected but the product is too large for the field's two-adicity, Karatsuba is
+/// used instead, so this never fails.
+pub fn multiply_with_thresholds<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    thresholds: &ThresholdTable,
+) -> Polynomial<FieldElement<F>> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Polynomial::new(&[]);
+    }
+
+    match thresholds.select(p1.coefficients.len(), p2.coefficients.len()) {
+        Algorithm::Naive => multiply_polynomials_naive(p1, p2),
+        Algorithm::Fft if DomainPlan::new(p1, p2).log_size() <= F::TWO_ADICITY => {
+            multiply_polynomials_fft_cached(p1, p2, &mut TwiddleCache::new())
+                .expect("the domain fits in the field's two-adicity")
+        }
+        Algorithm::Karatsuba | Algorithm::Fft => multiply_polynomials_karatsuba(p1, p2),
+    }
+}
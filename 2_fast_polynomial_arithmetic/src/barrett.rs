@@ -0,0 +1,126 @@
+//! Barrett reduction modulo a fixed polynomial.
+//!
+//! Arithmetic in a quotient ring `F[x]/(m(x))` reduces every product modulo the same `m` of degree
+//! `d`. Fast division spends most of its time inverting `rev_d(m)` as a power series, and that
+//! inverse depends only on `m`, so Barrett reduction computes it once. A polynomial `f` with at
+//! most `2d` coefficients, so of degree `n < 2d`, then costs two short products:
+//!
+//! ```text
+//! inv     = rev_d(m)^-1 mod x^d                (once per modulus)
+//! rev(q)  = rev_n(f) inv mod x^(n-d+1)
+//! f mod m = (f - q m) mod x^d
+//! ```
+//!
+//! The remainder has degree below `d`, so only the low `d` coefficients of `q m` are needed. A
+//! longer `f` is folded in from the top, `d` coefficients at a time: the running remainder times
+//! `x^d` plus the next chunk again has at most `2d` coefficients, for O(len(f) log d) in total.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::division::reversed;
+use crate::power_series::{inverse_mod_xn, truncated};
+use crate::{mul_low, multiply, MultiplicationError};
+
+/// A modulus `m` together with the power-series inverse of its reversal, for repeated reduction
+/// modulo `m`.
+#[derive(Debug, Clone)]
+pub struct BarrettModulus<F: IsFFTField> {
+    modulus: Polynomial<FieldElement<F>>,
+    rev_inv: Polynomial<FieldElement<F>>,
+}
+
+impl<F: IsFFTField> BarrettModulus<F> {
+    /// Precomputes the reduction data for `modulus`.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::DivisionByZeroPolynomial`] if `modulus` is the zero
+    /// polynomial.
+    pub fn new(modulus: &Polynomial<FieldElement<F>>) -> Result<Self, MultiplicationError> {
+        // Trim the modulus so its last coefficient is the true leading one.
+        let modulus = Polynomial::new(&modulus.coefficients);
+        if modulus.coefficients.is_empty() {
+            return Err(MultiplicationError::DivisionByZeroPolynomial);
+        }
+        let d = modulus.coefficients.len() - 1;
+        let rev_inv = inverse_mod_xn(&reversed(&modulus), d)
+            .expect("the leading coefficient of the modulus is non-zero");
+        Ok(Self { modulus, rev_inv })
+    }
+
+    /// Returns the modulus `m`, trimmed.
+    pub fn modulus(&self) -> &Polynomial<FieldElement<F>> {
+        &self.modulus
+    }
+
+    /// Returns `p mod m`, of degree less than `degree(m)`.
+    pub fn reduce(&self, p: &Polynomial<FieldElement<F>>) -> Polynomial<FieldElement<F>> {
+        let d = self.modulus.coefficients.len() - 1;
+        let coeffs = &p.coefficients;
+        if d == 0 {
+            // Every polynomial is a multiple of a non-zero constant.
+            return Polynomial::new(&[]);
+        }
+
+        // The top chunk may take 2d coefficients at once; every later one adds d.
+        let mut start = coeffs.len().saturating_sub(2 * d);
+        let mut r = self.reduce_short(&coeffs[start..]);
+        while start > 0 {
+            let end = start;
+            start = end.saturating_sub(d);
+            let mut f = coeffs[start..end].to_vec();
+            f.extend(r);
+            r = self.reduce_short(&f);
+        }
+        Polynomial::new(&r)
+    }
+
+    /// Returns `a * b mod m`.
+    pub fn mul_mod(
+        &self,
+        a: &Polynomial<FieldElement<F>>,
+        b: &Polynomial<FieldElement<F>>,
+    ) -> Polynomial<FieldElement<F>> {
+        self.reduce(&multiply(a, b))
+    }
+
+    /// Reduces the coefficients `f`, of which there are at most `2d`, returning at most `d`
+    /// coefficients.
+    fn reduce_short(&self, f: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
+        let d = self.modulus.coefficients.len() - 1;
+        if f.len() <= d {
+            return f.to_vec();
+        }
+        debug_assert!(
+            f.len() <= 2 * d,
+            "the chunk exceeds the precomputed precision"
+        );
+
+        // 1. rev(q) = rev(f) * inv mod x^k, where k = deg(f) - d + 1.
+        let k = f.len() - d;
+        let rev_f: Vec<FieldElement<F>> = f.iter().rev().cloned().collect();
+        let rev_q = truncated(&mul_low(&Polynomial::new(&rev_f), &self.rev_inv, k), k);
+        let q_coeffs: Vec<FieldElement<F>> = rev_q.into_iter().rev().collect();
+
+        // 2. Only the low d coefficients of q * m survive the subtraction.
+        let qm = truncated(&mul_low(&Polynomial::new(&q_coeffs), &self.modulus, d), d);
+        f.iter().zip(&qm).map(|(a, b)| a - b).collect()
+    }
+}
+
+/// Returns `p mod modulus` by Barrett reduction.
+///
+/// Reducing many polynomials by the same modulus should go through one [`BarrettModulus`], which
+/// computes the inverse of the reversed modulus only once.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DivisionByZeroPolynomial`] if `modulus` is the zero polynomial.
+pub fn reduce_mod<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    modulus: &Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    Ok(BarrettModulus::new(modulus)?.reduce(p))
+}
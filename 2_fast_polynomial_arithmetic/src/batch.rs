@@ -0,0 +1,92 @@
+//! Batched FFT multiplication of many independent pairs.
+//!
+//! A STARK prover computes many quotients at once, most of them over the same trace domain. Calling
+//! [`crate::multiply_polynomials_fft_auto`] for each pair regenerates the twiddles every time;
+//! [`multiply_batch`] instead sorts the pairs by domain size and runs every group of same-size
+//! transforms against one shared set of twiddles:
+//!
+//! ```text
+//! pairs:   (a_0, b_0) n=8   (a_1, b_1) n=16   (a_2, b_2) n=8
+//! groups:  n=8: {0, 2}      n=16: {1}
+//! result:  [a_0 b_0, a_1 b_1, a_2 b_2]        input order is kept
+//! ```
+//!
+//! The pairs of a group are independent, so with the `parallel` feature they are spread over
+//! the `rayon` thread pool.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{multiply_polynomials_fft, DomainPlan, MultiplicationError, TwiddleCache, F, FE};
+
+/// Multiplies every pair in `pairs` with the FFT, sharing the twiddles between the pairs that need
+/// the same domain size.
+///
+/// The function is specific to Babybear, whose elements can be sent across threads when the
+/// `parallel` feature is enabled.
+///
+/// # Arguments
+/// * `pairs` - The `(p1, p2)` operands of each product.
+///
+/// # Returns
+/// The products `p1 * p2`, in the order of `pairs`.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if any product is too large for the field's
+/// two-adicity. No product is computed in that case.
+pub fn multiply_batch(
+    pairs: &[(Polynomial<FE>, Polynomial<FE>)],
+) -> Result<Vec<Polynomial<FE>>, MultiplicationError> {
+    // 1. Group the pairs by domain size, rejecting any domain the field cannot provide up front.
+    // Products with the zero polynomial need no transform and stay out of every group.
+    let mut groups: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+    for (i, (p1, p2)) in pairs.iter().enumerate() {
+        if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+            continue;
+        }
+        let log_n = DomainPlan::new(p1, p2).log_size();
+        if log_n > F::TWO_ADICITY {
+            return Err(MultiplicationError::DomainTooLarge {
+                log_n,
+                two_adicity: F::TWO_ADICITY,
+            });
+        }
+        groups.entry(log_n).or_default().push(i);
+    }
+
+    // 2. Run each group against its plan and put the products back in input order.
+    let mut cache = TwiddleCache::<F>::new();
+    let mut results: Vec<Option<Polynomial<FE>>> = (0..pairs.len()).map(|_| None).collect();
+    for (log_n, indices) in groups {
+        let plan = cache.plan(log_n)?;
+        let multiply_pair = |&i: &usize| {
+            let (p1, p2) = &pairs[i];
+            multiply_polynomials_fft(p1, p2, plan)
+        };
+
+        #[cfg(feature = "parallel")]
+        let products = indices
+            .par_iter()
+            .map(multiply_pair)
+            .collect::<Result<Vec<_>, _>>()?;
+        #[cfg(not(feature = "parallel"))]
+        let products = indices
+            .iter()
+            .map(multiply_pair)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (i, product) in indices.into_iter().zip(products) {
+            results[i] = Some(product);
+        }
+    }
+
+    Ok(results
+        .into_iter()
+        .map(|product| product.unwrap_or_else(|| Polynomial::new(&[])))
+        .collect())
+}
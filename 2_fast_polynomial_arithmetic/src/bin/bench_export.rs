@@ -0,0 +1,139 @@
+//! This binary runs a reduced sweep of the multiplication benchmark's degree grid and writes the
+//! median time of every algorithm at every degree as JSON and CSV, ready to be plotted without
+//! going through criterion's directory layout:
+//!
+//! ```text
+//! cargo run --release --bin bench_export [-- <output directory>]
+//! # -> target/bench_export/medians.json, target/bench_export/medians.csv
+//! function,degree,median_ns
+//! Naive,10,...
+//! ```
+//!
+//! The numbers come from a plain `Instant` loop rather than criterion, so they are rougher than
+//! `cargo bench`, but a full sweep finishes in seconds, which is enough to locate the naive-vs-FFT
+//! crossover.
+
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use fast_polynomial_arithmetic::{
+    multiply, multiply_polynomials_fft_cached, multiply_polynomials_karatsuba,
+    multiply_polynomials_naive, strategies, TwiddleCache,
+};
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+/// Every few entries of the degree grid of the combined multiplication benchmark.
+const DEGREES: [usize; 17] = [
+    10, 16, 24, 32, 48, 64, 96, 128, 192, 256, 384, 512, 768, 1024, 1536, 2048, 3000,
+];
+
+/// The number of timed runs per algorithm and degree, whose median is reported.
+const SAMPLES: usize = 11;
+
+/// Returns the median, in nanoseconds, of [`SAMPLES`] runs of `f`.
+fn median_ns<T>(mut f: impl FnMut() -> T) -> f64 {
+    let mut times: Vec<f64> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed().as_nanos() as f64
+        })
+        .collect();
+    times.sort_by(f64::total_cmp);
+    times[SAMPLES / 2]
+}
+
+/// Times every algorithm on random operands of each degree of [`DEGREES`].
+///
+/// # Returns
+/// One `(function, degree, median_ns)` row per algorithm and degree.
+fn sweep() -> Vec<(&'static str, usize, f64)> {
+    let mut runner = TestRunner::default();
+    // The twiddles are shared across runs, so only the multiplication itself is timed.
+    let mut cache = TwiddleCache::<Babybear31PrimeField>::new();
+    let mut rows = Vec::new();
+
+    for &deg in DEGREES.iter() {
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+            strategies::arb_polynomial::<Babybear31PrimeField>(deg),
+        );
+        let (p1, p2) = strategy.new_tree(&mut runner).unwrap().current();
+        // Warm the twiddle cache, so the first FFT sample does not include building it.
+        multiply_polynomials_fft_cached(&p1, &p2, &mut cache).unwrap();
+
+        rows.push((
+            "Naive",
+            deg,
+            median_ns(|| multiply_polynomials_naive(&p1, &p2)),
+        ));
+        rows.push((
+            "Karatsuba",
+            deg,
+            median_ns(|| multiply_polynomials_karatsuba(&p1, &p2)),
+        ));
+        rows.push((
+            "FFT",
+            deg,
+            median_ns(|| multiply_polynomials_fft_cached(&p1, &p2, &mut cache).unwrap()),
+        ));
+        rows.push(("Adaptive", deg, median_ns(|| multiply(&p1, &p2))));
+        println!("Measured degree {deg}");
+    }
+
+    rows
+}
+
+/// Renders the rows as a JSON array of `{"function", "degree", "median_ns"}` objects.
+fn to_json(rows: &[(&str, usize, f64)]) -> String {
+    let entries: Vec<String> = rows
+        .iter()
+        .map(|(function, degree, median)| {
+            format!(
+                r#"  {{"function": "{function}", "degree": {degree}, "median_ns": {median:.1}}}"#
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+/// Renders the rows as CSV with a `function,degree,median_ns` header.
+fn to_csv(rows: &[(&str, usize, f64)]) -> String {
+    let mut csv = String::from("function,degree,median_ns\n");
+    for (function, degree, median) in rows {
+        csv.push_str(&format!("{function},{degree},{median:.1}\n"));
+    }
+    csv
+}
+
+fn main() {
+    let dir = std::env::args_os()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/bench_export"));
+
+    let rows = sweep();
+
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("Could not create {}: {}", dir.display(), e);
+        std::process::exit(1);
+    }
+    for (file, contents) in [
+        ("medians.json", to_json(&rows)),
+        ("medians.csv", to_csv(&rows)),
+    ] {
+        let path = dir.join(file);
+        match fs::write(&path, contents) {
+            Ok(()) => println!("Wrote {} results to {}", rows.len(), path.display()),
+            Err(e) => {
+                eprintln!("Could not write {}: {}", path.display(), e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! FFT multiplication without bit-reversal permutations.
+//!
+//! lambdaworks' `in_place_nr_2radix_fft` takes its input in natural order and leaves the
+//! evaluations in bit-reversed order, the data flow of a decimation-in-frequency (DIF) transform.
+//! The original pipeline permuted both operands' evaluations into natural order, multiplied them,
+//! ran the same transform as the inverse and permuted once more. None of these permutations are
+//! needed: the pointwise product pairs up equal positions, whatever their order, and the inverse
+//! can be run as a decimation-in-time (DIT) transform, bit-reversed order in and natural order out.
+//!
+//! That inverse is the forward network transposed. Writing the forward transform as `P * F(w)` for
+//! the DFT matrix `F(w)` and the bit-reversal permutation `P`, both symmetric,
+//!
+//! ```text
+//! (P * F(w^-1))^T = F(w^-1)^T * P^T = F(w^-1) * P
+//! ```
+//!
+//! so running the layers in reverse order with every butterfly transposed,
+//!
+//! ```text
+//! (a, b) -> (a + w b, a - w b)      forward, Cooley-Tukey
+//! (a, b) -> (a + b, w (a - b))      transposed, Gentleman-Sande
+//! ```
+//!
+//! interpolates the bit-reversed product evaluations straight into natural-order coefficients.
+//! The transposed layers read the same bit-reversed inverse twiddles as the forward ones, so no
+//! new tables are needed. [`crate::multiply_polynomials_fft`] uses this pipeline, through
+//! [`crate::FftPlan::forward_unordered`] and [`crate::FftPlan::inverse_unordered`];
+//! [`multiply_polynomials_fft_with_permutes`] keeps the original one for comparison.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::fft::cpu::bit_reversing::in_place_bit_reverse_permute;
+use lambdaworks_math::fft::cpu::fft::in_place_nr_2radix_fft;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{validate_plan, FftPlan, MultiplicationError};
+
+/// Runs the inverse radix-2 FFT on `input` in place, bit-reversed order in and natural order out,
+/// without the division by `N`.
+///
+/// # Arguments
+/// * `input` - The `N` values to transform, in bit-reversed order, with `N` a power of two.
+/// * `inv_twiddles` - The bit-reversed inverse twiddles, as from `get_twiddles`.
+pub fn in_place_rn_2radix_ifft<F: IsFFTField>(
+    input: &mut [FieldElement<F>],
+    inv_twiddles: &[FieldElement<F>],
+) {
+    let n = input.len();
+
+    // The layers of `in_place_nr_2radix_fft` in reverse: group `g` of each layer still uses the
+    // twiddle `inv_twiddles[g]`, but the groups grow from pairs to the whole input.
+    let mut group_count = n / 2;
+    let mut group_size = 2;
+    while group_size <= n {
+        let half = group_size / 2;
+        for (group, w) in inv_twiddles.iter().enumerate().take(group_count) {
+            let first = group * group_size;
+            for i in first..first + half {
+                let (a, b) = (&input[i], &input[i + half]);
+                let (y0, y1) = (a + b, w * (a - b));
+                input[i] = y0;
+                input[i + half] = y1;
+            }
+        }
+        group_count /= 2;
+        group_size *= 2;
+    }
+}
+
+/// Multiplies two polynomials using the FFT, with the bit-reversal permutation after each
+/// transform, as [`crate::multiply_polynomials_fft`] did before it dropped them.
+///
+/// The transforms use the plan's radix-2 twiddle tables directly, with lambdaworks' own
+/// permutation, so the comparison measures exactly the permutations that were dropped.
+///
+/// # Arguments
+/// * `p1`, `p2`, `plan` - As for [`crate::multiply_polynomials_fft`].
+///
+/// # Returns
+/// A new `Polynomial` representing the product `p1 * p2`.
+///
+/// # Errors
+/// Returns a [`MultiplicationError`] under the same conditions as
+/// [`crate::multiply_polynomials_fft`].
+pub fn multiply_polynomials_fft_with_permutes<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    plan: &FftPlan<F>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::new(&[]));
+    }
+    validate_plan(p1.degree() + p2.degree() + 1, plan)?;
+    let n = plan.size();
+
+    // 1. Evaluate both operands, permuted into natural order.
+    let evaluate = |p: &Polynomial<FieldElement<F>>| {
+        let mut evals = p.coefficients.to_vec();
+        evals.resize(n, FieldElement::zero());
+        in_place_nr_2radix_fft(&mut evals, plan.twiddles());
+        in_place_bit_reverse_permute(&mut evals);
+        evals
+    };
+    let p1_evals = evaluate(p1);
+    let p2_evals = evaluate(p2);
+
+    // 2. Multiply pointwise.
+    let mut c_coeffs: Vec<FieldElement<F>> = p1_evals
+        .iter()
+        .zip(p2_evals.iter())
+        .map(|(y1, y2)| y1 * y2)
+        .collect();
+
+    // 3. Interpolate with the forward transform, permute back and scale by 1/N.
+    in_place_nr_2radix_fft(&mut c_coeffs, plan.inv_twiddles());
+    in_place_bit_reverse_permute(&mut c_coeffs);
+    let n_inv = FieldElement::<F>::from(n as u64)
+        .inv()
+        .expect("Inverse of N should exist in the field.");
+    for c in c_coeffs.iter_mut() {
+        *c = &*c * &n_inv;
+    }
+
+    Ok(Polynomial::new(&c_coeffs))
+}
@@ -0,0 +1,160 @@
+//! Bluestein's (chirp-z) transform for arbitrary transform lengths.
+//!
+//! The radix-2 FFT only handles power-of-two lengths. Bluestein's algorithm computes a DFT of any
+//! length `n` for which the field has a primitive `n`-th root of unity `w`, by rewriting the
+//! exponent `jk` with binomial coefficients `T(m) = m(m - 1) / 2`:
+//!
+//! ```text
+//! jk = T(j + k) - T(j) - T(k)
+//! X[k] = sum_j x_j w^(jk) = w^(-T(k)) * sum_j (x_j w^(-T(j))) * w^(T(j + k))
+//! ```
+//!
+//! The sum is a correlation of the "chirped" input with the chirp `w^T(m)`, which is a
+//! convolution after reversing one side, and the crate's power-of-two FFT multiplication computes
+//! that convolution in O(n log n). This makes exact-size evaluation domains possible: a product
+//! with 5000 coefficients can be evaluated on a domain of 5120 points if `5120` divides `p - 1`.
+//!
+//! (The more common form of the identity, `jk = (j^2 + k^2 - (k - j)^2) / 2`, needs a `2n`-th root
+//! of unity; the binomial form works with an `n`-th root alone.)
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{multiply_polynomials_fft_auto, MultiplicationError};
+
+/// A field with a known generator of its multiplicative group, so that a primitive root of unity
+/// exists and can be computed for every order dividing `p - 1`.
+pub trait HasMultiplicativeGenerator: IsFFTField {
+    /// The order `p - 1` of the multiplicative group.
+    const MULTIPLICATIVE_GROUP_ORDER: u64;
+
+    /// Returns a generator of the multiplicative group.
+    fn multiplicative_generator() -> FieldElement<Self>;
+}
+
+/// Babybear: `p - 1 = 2^27 * 3 * 5`, generated by 31.
+impl HasMultiplicativeGenerator for Babybear31PrimeField {
+    const MULTIPLICATIVE_GROUP_ORDER: u64 = 2013265920;
+
+    fn multiplicative_generator() -> FieldElement<Self> {
+        FieldElement::from(31)
+    }
+}
+
+/// Returns a primitive `n`-th root of unity, `g^((p - 1) / n)`, or `None` if `n` does not divide
+/// `p - 1`.
+pub fn primitive_root_of_order<F: HasMultiplicativeGenerator>(n: u64) -> Option<FieldElement<F>> {
+    (n != 0 && F::MULTIPLICATIVE_GROUP_ORDER % n == 0)
+        .then(|| F::multiplicative_generator().pow(F::MULTIPLICATIVE_GROUP_ORDER / n))
+}
+
+/// Returns the smallest `n >= len` that divides `p - 1`, i.e. the smallest exact domain with at
+/// least `len` points, or `None` if `len > p - 1`.
+pub fn exact_domain_size<F: HasMultiplicativeGenerator>(len: usize) -> Option<usize> {
+    (len.max(1) as u64..=F::MULTIPLICATIVE_GROUP_ORDER)
+        .find(|n| F::MULTIPLICATIVE_GROUP_ORDER % n == 0)
+        .map(|n| n as usize)
+}
+
+/// Computes the DFT `X[k] = sum_j input[j] * root^(jk)` of any length `n = input.len()` with
+/// Bluestein's algorithm. `root` must be a primitive `n`-th root of unity.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the power-of-two convolution of size about
+/// `3n` is too large for the field's two-adicity.
+pub fn bluestein_dft<F: IsFFTField>(
+    input: &[FieldElement<F>],
+    root: &FieldElement<F>,
+) -> Result<Vec<FieldElement<F>>, MultiplicationError> {
+    let n = input.len();
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+
+    // chirp[m] = root^T(m) for m < 2n - 1, using T(m + 1) = T(m) + m.
+    let mut chirp = Vec::with_capacity(2 * n - 1);
+    let (mut chirp_m, mut root_m) = (FieldElement::<F>::one(), FieldElement::<F>::one());
+    for _ in 0..2 * n - 1 {
+        chirp.push(chirp_m.clone());
+        chirp_m = &chirp_m * &root_m;
+        root_m = &root_m * root;
+    }
+    let inv_chirp: Vec<FieldElement<F>> = chirp[..n]
+        .iter()
+        .map(|c| c.inv().expect("roots of unity are non-zero"))
+        .collect();
+
+    // Correlate the chirped input with the chirp: reversing the input turns
+    // sum_j a_j chirp[j + k] into coefficient n - 1 + k of the product.
+    let chirped_reversed: Vec<FieldElement<F>> = input
+        .iter()
+        .zip(inv_chirp.iter())
+        .map(|(x, c)| x * c)
+        .rev()
+        .collect();
+    let correlation = multiply_polynomials_fft_auto(
+        &Polynomial::new(&chirped_reversed),
+        &Polynomial::new(&chirp),
+    )?;
+
+    Ok((0..n)
+        .map(|k| {
+            let c = correlation
+                .coefficients
+                .get(n - 1 + k)
+                .cloned()
+                .unwrap_or_else(FieldElement::zero);
+            c * &inv_chirp[k]
+        })
+        .collect())
+}
+
+/// Multiplies two polynomials by evaluating them on the smallest exact domain that holds the
+/// product, i.e. the smallest divisor `n` of `p - 1` with `n >= degree(p1) + degree(p2) + 1`,
+/// using Bluestein's transform in both directions.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the product is too large for the field.
+pub fn multiply_polynomials_arbitrary_n<F: HasMultiplicativeGenerator>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::new(&[]));
+    }
+    let len = p1.degree() + p2.degree() + 1;
+    let too_large = MultiplicationError::DomainTooLarge {
+        log_n: len.next_power_of_two().trailing_zeros() as u64,
+        two_adicity: F::TWO_ADICITY,
+    };
+    let n = exact_domain_size::<F>(len).ok_or(too_large)?;
+    let root = primitive_root_of_order::<F>(n as u64).expect("n divides p - 1");
+    let inv_root = root.inv().expect("roots of unity are non-zero");
+
+    // 1. Evaluate both operands on the n-th roots of unity and multiply pointwise.
+    let evaluate = |p: &Polynomial<FieldElement<F>>| {
+        let mut coeffs = p.coefficients.to_vec();
+        coeffs.resize(n, FieldElement::zero());
+        bluestein_dft(&coeffs, &root)
+    };
+    let c_evals: Vec<FieldElement<F>> = evaluate(p1)?
+        .iter()
+        .zip(evaluate(p2)?.iter())
+        .map(|(y1, y2)| y1 * y2)
+        .collect();
+
+    // 2. Interpolate with the inverse root and scale by 1/n.
+    let n_inv = FieldElement::<F>::from(n as u64)
+        .inv()
+        .expect("Inverse of N should exist in the field.");
+    let c_coeffs: Vec<FieldElement<F>> = bluestein_dft(&c_evals, &inv_root)?
+        .iter()
+        .map(|c| c * &n_inv)
+        .collect();
+
+    Ok(Polynomial::new(&c_coeffs))
+}
@@ -0,0 +1,85 @@
+//! Formal derivative and antiderivative.
+//!
+//! Over a finite field a polynomial is differentiated term by term, exactly as over the reals, and
+//! the antiderivative with zero constant term divides every coefficient by its new exponent:
+//!
+//! ```text
+//! p(x)      = sum_i p_i x^i
+//! p'(x)     = sum_(i >= 1) i p_i x^(i - 1)
+//! int p(x)  = sum_i p_i / (i + 1) x^(i + 1)
+//! ```
+//!
+//! The derivative obeys the product and quotient rules, which the later workshops use on quotients
+//! such as `(f(x) - f(z)) / (x - z)`. The antiderivative needs the inverses of `1..=n`; rather than
+//! inverting each of them, all `n` come from a single inversion of `n!`,
+//!
+//! ```text
+//! 1/k = (k - 1)! * (1/k!),    1/(k - 1)! = k * (1/k!)
+//! ```
+//!
+//! so both functions cost O(N) multiplications. Unlike over the reals, the derivative of `x^p` is
+//! zero in characteristic `p`, and the antiderivative only exists below degree `p - 1`; neither
+//! limit is reached by the FFT-sized polynomials over the 31- and 64-bit fields.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsField;
+use lambdaworks_math::polynomial::Polynomial;
+
+/// Returns the formal derivative `p'` of `p`.
+///
+/// # Arguments
+/// * `p` - The polynomial to differentiate.
+///
+/// # Returns
+/// The polynomial `sum_(i >= 1) i p_i x^(i - 1)`; the derivative of a constant is zero.
+pub fn derivative<F: IsField>(p: &Polynomial<FieldElement<F>>) -> Polynomial<FieldElement<F>> {
+    let coeffs: Vec<FieldElement<F>> = p
+        .coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| c * FieldElement::<F>::from(i as u64))
+        .collect();
+    Polynomial::new(&coeffs)
+}
+
+/// Returns the antiderivative of `p` with zero constant term, so that `derivative` undoes it.
+///
+/// # Arguments
+/// * `p` - The polynomial to integrate.
+///
+/// # Returns
+/// The polynomial `sum_i p_i / (i + 1) x^(i + 1)`, of degree `deg(p) + 1`.
+///
+/// # Panics
+/// Panics if `deg(p) + 1` is at least the characteristic of the field, since the top coefficient
+/// would then be divided by zero.
+pub fn antiderivative<F: IsField>(p: &Polynomial<FieldElement<F>>) -> Polynomial<FieldElement<F>> {
+    let n = p.coefficients.len();
+    if n == 0 {
+        return Polynomial::new(&[]);
+    }
+
+    // factorials[k] = k!, for k = 0..=n.
+    let mut factorials = Vec::with_capacity(n + 1);
+    factorials.push(FieldElement::<F>::one());
+    for k in 1..=n {
+        let next = &factorials[k - 1] * FieldElement::<F>::from(k as u64);
+        factorials.push(next);
+    }
+
+    // Walk down from 1/n!, recovering 1/k for every exponent k = n, ..., 1.
+    let mut inv_factorial = factorials[n]
+        .inv()
+        .expect("Factorials below the characteristic should be invertible.");
+    let mut coeffs = Vec::with_capacity(n + 1);
+    coeffs.resize(n + 1, FieldElement::<F>::zero());
+    for k in (1..=n).rev() {
+        let inv_k = &inv_factorial * &factorials[k - 1];
+        coeffs[k] = &p.coefficients[k - 1] * inv_k;
+        inv_factorial *= FieldElement::<F>::from(k as u64);
+    }
+    Polynomial::new(&coeffs)
+}
@@ -0,0 +1,431 @@
+//! Experimental: polynomial multiplication over Mersenne31 with the circle FFT.
+//!
+//! Mersenne31, `p = 2^31 - 1`, makes field arithmetic very cheap, but `p - 1 = 2 * (2^30 - 1)`
+//! has two-adicity 1, so there is no radix-2 FFT over it. The circle `x^2 + y^2 = 1` over the
+//! field is a group of order `p + 1 = 2^31` under
+//!
+//! ```text
+//! (x1, y1) * (x2, y2) = (x1 x2 - y1 y2, x1 y2 + y1 x2)
+//! ```
+//!
+//! and that is what Circle STARKs transform over. A domain of `N = 2^n` points is the coset of
+//! odd powers `Q^1, Q^3, ..., Q^(2N-1)` of a point `Q` of order `2N`. It is closed under
+//! `(x, y) -> (x, -y)` and under `x -> -x`, and the squaring map `(x, y) -> (2x^2 - 1, 2xy)`
+//! takes it onto the domain of half the size, so the FFT has the same shape as the radix-2 one:
+//!
+//! ```text
+//! f(x, y) = f_0(x) + y f_1(x)               first layer, pairing (x, y) with (x, -y)
+//! g(x)    = g_0(2x^2 - 1) + x g_1(2x^2 - 1)   every other layer, pairing x with -x
+//! ```
+//!
+//! The coefficients it produces are not monomial ones but those of the circle basis
+//! `y^(j_0) v_0(x)^(j_1) v_1(x)^(j_2) ...`, with `v_0 = x` and `v_(t+1) = 2 v_t^2 - 1`. A
+//! univariate polynomial has no `y` part, so [`multiply_polynomials_circle`] skips the first layer
+//! and runs the `x` layers alone on the `N / 2` distinct x-coordinates. It also converts between
+//! the monomial and circle bases, by recursive division by the `v_t`, which costs O(N^2) and
+//! dominates the O(N log N) transforms. Circle STARK provers avoid that cost by never leaving the
+//! circle basis; the conversion is only here so the results can be compared with the other
+//! algorithms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{multiply_polynomials_karatsuba, Mersenne31FE, MultiplicationError};
+
+/// `log2` of the order of the circle group over Mersenne31.
+pub const CIRCLE_LOG_ORDER: u64 = 31;
+
+/// A generator of the circle group, of order `2^31`.
+const CIRCLE_GENERATOR: (u64, u64) = (2, 1_268_011_823);
+
+/// A point `(x, y)` with `x^2 + y^2 = 1` over Mersenne31.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CirclePoint {
+    /// The x-coordinate.
+    pub x: Mersenne31FE,
+    /// The y-coordinate.
+    pub y: Mersenne31FE,
+}
+
+impl CirclePoint {
+    /// Returns the identity of the group, `(1, 0)`.
+    pub fn identity() -> Self {
+        Self {
+            x: FieldElement::one(),
+            y: FieldElement::zero(),
+        }
+    }
+
+    /// Returns a point of order `2^log_order`, for `log_order <= 31`.
+    ///
+    /// # Panics
+    /// Panics if `log_order` exceeds [`CIRCLE_LOG_ORDER`].
+    pub fn generator_of_order(log_order: u64) -> Self {
+        assert!(
+            log_order <= CIRCLE_LOG_ORDER,
+            "The circle group has no point of order 2^{}",
+            log_order
+        );
+        let (x, y) = CIRCLE_GENERATOR;
+        let mut point = Self {
+            x: FieldElement::from(x),
+            y: FieldElement::from(y),
+        };
+        for _ in log_order..CIRCLE_LOG_ORDER {
+            point = point.double();
+        }
+        point
+    }
+
+    /// Returns `self * self`, computed with the squaring map `(x, y) -> (2x^2 - 1, 2xy)`.
+    pub fn double(&self) -> Self {
+        Self {
+            x: (self.x * self.x).double() - FieldElement::one(),
+            y: (self.x * self.y).double(),
+        }
+    }
+
+    /// Returns the inverse `(x, -y)`.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Mul for &CirclePoint {
+    type Output = CirclePoint;
+
+    fn mul(self, other: &CirclePoint) -> CirclePoint {
+        CirclePoint {
+            x: self.x * other.x - self.y * other.y,
+            y: self.x * other.y + self.y * other.x,
+        }
+    }
+}
+
+/// Returns the circle domain of `2^log_n` points, in the order used by [`circle_fft`]: the points
+/// `P_0, ..., P_(N/2-1)` with distinct x-coordinates, followed by their conjugates.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if `log_n` exceeds 30, since the domain needs a
+/// point of order `2^(log_n + 1)`.
+pub fn circle_domain(log_n: u64) -> Result<Vec<CirclePoint>, MultiplicationError> {
+    check_log_size(log_n)?;
+    if log_n == 0 {
+        return Ok(vec![CirclePoint::generator_of_order(1)]);
+    }
+    let half = half_domain(log_n);
+    let conjugates: Vec<CirclePoint> = half.iter().map(CirclePoint::conjugate).collect();
+    Ok([half, conjugates].concat())
+}
+
+/// Evaluates the function with the given circle-basis coefficients on the circle domain of
+/// `coeffs.len()` points.
+///
+/// # Arguments
+/// * `coeffs` - The coefficients in the circle basis. Index `j` weighs the basis function `y^(j_0)
+///   v_0(x)^(j_1) v_1(x)^(j_2) ...`, where `j_t` is bit `t` of `j`.
+///
+/// # Returns
+/// The evaluations on the points of [`circle_domain`], in the same order.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainNotPowerOfTwo`] if the length is not a power of two, and
+/// [`MultiplicationError::DomainTooLarge`] if it exceeds `2^30`.
+pub fn circle_fft(coeffs: &[Mersenne31FE]) -> Result<Vec<Mersenne31FE>, MultiplicationError> {
+    let log_n = check_domain_len(coeffs.len())?;
+    if log_n == 0 {
+        return Ok(coeffs.to_vec());
+    }
+    let half = half_domain(log_n);
+    let levels = x_levels(&half);
+
+    // The first layer splits f(x, y) = f_0(x) + y f_1(x).
+    let (f0, f1) = deinterleave(coeffs);
+    let e0 = x_evaluate(&f0, &levels);
+    let e1 = x_evaluate(&f1, &levels);
+    let (plus, minus): (Vec<_>, Vec<_>) = half
+        .iter()
+        .zip(e0.iter().zip(&e1))
+        .map(|(point, (a, b))| {
+            let yb = point.y * b;
+            (a + yb, a - yb)
+        })
+        .unzip();
+    Ok([plus, minus].concat())
+}
+
+/// Interpolates evaluations on the circle domain back to circle-basis coefficients. This is the
+/// inverse of [`circle_fft`].
+///
+/// # Errors
+/// Returns the same errors as [`circle_fft`].
+pub fn circle_ifft(evals: &[Mersenne31FE]) -> Result<Vec<Mersenne31FE>, MultiplicationError> {
+    let log_n = check_domain_len(evals.len())?;
+    if log_n == 0 {
+        return Ok(evals.to_vec());
+    }
+    let half = half_domain(log_n);
+    let levels = x_levels(&half);
+    let half_inv = two_inv();
+
+    // Undo the first layer: f_0 = (u + w) / 2 and f_1 = (u - w) / 2y on each conjugate pair.
+    let (plus, minus) = evals.split_at(evals.len() / 2);
+    let (f0, f1): (Vec<_>, Vec<_>) = half
+        .iter()
+        .zip(plus.iter().zip(minus))
+        .map(|(point, (u, w))| {
+            let y_inv = point.y.inv().expect("The domain has no point with y = 0.");
+            ((u + w) * half_inv, (u - w) * half_inv * y_inv)
+        })
+        .unzip();
+    let c0 = x_interpolate(&f0, &levels);
+    let c1 = x_interpolate(&f1, &levels);
+    Ok(c0.into_iter().zip(c1).flat_map(|(a, b)| [a, b]).collect())
+}
+
+/// Multiplies two polynomials over Mersenne31 with the `x` layers of the circle FFT.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+///
+/// # Returns
+/// A new `Polynomial` representing the product `p1 * p2`.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the product has more than `2^29`
+/// coefficients, since the `x` layers of a domain of `N` points only hold `N / 2`.
+pub fn multiply_polynomials_circle(
+    p1: &Polynomial<Mersenne31FE>,
+    p2: &Polynomial<Mersenne31FE>,
+) -> Result<Polynomial<Mersenne31FE>, MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::new(&[]));
+    }
+    let m = (p1.degree() + p2.degree() + 1).next_power_of_two();
+    let log_n = m.trailing_zeros() as u64 + 1;
+    check_log_size(log_n)?;
+
+    let vs = basis_polynomials(m.trailing_zeros());
+    let half = half_domain(log_n);
+    let levels = x_levels(&half);
+
+    // 1. Convert both operands to the circle basis and evaluate them on the x-coordinates.
+    let evaluate = |p: &Polynomial<Mersenne31FE>| {
+        let mut coeffs = p.coefficients.to_vec();
+        coeffs.resize(m, FieldElement::zero());
+        x_evaluate(&to_circle_basis(&coeffs, &vs), &levels)
+    };
+    let p1_evals = evaluate(p1);
+    let p2_evals = evaluate(p2);
+
+    // 2. Multiply pointwise.
+    let c_evals: Vec<Mersenne31FE> = p1_evals
+        .iter()
+        .zip(p2_evals.iter())
+        .map(|(y1, y2)| y1 * y2)
+        .collect();
+
+    // 3. Interpolate and convert back to the monomial basis.
+    let c_coeffs = from_circle_basis(&x_interpolate(&c_evals, &levels), &vs);
+    Ok(Polynomial::new(&c_coeffs))
+}
+
+/// Checks that a circle domain of `2^log_n` points exists.
+fn check_log_size(log_n: u64) -> Result<(), MultiplicationError> {
+    if log_n >= CIRCLE_LOG_ORDER {
+        return Err(MultiplicationError::DomainTooLarge {
+            log_n,
+            two_adicity: CIRCLE_LOG_ORDER - 1,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that `len` is the size of a circle domain and returns its `log2`.
+fn check_domain_len(len: usize) -> Result<u64, MultiplicationError> {
+    if !len.is_power_of_two() {
+        return Err(MultiplicationError::DomainNotPowerOfTwo(len));
+    }
+    let log_n = len.trailing_zeros() as u64;
+    check_log_size(log_n)?;
+    Ok(log_n)
+}
+
+/// Returns `1/2`.
+fn two_inv() -> Mersenne31FE {
+    Mersenne31FE::from(2)
+        .inv()
+        .expect("2 is invertible in an odd field.")
+}
+
+/// Returns the exponents `a` of the first half of the domain of `2^log_n` points, as points `Q^a`
+/// with `Q` of order `2^(log_n + 1)`. They are ordered so that the second half of the `x` layer is
+/// the negation of the first, `-x(Q^a) = x(Q^(N-a))`, and the squares of the first half are the
+/// next, smaller layer.
+fn half_domain_exponents(log_n: u64) -> Vec<usize> {
+    let mut exponents = vec![1];
+    for log_size in 2..=log_n {
+        let n = 1 << log_size;
+        let negated: Vec<usize> = exponents.iter().map(|a| n - a).collect();
+        exponents.extend(negated);
+    }
+    exponents
+}
+
+/// Returns the points of the domain of `2^log_n` points that have distinct x-coordinates, for
+/// `1 <= log_n <= 30`.
+fn half_domain(log_n: u64) -> Vec<CirclePoint> {
+    let q = CirclePoint::generator_of_order(log_n + 1);
+    let mut powers = Vec::with_capacity(1 << log_n);
+    let mut power = CirclePoint::identity();
+    for _ in 0..1usize << log_n {
+        powers.push(power.clone());
+        power = &power * &q;
+    }
+    half_domain_exponents(log_n)
+        .into_iter()
+        .map(|a| powers[a].clone())
+        .collect()
+}
+
+/// Returns the x-coordinates of every `x` layer, from the `N / 2` coordinates of `half` down to
+/// a single one. Each layer is the image of the first half of the previous under `x -> 2x^2 - 1`.
+fn x_levels(half: &[CirclePoint]) -> Vec<Vec<Mersenne31FE>> {
+    let mut levels = vec![half.iter().map(|point| point.x).collect::<Vec<_>>()];
+    while levels[levels.len() - 1].len() > 1 {
+        let last = &levels[levels.len() - 1];
+        let next = last[..last.len() / 2]
+            .iter()
+            .map(|x| (x * x).double() - FieldElement::one())
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+/// Splits `values` into its even- and odd-indexed entries.
+fn deinterleave(values: &[Mersenne31FE]) -> (Vec<Mersenne31FE>, Vec<Mersenne31FE>) {
+    let even = values.iter().step_by(2).cloned().collect();
+    let odd = values.iter().skip(1).step_by(2).cloned().collect();
+    (even, odd)
+}
+
+/// Evaluates `g = g_0(2x^2 - 1) + x g_1(2x^2 - 1)` on `levels[0]`, given its coefficients in the
+/// basis `v_0(x)^(j_0) v_1(x)^(j_1) ...`.
+fn x_evaluate(coeffs: &[Mersenne31FE], levels: &[Vec<Mersenne31FE>]) -> Vec<Mersenne31FE> {
+    if coeffs.len() == 1 {
+        return coeffs.to_vec();
+    }
+    let (g0, g1) = deinterleave(coeffs);
+    let e0 = x_evaluate(&g0, &levels[1..]);
+    let e1 = x_evaluate(&g1, &levels[1..]);
+    let (plus, minus): (Vec<_>, Vec<_>) = levels[0]
+        .iter()
+        .zip(e0.iter().zip(&e1))
+        .map(|(x, (a, b))| {
+            let xb = x * b;
+            (a + xb, a - xb)
+        })
+        .unzip();
+    [plus, minus].concat()
+}
+
+/// Interpolates the values of `g` on `levels[0]` back to its coefficients, the inverse of
+/// [`x_evaluate`].
+fn x_interpolate(values: &[Mersenne31FE], levels: &[Vec<Mersenne31FE>]) -> Vec<Mersenne31FE> {
+    if values.len() == 1 {
+        return values.to_vec();
+    }
+    let half_inv = two_inv();
+    let (plus, minus) = values.split_at(values.len() / 2);
+    let (g0, g1): (Vec<_>, Vec<_>) = levels[0]
+        .iter()
+        .zip(plus.iter().zip(minus))
+        .map(|(x, (u, w))| {
+            let x_inv = x.inv().expect("The x layers have no zero coordinate.");
+            ((u + w) * half_inv, (u - w) * half_inv * x_inv)
+        })
+        .unzip();
+    let c0 = x_interpolate(&g0, &levels[1..]);
+    let c1 = x_interpolate(&g1, &levels[1..]);
+    c0.into_iter().zip(c1).flat_map(|(a, b)| [a, b]).collect()
+}
+
+/// Returns the monomial coefficients of `v_0, ..., v_(count-1)`, with `v_0 = x` and
+/// `v_(t+1) = 2 v_t^2 - 1`.
+fn basis_polynomials(count: u32) -> Vec<Polynomial<Mersenne31FE>> {
+    let mut vs = Vec::with_capacity(count as usize);
+    let mut v = Polynomial::new(&[FieldElement::zero(), FieldElement::one()]);
+    for _ in 0..count {
+        let next = multiply_polynomials_karatsuba(&v, &v);
+        let mut coeffs: Vec<Mersenne31FE> = next.coefficients.iter().map(|c| c.double()).collect();
+        coeffs[0] = coeffs[0] - FieldElement::one();
+        vs.push(v);
+        v = Polynomial::new(&coeffs);
+    }
+    vs
+}
+
+/// Rewrites `coeffs`, the `2^L` monomial coefficients of a polynomial of degree below `2^L`, in
+/// the circle basis, by dividing by `vs[L - 1]` and recursing on the quotient and remainder.
+fn to_circle_basis(coeffs: &[Mersenne31FE], vs: &[Polynomial<Mersenne31FE>]) -> Vec<Mersenne31FE> {
+    let m = coeffs.len();
+    if m == 1 {
+        return coeffs.to_vec();
+    }
+    let h = m / 2;
+    let divisor = &vs[h.trailing_zeros() as usize].coefficients;
+    let lead_inv = divisor[h]
+        .inv()
+        .expect("The leading coefficient of v_t is a power of 2.");
+
+    // Long division by the degree `h` polynomial `v`: `f = q v + r` with `q` and `r` below `h`.
+    let mut remainder = coeffs.to_vec();
+    let mut quotient = vec![FieldElement::zero(); h];
+    for i in (h..m).rev() {
+        let q = remainder[i] * lead_inv;
+        for (j, d) in divisor.iter().enumerate() {
+            remainder[i - h + j] = remainder[i - h + j] - q * d;
+        }
+        quotient[i - h] = q;
+    }
+    remainder.truncate(h);
+
+    [
+        to_circle_basis(&remainder, vs),
+        to_circle_basis(&quotient, vs),
+    ]
+    .concat()
+}
+
+/// Rewrites `2^L` circle-basis coefficients as monomial ones, the inverse of [`to_circle_basis`].
+fn from_circle_basis(
+    coeffs: &[Mersenne31FE],
+    vs: &[Polynomial<Mersenne31FE>],
+) -> Vec<Mersenne31FE> {
+    let m = coeffs.len();
+    if m == 1 {
+        return coeffs.to_vec();
+    }
+    let h = m / 2;
+    let v = &vs[h.trailing_zeros() as usize].coefficients;
+    let high = from_circle_basis(&coeffs[h..], vs);
+
+    // f = low + v * high, with the product of degree at most h + (h - 1).
+    let mut result = from_circle_basis(&coeffs[..h], vs);
+    result.resize(m, FieldElement::zero());
+    for (i, a) in v.iter().enumerate() {
+        for (j, b) in high.iter().enumerate() {
+            result[i + j] += a * b;
+        }
+    }
+    result
+}
@@ -0,0 +1,96 @@
+//! Cyclic convolution: multiplication modulo `x^n - 1`.
+//!
+//! The FFT over the `n`-th roots of unity evaluates at the roots of `x^n - 1`, so the pointwise
+//! product of two length-`n` transforms is the product of the inputs modulo `x^n - 1`: the
+//! coefficient of `x^(i + j)` lands on `x^((i + j) mod n)`. Full multiplication avoids this
+//! wrap-around by choosing `n` larger than the product; here the wrap-around is the point.
+//!
+//! ```text
+//! c_k = sum over i + j = k (mod n) of a_i * b_j
+//! ```
+
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::{IsFFTField, IsField};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::MultiplicationError;
+
+/// Computes the cyclic convolution of `a` and `b` of length `n`, i.e. `a * b mod (x^n - 1)`.
+///
+/// Inputs of degree `n` or more are reduced modulo `x^n - 1` first.
+///
+/// # Arguments
+/// * `a` - The first polynomial.
+/// * `b` - The second polynomial.
+/// * `n` - The convolution length. Must be a power of two.
+///
+/// # Returns
+/// The product `a * b mod (x^n - 1)`, of degree less than `n`.
+///
+/// # Errors
+/// Returns a [`MultiplicationError`] if `n` is not a power of two or the field has no subgroup of
+/// size `n`.
+pub fn cyclic_convolution<F: IsFFTField>(
+    a: &Polynomial<FieldElement<F>>,
+    b: &Polynomial<FieldElement<F>>,
+    n: usize,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if !n.is_power_of_two() {
+        return Err(MultiplicationError::DomainNotPowerOfTwo(n));
+    }
+    let log_n = n.trailing_zeros() as u64;
+    if log_n > F::TWO_ADICITY {
+        return Err(MultiplicationError::DomainTooLarge {
+            log_n,
+            two_adicity: F::TWO_ADICITY,
+        });
+    }
+
+    let fft_error = |e| MultiplicationError::TwiddleGeneration(format!("{:?}", e));
+    let evals_a =
+        Polynomial::evaluate_fft::<F>(&fold_cyclic(a, n), 1, Some(n)).map_err(fft_error)?;
+    let evals_b =
+        Polynomial::evaluate_fft::<F>(&fold_cyclic(b, n), 1, Some(n)).map_err(fft_error)?;
+    let product_evals: Vec<FieldElement<F>> = evals_a
+        .iter()
+        .zip(evals_b.iter())
+        .map(|(x, y)| x * y)
+        .collect();
+    Polynomial::interpolate_fft::<F>(&product_evals).map_err(fft_error)
+}
+
+/// Computes the cyclic convolution of `a` and `b` of length `n` using the naive O(N^2) algorithm,
+/// adding every term whose power reaches `n` to the coefficient of `x^((i + j) mod n)`.
+///
+/// # Panics
+/// Panics if `n` is zero.
+pub fn cyclic_convolution_naive<F: IsField>(
+    a: &Polynomial<FieldElement<F>>,
+    b: &Polynomial<FieldElement<F>>,
+    n: usize,
+) -> Polynomial<FieldElement<F>> {
+    assert!(n > 0, "The convolution length must be positive.");
+    let mut result = vec![FieldElement::<F>::zero(); n];
+    for (i, x) in a.coefficients.iter().enumerate() {
+        for (j, y) in b.coefficients.iter().enumerate() {
+            let k = (i + j) % n;
+            result[k] = &result[k] + x * y;
+        }
+    }
+    Polynomial::new(&result)
+}
+
+/// Reduces `p` modulo `x^n - 1`.
+fn fold_cyclic<F: IsField>(
+    p: &Polynomial<FieldElement<F>>,
+    n: usize,
+) -> Polynomial<FieldElement<F>> {
+    let mut folded = vec![FieldElement::<F>::zero(); n];
+    for (i, c) in p.coefficients.iter().enumerate() {
+        folded[i % n] = &folded[i % n] + c;
+    }
+    Polynomial::new(&folded)
+}
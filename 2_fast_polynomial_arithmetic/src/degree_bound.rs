@@ -0,0 +1,56 @@
+//! Multiplication against a declared degree bound.
+//!
+//! When a product is committed to with a claimed degree, as the quotients of the commitment
+//! scheme in workshop 3 are, a product of higher degree must be rejected rather than silently
+//! committed to. Over a field the leading coefficients of nonzero operands never cancel,
+//!
+//! ```text
+//! deg(p1 * p2) = deg(p1) + deg(p2)
+//! ```
+//!
+//! so the bound is checked before any multiplication is done, and a violation costs nothing.
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{multiply, MultiplicationError};
+
+/// Multiplies `p1` by `p2` with [`multiply`], provided the product has degree at most `bound`.
+///
+/// The zero product has no degree and satisfies every bound.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+/// * `bound` - The largest degree the caller accepts for the product.
+///
+/// # Returns
+/// A new `Polynomial` representing the product `p1 * p2`.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DegreeBoundExceeded`] if `deg(p1) + deg(p2) > bound`.
+pub fn multiply_with_degree_bound<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    bound: usize,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    // Trim the operands so their last coefficients are the true leading ones.
+    let p1 = Polynomial::new(&p1.coefficients);
+    let p2 = Polynomial::new(&p2.coefficients);
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::new(&[]));
+    }
+
+    let degree = p1.degree() + p2.degree();
+    if degree > bound {
+        return Err(MultiplicationError::DegreeBoundExceeded { bound, degree });
+    }
+    let product = multiply(&p1, &p2);
+    debug_assert_eq!(
+        product.degree(),
+        degree,
+        "leading coefficients cannot cancel"
+    );
+    Ok(product)
+}
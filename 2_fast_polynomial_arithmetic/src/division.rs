@@ -0,0 +1,69 @@
+//! Fast polynomial division with remainder.
+//!
+//! Long division costs O((n - m) m) for a degree-`n` numerator and a degree-`m` denominator. The
+//! reversal trick turns it into a power-series problem: with `rev_k(f) = x^k f(1/x)` (the
+//! coefficients of `f` in reverse order), `f = q g + r` becomes
+//!
+//! ```text
+//! rev_n(f) = rev_(n-m)(q) rev_m(g) + x^(n-m+1) rev(r)
+//! ```
+//!
+//! so `rev(q) = rev(f) / rev(g) mod x^(n-m+1)`. The denominator's reversal has its leading
+//! coefficient as constant term, so it is invertible as a power series, and the inverse comes
+//! from [`inverse_mod_xn`]. Every step is a multiplication, giving O(N log N) with the FFT.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::power_series::{inverse_mod_xn, truncated};
+use crate::{mul_low, multiply, MultiplicationError};
+
+/// A quotient and remainder pair `(q, r)`.
+type QuotientRemainder<F> = (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>);
+
+/// Divides `num` by `den`, returning the quotient and remainder `(q, r)` such that
+/// `num = q * den + r` and `degree(r) < degree(den)`.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DivisionByZeroPolynomial`] if `den` is the zero polynomial.
+pub fn divide_polynomials_fast<F: IsFFTField>(
+    num: &Polynomial<FieldElement<F>>,
+    den: &Polynomial<FieldElement<F>>,
+) -> Result<QuotientRemainder<F>, MultiplicationError> {
+    // Trim the operands so their last coefficients are the true leading ones.
+    let num = Polynomial::new(&num.coefficients);
+    let den = Polynomial::new(&den.coefficients);
+    if den.coefficients.is_empty() {
+        return Err(MultiplicationError::DivisionByZeroPolynomial);
+    }
+    if num.coefficients.len() < den.coefficients.len() {
+        return Ok((Polynomial::new(&[]), num));
+    }
+
+    // 1. Compute rev(q) = rev(num) * rev(den)^-1 mod x^k, where k = deg(num) - deg(den) + 1.
+    let k = num.coefficients.len() - den.coefficients.len() + 1;
+    let rev_num = reversed(&num);
+    let rev_den = reversed(&den);
+    let rev_den_inv =
+        inverse_mod_xn(&rev_den, k).expect("the leading coefficient of den is non-zero");
+    let rev_q = truncated(&mul_low(&rev_num, &rev_den_inv, k), k);
+
+    // 2. Undo the reversal; q has exactly k coefficients.
+    let q_coeffs: Vec<FieldElement<F>> = rev_q.into_iter().rev().collect();
+    let q = Polynomial::new(&q_coeffs);
+
+    // 3. The remainder is whatever q * den leaves of num.
+    let r = &num - &multiply(&q, &den);
+    Ok((q, r))
+}
+
+/// Returns the coefficients of `p` in reverse order.
+pub(crate) fn reversed<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    let coeffs: Vec<FieldElement<F>> = p.coefficients.iter().rev().cloned().collect();
+    Polynomial::new(&coeffs)
+}
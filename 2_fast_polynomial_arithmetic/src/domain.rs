@@ -0,0 +1,70 @@
+//! Sizing of the evaluation domain for FFT multiplication.
+//!
+//! A product of polynomials of degrees `d1` and `d2` has `d1 + d2 + 1` coefficients, so the radix-2
+//! FFT needs a domain of at least that many points, rounded up to a power of two. Callers that
+//! multiply many polynomials can round further up so that a single set of twiddles serves them
+//! all.
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsField;
+use lambdaworks_math::polynomial::Polynomial;
+
+/// Returns the smallest power-of-two domain size that holds every coefficient of `p1 * p2`.
+pub fn required_domain_size<F: IsField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> usize {
+    DomainPlan::new(p1, p2).size()
+}
+
+/// The FFT domain chosen for a product: the number of coefficients it must hold and the
+/// power-of-two size picked for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DomainPlan {
+    product_len: usize,
+    size: usize,
+}
+
+impl DomainPlan {
+    /// Plans the minimal domain for `p1 * p2`.
+    pub fn new<F: IsField>(
+        p1: &Polynomial<FieldElement<F>>,
+        p2: &Polynomial<FieldElement<F>>,
+    ) -> Self {
+        let product_len = p1.degree() + p2.degree() + 1;
+        Self {
+            product_len,
+            size: product_len.next_power_of_two(),
+        }
+    }
+
+    /// Grows the domain so that it also has room for `extra` more coefficients, e.g. to reuse it
+    /// for a slightly larger product later.
+    pub fn with_headroom(self, extra: usize) -> Self {
+        self.at_least(self.product_len + extra)
+    }
+
+    /// Grows the domain to at least `min_size` points, rounded up to a power of two. A domain that
+    /// is already large enough is left unchanged.
+    pub fn at_least(self, min_size: usize) -> Self {
+        Self {
+            size: self.size.max(min_size.next_power_of_two()),
+            ..self
+        }
+    }
+
+    /// Returns the number of coefficients of the product, `degree(p1) + degree(p2) + 1`.
+    pub fn product_len(&self) -> usize {
+        self.product_len
+    }
+
+    /// Returns the domain size `n`, a power of two.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `log2(n)`, the order expected by `get_twiddles` and [`crate::TwiddleCache`].
+    pub fn log_size(&self) -> u64 {
+        self.size.trailing_zeros() as u64
+    }
+}
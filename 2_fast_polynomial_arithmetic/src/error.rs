@@ -0,0 +1,82 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Errors returned by the multiplication and division routines when their inputs cannot produce a
+/// correct result.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MultiplicationError {
+    /// The FFT domain size is not a power of two.
+    DomainNotPowerOfTwo(usize),
+    /// The FFT domain has fewer points than the product has coefficients.
+    DomainTooSmall { required: usize, got: usize },
+    /// A twiddle table does not hold the `n / 2` roots the domain size requires.
+    TwiddleLengthMismatch { expected: usize, got: usize },
+    /// The field has no multiplicative subgroup of size `2^log_n`.
+    DomainTooLarge { log_n: u64, two_adicity: u64 },
+    /// Generating the twiddle factors failed.
+    TwiddleGeneration(String),
+    /// Attempted to divide by the zero polynomial.
+    DivisionByZeroPolynomial,
+    /// The power series has a zero constant term, so it has no inverse modulo `x^n`.
+    ZeroConstantTerm,
+    /// An operand has more digits than the algorithm can multiply without overflowing the field.
+    OperandTooLong { max_len: usize, got: usize },
+    /// Reading, writing or mapping a file failed, or its contents are malformed.
+    Io(String),
+    /// No GPU device is available, or a GPU operation failed.
+    Gpu(String),
+    /// The product has a higher degree than the caller declared.
+    DegreeBoundExceeded { bound: usize, degree: usize },
+    /// The operation is undefined for the zero polynomial.
+    ZeroPolynomial,
+}
+
+impl fmt::Display for MultiplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MultiplicationError::DomainNotPowerOfTwo(n) => {
+                write!(f, "FFT domain size {} is not a power of two", n)
+            }
+            MultiplicationError::DomainTooSmall { required, got } => write!(
+                f,
+                "FFT domain of size {} is too small for a product with {} coefficients",
+                got, required
+            ),
+            MultiplicationError::TwiddleLengthMismatch { expected, got } => {
+                write!(f, "Expected {} twiddle factors, got {}", expected, got)
+            }
+            MultiplicationError::DomainTooLarge { log_n, two_adicity } => write!(
+                f,
+                "FFT domain of size 2^{} exceeds the field's two-adicity {}",
+                log_n, two_adicity
+            ),
+            MultiplicationError::TwiddleGeneration(msg) => {
+                write!(f, "Twiddle generation failed: {}", msg)
+            }
+            MultiplicationError::DivisionByZeroPolynomial => {
+                write!(f, "Division by the zero polynomial")
+            }
+            MultiplicationError::ZeroConstantTerm => {
+                write!(
+                    f,
+                    "A power series with zero constant term is not invertible"
+                )
+            }
+            MultiplicationError::OperandTooLong { max_len, got } => write!(
+                f,
+                "Operand of {} digits exceeds the maximum of {}",
+                got, max_len
+            ),
+            MultiplicationError::Io(msg) => write!(f, "I/O error: {}", msg),
+            MultiplicationError::Gpu(msg) => write!(f, "GPU error: {}", msg),
+            MultiplicationError::DegreeBoundExceeded { bound, degree } => write!(
+                f,
+                "Product of degree {} exceeds the declared bound {}",
+                degree, bound
+            ),
+            MultiplicationError::ZeroPolynomial => {
+                write!(f, "The operation is undefined for the zero polynomial")
+            }
+        }
+    }
+}
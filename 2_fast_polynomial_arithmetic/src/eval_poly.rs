@@ -0,0 +1,186 @@
+//! Polynomials in evaluation form over a fixed power-of-two domain.
+//!
+//! In the evaluation basis, addition and multiplication are pointwise and cost O(N), while the
+//! conversions to and from coefficients cost an FFT each. A prover computing `a * b * c + d`
+//! should therefore convert every input once, combine the evaluations, and interpolate only the
+//! final result, instead of round-tripping through an inverse FFT after every product.
+//!
+//! [`EvalPoly`] tracks an upper bound on the degree of the polynomial it represents. Pointwise
+//! products of evaluations are products modulo `x^n - 1`, so a multiplication that would overflow
+//! the domain panics instead of silently wrapping around.
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::cell::OnceCell;
+use core::ops::{Add, Mul, Sub};
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::MultiplicationError;
+
+/// A polynomial stored as its evaluations on the `n`-th roots of unity, in natural order, with the
+/// coefficient form computed lazily and cached.
+///
+/// The field defaults to Babybear.
+#[derive(Debug, Clone)]
+pub struct EvalPoly<F: IsFFTField = Babybear31PrimeField> {
+    evaluations: Vec<FieldElement<F>>,
+    degree_bound: usize,
+    coefficients: OnceCell<Polynomial<FieldElement<F>>>,
+}
+
+impl<F: IsFFTField> EvalPoly<F> {
+    /// Evaluates `p` on a domain of `n` points.
+    ///
+    /// # Errors
+    /// Returns a [`MultiplicationError`] if `n` is not a power of two, if `n <= degree(p)`, or if
+    /// the field has no subgroup of size `n`.
+    pub fn from_coefficients(
+        p: &Polynomial<FieldElement<F>>,
+        n: usize,
+    ) -> Result<Self, MultiplicationError> {
+        check_domain::<F>(n)?;
+        if n <= p.degree() {
+            return Err(MultiplicationError::DomainTooSmall {
+                required: p.degree() + 1,
+                got: n,
+            });
+        }
+        let evaluations = Polynomial::evaluate_fft::<F>(p, 1, Some(n))
+            .map_err(|e| MultiplicationError::TwiddleGeneration(format!("{:?}", e)))?;
+        Ok(Self {
+            evaluations,
+            degree_bound: p.degree(),
+            coefficients: OnceCell::from(p.clone()),
+        })
+    }
+
+    /// Wraps evaluations on the `n`-th roots of unity, `n = evaluations.len()`. Nothing is known
+    /// about the degree, so the bound is `n - 1`.
+    ///
+    /// # Errors
+    /// Returns a [`MultiplicationError`] if `n` is not a power of two or the field has no subgroup
+    /// of size `n`.
+    pub fn from_evaluations(
+        evaluations: Vec<FieldElement<F>>,
+    ) -> Result<Self, MultiplicationError> {
+        check_domain::<F>(evaluations.len())?;
+        Ok(Self {
+            degree_bound: evaluations.len() - 1,
+            evaluations,
+            coefficients: OnceCell::new(),
+        })
+    }
+
+    /// Returns the domain size `n`.
+    pub fn domain_size(&self) -> usize {
+        self.evaluations.len()
+    }
+
+    /// Returns the evaluations in natural order of the domain.
+    pub fn evaluations(&self) -> &[FieldElement<F>] {
+        &self.evaluations
+    }
+
+    /// Returns an upper bound on the degree of the represented polynomial.
+    pub fn degree_bound(&self) -> usize {
+        self.degree_bound
+    }
+
+    /// Returns the coefficient form, interpolating it with an inverse FFT on first use.
+    pub fn to_polynomial(&self) -> &Polynomial<FieldElement<F>> {
+        self.coefficients.get_or_init(|| {
+            Polynomial::interpolate_fft::<F>(&self.evaluations)
+                .expect("the domain size was validated on construction")
+        })
+    }
+
+    fn combine(
+        &self,
+        other: &Self,
+        degree_bound: usize,
+        op: impl Fn(&FieldElement<F>, &FieldElement<F>) -> FieldElement<F>,
+    ) -> Self {
+        assert_eq!(
+            self.domain_size(),
+            other.domain_size(),
+            "evaluation domains must match"
+        );
+        Self {
+            evaluations: self
+                .evaluations
+                .iter()
+                .zip(other.evaluations.iter())
+                .map(|(a, b)| op(a, b))
+                .collect(),
+            degree_bound,
+            coefficients: OnceCell::new(),
+        }
+    }
+}
+
+/// Pointwise sum.
+///
+/// # Panics
+/// Panics if the domains differ in size.
+impl<F: IsFFTField> Add for &EvalPoly<F> {
+    type Output = EvalPoly<F>;
+
+    fn add(self, other: Self) -> EvalPoly<F> {
+        self.combine(other, self.degree_bound.max(other.degree_bound), |a, b| {
+            a + b
+        })
+    }
+}
+
+/// Pointwise difference.
+///
+/// # Panics
+/// Panics if the domains differ in size.
+impl<F: IsFFTField> Sub for &EvalPoly<F> {
+    type Output = EvalPoly<F>;
+
+    fn sub(self, other: Self) -> EvalPoly<F> {
+        self.combine(other, self.degree_bound.max(other.degree_bound), |a, b| {
+            a - b
+        })
+    }
+}
+
+/// Pointwise product.
+///
+/// # Panics
+/// Panics if the domains differ in size, or if the product's degree bound does not fit in the
+/// domain, since the evaluations would then describe the product modulo `x^n - 1`.
+impl<F: IsFFTField> Mul for &EvalPoly<F> {
+    type Output = EvalPoly<F>;
+
+    fn mul(self, other: Self) -> EvalPoly<F> {
+        let degree_bound = self.degree_bound + other.degree_bound;
+        assert!(
+            degree_bound < self.domain_size(),
+            "product of degree up to {} does not fit in a domain of {} points",
+            degree_bound,
+            self.domain_size()
+        );
+        self.combine(other, degree_bound, |a, b| a * b)
+    }
+}
+
+/// Checks that `n` is a power of two within the field's two-adicity.
+fn check_domain<F: IsFFTField>(n: usize) -> Result<(), MultiplicationError> {
+    if !n.is_power_of_two() {
+        return Err(MultiplicationError::DomainNotPowerOfTwo(n));
+    }
+    let log_n = n.trailing_zeros() as u64;
+    if log_n > F::TWO_ADICITY {
+        return Err(MultiplicationError::DomainTooLarge {
+            log_n,
+            two_adicity: F::TWO_ADICITY,
+        });
+    }
+    Ok(())
+}
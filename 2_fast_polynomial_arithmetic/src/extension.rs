@@ -0,0 +1,129 @@
+//! Multiplication of polynomials over the quartic extension of Babybear.
+//!
+//! Babybear is only 31 bits, so protocols draw challenges (such as the DEEP point) from the
+//! degree-4 extension `GF(p^4) = GF(p)[u] / (u^4 - 11)`, and must then multiply polynomials whose
+//! coefficients live in the extension. `u^4 - 11` is irreducible because 11 is not a square modulo
+//! `p` and `p = 1 mod 4`.
+//!
+//! The extension has no roots of unity beyond those of the base field that the FFT cares about, so
+//! the transforms can stay in the base field: writing `P = P0 + P1 u + P2 u^2 + P3 u^3` with
+//! base-field polynomials `Pi`, and using that the FFT is linear and the domain points lie in the
+//! base field, `P(w) = P0(w) + P1(w) u + P2(w) u^2 + P3(w) u^3`. Each operand therefore needs four
+//! base-field FFTs, the pointwise products are extension multiplications, and four inverse FFTs
+//! recover the components of the product.
+//!
+//! The extension is lambdaworks' [`Degree4BabyBearExtensionField`], the same one the FRI crate
+//! draws its challenges from. It is built over lambdaworks' Montgomery-form Babybear rather than
+//! the `babybear_u32` field of [`crate::FE`], so the component FFTs run in that field.
+
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+use lambdaworks_math::field::fields::fft_friendly::quartic_babybear::Degree4BabyBearExtensionField;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::MultiplicationError;
+
+/// An element `a0 + a1 u + a2 u^2 + a3 u^3` of Babybear's quartic extension, with `u^4 = 11`.
+pub type Fp4 = FieldElement<Degree4BabyBearExtensionField>;
+
+/// The base field of [`Fp4`].
+type B = Babybear31PrimeField;
+
+/// Multiplies two polynomials with coefficients in the quartic extension using the naive O(N^2)
+/// algorithm. Coefficients are in ascending order of power; the result has no trailing zeros.
+pub fn multiply_quartic_naive(p1: &[Fp4], p2: &[Fp4]) -> Vec<Fp4> {
+    let (p1, p2) = (trimmed(p1), trimmed(p2));
+    if p1.is_empty() || p2.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![Fp4::zero(); p1.len() + p2.len() - 1];
+    for (i, a) in p1.iter().enumerate() {
+        for (j, b) in p2.iter().enumerate() {
+            result[i + j] = &result[i + j] + a * b;
+        }
+    }
+    result
+}
+
+/// Multiplies two polynomials with coefficients in the quartic extension using four base-field
+/// FFTs per operand, extension multiplications at each domain point, and four inverse FFTs.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the product is too large for Babybear's
+/// two-adicity.
+pub fn multiply_quartic_fft(p1: &[Fp4], p2: &[Fp4]) -> Result<Vec<Fp4>, MultiplicationError> {
+    let (p1, p2) = (trimmed(p1), trimmed(p2));
+    if p1.is_empty() || p2.is_empty() {
+        return Ok(Vec::new());
+    }
+    let product_len = p1.len() + p2.len() - 1;
+    let n = product_len.next_power_of_two();
+    let log_n = n.trailing_zeros() as u64;
+    if log_n > B::TWO_ADICITY {
+        return Err(MultiplicationError::DomainTooLarge {
+            log_n,
+            two_adicity: B::TWO_ADICITY,
+        });
+    }
+    let fft_error = |e| MultiplicationError::TwiddleGeneration(format!("{:?}", e));
+
+    // 1. Evaluate the four components of each operand on the base-field domain.
+    let evaluate = |p: &[Fp4]| -> Result<Vec<Fp4>, MultiplicationError> {
+        let mut components = Vec::with_capacity(4);
+        for i in 0..4 {
+            components.push(
+                Polynomial::evaluate_fft::<B>(&component(p, i), 1, Some(n)).map_err(fft_error)?,
+            );
+        }
+        Ok((0..n)
+            .map(|k| Fp4::new(core::array::from_fn(|i| components[i][k])))
+            .collect())
+    };
+    let evals1 = evaluate(p1)?;
+    let evals2 = evaluate(p2)?;
+
+    // 2. Multiply pointwise in the extension.
+    let product_evals: Vec<Fp4> = evals1
+        .iter()
+        .zip(evals2.iter())
+        .map(|(a, b)| a * b)
+        .collect();
+
+    // 3. Interpolate each component of the product separately.
+    let mut components = Vec::with_capacity(4);
+    for i in 0..4 {
+        let evals: Vec<FieldElement<B>> = product_evals.iter().map(|e| e.value()[i]).collect();
+        components.push(Polynomial::interpolate_fft::<B>(&evals).map_err(fft_error)?);
+    }
+    let coeffs: Vec<Fp4> = (0..product_len)
+        .map(|k| {
+            Fp4::new(core::array::from_fn(|i| {
+                components[i]
+                    .coefficients
+                    .get(k)
+                    .copied()
+                    .unwrap_or_else(FieldElement::zero)
+            }))
+        })
+        .collect();
+    Ok(trimmed(&coeffs).to_vec())
+}
+
+/// Returns the base-field polynomial formed by component `i` of every coefficient.
+fn component(p: &[Fp4], i: usize) -> Polynomial<FieldElement<B>> {
+    let coeffs: Vec<FieldElement<B>> = p.iter().map(|c| c.value()[i]).collect();
+    Polynomial::new(&coeffs)
+}
+
+/// Drops trailing zero coefficients.
+fn trimmed(p: &[Fp4]) -> &[Fp4] {
+    let len = p
+        .iter()
+        .rposition(|c| *c != Fp4::zero())
+        .map_or(0, |i| i + 1);
+    &p[..len]
+}
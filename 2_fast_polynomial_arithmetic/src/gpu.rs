@@ -0,0 +1,322 @@
+//! FFT multiplication on the GPU, enabled by the `gpu` feature.
+//!
+//! The forward transforms, the pointwise product and the inverse transform run as `wgpu` compute
+//! shaders (`shaders/ntt.wgsl`), one dispatch per radix-2 stage with one thread per butterfly.
+//! Only the coefficients travel over the bus: they are uploaded once, and the product is read back
+//! once, so for domains of `2^22` points and more the arithmetic dominates and the GPU's thousands
+//! of lanes pay off.
+//!
+//! No bit-reversal pass is needed. The forward transform uses decimation in frequency, which takes
+//! natural-order input and leaves the evaluations in bit-reversed order; the pointwise product does
+//! not care about the order; and the inverse transform uses decimation in time, which takes
+//! bit-reversed input and produces natural-order output.
+//!
+//! ```text
+//! DIF stage (half h):  (u, v) -> (u + v, (u - v) * w^j)
+//! DIT stage (half h):  (u, v) -> (u + w^j v, u - w^j v),   w = primitive 2h-th root
+//! ```
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use std::sync::mpsc;
+
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+use wgpu::util::DeviceExt;
+
+use crate::{MultiplicationError, PolynomialMultiplier, F, FE};
+
+/// The number of threads per workgroup, matching `@workgroup_size` in the shader.
+const WORKGROUP_SIZE: u32 = 256;
+
+/// The largest workgroup count dispatched along one dimension.
+const MAX_GROUPS_PER_DIMENSION: u32 = 32_768;
+
+/// A GPU device with the NTT pipelines compiled for it.
+///
+/// Creating one requests an adapter and compiles the shaders, so build it once and reuse it.
+pub struct GpuMultiplier {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    layout: wgpu::BindGroupLayout,
+    dif_stage: wgpu::ComputePipeline,
+    dit_stage: wgpu::ComputePipeline,
+    pointwise: wgpu::ComputePipeline,
+}
+
+impl GpuMultiplier {
+    /// Requests the default adapter and compiles the NTT pipelines.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::Gpu`] if no adapter or device is available.
+    pub fn new() -> Result<Self, MultiplicationError> {
+        let instance = wgpu::Instance::default();
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .ok_or_else(|| MultiplicationError::Gpu("no GPU adapter found".to_string()))?;
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None))
+                .map_err(|e| MultiplicationError::Gpu(e.to_string()))?;
+
+        let storage = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("ntt bindings"),
+            entries: &[
+                storage(0, false),
+                storage(1, true),
+                storage(2, true),
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("ntt layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+        let module = device.create_shader_module(wgpu::include_wgsl!("shaders/ntt.wgsl"));
+        let pipeline = |entry_point| {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: Some(&pipeline_layout),
+                module: &module,
+                entry_point: Some(entry_point),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        };
+
+        Ok(Self {
+            dif_stage: pipeline("dif_stage"),
+            dit_stage: pipeline("dit_stage"),
+            pointwise: pipeline("pointwise"),
+            device,
+            queue,
+            layout,
+        })
+    }
+
+    /// Multiplies two Babybear polynomials on the GPU.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::DomainTooLarge`] if the product is too large for Babybear's
+    /// two-adicity, or [`MultiplicationError::Gpu`] if reading the result back fails.
+    pub fn multiply(
+        &self,
+        p1: &Polynomial<FE>,
+        p2: &Polynomial<FE>,
+    ) -> Result<Polynomial<FE>, MultiplicationError> {
+        if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+            return Ok(Polynomial::zero());
+        }
+        let n = (p1.coefficients.len() + p2.coefficients.len() - 1).next_power_of_two();
+        let log_n = n.trailing_zeros() as u64;
+        if log_n > F::TWO_ADICITY {
+            return Err(MultiplicationError::DomainTooLarge {
+                log_n,
+                two_adicity: F::TWO_ADICITY,
+            });
+        }
+        let root = F::get_primitive_root_of_unity(log_n)
+            .map_err(|e| MultiplicationError::TwiddleGeneration(format!("{:?}", e)))?;
+        let root_inv = root.inv().expect("Roots of unity are invertible.");
+
+        // 1. Upload the padded coefficients and the twiddles of every stage.
+        let storage_usage = wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC;
+        let a = self.upload("lhs", &padded(p1, n), storage_usage);
+        let b = self.upload("rhs", &padded(p2, n), storage_usage);
+        let twiddles = self.upload("twiddles", &stage_twiddles(&root, n), storage_usage);
+        let inv_twiddles = self.upload(
+            "inverse twiddles",
+            &stage_twiddles(&root_inv, n),
+            storage_usage,
+        );
+
+        // 2. Record every stage of both forward transforms, the product and the inverse.
+        let halves: Vec<usize> = (0..log_n).map(|s| 1 << s).collect();
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        for (values, other) in [(&a, &b), (&b, &a)] {
+            for &half in halves.iter().rev() {
+                self.dispatch(
+                    &mut encoder,
+                    &self.dif_stage,
+                    values,
+                    other,
+                    &twiddles,
+                    half,
+                    n / 2,
+                );
+            }
+        }
+        self.dispatch(&mut encoder, &self.pointwise, &a, &b, &twiddles, 1, n);
+        for &half in &halves {
+            self.dispatch(
+                &mut encoder,
+                &self.dit_stage,
+                &a,
+                &b,
+                &inv_twiddles,
+                half,
+                n / 2,
+            );
+        }
+
+        // 3. Copy the result to a mappable buffer and read it back.
+        let size = (n * 4) as u64;
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("staging"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        encoder.copy_buffer_to_buffer(&a, 0, &staging, 0, size);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = staging.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .map_err(|e| MultiplicationError::Gpu(e.to_string()))?
+            .map_err(|e| MultiplicationError::Gpu(e.to_string()))?;
+
+        // The inverse transform is unscaled, so fold in 1/N while decoding.
+        let n_inv = FE::from(n as u64)
+            .inv()
+            .expect("Inverse of N should exist in the field.");
+        let coeffs: Vec<FE> = slice
+            .get_mapped_range()
+            .chunks_exact(4)
+            .map(|word| FE::from(u32::from_le_bytes(word.try_into().unwrap()) as u64) * n_inv)
+            .collect();
+        staging.unmap();
+        Ok(Polynomial::new(&coeffs))
+    }
+
+    /// Creates a buffer holding `words` as little-endian `u32`s.
+    fn upload(&self, label: &str, words: &[u32], usage: wgpu::BufferUsages) -> wgpu::Buffer {
+        let contents: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+        self.device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some(label),
+                contents: &contents,
+                usage,
+            })
+    }
+
+    /// Records one compute pass of `pipeline` over `count` threads, with stage half-size `half`.
+    #[allow(clippy::too_many_arguments)]
+    fn dispatch(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        pipeline: &wgpu::ComputePipeline,
+        values: &wgpu::Buffer,
+        other: &wgpu::Buffer,
+        twiddles: &wgpu::Buffer,
+        half: usize,
+        count: usize,
+    ) {
+        // The uniform is padded to 16 bytes, the minimum uniform binding alignment.
+        let params = self.upload(
+            "params",
+            &[half as u32, count as u32, 0, 0],
+            wgpu::BufferUsages::UNIFORM,
+        );
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &self.layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: values.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: other.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: twiddles.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: params.as_entire_binding(),
+                },
+            ],
+        });
+
+        let groups = (count as u32).div_ceil(WORKGROUP_SIZE);
+        let groups_x = groups.min(MAX_GROUPS_PER_DIMENSION);
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        pass.set_pipeline(pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(groups_x, groups.div_ceil(groups_x), 1);
+    }
+}
+
+impl PolynomialMultiplier for GpuMultiplier {
+    fn multiply(
+        &self,
+        p1: &Polynomial<FE>,
+        p2: &Polynomial<FE>,
+    ) -> Result<Polynomial<FE>, MultiplicationError> {
+        GpuMultiplier::multiply(self, p1, p2)
+    }
+}
+
+/// Returns the canonical coefficients of `p`, zero-padded to `n`.
+fn padded(p: &Polynomial<FE>, n: usize) -> Vec<u32> {
+    let mut words: Vec<u32> = p
+        .coefficients
+        .iter()
+        .map(|c| u64::from(c.representative()) as u32)
+        .collect();
+    words.resize(n, 0);
+    words
+}
+
+/// Returns the twiddles of every stage in Montgomery form, laid out as the shader expects: the
+/// stage with half-size `h` holds `w_(2h)^j` for `j < h` at offset `h - 1`, with
+/// `w_(2h) = root^(n / 2h)`.
+fn stage_twiddles(root: &FE, n: usize) -> Vec<u32> {
+    let montgomery = FE::from(1u64 << 32);
+    let mut words = Vec::with_capacity(n.max(1));
+    let mut half = 1;
+    while half < n {
+        let w = root.pow((n / (2 * half)) as u64);
+        let mut power = montgomery;
+        for _ in 0..half {
+            words.push(u64::from(power.representative()) as u32);
+            power *= w;
+        }
+        half *= 2;
+    }
+    // Storage bindings cannot be empty, which they would be for a one-point domain.
+    if words.is_empty() {
+        words.push(0);
+    }
+    words
+}
@@ -0,0 +1,87 @@
+//! Big-integer multiplication with the number-theoretic transform.
+//!
+//! An integer written in base `B` is the evaluation at `x = B` of the polynomial whose
+//! coefficients are its digits. The product of two integers is therefore the product of their
+//! digit polynomials evaluated at `B`, and the polynomial product is exactly what the FFT over a
+//! prime field (the NTT) computes quickly. This is the idea behind the FFT multipliers that let
+//! libraries such as GMP beat the schoolbook algorithm on large inputs.
+//!
+//! ```text
+//! a = sum a_i B^i,   b = sum b_j B^j
+//! c(x) = a(x) * b(x)            (coefficients c_k = sum over i + j = k of a_i * b_j)
+//! a * b = c(B)                  (propagate carries from c_0 upwards)
+//! ```
+//!
+//! The NTT computes each `c_k` modulo `p`, so the digits must be small enough that no convolution
+//! coefficient reaches `p`. With bytes as digits (`B = 256`), a coefficient is at most
+//! `min(len_a, len_b) * 255^2`, which stays below the Babybear modulus as long as the shorter
+//! operand has at most [`MAX_SHORTER_OPERAND_BYTES`] bytes.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{multiply_polynomials_fft_auto, MultiplicationError, FE};
+
+/// The Babybear modulus `p = 15 * 2^27 + 1`.
+const BABYBEAR_MODULUS: u64 = 2_013_265_921;
+
+/// The longest shorter operand, in bytes, whose convolution coefficients all stay below the
+/// Babybear modulus.
+pub const MAX_SHORTER_OPERAND_BYTES: usize = ((BABYBEAR_MODULUS - 1) / (255 * 255)) as usize;
+
+/// Multiplies two non-negative integers given as little-endian bytes, using the FFT over Babybear
+/// on their base-256 digit polynomials.
+///
+/// # Arguments
+/// * `a` - The first integer, least significant byte first.
+/// * `b` - The second integer, least significant byte first.
+///
+/// # Returns
+/// The product, least significant byte first, without trailing (most significant) zero bytes. Zero
+/// is the empty vector.
+///
+/// # Errors
+/// Returns [`MultiplicationError::OperandTooLong`] if both operands are longer than
+/// [`MAX_SHORTER_OPERAND_BYTES`], since the convolution would then wrap around the modulus, and
+/// [`MultiplicationError::DomainTooLarge`] if the product is too large for Babybear's two-adicity.
+pub fn multiply_integers(a: &[u8], b: &[u8]) -> Result<Vec<u8>, MultiplicationError> {
+    let (a, b) = (trimmed(a), trimmed(b));
+    if a.is_empty() || b.is_empty() {
+        return Ok(Vec::new());
+    }
+    let shorter = a.len().min(b.len());
+    if shorter > MAX_SHORTER_OPERAND_BYTES {
+        return Err(MultiplicationError::OperandTooLong {
+            max_len: MAX_SHORTER_OPERAND_BYTES,
+            got: shorter,
+        });
+    }
+
+    // 1. Multiply the digit polynomials; every coefficient is the exact integer convolution.
+    let to_poly = |digits: &[u8]| {
+        let coeffs: Vec<FE> = digits.iter().map(|&d| FE::from(d as u64)).collect();
+        Polynomial::new(&coeffs)
+    };
+    let product = multiply_polynomials_fft_auto(&to_poly(a), &to_poly(b))?;
+
+    // 2. Evaluate at x = 256 by propagating carries from the lowest digit upwards.
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    let mut carry: u64 = 0;
+    for c in &product.coefficients {
+        carry += u64::from(c.representative());
+        result.push((carry & 0xff) as u8);
+        carry >>= 8;
+    }
+    while carry > 0 {
+        result.push((carry & 0xff) as u8);
+        carry >>= 8;
+    }
+    Ok(trimmed(&result).to_vec())
+}
+
+/// Drops the most significant zero bytes.
+fn trimmed(digits: &[u8]) -> &[u8] {
+    let len = digits.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+    &digits[..len]
+}
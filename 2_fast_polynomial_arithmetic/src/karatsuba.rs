@@ -0,0 +1,105 @@
+//! Karatsuba polynomial multiplication.
+//!
+//! Splitting each operand at `m` as `A = A0 + x^m A1` gives
+//!
+//! ```text
+//! A * B = A0 B0 + x^m ((A0 + A1)(B0 + B1) - A0 B0 - A1 B1) + x^(2m) A1 B1
+//! ```
+//!
+//! which needs three half-size products instead of four, for O(N^log2(3)) ≈ O(N^1.585) work. It
+//! sits between the naive O(N^2) method and the FFT: faster than naive beyond a few dozen
+//! coefficients, with no roots of unity, so it works over any field.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsField;
+use lambdaworks_math::polynomial::Polynomial;
+
+/// Operand length at or below which the recursion falls back to schoolbook multiplication.
+pub const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Multiplies two polynomials using Karatsuba's algorithm.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+///
+/// # Returns
+/// A new `Polynomial` representing the product `p1 * p2`.
+pub fn multiply_polynomials_karatsuba<F: IsField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Polynomial::new(&[]);
+    }
+
+    // Pad both operands to a common length so every split is balanced.
+    let n = p1.coefficients.len().max(p2.coefficients.len());
+    let mut a = p1.coefficients.to_vec();
+    a.resize(n, FieldElement::zero());
+    let mut b = p2.coefficients.to_vec();
+    b.resize(n, FieldElement::zero());
+
+    // `Polynomial::new` trims the zero coefficients the padding introduces.
+    Polynomial::new(&karatsuba(&a, &b))
+}
+
+/// Returns the `2n - 1` coefficients of the product of two length-`n` coefficient vectors.
+pub(crate) fn karatsuba<F: IsField>(
+    a: &[FieldElement<F>],
+    b: &[FieldElement<F>],
+) -> Vec<FieldElement<F>> {
+    let n = a.len();
+    if n <= KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+
+    // Split at m = n / 2, so the high halves have length n - m >= m.
+    let m = n / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    let z0 = karatsuba(a0, b0);
+    let z2 = karatsuba(a1, b1);
+    let z1 = karatsuba(&add_halves(a0, a1), &add_halves(b0, b1));
+
+    let mut result = vec![FieldElement::<F>::zero(); 2 * n - 1];
+    for (i, c) in z0.into_iter().enumerate() {
+        result[i + m] = &result[i + m] - &c;
+        result[i] += c;
+    }
+    for (i, c) in z2.into_iter().enumerate() {
+        result[i + m] = &result[i + m] - &c;
+        result[i + 2 * m] += c;
+    }
+    for (i, c) in z1.into_iter().enumerate() {
+        result[i + m] += c;
+    }
+    result
+}
+
+/// Returns `low + high` for the split halves of an operand, where `high` is at least as long.
+fn add_halves<F: IsField>(
+    low: &[FieldElement<F>],
+    high: &[FieldElement<F>],
+) -> Vec<FieldElement<F>> {
+    let mut sum = high.to_vec();
+    for (s, c) in sum.iter_mut().zip(low) {
+        *s = &*s + c;
+    }
+    sum
+}
+
+/// Schoolbook multiplication of two coefficient vectors of the same length.
+fn schoolbook<F: IsField>(a: &[FieldElement<F>], b: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
+    let mut result = vec![FieldElement::<F>::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] += x * y;
+        }
+    }
+    result
+}
@@ -1,95 +1,287 @@
 //! This crate provides functions for polynomial multiplication using
-//! both the Fast Fourier Transform (FFT) algorithm and a naive O(N^2) approach.
+//! the Fast Fourier Transform (FFT) algorithm, the Toom-Cook-3 (O(N^1.465)) and Karatsuba
+//! (O(N^1.585)) algorithms, and a naive O(N^2) approach. The [`multiply`] front door picks one of
+//! them from the operand sizes.
 //! It leverages the `lambdaworks_math` library for field arithmetic and FFT primitives.
+//!
+//! Every function is generic over an FFT-friendly field `F: IsFFTField`, such as Babybear,
+//! Goldilocks, or Stark252. The [`F`] and [`FE`] aliases name the default Babybear field used by
+//! the demo and the benchmarks, and [`GoldilocksF`] and [`GoldilocksFE`] the 64-bit Goldilocks
+//! field the benchmarks compare it with.
+//!
+//! The experimental [`circle`] module multiplies over Mersenne31, which has no radix-2 FFT, with
+//! the circle FFT of Circle STARKs.
+//!
+//! Optional features: `streaming` adds out-of-core multiplication over memory-mapped files, `simd`
+//! adds vectorized Babybear NTT kernels (it needs the nightly `portable_simd` feature), `gpu`
+//! runs the transforms as `wgpu` compute shaders, `serde` makes Babybear polynomials and
+//! twiddle tables serializable so inputs can be saved and shared across runs, and `parallel`
+//! spreads [`multiply_batch`] and the output coefficients of `multiply_polynomials_naive_parallel`
+//! over the `rayon` thread pool.
+//!
+//! The zero polynomial is the one without coefficients; `Polynomial::new` strips trailing zeros,
+//! so `Polynomial::new(&[FE::zero()])` is zero too. Every multiplication returns the zero
+//! polynomial when either operand is zero, without checking that the product would fit in an FFT
+//! domain, and multiplies constants like any other polynomial.
+//!
+//! The crate is `no_std` and only needs `alloc`. The default `std` feature adds the proptest
+//! [`strategies`] and the [`tune`] autotuner, and is required by the `streaming` and `gpu`
+//! features.
+
+#![no_std]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+use alloc::vec;
+use alloc::vec::Vec;
 
-use lambdaworks_math::fft::cpu::bit_reversing::in_place_bit_reverse_permute;
-use lambdaworks_math::fft::cpu::fft::in_place_nr_2radix_fft;
 use lambdaworks_math::field::element::FieldElement;
 use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use lambdaworks_math::field::fields::mersenne31::field::Mersenne31Field;
+use lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
+use lambdaworks_math::field::traits::{IsFFTField, IsField};
 use lambdaworks_math::polynomial::Polynomial;
 
-// Type aliases for convenience, specifying the field to be Babybear31PrimeField.
-type F = Babybear31PrimeField;
-type FE = FieldElement<F>;
+pub mod adaptive;
+pub mod barrett;
+pub mod batch;
+pub mod bit_reverse_free;
+pub mod bluestein;
+pub mod calculus;
+pub mod circle;
+pub mod cyclic;
+pub mod degree_bound;
+pub mod division;
+pub mod domain;
+pub mod error;
+pub mod eval_poly;
+pub mod extension;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+pub mod integer_mul;
+pub mod karatsuba;
+pub mod middle_product;
+pub mod mixed_radix;
+pub mod multiplier;
+pub mod multipoint;
+pub mod negacyclic;
+pub mod plan;
+pub mod power;
+pub mod power_series;
+pub mod product_tree;
+pub mod radix;
+pub mod resultant;
+pub mod scaled_inverse;
+pub mod scratch;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod short_product;
+#[cfg(feature = "simd")]
+pub mod simd;
+pub mod sparse;
+pub mod square;
+#[cfg(feature = "streaming")]
+pub mod streaming;
+pub mod taylor;
+pub mod toom3;
+#[cfg(feature = "std")]
+pub mod tune;
+pub mod twiddles;
+
+pub use adaptive::{multiply, multiply_with_thresholds, Algorithm, ThresholdTable};
+pub use barrett::{reduce_mod, BarrettModulus};
+pub use batch::multiply_batch;
+pub use bit_reverse_free::{in_place_rn_2radix_ifft, multiply_polynomials_fft_with_permutes};
+pub use bluestein::{
+    bluestein_dft, exact_domain_size, multiply_polynomials_arbitrary_n, primitive_root_of_order,
+    HasMultiplicativeGenerator,
+};
+pub use calculus::{antiderivative, derivative};
+pub use circle::{
+    circle_domain, circle_fft, circle_ifft, multiply_polynomials_circle, CirclePoint,
+};
+pub use cyclic::{cyclic_convolution, cyclic_convolution_naive};
+pub use degree_bound::multiply_with_degree_bound;
+pub use division::divide_polynomials_fast;
+pub use domain::{required_domain_size, DomainPlan};
+pub use error::MultiplicationError;
+pub use eval_poly::EvalPoly;
+pub use extension::{multiply_quartic_fft, multiply_quartic_naive, Fp4};
+#[cfg(feature = "gpu")]
+pub use gpu::GpuMultiplier;
+pub use integer_mul::{multiply_integers, MAX_SHORTER_OPERAND_BYTES};
+pub use karatsuba::multiply_polynomials_karatsuba;
+pub use middle_product::middle_product;
+pub use mixed_radix::{
+    mixed_radix_domain_size, multiply_polynomials_mixed_radix, IsMixedRadixField,
+};
+pub use multiplier::{FftMultiplier, KaratsubaMultiplier, NaiveMultiplier, PolynomialMultiplier};
+pub use multipoint::evaluate_at_points_fast;
+pub use negacyclic::{multiply_negacyclic, multiply_negacyclic_naive};
+pub use plan::FftPlan;
+pub use power::pow_polynomial;
+pub use power_series::inverse_mod_xn;
+pub use product_tree::multiply_many;
+pub use radix::{multiply_polynomials_fft_with_radix, FftRadix};
+pub use resultant::{composed_product, composed_sum, resultant};
+pub use scaled_inverse::{
+    get_scaled_inv_twiddles, in_place_nr_2radix_ifft_scaled, multiply_polynomials_fft_scaled,
+};
+pub use scratch::{multiply_polynomials_fft_into, FftScratch};
+#[cfg(feature = "serde")]
+pub use serialization::{SerializablePolynomial, TwiddleSet};
+pub use short_product::{mul_high, mul_low};
+#[cfg(feature = "simd")]
+pub use simd::{multiply_polynomials_simd, SimdMultiplier};
+pub use sparse::{multiply_sparse_dense, SparsePoly};
+pub use square::{square_polynomial_fft, square_polynomial_naive};
+#[cfg(feature = "streaming")]
+pub use streaming::{multiply_streaming, read_coefficients, write_coefficients};
+pub use taylor::{scale, shift};
+pub use toom3::multiply_polynomials_toom3;
+#[cfg(feature = "std")]
+pub use tune::{load_or_tune, tune, tune_with_sizes, TUNING_SIZES};
+pub use twiddles::TwiddleCache;
+
+/// The default field, Babybear31PrimeField.
+pub type F = Babybear31PrimeField;
+/// An element of the default field.
+pub type FE = FieldElement<F>;
+/// The Goldilocks field, `p = 2^64 - 2^32 + 1`, with two-adicity 32.
+pub type GoldilocksF = Goldilocks64Field;
+/// An element of the Goldilocks field.
+pub type GoldilocksFE = FieldElement<GoldilocksF>;
+/// The Mersenne31 field, `p = 2^31 - 1`, used by the experimental [`circle`] FFT.
+pub type Mersenne31F = Mersenne31Field;
+/// An element of the Mersenne31 field.
+pub type Mersenne31FE = FieldElement<Mersenne31F>;
 
 /// Multiplies two polynomials using the Fast Fourier Transform (FFT) algorithm.
 ///
 /// This function performs polynomial multiplication in O(N log N) time, where N is
-/// the size of the evaluation domain (a power of 2).
+/// the size of the evaluation domain (a power of 2). The transforms are those of `plan`, run with
+/// [`FftPlan::forward_unordered`] and [`FftPlan::inverse_unordered`], so no bit-reversal
+/// permutation is run; see [`bit_reverse_free`].
 ///
 /// # Arguments
 /// * `p1` - The first polynomial.
 /// * `p2` - The second polynomial.
-/// * `n` - The size of the FFT domain. Must be a power of 2 and `n >= degree(p1) + degree(p2) + 1`.
-/// * `twiddles` - Precomputed bit-reversed roots of unity for the forward FFT.
-/// * `inv_twiddles` - Precomputed bit-reversed inverse roots of unity for the Inverse FFT.
+/// * `plan` - The FFT plan for the domain. Its size must be at least `degree(p1) + degree(p2) + 1`.
 ///
 /// # Returns
 /// A new `Polynomial` representing the product `p1 * p2`.
 ///
-/// # Panics
-/// This function does not explicitly panic, but relies on the correctness of `lambdaworks_math`
-/// functions. Incorrect `n` or precomputed twiddles may lead to incorrect results.
-pub fn multiply_polynomials_fft(
-    p1: &Polynomial<FE>,
-    p2: &Polynomial<FE>,
-    n: usize,
-    twiddles: &[FieldElement<F>],
-    inv_twiddles: &[FieldElement<F>],
-) -> Polynomial<FE> {
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooSmall`] if the plan's domain cannot hold the product.
+pub fn multiply_polynomials_fft<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    plan: &FftPlan<F>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::new(&[]));
+    }
+    // 0. Validate the domain, since a domain that is too small silently wraps the product around.
+    validate_plan(p1.degree() + p2.degree() + 1, plan)?;
+    let n = plan.size();
+
     // 1. Pad coefficients to match the FFT domain size `n`.
     // The FFT algorithm requires the input vectors to have a length equal to the domain size.
-    let mut p1_coeffs = p1.coefficients.to_vec();
-    p1_coeffs.resize(n, FE::zero()); // Pad with zeros.
+    let mut p1_evals = p1.coefficients.to_vec();
+    p1_evals.resize(n, FieldElement::zero()); // Pad with zeros.
 
-    let mut p2_coeffs = p2.coefficients.to_vec();
-    p2_coeffs.resize(n, FE::zero()); // Pad with zeros.
+    let mut p2_evals = p2.coefficients.to_vec();
+    p2_evals.resize(n, FieldElement::zero()); // Pad with zeros.
 
     // 2. Perform Fast Fourier Transform (FFT) on the padded coefficients.
-    // The `in_place_nr_2radix_fft` function expects and produces bit-reversed evaluations
-    // when used with bit-reversed twiddles.
-    let mut p1_evals_bit_rev = p1_coeffs;
-    in_place_nr_2radix_fft(&mut p1_evals_bit_rev, twiddles);
-
-    let mut p2_evals_bit_rev = p2_coeffs;
-    in_place_nr_2radix_fft(&mut p2_evals_bit_rev, twiddles);
+    // The evaluations are left in the plan's own order, bit-reversed for radix 2.
+    plan.forward_unordered(&mut p1_evals);
+    plan.forward_unordered(&mut p2_evals);
 
-    // 3. Apply bit-reversal permutation to get naturally ordered evaluations.
-    // This step converts the bit-reversed output of the FFT into standard order.
-    let mut p1_evals = p1_evals_bit_rev;
-    in_place_bit_reverse_permute(&mut p1_evals);
-
-    let mut p2_evals = p2_evals_bit_rev;
-    in_place_bit_reverse_permute(&mut p2_evals);
-
-    // 4. Perform pointwise multiplication of the evaluations.
+    // 3. Perform pointwise multiplication of the evaluations.
     // This is the core step where the polynomial multiplication in the coefficient domain
     // is transformed into simple element-wise multiplication in the evaluation domain.
-    let c_evals: Vec<FE> = p1_evals
+    // Both vectors are in the same order, so no permutation is needed first.
+    let mut c_coeffs: Vec<FieldElement<F>> = p1_evals
         .iter()
         .zip(p2_evals.iter()) // Iterate over both evaluation vectors simultaneously.
         .map(|(y1, y2)| y1 * y2) // Multiply corresponding evaluations.
         .collect();
 
-    // 5. Perform Inverse Fast Fourier Transform (IFFT) on the product evaluations.
-    // This transforms the product evaluations back into product coefficients (still bit-reversed).
-    let mut c_coeffs_bit_rev = c_evals;
-    in_place_nr_2radix_fft(&mut c_coeffs_bit_rev, inv_twiddles);
-
-    // 6. Apply bit-reversal permutation again to get naturally ordered, scaled coefficients.
-    let mut c_coeffs_scaled = c_coeffs_bit_rev;
-    in_place_bit_reverse_permute(&mut c_coeffs_scaled);
-
-    // 7. Scale the coefficients by 1/N.
-    // The IFFT process introduces a scaling factor of N (the domain size),
-    // so we need to divide each coefficient by N to get the true coefficients.
-    let n_inv = FE::from(n as u64)
-        .inv()
-        .expect("Inverse of N should exist in the field.");
-    let c_coeffs: Vec<FE> = c_coeffs_scaled.iter().map(|c| c * n_inv).collect();
-
-    // 8. Construct the resulting polynomial from the computed coefficients.
-    Polynomial::new(&c_coeffs)
+    // 4. Perform Inverse Fast Fourier Transform (IFFT) on the product evaluations.
+    // The inverse takes the evaluations in the order the forward transform left them and
+    // returns naturally ordered coefficients, already divided by N.
+    plan.inverse_unordered(&mut c_coeffs);
+
+    // 5. Construct the resulting polynomial from the computed coefficients.
+    Ok(Polynomial::new(&c_coeffs))
+}
+
+/// Checks that the domain of `plan` can hold a product with `required` coefficients.
+pub(crate) fn validate_plan<F: IsFFTField>(
+    required: usize,
+    plan: &FftPlan<F>,
+) -> Result<(), MultiplicationError> {
+    if plan.size() < required {
+        return Err(MultiplicationError::DomainTooSmall {
+            required,
+            got: plan.size(),
+        });
+    }
+    Ok(())
+}
+
+/// Multiplies two polynomials using the FFT, choosing the domain size and computing the twiddle
+/// factors internally.
+///
+/// The domain size `n` is the smallest power of two with `n >= degree(p1) + degree(p2) + 1`, and
+/// the forward and inverse bit-reversed twiddles for that size are generated on every call. Use
+/// [`multiply_polynomials_fft_cached`] when multiplying many polynomials.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+///
+/// # Returns
+/// A new `Polynomial` representing the product `p1 * p2`.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the field has no multiplicative subgroup of
+/// size `n`, i.e. if the product is too large for the field's two-adicity.
+pub fn multiply_polynomials_fft_auto<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    multiply_polynomials_fft_cached(p1, p2, &mut TwiddleCache::new())
+}
+
+/// Multiplies two polynomials using the FFT, taking the plan from `cache`.
+///
+/// Behaves like [`multiply_polynomials_fft_auto`], but the plan for each domain size is built
+/// only the first time that size is seen, so loops over many products pay for its twiddles once.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+/// * `cache` - The plan cache to read from and fill.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the field has no multiplicative subgroup of
+/// the required size.
+pub fn multiply_polynomials_fft_cached<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    cache: &mut TwiddleCache<F>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::new(&[]));
+    }
+    let plan = cache.plan(DomainPlan::new(p1, p2).log_size())?;
+
+    multiply_polynomials_fft(p1, p2, plan)
 }
 
 /// Multiplies two polynomials using a naive O(N^2) algorithm.
@@ -103,101 +295,2134 @@ pub fn multiply_polynomials_fft(
 ///
 /// # Returns
 /// A new `Polynomial` representing the product `p1 * p2`.
-pub fn multiply_polynomials_naive(p1: &Polynomial<FE>, p2: &Polynomial<FE>) -> Polynomial<FE> {
+pub fn multiply_polynomials_naive<F: IsField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    // The zero polynomial has no coefficients to index, and annihilates the product.
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Polynomial::new(&[]);
+    }
+
     let deg1 = p1.degree();
     let deg2 = p2.degree();
 
     // The degree of the product polynomial is deg1 + deg2.
     // The number of coefficients will be deg1 + deg2 + 1.
-    let mut result_coeffs = vec![FE::zero(); deg1 + deg2 + 1];
+    let mut result_coeffs = vec![FieldElement::<F>::zero(); deg1 + deg2 + 1];
 
     // Perform the standard polynomial multiplication by iterating through
     // each coefficient of p1 and multiplying it by each coefficient of p2.
     // The product of x^i and x^j contributes to the x^(i+j) term.
     for i in 0..=deg1 {
         for j in 0..=deg2 {
-            result_coeffs[i + j] += p1.coefficients[i] * p2.coefficients[j];
+            result_coeffs[i + j] += &p1.coefficients[i] * &p2.coefficients[j];
         }
     }
 
     Polynomial::new(&result_coeffs)
 }
 
+/// Multiplies two polynomials using the naive O(N^2) algorithm, computing the output coefficients
+/// in parallel on the `rayon` thread pool.
+///
+/// Each output coefficient `c_k = sum_i p1[i] * p2[k - i]` is an independent dot product, so the
+/// work splits without synchronization. The result equals [`multiply_polynomials_naive`]; the two
+/// are separate functions because sharing the operands across threads needs the `Send + Sync`
+/// bound, which generic callers of the sequential version cannot provide. Benchmarking against
+/// this version keeps multi-core effects out of the comparison with the parallel backends.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+///
+/// # Returns
+/// A new `Polynomial` representing the product `p1 * p2`.
+#[cfg(feature = "parallel")]
+pub fn multiply_polynomials_naive_parallel<F: IsField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>>
+where
+    FieldElement<F>: Send + Sync,
+{
+    use rayon::prelude::*;
+
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Polynomial::new(&[]);
+    }
+
+    let deg1 = p1.degree();
+    let deg2 = p2.degree();
+
+    // Output coefficient `k` collects the products `p1[i] * p2[k - i]` with both indices in range.
+    let result_coeffs: Vec<FieldElement<F>> = (0..deg1 + deg2 + 1)
+        .into_par_iter()
+        .map(|k| {
+            let lo = k.saturating_sub(deg2);
+            let hi = k.min(deg1);
+            (lo..=hi).fold(FieldElement::<F>::zero(), |acc, i| {
+                acc + &p1.coefficients[i] * &p2.coefficients[k - i]
+            })
+        })
+        .collect();
+
+    Polynomial::new(&result_coeffs)
+}
+
+#[cfg(feature = "std")]
 pub mod strategies {
+    use alloc::vec::Vec;
+
+    use lambdaworks_math::field::element::FieldElement;
+    use lambdaworks_math::field::traits::IsField;
+    use lambdaworks_math::polynomial::Polynomial;
     use proptest::collection::vec;
     use proptest::prelude::{any, Strategy};
 
-    use super::*;
-
-    pub fn next_power_of_2(n: usize) -> usize {
-        let mut k = 1;
-        while k < n {
-            k <<= 1;
-        }
-        k
-    }
-
     /// Generates a polynomial with coefficients as `FE` elements,
     /// with a degree up to `max_degree`.
-    pub fn arb_polynomial(max_degree: usize) -> impl Strategy<Value = Polynomial<FE>> {
+    pub fn arb_polynomial<F: IsField>(
+        max_degree: usize,
+    ) -> impl Strategy<Value = Polynomial<FieldElement<F>>> {
         // Generate a vector of coefficients. The range `1..=max_degree`
         // ensures that the polynomial has at least one term (a constant).
-        vec(any::<u64>().prop_map(FE::from), 1..=max_degree)
+        vec(any::<u64>().prop_map(FieldElement::from), 1..=max_degree)
             .prop_map(|coeffs| Polynomial::new(&coeffs))
     }
+
+    /// Generates a polynomial over the Goldilocks field, with a degree up to `max_degree`. The
+    /// coefficients are drawn from all of `u64` and reduced, so they cover the whole field.
+    pub fn arb_goldilocks_polynomial(
+        max_degree: usize,
+    ) -> impl Strategy<Value = Polynomial<crate::GoldilocksFE>> {
+        arb_polynomial::<crate::GoldilocksF>(max_degree)
+    }
+
+    /// Generates a polynomial over Babybear's quartic extension, as a coefficient vector with at
+    /// least one and at most `max_degree` entries.
+    pub fn arb_quartic_polynomial(max_degree: usize) -> impl Strategy<Value = Vec<crate::Fp4>> {
+        let element = (any::<u64>(), any::<u64>(), any::<u64>(), any::<u64>())
+            .prop_map(|(a0, a1, a2, a3)| crate::Fp4::new([a0, a1, a2, a3].map(FieldElement::from)));
+        vec(element, 1..=max_degree)
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use lambdaworks_math::fft::cpu::roots_of_unity::get_twiddles;
+    use alloc::vec::Vec;
+    use alloc::{format, vec};
+    use std::cell::RefCell;
+
+    use lambdaworks_math::fft::cpu::fft::in_place_nr_2radix_fft;
+    use lambdaworks_math::fft::cpu::roots_of_unity::{get_powers_of_primitive_root, get_twiddles};
+    use lambdaworks_math::field::element::FieldElement;
     use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
-    use lambdaworks_math::field::traits::RootsConfig;
-    use proptest::prop_assert_eq;
+    use lambdaworks_math::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+    use lambdaworks_math::field::traits::{IsFFTField, RootsConfig};
+    use lambdaworks_math::polynomial::Polynomial;
+    use num_bigint::BigUint;
+    use proptest::collection::vec;
+    use proptest::prelude::{any, Just, Strategy};
     use proptest::test_runner::{Config, TestRunner};
+    use proptest::{prop_assert, prop_assert_eq};
+
+    use crate::{
+        antiderivative, bluestein_dft, circle_domain, circle_fft, circle_ifft, composed_product,
+        composed_sum, cyclic_convolution, cyclic_convolution_naive, derivative,
+        divide_polynomials_fast, evaluate_at_points_fast, exact_domain_size,
+        get_scaled_inv_twiddles, in_place_rn_2radix_ifft, inverse_mod_xn, middle_product,
+        mixed_radix_domain_size, mul_high, mul_low, multiply, multiply_batch, multiply_integers,
+        multiply_many, multiply_negacyclic, multiply_negacyclic_naive,
+        multiply_polynomials_arbitrary_n, multiply_polynomials_circle, multiply_polynomials_fft,
+        multiply_polynomials_fft_auto, multiply_polynomials_fft_cached,
+        multiply_polynomials_fft_into, multiply_polynomials_fft_scaled,
+        multiply_polynomials_fft_with_permutes, multiply_polynomials_fft_with_radix,
+        multiply_polynomials_karatsuba, multiply_polynomials_mixed_radix,
+        multiply_polynomials_naive, multiply_polynomials_toom3, multiply_quartic_fft,
+        multiply_quartic_naive, multiply_sparse_dense, multiply_with_degree_bound,
+        multiply_with_thresholds, pow_polynomial, primitive_root_of_order, reduce_mod,
+        required_domain_size, resultant, scale, shift, square_polynomial_fft,
+        square_polynomial_naive, strategies, Algorithm, BarrettModulus, DomainPlan, EvalPoly,
+        FftMultiplier, FftPlan, FftRadix, FftScratch, Fp4, GoldilocksF, IsMixedRadixField,
+        KaratsubaMultiplier, Mersenne31F, Mersenne31FE, MultiplicationError, NaiveMultiplier,
+        PolynomialMultiplier, SparsePoly, ThresholdTable, TwiddleCache, FE,
+        MAX_SHORTER_OPERAND_BYTES,
+    };
+
+    /// Checks that the FFT multiplication produces the same result as the naive multiplication
+    /// over the field `F` for a range of randomly generated polynomials.
+    fn check_fft_vs_naive_multiplication<F: IsFFTField>(max_degree: usize) {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<F>(max_degree),
+            strategies::arb_polynomial::<F>(max_degree),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                // Calculate expected result using the naive method.
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+
+                // Determine the FFT domain size and plan its transforms.
+                let n = required_domain_size(&p1, &p2);
+                let plan = FftPlan::<F>::new(n.trailing_zeros() as u64).unwrap();
 
-    use crate::strategies::{self, next_power_of_2};
-    use crate::{multiply_polynomials_fft, multiply_polynomials_naive};
+                // Calculate actual result using the FFT method.
+                let actual_poly = multiply_polynomials_fft(&p1, &p2, &plan).unwrap();
+
+                // Assert that the coefficients are equal.
+                prop_assert_eq!(
+                    actual_poly.coefficients,
+                    expected_poly.coefficients,
+                    "FFT and Naive multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
 
     /// This test verifies that the FFT multiplication produces the same result
     /// as the naive multiplication for a range of randomly generated polynomials.
     #[test]
     fn proptest_fft_vs_naive_multiplication() {
+        check_fft_vs_naive_multiplication::<Babybear31PrimeField>(1000);
+    }
+
+    /// The same property over the 252-bit Stark field, so the generic code path is exercised on
+    /// a field with a different representation and two-adicity.
+    #[test]
+    fn proptest_fft_vs_naive_multiplication_stark252() {
+        check_fft_vs_naive_multiplication::<Stark252PrimeField>(200);
+    }
+
+    /// The same property over Goldilocks, whose 64-bit elements the benchmarks compare with
+    /// Babybear's 31-bit ones.
+    #[test]
+    fn proptest_fft_vs_naive_multiplication_goldilocks() {
+        check_fft_vs_naive_multiplication::<GoldilocksF>(1000);
+    }
+
+    /// This test verifies that the self-sizing FFT multiplication agrees with the naive
+    /// multiplication without the caller providing a domain size or twiddles.
+    #[test]
+    fn proptest_fft_auto_vs_naive_multiplication() {
         let mut runner = TestRunner::new(Config::default());
 
-        let max_degree_for_proptest = 1000;
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let actual_poly = multiply_polynomials_fft_auto(&p1, &p2).unwrap();
+
+                prop_assert_eq!(
+                    actual_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Auto-sized FFT and Naive multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// This test verifies that products computed through a shared `TwiddleCache` match the naive
+    /// multiplication, and that the cache holds exactly one entry per domain size it has seen.
+    #[test]
+    fn proptest_fft_cached_vs_naive_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+        let cache = RefCell::new(TwiddleCache::new());
 
         let strategy = (
-            strategies::arb_polynomial(max_degree_for_proptest),
-            strategies::arb_polynomial(max_degree_for_proptest),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
         );
 
         runner
             .run(&strategy, |(p1, p2)| {
-                // Calculate expected result using the naive method.
                 let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let actual_poly =
+                    multiply_polynomials_fft_cached(&p1, &p2, &mut cache.borrow_mut()).unwrap();
 
-                // Determine the FFT domain size and precompute twiddles.
-                let min_domain_size = p1.degree() + p2.degree() + 1;
-                let n = next_power_of_2(min_domain_size);
-                let twiddles = get_twiddles::<Babybear31PrimeField>(
-                    n.trailing_zeros() as u64,
-                    RootsConfig::BitReverse,
-                )
-                .unwrap();
-                let inv_twiddles = get_twiddles::<Babybear31PrimeField>(
-                    n.trailing_zeros() as u64,
-                    RootsConfig::BitReverseInversed,
+                prop_assert_eq!(actual_poly.coefficients, expected_poly.coefficients);
+                Ok(())
+            })
+            .unwrap();
+
+        // Products of degree below 600 need domains of size 2^0 through 2^10.
+        assert!(cache.borrow().len() <= 11);
+    }
+
+    /// The cached plans hold exactly the tables `get_twiddles` produces.
+    #[test]
+    fn twiddle_cache_matches_get_twiddles() {
+        let mut cache = TwiddleCache::<Babybear31PrimeField>::new();
+        assert!(cache.is_empty());
+        for log_n in [0, 1, 5, 10] {
+            let plan = cache.plan(log_n).unwrap();
+            assert_eq!(plan.size(), 1 << log_n);
+            assert_eq!(
+                plan.twiddles(),
+                get_twiddles::<Babybear31PrimeField>(log_n, RootsConfig::BitReverse).unwrap()
+            );
+            assert_eq!(
+                plan.inv_twiddles(),
+                get_twiddles::<Babybear31PrimeField>(log_n, RootsConfig::BitReverseInversed)
+                    .unwrap()
+            );
+        }
+        assert_eq!(cache.len(), 4);
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    /// Bad domain sizes and twiddle tables are reported as errors instead of producing a wrong
+    /// product.
+    #[test]
+    fn fft_multiplication_rejects_invalid_inputs() {
+        // (1 + x + x^2)^2 has 5 coefficients, so it needs a domain of at least 8 points.
+        let p = Polynomial::new(&[FE::one(), FE::one(), FE::one()]);
+        let plan8 = FftPlan::<Babybear31PrimeField>::new(3).unwrap();
+        let plan4 = FftPlan::<Babybear31PrimeField>::new(2).unwrap();
+
+        assert!(multiply_polynomials_fft(&p, &p, &plan8).is_ok());
+        assert_eq!(
+            multiply_polynomials_fft(&p, &p, &plan4).unwrap_err(),
+            MultiplicationError::DomainTooSmall {
+                required: 5,
+                got: 4
+            }
+        );
+        let two_adicity = Babybear31PrimeField::TWO_ADICITY;
+        assert_eq!(
+            FftPlan::<Babybear31PrimeField>::new(two_adicity + 1).unwrap_err(),
+            MultiplicationError::DomainTooLarge {
+                log_n: two_adicity + 1,
+                two_adicity
+            }
+        );
+        // The scaled inverse twiddles report a domain too large to index instead of overflowing.
+        assert!(get_scaled_inv_twiddles::<Babybear31PrimeField>(64).is_err());
+
+        // Loaded tables become a plan only if they have the length of their domain.
+        let (tw8, inv_tw8) = (plan8.twiddles().to_vec(), plan8.inv_twiddles().to_vec());
+        let (tw4, inv_tw4) = (plan4.twiddles().to_vec(), plan4.inv_twiddles().to_vec());
+        assert_eq!(
+            FftPlan::from_twiddles(3, tw8.clone(), inv_tw8.clone()),
+            Ok(plan8.clone())
+        );
+        assert_eq!(
+            FftPlan::from_twiddles(3, tw8[..3].to_vec(), inv_tw8[..3].to_vec()).unwrap_err(),
+            MultiplicationError::TwiddleLengthMismatch {
+                expected: 4,
+                got: 3
+            }
+        );
+        for (tw, inv_tw) in [(tw4, inv_tw8.clone()), (tw8.clone(), inv_tw4)] {
+            assert_eq!(
+                FftPlan::from_twiddles(3, tw, inv_tw).unwrap_err(),
+                MultiplicationError::TwiddleLengthMismatch {
+                    expected: 4,
+                    got: 2
+                }
+            );
+        }
+        assert_eq!(
+            FftPlan::from_twiddles(two_adicity + 1, tw8, inv_tw8).unwrap_err(),
+            MultiplicationError::DomainTooLarge {
+                log_n: two_adicity + 1,
+                two_adicity
+            }
+        );
+    }
+
+    /// A plan's forward transform evaluates at the powers of its root of unity in natural order,
+    /// whatever the radix, and its inverse undoes it.
+    #[test]
+    fn fft_plan_forward_and_inverse() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (0u64..9).prop_flat_map(|log_n| {
+            (
+                Just(log_n),
+                vec(any::<u64>().prop_map(FE::from), 1usize << log_n),
+            )
+        });
+
+        runner
+            .run(&strategy, |(log_n, coeffs)| {
+                let n = 1usize << log_n;
+                let poly = Polynomial::new(&coeffs);
+                let roots = get_powers_of_primitive_root::<Babybear31PrimeField>(
+                    log_n,
+                    n,
+                    RootsConfig::Natural,
                 )
                 .unwrap();
+                let expected: Vec<FE> = roots.iter().map(|w| poly.evaluate(w)).collect();
 
-                // Calculate actual result using the FFT method.
-                let actual_poly = multiply_polynomials_fft(&p1, &p2, n, &twiddles, &inv_twiddles);
+                for radix in [FftRadix::Radix2, FftRadix::Radix4] {
+                    let plan = FftPlan::with_radix(log_n, radix).unwrap();
+                    prop_assert_eq!(plan.radix(), radix);
+                    let mut buf = coeffs.clone();
+                    plan.forward(&mut buf);
+                    prop_assert_eq!(
+                        &buf,
+                        &expected,
+                        "Plan evaluations are not in natural order!"
+                    );
+                    plan.inverse(&mut buf);
+                    prop_assert_eq!(&buf, &coeffs, "Plan inverse does not undo the forward FFT!");
 
-                // Assert that the coefficients are equal.
+                    plan.forward_unordered(&mut buf);
+                    plan.inverse_unordered(&mut buf);
+                    prop_assert_eq!(&buf, &coeffs);
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The FFT with the scaling folded into the inverse twiddles must agree with the naive
+    /// multiplication, and its scaled table must be the inverse twiddles divided by `N`.
+    #[test]
+    fn proptest_fft_scaled_vs_naive_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let n = required_domain_size(&p1, &p2);
+                let log_n = n.trailing_zeros() as u64;
+                let plan = FftPlan::<Babybear31PrimeField>::new(log_n).unwrap();
+                let scaled = get_scaled_inv_twiddles::<Babybear31PrimeField>(log_n).unwrap();
+
+                let n_fe = FE::from(n as u64);
+                prop_assert!(scaled
+                    .iter()
+                    .zip(plan.inv_twiddles())
+                    .all(|(s, w)| s * n_fe == *w));
+                prop_assert_eq!(plan.scaled_inv_twiddles(), &scaled[..]);
+
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let result_poly = multiply_polynomials_fft_scaled(&p1, &p2, &plan).unwrap();
                 prop_assert_eq!(
-                    actual_poly.coefficients,
+                    result_poly.coefficients,
                     expected_poly.coefficients,
-                    "FFT and Naive multiplication results differ!"
+                    "Scaled FFT and Naive results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Multiplies through any backend, as downstream code generic over the trait would.
+    fn multiply_with<M: PolynomialMultiplier>(
+        multiplier: &M,
+        p1: &Polynomial<FE>,
+        p2: &Polynomial<FE>,
+    ) -> Polynomial<FE> {
+        multiplier.multiply(p1, p2).unwrap()
+    }
+
+    /// Every backend behind the trait must agree with the naive multiplication, and the FFT
+    /// backend must keep its twiddles across calls.
+    #[test]
+    fn polynomial_multiplier_backends_agree() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+        );
+        let fft = FftMultiplier::new();
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                for actual_poly in [
+                    multiply_with(&NaiveMultiplier, &p1, &p2),
+                    multiply_with(&KaratsubaMultiplier, &p1, &p2),
+                    multiply_with(&fft, &p1, &p2),
+                ] {
+                    prop_assert_eq!(
+                        &actual_poly.coefficients,
+                        &expected_poly.coefficients,
+                        "Backend and Naive results differ!"
+                    );
+                }
+                Ok(())
+            })
+            .unwrap();
+
+        // The naive and Karatsuba backends are generic over the field too.
+        let p = Polynomial::new(&[FieldElement::<Stark252PrimeField>::from(3)]);
+        assert_eq!(
+            PolynomialMultiplier::multiply(&KaratsubaMultiplier, &p, &p)
+                .unwrap()
+                .coefficients,
+            [FieldElement::from(9)]
+        );
+    }
+
+    /// Every backend returns the zero polynomial when an operand is zero, whether it has no
+    /// coefficients or only zero ones, and multiplies constants like any other polynomial.
+    #[test]
+    fn zero_and_constant_operands_across_backends() {
+        type Backend = fn(&Polynomial<FE>, &Polynomial<FE>) -> Polynomial<FE>;
+        let backends: [(&str, Backend); 18] = [
+            ("Naive", |a, b| multiply_polynomials_naive(a, b)),
+            ("Karatsuba", |a, b| multiply_polynomials_karatsuba(a, b)),
+            ("Toom-3", |a, b| multiply_polynomials_toom3(a, b)),
+            ("FFT", |a, b| {
+                let plan = FftPlan::for_product(a, b).unwrap();
+                multiply_polynomials_fft(a, b, &plan).unwrap()
+            }),
+            ("FFT auto", |a, b| {
+                multiply_polynomials_fft_auto(a, b).unwrap()
+            }),
+            ("FFT cached", |a, b| {
+                multiply_polynomials_fft_cached(a, b, &mut TwiddleCache::new()).unwrap()
+            }),
+            ("Radix-4", |a, b| {
+                multiply_polynomials_fft_with_radix(a, b, FftRadix::Radix4).unwrap()
+            }),
+            ("FFT into", |a, b| {
+                let mut out = Vec::new();
+                multiply_polynomials_fft_into(a, b, &mut FftScratch::new(), &mut out).unwrap();
+                Polynomial::new(&out)
+            }),
+            ("Scaled FFT", |a, b| {
+                let plan = FftPlan::for_product(a, b).unwrap();
+                multiply_polynomials_fft_scaled(a, b, &plan).unwrap()
+            }),
+            ("Mixed radix", |a, b| {
+                multiply_polynomials_mixed_radix(a, b).unwrap()
+            }),
+            ("Bluestein", |a, b| {
+                multiply_polynomials_arbitrary_n(a, b).unwrap()
+            }),
+            ("Adaptive", |a, b| multiply(a, b)),
+            ("Trait", |a, b| FftMultiplier::new().multiply(a, b).unwrap()),
+            ("Batch", |a, b| {
+                multiply_batch(&[(a.clone(), b.clone())]).unwrap().remove(0)
+            }),
+            ("Sparse", |a, b| {
+                multiply_sparse_dense(&SparsePoly::from_dense(a), b).unwrap()
+            }),
+            ("Product tree", |a, b| {
+                multiply_many(&[a.clone(), b.clone()]).unwrap()
+            }),
+            ("Low product", |a, b| mul_low(a, b, 64)),
+            ("Cyclic", |a, b| cyclic_convolution(a, b, 64).unwrap()),
+        ];
+
+        let p = Polynomial::new(&[3, 1, 4, 1, 5].map(FE::from));
+        let c = Polynomial::new(&[FE::from(7)]);
+        let zeros = [
+            Polynomial::new(&[]),
+            Polynomial::new(&[FE::zero()]),
+            Polynomial::new(&[FE::zero(); 4]),
+        ];
+        let p_times_c = [21, 7, 28, 7, 35].map(FE::from);
+
+        for (name, backend) in backends {
+            for zero in &zeros {
+                for (a, b) in [(zero, &p), (&p, zero), (zero, zero), (zero, &c)] {
+                    assert!(
+                        backend(a, b).coefficients.is_empty(),
+                        "{} with a zero",
+                        name
+                    );
+                }
+            }
+            assert_eq!(backend(&c, &c).coefficients, [FE::from(49)], "{}", name);
+            assert_eq!(backend(&c, &p).coefficients, p_times_c, "{}", name);
+            assert_eq!(backend(&p, &c).coefficients, p_times_c, "{}", name);
+        }
+
+        // The same over Mersenne31, for the circle FFT.
+        let zero = Polynomial::new(&[]);
+        let c = Polynomial::new(&[Mersenne31FE::from(7)]);
+        assert!(multiply_polynomials_circle(&zero, &c)
+            .unwrap()
+            .coefficients
+            .is_empty());
+        assert_eq!(
+            multiply_polynomials_circle(&c, &c).unwrap().coefficients,
+            [Mersenne31FE::from(49)]
+        );
+    }
+
+    /// A zero operand gives zero even when the plan could not hold the product of the nonzero one
+    /// with anything, while the same plan is rejected for an actual product.
+    #[test]
+    fn zero_operands_skip_plan_validation() {
+        let plan = FftPlan::<Babybear31PrimeField>::new(0).unwrap();
+        let p = Polynomial::new(&[3, 1, 4, 1, 5].map(FE::from));
+        let zero = Polynomial::new(&[]);
+        type PlannedBackend = fn(
+            &Polynomial<FE>,
+            &Polynomial<FE>,
+            &FftPlan<Babybear31PrimeField>,
+        ) -> Result<Polynomial<FE>, MultiplicationError>;
+        let backends: [(&str, PlannedBackend); 3] = [
+            ("FFT", multiply_polynomials_fft),
+            ("Permutes", multiply_polynomials_fft_with_permutes),
+            ("Scaled FFT", multiply_polynomials_fft_scaled),
+        ];
+        for (name, backend) in backends {
+            for (a, b) in [(&zero, &p), (&p, &zero)] {
+                assert_eq!(backend(a, b, &plan), Ok(Polynomial::new(&[])), "{}", name);
+            }
+            assert!(
+                matches!(
+                    backend(&p, &p, &plan),
+                    Err(MultiplicationError::DomainTooSmall { .. })
+                ),
+                "{}",
+                name
+            );
+        }
+    }
+
+    /// A product within the declared bound must match the naive product, and one above it must be
+    /// rejected with its actual degree.
+    #[test]
+    fn multiply_with_degree_bound_vs_naive() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            0usize..700,
+        );
+
+        runner
+            .run(&strategy, |(p1, p2, bound)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let degree = expected_poly.degree();
+                match multiply_with_degree_bound(&p1, &p2, bound) {
+                    Ok(result_poly) => {
+                        prop_assert!(degree <= bound);
+                        prop_assert_eq!(
+                            result_poly.coefficients,
+                            expected_poly.coefficients,
+                            "Degree-bound and Naive results differ!"
+                        );
+                    }
+                    Err(e) => {
+                        prop_assert!(degree > bound);
+                        prop_assert_eq!(
+                            e,
+                            MultiplicationError::DegreeBoundExceeded { bound, degree }
+                        );
+                    }
+                }
+                Ok(())
+            })
+            .unwrap();
+
+        // The zero product satisfies every bound, and trailing zeros do not count.
+        let zero = Polynomial::new(&[]);
+        let x = Polynomial {
+            coefficients: vec![FE::zero(), FE::one(), FE::zero()],
+        };
+        assert_eq!(multiply_with_degree_bound(&zero, &x, 0), Ok(zero));
+        assert_eq!(multiply_with_degree_bound(&x, &x, 2).unwrap().degree(), 2);
+    }
+
+    /// The pipeline with bit-reversal permutations must still match the naive product, and the
+    /// bit-reversed-in inverse must undo the forward transform up to the factor `n`.
+    #[test]
+    fn bit_reverse_free_vs_with_permutes() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let n = required_domain_size(&p1, &p2);
+                let plan = FftPlan::new(n.trailing_zeros() as u64).unwrap();
+
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let result_poly = multiply_polynomials_fft_with_permutes(&p1, &p2, &plan).unwrap();
+                prop_assert_eq!(
+                    result_poly.coefficients,
+                    expected_poly.coefficients,
+                    "With-permutes FFT and Naive results differ!"
+                );
+
+                let mut values = p1.coefficients.clone();
+                values.resize(n, FE::zero());
+                in_place_nr_2radix_fft(&mut values, plan.twiddles());
+                in_place_rn_2radix_ifft(&mut values, plan.inv_twiddles());
+                let n_fe = FE::from(n as u64);
+                let mut expected = p1.coefficients.clone();
+                expected.resize(n, FE::zero());
+                let expected: Vec<FE> = expected.iter().map(|c| *c * n_fe).collect();
+                prop_assert_eq!(values, expected, "Inverse transform does not undo the FFT!");
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn proptest_parallel_naive_vs_naive_multiplication() {
+        use crate::multiply_polynomials_naive_parallel;
+
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let result_poly = multiply_polynomials_naive_parallel(&p1, &p2);
+                prop_assert_eq!(
+                    result_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Parallel Naive and Naive results differ!"
+                );
+                prop_assert_eq!(
+                    multiply_polynomials_naive_parallel(&p2, &p1),
+                    multiply_polynomials_naive(&p2, &p1)
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The derivative must undo the antiderivative and obey the product rule.
+    #[test]
+    fn derivative_and_antiderivative() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(200),
+            strategies::arb_polynomial::<Babybear31PrimeField>(200),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let integral = antiderivative(&p1);
+                if !p1.coefficients.is_empty() {
+                    prop_assert_eq!(&integral.coefficients[0], &FE::zero());
+                }
+                prop_assert_eq!(derivative(&integral), p1.clone());
+
+                let lhs = derivative(&multiply_polynomials_naive(&p1, &p2));
+                let rhs = multiply_polynomials_naive(&derivative(&p1), &p2)
+                    + multiply_polynomials_naive(&p1, &derivative(&p2));
+                prop_assert_eq!(
+                    lhs.coefficients,
+                    rhs.coefficients,
+                    "Product rule does not hold!"
+                );
+                Ok(())
+            })
+            .unwrap();
+
+        // d/dx (3 + 2x + 5x^3) = 2 + 15x^2, and constants vanish.
+        let p = Polynomial::new(&[FE::from(3), FE::from(2), FE::zero(), FE::from(5)]);
+        assert_eq!(
+            derivative(&p),
+            Polynomial::new(&[FE::from(2), FE::zero(), FE::from(15)])
+        );
+        assert_eq!(
+            derivative(&Polynomial::new(&[FE::from(7)])),
+            Polynomial::new(&[])
+        );
+    }
+
+    /// A batch of pairs of mixed sizes must match the naive products, in input order.
+    #[test]
+    fn multiply_batch_vs_naive() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = vec(
+            (
+                strategies::arb_polynomial::<Babybear31PrimeField>(100),
+                strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            ),
+            0..10,
+        );
+
+        runner
+            .run(&strategy, |pairs| {
+                let products = multiply_batch(&pairs).unwrap();
+                prop_assert_eq!(products.len(), pairs.len());
+                for ((p1, p2), result_poly) in pairs.iter().zip(products) {
+                    let expected_poly = multiply_polynomials_naive(p1, p2);
+                    prop_assert_eq!(
+                        result_poly.coefficients,
+                        expected_poly.coefficients,
+                        "Batch and Naive results differ!"
+                    );
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The circle FFT multiplication over Mersenne31 must agree with the naive multiplication.
+    #[test]
+    fn proptest_circle_vs_naive_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Mersenne31F>(300),
+            strategies::arb_polynomial::<Mersenne31F>(300),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let result_poly = multiply_polynomials_circle(&p1, &p2).unwrap();
+                prop_assert_eq!(
+                    result_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Circle FFT and Naive results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The circle domain lies on `x^2 + y^2 = 1`, the circle FFT evaluates the circle basis
+    /// `y^(j_0) v_0(x)^(j_1) v_1(x)^(j_2) ...` on it, and the inverse transform undoes it.
+    #[test]
+    fn circle_fft_evaluates_circle_basis() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (0u64..7)
+            .prop_flat_map(|log_n| vec(any::<u64>().prop_map(Mersenne31FE::from), 1usize << log_n));
+
+        runner
+            .run(&strategy, |coeffs| {
+                let log_n = coeffs.len().trailing_zeros() as u64;
+                let domain = circle_domain(log_n).unwrap();
+                let evals = circle_fft(&coeffs).unwrap();
+
+                for (point, eval) in domain.iter().zip(&evals) {
+                    prop_assert_eq!(point.x * point.x + point.y * point.y, Mersenne31FE::one());
+
+                    let mut expected = Mersenne31FE::zero();
+                    for (j, c) in coeffs.iter().enumerate() {
+                        let mut term = if j & 1 == 1 { c * point.y } else { *c };
+                        let mut v = point.x;
+                        for bit in 1..=log_n {
+                            if (j >> bit) & 1 == 1 {
+                                term *= v;
+                            }
+                            v = v.square().double() - Mersenne31FE::one();
+                        }
+                        expected += term;
+                    }
+                    prop_assert_eq!(eval, &expected);
+                }
+                prop_assert_eq!(circle_ifft(&evals).unwrap(), coeffs);
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(
+            circle_fft(&[Mersenne31FE::one(); 3]),
+            Err(MultiplicationError::DomainNotPowerOfTwo(3))
+        );
+    }
+
+    /// The planned domain is the smallest power of two holding the product, and only grows when
+    /// headroom is requested.
+    #[test]
+    fn domain_plan_sizes() {
+        // Degrees 2 and 1: the product has 4 coefficients, which fit exactly in 4 points.
+        let p1 = Polynomial::new(&[FE::one(), FE::one(), FE::one()]);
+        let p2 = Polynomial::new(&[FE::one(), FE::one()]);
+        let plan = DomainPlan::new(&p1, &p2);
+        assert_eq!(plan.product_len(), 4);
+        assert_eq!((plan.size(), plan.log_size()), (4, 2));
+        assert_eq!(required_domain_size(&p1, &p2), 4);
+
+        assert_eq!(plan.with_headroom(0).size(), 4);
+        assert_eq!(plan.with_headroom(1).size(), 8);
+        assert_eq!(plan.at_least(3).size(), 4);
+        assert_eq!(plan.at_least(100).size(), 128);
+        assert_eq!(plan.at_least(100).product_len(), 4);
+
+        // A plan with headroom is still a valid domain for the product.
+        let roomy = FftPlan::new(plan.at_least(16).log_size()).unwrap();
+        let product = multiply_polynomials_fft(&p1, &p2, &roomy).unwrap();
+        assert_eq!(
+            product.coefficients,
+            multiply_polynomials_naive(&p1, &p2).coefficients
+        );
+    }
+
+    /// Karatsuba must agree with the naive multiplication, including for operands long enough to
+    /// recurse past the schoolbook threshold and of unequal lengths.
+    #[test]
+    fn test_karatsuba_vs_naive_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let actual_poly = multiply_polynomials_karatsuba(&p1, &p2);
+
+                prop_assert_eq!(
+                    actual_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Karatsuba and Naive multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Toom-3 must agree with the naive multiplication, including for operands long enough to
+    /// recurse past the Karatsuba threshold and whose length is not a multiple of 3.
+    #[test]
+    fn test_toom3_vs_naive_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(700),
+            strategies::arb_polynomial::<Babybear31PrimeField>(700),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let actual_poly = multiply_polynomials_toom3(&p1, &p2);
+
+                prop_assert_eq!(
+                    actual_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Toom-3 and Naive multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The adaptive front door must agree with the naive multiplication whichever algorithm it
+    /// picks, with the default thresholds and with thresholds forcing every branch.
+    #[test]
+    fn test_adaptive_vs_naive_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(400),
+            strategies::arb_polynomial::<Babybear31PrimeField>(400),
+        );
+        let all_fft = ThresholdTable {
+            karatsuba: 0,
+            fft: 0,
+        };
+        let all_karatsuba = ThresholdTable {
+            karatsuba: 0,
+            fft: usize::MAX,
+        };
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+
+                for actual_poly in [
+                    multiply(&p1, &p2),
+                    multiply_with_thresholds(&p1, &p2, &all_fft),
+                    multiply_with_thresholds(&p1, &p2, &all_karatsuba),
+                ] {
+                    prop_assert_eq!(
+                        &actual_poly.coefficients,
+                        &expected_poly.coefficients,
+                        "Adaptive and Naive multiplication results differ!"
+                    );
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The selection is driven by the shorter operand.
+    #[test]
+    fn threshold_table_selects_by_shorter_operand() {
+        let thresholds = ThresholdTable {
+            karatsuba: 10,
+            fft: 100,
+        };
+        assert_eq!(thresholds.select(5, 1000), Algorithm::Naive);
+        assert_eq!(thresholds.select(10, 10), Algorithm::Karatsuba);
+        assert_eq!(thresholds.select(1000, 99), Algorithm::Karatsuba);
+        assert_eq!(thresholds.select(100, 1000), Algorithm::Fft);
+    }
+
+    /// A tuned table must order its crossovers, survive a save and load round trip, and drive the
+    /// adaptive front door to the same product as the naive method.
+    #[test]
+    fn tuned_thresholds_round_trip() {
+        use crate::tune_with_sizes;
+
+        let thresholds = tune_with_sizes::<Babybear31PrimeField>(&[4, 16, 64, 256]);
+        assert!(thresholds.karatsuba <= thresholds.fft);
+
+        let path = std::env::temp_dir().join(format!("fpa_thresholds_{}", std::process::id()));
+        thresholds.save(&path).unwrap();
+        assert_eq!(ThresholdTable::load(&path).unwrap(), thresholds);
+
+        std::fs::write(&path, "karatsuba = 8\nradix = 4\n").unwrap();
+        assert!(matches!(
+            ThresholdTable::load(&path),
+            Err(MultiplicationError::Io(_))
+        ));
+        std::fs::remove_file(&path).unwrap();
+
+        let p1 = Polynomial::new(&vec![FE::from(3); 300]);
+        let p2 = Polynomial::new(&vec![FE::from(5); 300]);
+        assert_eq!(
+            multiply_with_thresholds(&p1, &p2, &thresholds).coefficients,
+            multiply_polynomials_naive(&p1, &p2).coefficients
+        );
+    }
+
+    /// Both squaring routines must agree with multiplying the polynomial by itself.
+    #[test]
+    fn test_squaring_vs_naive_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = strategies::arb_polynomial::<Babybear31PrimeField>(500);
+
+        runner
+            .run(&strategy, |p| {
+                let expected_poly = multiply_polynomials_naive(&p, &p);
+
+                let plan = FftPlan::for_product(&p, &p).unwrap();
+                let fft_square = square_polynomial_fft(&p, &plan).unwrap();
+                prop_assert_eq!(
+                    &fft_square.coefficients,
+                    &expected_poly.coefficients,
+                    "FFT squaring and Naive multiplication results differ!"
+                );
+
+                let naive_square = square_polynomial_naive(&p);
+                prop_assert_eq!(
+                    &naive_square.coefficients,
+                    &expected_poly.coefficients,
+                    "Naive squaring and Naive multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The product tree must agree with folding the naive multiplication over the same factors.
+    #[test]
+    fn test_multiply_many_vs_sequential_naive() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = proptest::collection::vec(
+            strategies::arb_polynomial::<Babybear31PrimeField>(20),
+            1..=17,
+        );
+
+        runner
+            .run(&strategy, |polys| {
+                let expected_poly = polys[1..].iter().fold(polys[0].clone(), |acc, p| {
+                    multiply_polynomials_naive(&acc, p)
+                });
+                let actual_poly = multiply_many(&polys).unwrap();
+
+                prop_assert_eq!(
+                    &actual_poly.coefficients,
+                    &expected_poly.coefficients,
+                    "Product tree and sequential Naive multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The product of the linear factors `x - a` is a zerofier: it vanishes exactly on the `a`s.
+    #[test]
+    fn multiply_many_builds_a_zerofier() {
+        let roots: Vec<FE> = (1..=9).map(FE::from).collect();
+        let factors: Vec<_> = roots
+            .iter()
+            .map(|a| Polynomial::new(&[-a, FE::one()]))
+            .collect();
+
+        let zerofier = multiply_many(&factors).unwrap();
+        assert_eq!(zerofier.degree(), roots.len());
+        assert!(roots.iter().all(|a| zerofier.evaluate(a) == FE::zero()));
+        assert_ne!(zerofier.evaluate(&FE::from(10)), FE::zero());
+
+        let empty: [Polynomial<FE>; 0] = [];
+        assert_eq!(multiply_many(&empty).unwrap().coefficients, vec![FE::one()]);
+    }
+
+    /// Fast division must agree with lambdaworks' long division, including for quotients long
+    /// enough that the products go through the FFT.
+    #[test]
+    fn test_fast_division_vs_long_division() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(1000),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+        );
+
+        runner
+            .run(&strategy, |(num, den)| {
+                if den.coefficients.is_empty() {
+                    return Ok(());
+                }
+                let (expected_q, expected_r) = num.clone().long_division_with_remainder(&den);
+                let (q, r) = divide_polynomials_fast(&num, &den).unwrap();
+
+                prop_assert_eq!(
+                    &q.coefficients,
+                    &expected_q.coefficients,
+                    "Fast and long division quotients differ!"
+                );
+                prop_assert_eq!(
+                    &r.coefficients,
+                    &expected_r.coefficients,
+                    "Fast and long division remainders differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Dividing by the zero polynomial is an error, and a numerator of lower degree is its own
+    /// remainder.
+    #[test]
+    fn fast_division_edge_cases() {
+        let num = Polynomial::new(&[FE::from(1), FE::from(2)]);
+        let den = Polynomial::new(&[FE::from(3), FE::from(4), FE::from(5)]);
+
+        assert_eq!(
+            divide_polynomials_fast(&num, &Polynomial::new(&[])).unwrap_err(),
+            MultiplicationError::DivisionByZeroPolynomial
+        );
+        let (q, r) = divide_polynomials_fast(&num, &den).unwrap();
+        assert!(q.coefficients.is_empty());
+        assert_eq!(r.coefficients, num.coefficients);
+    }
+
+    /// Barrett reduction must leave the same remainder as long division, for numerators several
+    /// chunks longer than the modulus, and so must its modular product.
+    #[test]
+    fn test_barrett_reduction_vs_long_division() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(1000),
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+        );
+
+        runner
+            .run(&strategy, |(p, m)| {
+                if m.coefficients.is_empty() {
+                    return Ok(());
+                }
+                let (_, expected) = p.clone().long_division_with_remainder(&m);
+                let modulus = BarrettModulus::new(&m).unwrap();
+
+                prop_assert_eq!(
+                    &reduce_mod(&p, &m).unwrap().coefficients,
+                    &expected.coefficients,
+                    "Barrett reduction and long division remainders differ!"
+                );
+                let (_, expected_square) =
+                    multiply(&expected, &expected).long_division_with_remainder(&m);
+                prop_assert_eq!(
+                    &modulus.mul_mod(&expected, &expected).coefficients,
+                    &expected_square.coefficients,
+                    "Barrett modular product and reduced product differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+
+        // Reducing by zero is an error, and by a non-zero constant always leaves zero.
+        let p = Polynomial::new(&[FE::from(1), FE::from(2)]);
+        assert_eq!(
+            reduce_mod(&p, &Polynomial::new(&[])).unwrap_err(),
+            MultiplicationError::DivisionByZeroPolynomial
+        );
+        assert!(reduce_mod(&p, &Polynomial::new(&[FE::from(3)]))
+            .unwrap()
+            .coefficients
+            .is_empty());
+    }
+
+    /// On polynomials built from their roots, the resultant must be `lc(p)^deg(q)` times the
+    /// product of `q` over the roots of `p`, and the composed product and sum must be the products
+    /// of `x - a b` and `x - (a + b)` over every pair of roots.
+    #[test]
+    fn resultant_and_composed_operations_vs_roots() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(6),
+            strategies::arb_polynomial::<Babybear31PrimeField>(6),
+            strategies::arb_polynomial::<Babybear31PrimeField>(6),
+        );
+        let from_roots = |roots: &[FE], lc: u64| {
+            let mut factors: Vec<Polynomial<FE>> = roots
+                .iter()
+                .map(|r| Polynomial::new(&[-r, FE::one()]))
+                .collect();
+            factors.push(Polynomial::new(&[FE::from(lc)]));
+            multiply_many(&factors).unwrap()
+        };
+
+        runner
+            .run(&strategy, |(a, b, q)| {
+                let (a, b) = (&a.coefficients, &b.coefficients);
+                let p = from_roots(a, 3);
+                let expected = a.iter().fold(
+                    FE::from(3).pow(q.coefficients.len().saturating_sub(1) as u64),
+                    |acc, r| acc * q.evaluate(r),
+                );
+                prop_assert_eq!(
+                    resultant(&p, &q),
+                    expected,
+                    "Resultant and root product differ!"
+                );
+
+                let r = from_roots(b, 5);
+                let products: Vec<FE> = a
+                    .iter()
+                    .flat_map(|x| b.iter().map(move |y| x * y))
+                    .collect();
+                let sums: Vec<FE> = a
+                    .iter()
+                    .flat_map(|x| b.iter().map(move |y| x + y))
+                    .collect();
+                prop_assert_eq!(
+                    &composed_product(&p, &r).unwrap().coefficients,
+                    &from_roots(&products, 1).coefficients,
+                    "Composed product and root product differ!"
+                );
+                prop_assert_eq!(
+                    &composed_sum(&p, &r).unwrap().coefficients,
+                    &from_roots(&sums, 1).coefficients,
+                    "Composed sum and root product differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+
+        // The zero polynomial has a zero resultant and no composed operations.
+        let p = Polynomial::new(&[FE::from(1), FE::from(2)]);
+        let zero = Polynomial::new(&[]);
+        assert_eq!(resultant(&p, &zero), FE::zero());
+        assert_eq!(
+            composed_sum(&p, &zero).unwrap_err(),
+            MultiplicationError::ZeroPolynomial
+        );
+    }
+
+    /// The power-series inverse times the original is 1 modulo `x^n`, for precisions that are and
+    /// are not powers of two.
+    #[test]
+    fn test_inverse_mod_xn() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            1usize..700,
+        );
+
+        runner
+            .run(&strategy, |(p, n)| {
+                if p.coefficients.first().is_none_or(|a0| *a0 == FE::zero()) {
+                    return Ok(());
+                }
+                let g = inverse_mod_xn(&p, n).unwrap();
+                prop_assert!(g.coefficients.len() <= n);
+
+                let mut product = multiply_polynomials_naive(&p, &g).coefficients;
+                product.resize(n.max(product.len()), FE::zero());
+                prop_assert_eq!(&product[0], &FE::one());
+                prop_assert!(product[1..n].iter().all(|c| *c == FE::zero()));
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// A zero constant term has no inverse, and the inverse modulo `x^0` is zero.
+    #[test]
+    fn inverse_mod_xn_edge_cases() {
+        let x = Polynomial::new(&[FE::zero(), FE::one()]);
+        assert_eq!(
+            inverse_mod_xn(&x, 4).unwrap_err(),
+            MultiplicationError::ZeroConstantTerm
+        );
+        assert_eq!(
+            inverse_mod_xn(&Polynomial::<FE>::new(&[]), 4).unwrap_err(),
+            MultiplicationError::ZeroConstantTerm
+        );
+        assert!(inverse_mod_xn(&x, 0).unwrap().coefficients.is_empty());
+
+        // 1 / (1 - x) = 1 + x + x^2 + ...
+        let one_minus_x = Polynomial::new(&[FE::one(), -FE::one()]);
+        assert_eq!(
+            inverse_mod_xn(&one_minus_x, 5).unwrap().coefficients,
+            vec![FE::one(); 5]
+        );
+    }
+
+    /// The remainder tree must agree with Horner evaluation at every point, including repeated
+    /// points and point counts that are not powers of two.
+    #[test]
+    fn test_fast_multipoint_evaluation_vs_horner() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            proptest::collection::vec(any::<u64>().prop_map(FE::from), 0..300),
+        );
+
+        runner
+            .run(&strategy, |(poly, points)| {
+                let expected = poly.evaluate_slice(&points);
+                let actual = evaluate_at_points_fast(&poly, &points);
+
+                prop_assert_eq!(actual, expected, "Fast and Horner evaluations differ!");
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The radix-4 FFT must agree with the radix-2 path, for domains with both even and odd
+    /// `log2(n)`.
+    #[test]
+    fn test_radix4_vs_radix2_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(600),
+            strategies::arb_polynomial::<Babybear31PrimeField>(600),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly =
+                    multiply_polynomials_fft_with_radix(&p1, &p2, FftRadix::Radix2).unwrap();
+                let actual_poly =
+                    multiply_polynomials_fft_with_radix(&p1, &p2, FftRadix::Radix4).unwrap();
+
+                prop_assert_eq!(
+                    actual_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Radix-4 and radix-2 multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The mixed-radix FFT must agree with the naive multiplication, on domains that do and do
+    /// not contain a factor of 3.
+    #[test]
+    fn test_mixed_radix_vs_naive_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(400),
+            strategies::arb_polynomial::<Babybear31PrimeField>(400),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let actual_poly = multiply_polynomials_mixed_radix(&p1, &p2).unwrap();
+
+                prop_assert_eq!(
+                    actual_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Mixed-radix and Naive multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Babybear supports domains `2^a` and `3 * 2^a`, and its cube root of unity is primitive.
+    #[test]
+    fn mixed_radix_domain_sizes() {
+        let w3 = Babybear31PrimeField::three_adic_primitive_root_of_unity();
+        assert_ne!(w3, FE::one());
+        assert_eq!(w3.pow(3u64), FE::one());
+
+        let size = mixed_radix_domain_size::<Babybear31PrimeField>;
+        assert_eq!(size(0), Some(1));
+        assert_eq!(size(3), Some(3));
+        assert_eq!(size(5), Some(6));
+        assert_eq!(size(7), Some(8));
+        assert_eq!(size(5000), Some(6144));
+        let max_pow2 = 1 << Babybear31PrimeField::TWO_ADICITY;
+        assert_eq!(size(max_pow2), Some(max_pow2));
+        assert_eq!(size(max_pow2 + 1), Some(3 * max_pow2 / 2));
+        assert_eq!(size(3 * max_pow2 + 1), None);
+    }
+
+    /// Bluestein's transform must agree with the DFT computed from its definition, for lengths
+    /// that are not powers of two.
+    #[test]
+    fn test_bluestein_dft_vs_definition() {
+        for n in [1usize, 3, 5, 10, 15, 20, 40, 60, 96, 120] {
+            let root = primitive_root_of_order::<Babybear31PrimeField>(n as u64).unwrap();
+            let input: Vec<FE> = (0..n as u64).map(|i| FE::from(i * i + 7)).collect();
+
+            let expected: Vec<FE> = (0..n as u64)
+                .map(|k| {
+                    let root_k = root.pow(k);
+                    Polynomial::new(&input).evaluate(&root_k)
+                })
+                .collect();
+            assert_eq!(bluestein_dft(&input, &root).unwrap(), expected);
+        }
+    }
+
+    /// Multiplication on exact-size domains must agree with the naive multiplication.
+    #[test]
+    fn test_arbitrary_n_vs_naive_multiplication() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(200),
+            strategies::arb_polynomial::<Babybear31PrimeField>(200),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let actual_poly = multiply_polynomials_arbitrary_n(&p1, &p2).unwrap();
+
+                prop_assert_eq!(
+                    actual_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Bluestein and Naive multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Exact domains are the divisors of `p - 1 = 2^27 * 3 * 5`.
+    #[test]
+    fn exact_domain_sizes() {
+        let size = exact_domain_size::<Babybear31PrimeField>;
+        assert_eq!(size(0), Some(1));
+        assert_eq!(size(7), Some(8));
+        assert_eq!(size(9), Some(10));
+        assert_eq!(size(17), Some(20));
+        assert_eq!(size(5000), Some(5120));
+
+        assert!(primitive_root_of_order::<Babybear31PrimeField>(7).is_none());
+        let root = primitive_root_of_order::<Babybear31PrimeField>(5).unwrap();
+        assert_ne!(root, FE::one());
+        assert_eq!(root.pow(5u64), FE::one());
+    }
+
+    /// Reusing one scratch space and output vector across products of varying sizes must give the
+    /// same coefficients as the allocating API.
+    #[test]
+    fn test_fft_into_vs_fft_auto() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(500),
+            strategies::arb_polynomial::<Babybear31PrimeField>(500),
+        );
+        let scratch = RefCell::new(FftScratch::new());
+        let out = RefCell::new(Vec::new());
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_fft_auto(&p1, &p2).unwrap();
+                let (mut scratch, mut out) = (scratch.borrow_mut(), out.borrow_mut());
+                multiply_polynomials_fft_into(&p1, &p2, &mut scratch, &mut out).unwrap();
+
+                prop_assert_eq!(
+                    &*out,
+                    &expected_poly.coefficients,
+                    "In-place and allocating FFT multiplication results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// A chain of products and sums in evaluation form, interpolated once at the end, must agree
+    /// with the naive computation in coefficient form.
+    #[test]
+    fn test_eval_poly_chain_vs_naive() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+        );
+
+        runner
+            .run(&strategy, |(a, b, c, d)| {
+                // a * b * c + d has degree at most 300, so 512 points hold it.
+                let expected_poly =
+                    multiply_polynomials_naive(&multiply_polynomials_naive(&a, &b), &c) + d.clone();
+
+                let to_eval = |p| EvalPoly::from_coefficients(p, 512).unwrap();
+                let result = &(&(&to_eval(&a) * &to_eval(&b)) * &to_eval(&c)) + &to_eval(&d);
+
+                prop_assert_eq!(
+                    &result.to_polynomial().coefficients,
+                    &expected_poly.coefficients,
+                    "Evaluation-form and Naive results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Construction validates the domain, and the coefficient form of an input is kept as is.
+    #[test]
+    fn eval_poly_domain_checks() {
+        let p = Polynomial::new(&[FE::from(1), FE::from(2), FE::from(3)]);
+        assert_eq!(
+            EvalPoly::from_coefficients(&p, 2).unwrap_err(),
+            MultiplicationError::DomainTooSmall {
+                required: 3,
+                got: 2
+            }
+        );
+        assert_eq!(
+            EvalPoly::from_coefficients(&p, 6).unwrap_err(),
+            MultiplicationError::DomainNotPowerOfTwo(6)
+        );
+
+        let eval = EvalPoly::from_coefficients(&p, 4).unwrap();
+        assert_eq!(eval.degree_bound(), 2);
+        assert_eq!(eval.evaluations().len(), 4);
+        assert_eq!(eval.to_polynomial().coefficients, p.coefficients);
+
+        let wrapped = EvalPoly::from_evaluations(eval.evaluations().to_vec()).unwrap();
+        assert_eq!(wrapped.degree_bound(), 3);
+        assert_eq!(wrapped.to_polynomial().coefficients, p.coefficients);
+    }
+
+    /// A product that does not fit in the domain would wrap around, so it panics.
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn eval_poly_product_overflow_panics() {
+        let p = Polynomial::new(&[FE::from(1), FE::from(2), FE::from(3)]);
+        let eval = EvalPoly::from_coefficients(&p, 4).unwrap();
+        let _ = &eval * &eval;
+    }
+
+    /// Tests extension-field multiplication through base-field FFTs against the naive algorithm.
+    #[test]
+    fn quartic_fft_vs_naive() {
+        let mut runner = TestRunner::default();
+        let strategy = (
+            strategies::arb_quartic_polynomial(100),
+            strategies::arb_quartic_polynomial(100),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected = multiply_quartic_naive(&p1, &p2);
+                let result = multiply_quartic_fft(&p1, &p2).unwrap();
+
+                prop_assert_eq!(&result, &expected, "Quartic FFT and Naive results differ!");
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The generator `u` satisfies `u^4 = 11`, and base-field elements multiply as before.
+    #[test]
+    fn quartic_extension_arithmetic() {
+        let u = Fp4::new([0, 1, 0, 0].map(FieldElement::from));
+        assert_eq!(u.pow(4_u64), Fp4::from(11));
+        assert_eq!(Fp4::from(6) * Fp4::from(7), Fp4::from(42));
+        assert_eq!(&u * Fp4::one(), u);
+        assert!(multiply_quartic_fft(&[u], &[Fp4::zero()])
+            .unwrap()
+            .is_empty());
+    }
+
+    /// Tests the twisted-FFT negacyclic product against the naive wrap-around with negation, for
+    /// inputs both shorter and longer than the ring dimension.
+    #[test]
+    fn negacyclic_fft_vs_naive() {
+        let mut runner = TestRunner::default();
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            0u32..8,
+        );
+
+        runner
+            .run(&strategy, |(p1, p2, log_n)| {
+                let n = 1 << log_n;
+                let expected_poly = multiply_negacyclic_naive(&p1, &p2, n);
+                let result_poly = multiply_negacyclic(&p1, &p2, n).unwrap();
+
+                prop_assert_eq!(
+                    result_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Negacyclic FFT and Naive results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// In `F[x] / (x^n + 1)`, `x^(n-1) * x = -1`, and the ring dimension must be a power of two.
+    #[test]
+    fn negacyclic_wraps_with_negation() {
+        let mut x_pow = vec![FE::zero(); 8];
+        x_pow[7] = FE::one();
+        let x_pow = Polynomial::new(&x_pow);
+        let x = Polynomial::new(&[FE::zero(), FE::one()]);
+
+        let product = multiply_negacyclic(&x_pow, &x, 8).unwrap();
+        assert_eq!(product.coefficients, vec![-FE::one()]);
+        assert_eq!(
+            multiply_negacyclic(&x_pow, &x, 6).unwrap_err(),
+            MultiplicationError::DomainNotPowerOfTwo(6)
+        );
+    }
+
+    /// Tests the FFT cyclic convolution against the naive wrap-around, for inputs both shorter and
+    /// longer than the convolution length.
+    #[test]
+    fn cyclic_convolution_fft_vs_naive() {
+        let mut runner = TestRunner::default();
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            0u32..8,
+        );
+
+        runner
+            .run(&strategy, |(a, b, log_n)| {
+                let n = 1 << log_n;
+                let expected_poly = cyclic_convolution_naive(&a, &b, n);
+                let result_poly = cyclic_convolution(&a, &b, n).unwrap();
+
+                prop_assert_eq!(
+                    result_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Cyclic convolution FFT and Naive results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// With a length large enough to hold the product there is no wrap-around, so the cyclic
+    /// convolution equals the full product; with a shorter one, `x^(n-1) * x = 1`.
+    #[test]
+    fn cyclic_convolution_wraps_around() {
+        let p1 = Polynomial::new(&[FE::from(1), FE::from(2), FE::from(3)]);
+        let p2 = Polynomial::new(&[FE::from(2), FE::from(5)]);
+        assert_eq!(
+            cyclic_convolution(&p1, &p2, 4).unwrap().coefficients,
+            multiply_polynomials_naive(&p1, &p2).coefficients
+        );
+
+        let mut x_pow = vec![FE::zero(); 8];
+        x_pow[7] = FE::one();
+        let x_pow = Polynomial::new(&x_pow);
+        let x = Polynomial::new(&[FE::zero(), FE::one()]);
+        assert_eq!(
+            cyclic_convolution(&x_pow, &x, 8).unwrap().coefficients,
+            vec![FE::one()]
+        );
+        assert_eq!(
+            cyclic_convolution(&x_pow, &x, 0).unwrap_err(),
+            MultiplicationError::DomainNotPowerOfTwo(0)
+        );
+    }
+
+    /// Tests NTT-based integer multiplication against `num-bigint`.
+    #[test]
+    fn integer_multiplication_vs_num_bigint() {
+        let mut runner = TestRunner::default();
+        let strategy = (vec(any::<u8>(), 0..=300), vec(any::<u8>(), 0..=300));
+
+        runner
+            .run(&strategy, |(a, b)| {
+                let expected = &BigUint::from_bytes_le(&a) * &BigUint::from_bytes_le(&b);
+                let result = multiply_integers(&a, &b).unwrap();
+
+                prop_assert_eq!(
+                    BigUint::from_bytes_le(&result),
+                    expected,
+                    "NTT and num-bigint products differ!"
+                );
+                prop_assert!(
+                    result.last() != Some(&0),
+                    "Product has a leading zero byte!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The largest digits at the maximum length do not wrap around the modulus, and one more
+    /// digit on both sides is rejected.
+    #[test]
+    fn integer_multiplication_length_limit() {
+        let a = vec![0xff; MAX_SHORTER_OPERAND_BYTES];
+        let expected = &BigUint::from_bytes_le(&a) * &BigUint::from_bytes_le(&a);
+        assert_eq!(
+            BigUint::from_bytes_le(&multiply_integers(&a, &a).unwrap()),
+            expected
+        );
+
+        let too_long = vec![0xff; MAX_SHORTER_OPERAND_BYTES + 1];
+        assert_eq!(
+            multiply_integers(&too_long, &too_long).unwrap_err(),
+            MultiplicationError::OperandTooLong {
+                max_len: MAX_SHORTER_OPERAND_BYTES,
+                got: MAX_SHORTER_OPERAND_BYTES + 1
+            }
+        );
+        assert!(multiply_integers(&too_long, &[0, 0]).unwrap().is_empty());
+    }
+
+    /// The shifted and scaled polynomials must agree with the original evaluated at the shifted
+    /// and scaled points.
+    #[test]
+    fn taylor_shift_and_scale_vs_evaluation() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            any::<u64>().prop_map(FE::from),
+            any::<u64>().prop_map(FE::from),
+            any::<u64>().prop_map(FE::from),
+        );
+
+        runner
+            .run(&strategy, |(p, a, c, x)| {
+                prop_assert_eq!(
+                    shift(&p, &a).evaluate(&x),
+                    p.evaluate(&(x + a)),
+                    "Shifted polynomial and shifted evaluation differ!"
+                );
+                prop_assert_eq!(
+                    scale(&p, &c).evaluate(&x),
+                    p.evaluate(&(c * x)),
+                    "Scaled polynomial and scaled evaluation differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// The truncated products must match the corresponding slices of the naive product, for `n`
+    /// both below and beyond its length.
+    #[test]
+    fn short_products_vs_naive() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            0usize..700,
+        );
+
+        runner
+            .run(&strategy, |(p1, p2, n)| {
+                let full = multiply_polynomials_naive(&p1, &p2).coefficients;
+                let low = Polynomial::new(&full[..n.min(full.len())]);
+                let high = Polynomial::new(&full[full.len().saturating_sub(n)..]);
+                prop_assert_eq!(
+                    mul_low(&p1, &p2, n).coefficients,
+                    low.coefficients,
+                    "Low product and Naive results differ!"
+                );
+                prop_assert_eq!(
+                    mul_high(&p1, &p2, n).coefficients,
+                    high.coefficients,
+                    "High product and Naive results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Sparse-dense products must match the naive product, both for a few scattered terms, done
+    /// term by term, and for a sparse operand dense enough to fall back to the FFT.
+    #[test]
+    fn sparse_dense_vs_naive() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (
+            vec((0usize..500, any::<u64>().prop_map(FE::from)), 0..8),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+            strategies::arb_polynomial::<Babybear31PrimeField>(300),
+        );
+
+        runner
+            .run(&strategy, |(terms, full, dense)| {
+                for sparse in [SparsePoly::new(&terms), SparsePoly::from_dense(&full)] {
+                    prop_assert_eq!(SparsePoly::from_dense(&sparse.to_dense()), sparse.clone());
+                    let result_poly = multiply_sparse_dense(&sparse, &dense).unwrap();
+                    if sparse.is_empty() {
+                        prop_assert!(result_poly.coefficients.is_empty());
+                        continue;
+                    }
+                    let expected_poly = multiply_polynomials_naive(&sparse.to_dense(), &dense);
+                    prop_assert_eq!(
+                        result_poly.coefficients,
+                        expected_poly.coefficients,
+                        "Sparse and Naive results differ!"
+                    );
+                }
+                Ok(())
+            })
+            .unwrap();
+
+        // Repeated exponents are merged, and terms cancelling out are dropped.
+        let sparse = SparsePoly::new(&[(3, FE::one()), (0, -FE::one()), (3, -FE::one())]);
+        assert_eq!(sparse.terms(), [(0, -FE::one())]);
+        assert!(SparsePoly::<Babybear31PrimeField>::new(&[]).is_empty());
+    }
+
+    /// The middle product must match the middle slice of the naive product, and be the transpose
+    /// of multiplication by the reversal of `b`: `<MP(a, b), c> = <a, rev(b) * c>` for every `c`
+    /// of `n` coefficients.
+    #[test]
+    fn middle_product_vs_naive() {
+        let mut runner = TestRunner::new(Config::default());
+
+        let strategy = (1usize..200).prop_flat_map(|n| {
+            (
+                Just(n),
+                vec(any::<u64>().prop_map(FE::from), 2 * n - 1),
+                vec(any::<u64>().prop_map(FE::from), n),
+                vec(any::<u64>().prop_map(FE::from), n),
+            )
+        });
+        let dot = |x: &[FE], y: &[FE]| x.iter().zip(y).fold(FE::zero(), |acc, (u, v)| acc + u * v);
+
+        runner
+            .run(&strategy, |(n, a, b, c)| {
+                let rev_b: Vec<FE> = b.iter().rev().cloned().collect();
+                let (a, b, c) = (
+                    Polynomial::new(&a),
+                    Polynomial::new(&b),
+                    Polynomial::new(&c),
+                );
+                let result_poly = middle_product(&a, &b, n).unwrap();
+
+                let full = multiply_polynomials_naive(&a, &b).coefficients;
+                let middle: Vec<FE> = (n - 1..2 * n - 1)
+                    .map(|k| full.get(k).cloned().unwrap_or_else(FE::zero))
+                    .collect();
+                prop_assert_eq!(
+                    &result_poly.coefficients,
+                    &Polynomial::new(&middle).coefficients,
+                    "Middle product and Naive results differ!"
+                );
+
+                let rev_b_c = multiply_polynomials_naive(&Polynomial::new(&rev_b), &c).coefficients;
+                prop_assert_eq!(
+                    dot(&result_poly.coefficients, &c.coefficients),
+                    dot(&a.coefficients, &rev_b_c),
+                    "Middle product is not the transpose of multiplication!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Repeated squaring must agree with multiplying `p` by itself `k` times, with and without
+    /// truncation.
+    #[test]
+    fn pow_polynomial_vs_iterated_naive() {
+        let mut runner = TestRunner::new(Config::with_cases(32));
+
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(20),
+            0u64..12,
+            0usize..60,
+        );
+
+        runner
+            .run(&strategy, |(p, k, max_degree)| {
+                let expected_poly = (0..k).fold(Polynomial::new(&[FE::one()]), |acc, _| {
+                    multiply_polynomials_naive(&acc, &p)
+                });
+                let truncated_len = expected_poly.coefficients.len().min(max_degree + 1);
+                let expected_truncated =
+                    Polynomial::new(&expected_poly.coefficients[..truncated_len]);
+
+                prop_assert_eq!(
+                    pow_polynomial(&p, k, None).unwrap().coefficients,
+                    expected_poly.coefficients,
+                    "Power and Naive results differ!"
+                );
+                prop_assert_eq!(
+                    pow_polynomial(&p, k, Some(max_degree))
+                        .unwrap()
+                        .coefficients,
+                    expected_truncated.coefficients,
+                    "Truncated Power and Naive results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// `p^0 = 1` for every `p`, including zero, and `0^k = 0` for `k > 0`.
+    #[test]
+    fn pow_polynomial_edge_cases() {
+        let zero = Polynomial::<FE>::new(&[]);
+        let p = Polynomial::new(&[FE::from(2), FE::from(3)]);
+        assert_eq!(
+            pow_polynomial(&zero, 0, None).unwrap().coefficients,
+            [FE::one()]
+        );
+        assert_eq!(
+            pow_polynomial(&p, 0, Some(0)).unwrap().coefficients,
+            [FE::one()]
+        );
+        assert!(pow_polynomial(&zero, 5, None)
+            .unwrap()
+            .coefficients
+            .is_empty());
+        assert_eq!(
+            pow_polynomial(&p, 3, Some(1)).unwrap().coefficients,
+            [FE::from(8), FE::from(36)]
+        );
+    }
+
+    /// Shifting by `a` and then by `-a` is the identity, and `(x + 1)^2` shifts to
+    /// `x^2 + 4x + 4`.
+    #[test]
+    fn taylor_shift_round_trip() {
+        let p = Polynomial::new(&[FE::from(1), FE::from(2), FE::from(1)]);
+        assert_eq!(
+            shift(&p, &FE::one()).coefficients,
+            [FE::from(4), FE::from(4), FE::from(1)]
+        );
+        assert_eq!(
+            shift(&shift(&p, &FE::from(7)), &-FE::from(7)).coefficients,
+            p.coefficients
+        );
+        assert!(shift(&Polynomial::<FE>::new(&[]), &FE::one())
+            .coefficients
+            .is_empty());
+    }
+
+    /// A polynomial and a twiddle table saved as JSON load back unchanged, and the loaded twiddles
+    /// still multiply correctly.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trip() {
+        use crate::{SerializablePolynomial, TwiddleSet};
+
+        let poly = SerializablePolynomial(Polynomial::new(&[
+            FE::from(1),
+            FE::zero(),
+            FE::from(3),
+            -FE::one(),
+        ]));
+        let json = serde_json::to_string(&poly).unwrap();
+        let loaded: SerializablePolynomial = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, poly);
+
+        let set = TwiddleSet::new(4).unwrap();
+        let loaded: TwiddleSet =
+            serde_json::from_str(&serde_json::to_string(&set).unwrap()).unwrap();
+        assert_eq!(loaded, set);
+        let plan =
+            FftPlan::from_twiddles(loaded.log_n, loaded.twiddles, loaded.inv_twiddles).unwrap();
+        assert_eq!(plan.size(), 16);
+        assert_eq!(
+            multiply_polynomials_fft(&poly.0, &poly.0, &plan)
+                .unwrap()
+                .coefficients,
+            multiply_polynomials_naive(&poly.0, &poly.0).coefficients
+        );
+
+        // The single point of `log_n = 0` has no twiddles at all, and multiplies constants.
+        let set = TwiddleSet::new(0).unwrap();
+        let loaded: TwiddleSet =
+            serde_json::from_str(&serde_json::to_string(&set).unwrap()).unwrap();
+        assert_eq!(loaded, set);
+        assert!(loaded.twiddles.is_empty());
+        let plan =
+            FftPlan::from_twiddles(loaded.log_n, loaded.twiddles, loaded.inv_twiddles).unwrap();
+        assert_eq!(plan.size(), 1);
+        let constant = Polynomial::new(&[FE::from(3)]);
+        assert_eq!(
+            multiply_polynomials_fft(&constant, &constant, &plan)
+                .unwrap()
+                .coefficients,
+            vec![FE::from(9)]
+        );
+    }
+
+    /// Tests blocked multiplication through memory-mapped files against the naive algorithm, with
+    /// blocks both shorter and longer than the operands.
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn streaming_vs_naive() {
+        use crate::{multiply_streaming, read_coefficients, write_coefficients};
+
+        let dir = std::env::temp_dir().join(format!("fpa_streaming_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (path1, path2, out) = (dir.join("p1"), dir.join("p2"), dir.join("out"));
+
+        let mut runner = TestRunner::new(Config::with_cases(32));
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            strategies::arb_polynomial::<Babybear31PrimeField>(100),
+            0u32..8,
+        );
+
+        runner
+            .run(&strategy, |(p1, p2, log_block)| {
+                write_coefficients(&path1, &p1.coefficients).unwrap();
+                write_coefficients(&path2, &p2.coefficients).unwrap();
+                multiply_streaming(&path1, &path2, &out, 1 << log_block).unwrap();
+
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let result_poly = Polynomial::new(&read_coefficients(&out).unwrap());
+                prop_assert_eq!(
+                    result_poly.coefficients,
+                    expected_poly.coefficients,
+                    "Streaming and Naive results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Tests that the SIMD butterfly and pointwise kernels are bit-identical to the scalar ones,
+    /// including on lengths that are not a multiple of the lane count.
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_kernels_vs_scalar() {
+        use crate::simd::{butterfly, butterfly_scalar, pointwise_mul, pointwise_mul_scalar, P};
+
+        let mut runner = TestRunner::default();
+        let strategy =
+            (1usize..100).prop_flat_map(|len| (vec(0..P, len), vec(0..P, len), vec(0..P, len)));
+
+        runner
+            .run(&strategy, |(lo, hi, twiddles)| {
+                let (mut simd_lo, mut simd_hi) = (lo.clone(), hi.clone());
+                let (mut scalar_lo, mut scalar_hi) = (lo.clone(), hi.clone());
+                butterfly(&mut simd_lo, &mut simd_hi, &twiddles);
+                butterfly_scalar(&mut scalar_lo, &mut scalar_hi, &twiddles);
+                prop_assert_eq!(&simd_lo, &scalar_lo, "SIMD and scalar butterflies differ!");
+                prop_assert_eq!(&simd_hi, &scalar_hi, "SIMD and scalar butterflies differ!");
+
+                let (mut simd_product, mut scalar_product) = (lo.clone(), lo);
+                pointwise_mul(&mut simd_product, &hi);
+                pointwise_mul_scalar(&mut scalar_product, &hi);
+                prop_assert_eq!(
+                    &simd_product,
+                    &scalar_product,
+                    "SIMD and scalar pointwise products differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Tests the SIMD NTT multiplication, through the multiplier trait, against the naive
+    /// algorithm.
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_fft_vs_naive() {
+        use crate::SimdMultiplier;
+
+        let mut runner = TestRunner::default();
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(200),
+            strategies::arb_polynomial::<Babybear31PrimeField>(200),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let result_poly = multiply_with(&SimdMultiplier, &p1, &p2);
+
+                prop_assert_eq!(
+                    result_poly.coefficients,
+                    expected_poly.coefficients,
+                    "SIMD FFT and Naive results differ!"
+                );
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Tests GPU multiplication, through the multiplier trait, against the naive algorithm.
+    /// Machines without a GPU adapter skip the comparison.
+    #[cfg(feature = "gpu")]
+    #[test]
+    fn gpu_fft_vs_naive() {
+        use std::eprintln;
+
+        use crate::GpuMultiplier;
+
+        let Ok(gpu) = GpuMultiplier::new() else {
+            eprintln!("No GPU adapter available, skipping.");
+            return;
+        };
+        let mut runner = TestRunner::new(Config::with_cases(16));
+        let strategy = (
+            strategies::arb_polynomial::<Babybear31PrimeField>(1000),
+            strategies::arb_polynomial::<Babybear31PrimeField>(1000),
+        );
+
+        runner
+            .run(&strategy, |(p1, p2)| {
+                let expected_poly = multiply_polynomials_naive(&p1, &p2);
+                let result_poly = multiply_with(&gpu, &p1, &p2);
+
+                prop_assert_eq!(
+                    result_poly.coefficients,
+                    expected_poly.coefficients,
+                    "GPU FFT and Naive results differ!"
                 );
                 Ok(())
             })
@@ -5,11 +5,11 @@
 
 // Import the FFT multiplication function from your library.
 // Make sure this path is correct based on your crate structure.
-use fast_polynomial_arithmetic::multiply_polynomials_fft;
-use lambdaworks_math::fft::cpu::roots_of_unity::get_twiddles;
+use fast_polynomial_arithmetic::{
+    multiply_polynomials_fft, multiply_polynomials_fft_auto, FftPlan,
+};
 use lambdaworks_math::field::element::FieldElement;
 use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
-use lambdaworks_math::field::traits::RootsConfig;
 use lambdaworks_math::polynomial::Polynomial;
 
 // Type aliases for convenience, specifying the field.
@@ -35,16 +35,13 @@ fn main() {
     println!("P2(x) = {}", p2.print_as_sage_poly(None));
     println!("--------------------------------------\n");
 
-    // Generate bit-reversed twiddle factors (roots of unity) for the forward FFT.
+    // Plan the FFTs for the domain: this generates the bit-reversed twiddle factors (roots of
+    // unity) for the forward FFT and the inverse ones for the Inverse FFT (IFFT) once.
     // The `n.trailing_zeros()` gives log2(N), which is the 'k' for 2^k = N.
-    let twiddles = get_twiddles::<F>(n.trailing_zeros() as u64, RootsConfig::BitReverse).unwrap();
-
-    // Generate bit-reversed inverse twiddle factors for the Inverse FFT (IFFT).
-    let inv_twiddles =
-        get_twiddles::<F>(n.trailing_zeros() as u64, RootsConfig::BitReverseInversed).unwrap();
+    let plan = FftPlan::<F>::new(n.trailing_zeros() as u64).unwrap();
 
     // Perform the polynomial multiplication using the FFT algorithm.
-    let c_poly = multiply_polynomials_fft(&p1, &p2, n, &twiddles, &inv_twiddles);
+    let c_poly = multiply_polynomials_fft(&p1, &p2, &plan).unwrap();
 
     // --- VERIFICATION ---
     println!("--- Verification ---");
@@ -64,4 +61,9 @@ fn main() {
         "The computed polynomial does not match the expected one."
     );
     println!("\nSuccess! The low-level FFT process produced the correct polynomial.");
+
+    // The one-call API derives the domain size and twiddles from the degrees itself.
+    let auto_poly = multiply_polynomials_fft_auto(&p1, &p2).unwrap();
+    assert_eq!(auto_poly.coefficients, expected_poly.coefficients);
+    println!("The one-call FFT API produced the same polynomial.");
 }
@@ -0,0 +1,63 @@
+//! The middle product: the middle coefficients of a product, without computing the rest.
+//!
+//! For `b` with `n` coefficients and `a` with `2n - 1`, the product `a * b` has `3n - 2`
+//! coefficients, but Newton iteration and fast interpolation only consume the `n` in the middle,
+//!
+//! ```text
+//! MP(a, b)_t = c_(n-1+t) = sum_(j < n) a_(n-1+t-j) b_j,   0 <= t < n
+//! ```
+//!
+//! It is the transpose of the map `c -> rev(b) * c` on polynomials of `n` coefficients, where
+//! `rev(b)` lists the `n` coefficients of `b` backwards, which is why it costs the same as a
+//! product of two `n`-coefficient polynomials (Tellegen's principle). With
+//! the FFT the transposition shows up as wrap-around: a cyclic convolution of length `N >= 2n - 1`
+//! folds the coefficients from `x^N` upwards onto `x^0 .. x^(n-2)`, below the middle, so the
+//! middle survives. The full product would need `N >= 3n - 2`, up to twice the transform size.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{cyclic_convolution, MultiplicationError};
+
+/// Returns the middle product of `a` and `b`: the coefficients of `x^(n-1)` up to `x^(2n-2)` of
+/// `a * b`.
+///
+/// Only the first `2n - 1` coefficients of `a` and the first `n` of `b` are used.
+///
+/// # Arguments
+/// * `a` - The long operand, of up to `2n - 1` coefficients.
+/// * `b` - The short operand, of up to `n` coefficients.
+/// * `n` - The number of middle coefficients to compute.
+///
+/// # Returns
+/// The polynomial whose `t`-th coefficient is the `(n - 1 + t)`-th coefficient of `a * b`.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the field has no subgroup of size
+/// `(2n - 1).next_power_of_two()`.
+pub fn middle_product<F: IsFFTField>(
+    a: &Polynomial<FieldElement<F>>,
+    b: &Polynomial<FieldElement<F>>,
+    n: usize,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if n == 0 {
+        return Ok(Polynomial::new(&[]));
+    }
+    let a = Polynomial::new(&a.coefficients[..a.coefficients.len().min(2 * n - 1)]);
+    let b = Polynomial::new(&b.coefficients[..b.coefficients.len().min(n)]);
+
+    let wrapped = cyclic_convolution(&a, &b, (2 * n - 1).next_power_of_two())?;
+    let coeffs: Vec<FieldElement<F>> = (n - 1..2 * n - 1)
+        .map(|k| {
+            wrapped
+                .coefficients
+                .get(k)
+                .cloned()
+                .unwrap_or_else(FieldElement::zero)
+        })
+        .collect();
+    Ok(Polynomial::new(&coeffs))
+}
@@ -0,0 +1,172 @@
+//! Mixed-radix FFT for domains of size `2^a * 3^b`.
+//!
+//! A radix-2 FFT needs a power-of-two domain, so a product with just over `2^k` coefficients pays
+//! for a domain of `2^(k+1)`. If `3` also divides the multiplicative group order `p - 1`, the field
+//! has roots of unity of order `2^a * 3^b`, and a size-`n` transform can split into three thirds
+//! with the radix-3 butterfly
+//!
+//! ```text
+//! X[k]        = t0 +       t1 +       t2
+//! X[k + n/3]  = t0 + w3   t1 + w3^2 t2
+//! X[k + 2n/3] = t0 + w3^2 t1 + w3   t2
+//! ```
+//!
+//! where `w3` is a primitive cube root of unity and `t_j = w^(jk) s_j[k]`. Mixing radix-3 and
+//! radix-2 steps then covers every size `2^a * 3^b`: a product with 5000 coefficients, for
+//! example, fits in `3 * 2^11 = 6144` points instead of `8192`.
+
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::MultiplicationError;
+
+/// An FFT-friendly field whose multiplicative group order is also divisible by a power of 3.
+pub trait IsMixedRadixField: IsFFTField {
+    /// The largest `b` such that `3^b` divides `p - 1`.
+    const THREE_ADICITY: u64;
+
+    /// Returns a primitive `3^THREE_ADICITY`-th root of unity.
+    fn three_adic_primitive_root_of_unity() -> FieldElement<Self>;
+}
+
+/// Babybear: `p - 1 = 2^27 * 3 * 5`.
+impl IsMixedRadixField for Babybear31PrimeField {
+    const THREE_ADICITY: u64 = 1;
+
+    /// `31^((p - 1) / 3)`, where 31 generates the multiplicative group.
+    fn three_adic_primitive_root_of_unity() -> FieldElement<Self> {
+        FieldElement::from(1314723123)
+    }
+}
+
+/// Returns the smallest domain size `n = 2^a * 3^b >= len` that the field `F` supports, or `None`
+/// if `len` exceeds every such size.
+pub fn mixed_radix_domain_size<F: IsMixedRadixField>(len: usize) -> Option<usize> {
+    (0..=F::THREE_ADICITY)
+        .filter_map(|b| {
+            let three_b = 3usize.checked_pow(b as u32)?;
+            let two_a = len.div_ceil(three_b).max(1).checked_next_power_of_two()?;
+            (two_a.trailing_zeros() as u64 <= F::TWO_ADICITY).then_some(two_a * three_b)
+        })
+        .min()
+}
+
+/// Multiplies two polynomials using a mixed-radix FFT over the smallest supported `2^a * 3^b`
+/// domain that holds the product.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if no supported domain is large enough for the
+/// product.
+pub fn multiply_polynomials_mixed_radix<F: IsMixedRadixField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::new(&[]));
+    }
+    let len = p1.degree() + p2.degree() + 1;
+    let n = mixed_radix_domain_size::<F>(len).ok_or(MultiplicationError::DomainTooLarge {
+        log_n: len.next_power_of_two().trailing_zeros() as u64,
+        two_adicity: F::TWO_ADICITY,
+    })?;
+
+    // 1. Build the powers of a primitive n-th root of unity, w = w_(2^a) * w_(3^b).
+    let (a, b) = split_domain_size(n);
+    let two_part = F::get_primitive_root_of_unity(a)
+        .map_err(|e| MultiplicationError::TwiddleGeneration(format!("{:?}", e)))?;
+    let three_part =
+        F::three_adic_primitive_root_of_unity().pow(3u64.pow((F::THREE_ADICITY - b) as u32));
+    let root = two_part * three_part;
+    let roots = powers(&root, n);
+    let inv_roots = powers(&root.inv().expect("roots of unity are non-zero"), n);
+
+    // 2. Evaluate both operands, multiply pointwise, and interpolate back.
+    let evaluate = |p: &Polynomial<FieldElement<F>>| {
+        let mut coeffs = p.coefficients.to_vec();
+        coeffs.resize(n, FieldElement::zero());
+        let mut evals = vec![FieldElement::zero(); n];
+        mixed_radix_transform(&coeffs, 1, &mut evals, &roots, 1);
+        evals
+    };
+    let c_evals: Vec<FieldElement<F>> = evaluate(p1)
+        .iter()
+        .zip(evaluate(p2).iter())
+        .map(|(y1, y2)| y1 * y2)
+        .collect();
+
+    let mut c_coeffs = vec![FieldElement::zero(); n];
+    mixed_radix_transform(&c_evals, 1, &mut c_coeffs, &inv_roots, 1);
+    let n_inv = FieldElement::<F>::from(n as u64)
+        .inv()
+        .expect("Inverse of N should exist in the field.");
+    let c_coeffs: Vec<FieldElement<F>> = c_coeffs.iter().map(|c| c * &n_inv).collect();
+
+    Ok(Polynomial::new(&c_coeffs))
+}
+
+/// Returns `(a, b)` with `n = 2^a * 3^b`.
+fn split_domain_size(mut n: usize) -> (u64, u64) {
+    let mut b = 0;
+    while n % 3 == 0 {
+        n /= 3;
+        b += 1;
+    }
+    (n.trailing_zeros() as u64, b)
+}
+
+/// Returns `[1, root, root^2, ..., root^(n-1)]`.
+fn powers<F: IsFFTField>(root: &FieldElement<F>, n: usize) -> Vec<FieldElement<F>> {
+    core::iter::successors(Some(FieldElement::one()), |x| Some(x * root))
+        .take(n)
+        .collect()
+}
+
+/// Computes `out[k] = sum_j input[j * stride] * w^(jk)` for `k < n = out.len()`, where `w` is a
+/// primitive `n`-th root of unity taken from `roots`, the powers of a primitive `N`-th root with
+/// `N = n * root_stride`. Factors of 3 are split off first, then factors of 2.
+fn mixed_radix_transform<F: IsFFTField>(
+    input: &[FieldElement<F>],
+    stride: usize,
+    out: &mut [FieldElement<F>],
+    roots: &[FieldElement<F>],
+    root_stride: usize,
+) {
+    let n = out.len();
+    if n == 1 {
+        out[0] = input[0].clone();
+        return;
+    }
+    let radix = if n % 3 == 0 { 3 } else { 2 };
+    let m = n / radix;
+    for (j, part) in out.chunks_mut(m).enumerate() {
+        mixed_radix_transform(
+            &input[j * stride..],
+            radix * stride,
+            part,
+            roots,
+            radix * root_stride,
+        );
+    }
+
+    for k in 0..m {
+        let t0 = out[k].clone();
+        let t1 = &out[m + k] * &roots[k * root_stride];
+        if radix == 2 {
+            out[k] = &t0 + &t1;
+            out[m + k] = &t0 - &t1;
+        } else {
+            let t2 = &out[2 * m + k] * &roots[2 * k * root_stride];
+            // The primitive cube root of unity `w3 = w^(n/3)` and its square.
+            let w3 = &roots[m * root_stride];
+            let w3_sq = &roots[2 * m * root_stride];
+            out[k] = &t0 + &t1 + &t2;
+            out[m + k] = &t0 + &t1 * w3 + &t2 * w3_sq;
+            out[2 * m + k] = &t0 + &t1 * w3_sq + &t2 * w3;
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! A common interface over the multiplication backends.
+//!
+//! The free functions of this crate each fix an algorithm. Code that only needs "a product" —
+//! a commitment scheme computing quotients, or a prover building constraint polynomials — can
+//! instead take any [`PolynomialMultiplier`] and let the caller choose the backend:
+//!
+//! ```text
+//! fn quotient<M: PolynomialMultiplier>(m: &M, ...) -> ... { m.multiply(&a, &b)? ... }
+//! quotient(&NaiveMultiplier, ...)        // reference results
+//! quotient(&FftMultiplier::new(), ...)   // large inputs, twiddles reused across calls
+//! ```
+//!
+//! The SIMD and GPU backends implement the trait for Babybear behind their features.
+
+use core::cell::RefCell;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsField};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{
+    multiply_polynomials_fft_cached, multiply_polynomials_karatsuba, multiply_polynomials_naive,
+    MultiplicationError, TwiddleCache,
+};
+
+/// A polynomial multiplication backend over the field `F`, which defaults to Babybear.
+pub trait PolynomialMultiplier<F: IsField = Babybear31PrimeField> {
+    /// Multiplies `p1` by `p2`.
+    ///
+    /// # Errors
+    /// Returns a [`MultiplicationError`] if the backend cannot handle the operands, for instance
+    /// if the product is too large for an FFT over `F`.
+    fn multiply(
+        &self,
+        p1: &Polynomial<FieldElement<F>>,
+        p2: &Polynomial<FieldElement<F>>,
+    ) -> Result<Polynomial<FieldElement<F>>, MultiplicationError>;
+}
+
+/// The naive O(N^2) algorithm, see [`multiply_polynomials_naive`]. It never fails.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NaiveMultiplier;
+
+impl<F: IsField> PolynomialMultiplier<F> for NaiveMultiplier {
+    fn multiply(
+        &self,
+        p1: &Polynomial<FieldElement<F>>,
+        p2: &Polynomial<FieldElement<F>>,
+    ) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+        Ok(multiply_polynomials_naive(p1, p2))
+    }
+}
+
+/// Karatsuba's algorithm, see [`multiply_polynomials_karatsuba`]. It never fails.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KaratsubaMultiplier;
+
+impl<F: IsField> PolynomialMultiplier<F> for KaratsubaMultiplier {
+    fn multiply(
+        &self,
+        p1: &Polynomial<FieldElement<F>>,
+        p2: &Polynomial<FieldElement<F>>,
+    ) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+        Ok(multiply_polynomials_karatsuba(p1, p2))
+    }
+}
+
+/// The radix-2 FFT, see [`multiply_polynomials_fft_cached`], with the twiddles of every domain
+/// size it has seen kept for later calls.
+///
+/// The cache sits behind a `RefCell` so that the backend can be shared by `&` reference, and the
+/// multiplier is therefore not `Sync`; give each thread its own.
+#[derive(Debug, Clone)]
+pub struct FftMultiplier<F: IsFFTField = Babybear31PrimeField> {
+    cache: RefCell<TwiddleCache<F>>,
+}
+
+impl<F: IsFFTField> Default for FftMultiplier<F> {
+    fn default() -> Self {
+        Self {
+            cache: RefCell::new(TwiddleCache::new()),
+        }
+    }
+}
+
+impl<F: IsFFTField> FftMultiplier<F> {
+    /// Creates a multiplier with an empty twiddle cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<F: IsFFTField> PolynomialMultiplier<F> for FftMultiplier<F> {
+    fn multiply(
+        &self,
+        p1: &Polynomial<FieldElement<F>>,
+        p2: &Polynomial<FieldElement<F>>,
+    ) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+        multiply_polynomials_fft_cached(p1, p2, &mut self.cache.borrow_mut())
+    }
+}
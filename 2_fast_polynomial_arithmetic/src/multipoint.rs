@@ -0,0 +1,87 @@
+//! Fast multipoint evaluation via a remainder tree.
+//!
+//! Evaluating a degree-`n` polynomial at `m` points with Horner's rule costs O(n m). Since
+//! `f(a) = f mod (x - a)`, all values can instead be read off the remainders of `f` modulo the
+//! linear factors. The **subproduct tree** stores the products of the factors over ever larger
+//! groups of points, and reducing `f` from the root down (each node's remainder reduced modulo its
+//! children) shrinks the problem by half at every level. With fast division this takes
+//! O(N log^2 N).
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{divide_polynomials_fast, multiply};
+
+/// Evaluates `poly` at every point of `points`, returning the values in the same order.
+pub fn evaluate_at_points_fast<F: IsFFTField>(
+    poly: &Polynomial<FieldElement<F>>,
+    points: &[FieldElement<F>],
+) -> Vec<FieldElement<F>> {
+    if points.is_empty() {
+        return Vec::new();
+    }
+    let tree = subproduct_tree(points);
+
+    // Walk the tree from the root down, replacing each node by the remainder of its parent's
+    // remainder modulo that node. A node's remainder agrees with `poly` on the node's points.
+    let root = tree.last().expect("the tree has a root level");
+    let mut remainders = vec![remainder(poly, &root[0])];
+    for level in tree.iter().rev().skip(1) {
+        remainders = level
+            .iter()
+            .enumerate()
+            .map(|(i, node)| remainder(&remainders[i / 2], node))
+            .collect();
+    }
+
+    // The leaves are the factors `x - a`, so each remainder is the constant `poly(a)`.
+    remainders
+        .into_iter()
+        .map(|r| {
+            r.coefficients
+                .first()
+                .cloned()
+                .unwrap_or_else(FieldElement::zero)
+        })
+        .collect()
+}
+
+/// Builds the subproduct tree of `points`: level 0 holds the factors `x - a`, and each node of the
+/// next level is the product of a pair of neighbours, with an odd one out carried up unchanged.
+/// The last level holds the single product of all factors.
+fn subproduct_tree<F: IsFFTField>(
+    points: &[FieldElement<F>],
+) -> Vec<Vec<Polynomial<FieldElement<F>>>> {
+    let leaves: Vec<_> = points
+        .iter()
+        .map(|a| Polynomial::new(&[-a, FieldElement::one()]))
+        .collect();
+    let mut tree = vec![leaves];
+    while tree.last().expect("the tree is not empty").len() > 1 {
+        let next = tree
+            .last()
+            .expect("the tree is not empty")
+            .chunks(2)
+            .map(|pair| match pair {
+                [p1, p2] => multiply(p1, p2),
+                [p] => p.clone(),
+                _ => unreachable!("chunks(2) yields one or two polynomials"),
+            })
+            .collect();
+        tree.push(next);
+    }
+    tree
+}
+
+/// Returns `p mod m` for a monic tree node `m`.
+fn remainder<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    m: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    let (_, r) = divide_polynomials_fast(p, m).expect("tree nodes are monic, hence non-zero");
+    r
+}
@@ -0,0 +1,136 @@
+//! Negacyclic convolution: multiplication modulo `x^n + 1`.
+//!
+//! A length-`n` FFT multiplies modulo `x^n - 1`, so coefficients that overflow the domain wrap
+//! around to the bottom. Lattice schemes such as Kyber and Dilithium work in `F[x] / (x^n + 1)`
+//! instead, where the wrapped coefficients come back negated. The two are related by a twist: if
+//! `psi` is a primitive `2n`-th root of unity, then `psi^n = -1`, and substituting `x = psi * y`
+//! turns `x^n + 1` into `-(y^n - 1)`. Scaling the inputs by powers of `psi` therefore turns a
+//! negacyclic product into a cyclic one, which a plain FFT computes, and the result is untwisted by
+//! the inverse powers.
+//!
+//! ```text
+//! a'_i = psi^i * a_i,   b'_i = psi^i * b_i
+//! c'   = IFFT(FFT(a') * FFT(b'))
+//! c_i  = psi^(-i) * c'_i
+//! ```
+//!
+//! The twist needs a subgroup of size `2n`, one more bit of two-adicity than the cyclic product.
+
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::{IsFFTField, IsField};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::MultiplicationError;
+
+/// Multiplies two polynomials modulo `x^n + 1` using the twisted FFT.
+///
+/// Inputs of degree `n` or more are reduced modulo `x^n + 1` first.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+/// * `n` - The ring dimension. Must be a power of two.
+///
+/// # Returns
+/// The product `p1 * p2 mod (x^n + 1)`, of degree less than `n`.
+///
+/// # Errors
+/// Returns a [`MultiplicationError`] if `n` is not a power of two or the field has no subgroup of
+/// size `2n`.
+pub fn multiply_negacyclic<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    n: usize,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if !n.is_power_of_two() {
+        return Err(MultiplicationError::DomainNotPowerOfTwo(n));
+    }
+    let log_2n = n.trailing_zeros() as u64 + 1;
+    if log_2n > F::TWO_ADICITY {
+        return Err(MultiplicationError::DomainTooLarge {
+            log_n: log_2n,
+            two_adicity: F::TWO_ADICITY,
+        });
+    }
+
+    // 1. Reduce the inputs modulo x^n + 1 and twist them by the powers of psi.
+    let psi = F::get_primitive_root_of_unity(log_2n)
+        .map_err(|e| MultiplicationError::TwiddleGeneration(format!("{:?}", e)))?;
+    let psi_powers: Vec<FieldElement<F>> =
+        core::iter::successors(Some(FieldElement::<F>::one()), |power| Some(power * &psi))
+            .take(n)
+            .collect();
+    let twist = |p: &Polynomial<FieldElement<F>>| {
+        let coeffs: Vec<FieldElement<F>> = fold_negacyclic(p, n)
+            .iter()
+            .zip(&psi_powers)
+            .map(|(c, power)| c * power)
+            .collect();
+        Polynomial::new(&coeffs)
+    };
+
+    // 2. Multiply the twisted inputs cyclically.
+    let fft_error = |e| MultiplicationError::TwiddleGeneration(format!("{:?}", e));
+    let evals1 = Polynomial::evaluate_fft::<F>(&twist(p1), 1, Some(n)).map_err(fft_error)?;
+    let evals2 = Polynomial::evaluate_fft::<F>(&twist(p2), 1, Some(n)).map_err(fft_error)?;
+    let product_evals: Vec<FieldElement<F>> = evals1
+        .iter()
+        .zip(evals2.iter())
+        .map(|(a, b)| a * b)
+        .collect();
+    let twisted_product = Polynomial::interpolate_fft::<F>(&product_evals).map_err(fft_error)?;
+
+    // 3. Untwist by the inverse powers, psi^(-i) = -psi^(n - i) for i > 0 since psi^n = -1.
+    let coeffs: Vec<FieldElement<F>> = twisted_product
+        .coefficients
+        .iter()
+        .enumerate()
+        .map(|(i, c)| match i {
+            0 => c.clone(),
+            i => -(c * &psi_powers[n - i]),
+        })
+        .collect();
+    Ok(Polynomial::new(&coeffs))
+}
+
+/// Multiplies two polynomials modulo `x^n + 1` using the naive O(N^2) algorithm, subtracting every
+/// term whose power reaches `n` from the coefficient of `x^(i + j - n)`.
+///
+/// # Panics
+/// Panics if `n` is zero.
+pub fn multiply_negacyclic_naive<F: IsField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    n: usize,
+) -> Polynomial<FieldElement<F>> {
+    assert!(n > 0, "The ring dimension must be positive.");
+    let mut result = vec![FieldElement::<F>::zero(); n];
+    for (i, a) in p1.coefficients.iter().enumerate() {
+        for (j, b) in p2.coefficients.iter().enumerate() {
+            let k = (i + j) % n;
+            // x^n = -1, so each full turn around the ring flips the sign.
+            if ((i + j) / n) % 2 == 0 {
+                result[k] = &result[k] + a * b;
+            } else {
+                result[k] = &result[k] - a * b;
+            }
+        }
+    }
+    Polynomial::new(&result)
+}
+
+/// Reduces `p` modulo `x^n + 1`, returning exactly `n` coefficients.
+fn fold_negacyclic<F: IsField>(p: &Polynomial<FieldElement<F>>, n: usize) -> Vec<FieldElement<F>> {
+    let mut folded = vec![FieldElement::<F>::zero(); n];
+    for (i, c) in p.coefficients.iter().enumerate() {
+        if (i / n) % 2 == 0 {
+            folded[i % n] = &folded[i % n] + c;
+        } else {
+            folded[i % n] = &folded[i % n] - c;
+        }
+    }
+    folded
+}
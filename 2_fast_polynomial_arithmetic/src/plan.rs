@@ -0,0 +1,293 @@
+//! Precomputed FFT plans, in the manner of FFTW.
+//!
+//! Every FFT of size `N = 2^log_n` needs the same precomputed data: the forward and inverse
+//! twiddles, `1/N` for the inverse, the inverse twiddles multiplied by `1/N` for the scaled
+//! inverse, and, to return evaluations in natural order, the bit-reversal permutation. An
+//! [`FftPlan`] computes all of it once for one size and radix, and is then executed any number of
+//! times on caller-owned buffers:
+//!
+//! ```text
+//! let plan = FftPlan::new(log_n)?;      // twiddles, 1/N, permutation table
+//! plan.forward(&mut buf);               // coefficients -> evaluations at w^0, ..., w^(N-1)
+//! plan.inverse(&mut buf);               // evaluations  -> coefficients, divided by N
+//! ```
+//!
+//! Multiplication only multiplies the evaluations pointwise, so it does not care in which order
+//! they are. [`FftPlan::forward_unordered`] and [`FftPlan::inverse_unordered`] leave them in
+//! whatever order the radix produces and skip the permutation altogether; this is the pair
+//! [`crate::multiply_polynomials_fft`] runs.
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use lambdaworks_math::fft::cpu::fft::in_place_nr_2radix_fft;
+use lambdaworks_math::fft::cpu::roots_of_unity::{get_powers_of_primitive_root, get_twiddles};
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, RootsConfig};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::radix::radix4_transform;
+use crate::{in_place_rn_2radix_ifft, DomainPlan, FftRadix, MultiplicationError};
+
+/// The precomputed data for FFTs of one size and radix.
+///
+/// The radix-2 bit-reversed twiddle tables are always present, since the scaled inverse and the
+/// permuting pipeline read them directly; a radix-4 plan additionally holds the natural-order
+/// roots its butterflies index.
+///
+/// The field defaults to Babybear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FftPlan<F: IsFFTField = Babybear31PrimeField> {
+    log_n: u64,
+    radix: FftRadix,
+    twiddles: Vec<FieldElement<F>>,
+    inv_twiddles: Vec<FieldElement<F>>,
+    roots: Vec<FieldElement<F>>,
+    inv_roots: Vec<FieldElement<F>>,
+    scaled_inv_twiddles: Vec<FieldElement<F>>,
+    swaps: Vec<(usize, usize)>,
+    n_inv: FieldElement<F>,
+}
+
+impl<F: IsFFTField> FftPlan<F> {
+    /// Plans radix-2 FFTs on a domain of size `2^log_n`.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::DomainTooLarge`] if the field has no multiplicative subgroup
+    /// of size `2^log_n`.
+    pub fn new(log_n: u64) -> Result<Self, MultiplicationError> {
+        Self::with_radix(log_n, FftRadix::Radix2)
+    }
+
+    /// Plans FFTs with the given radix on a domain of size `2^log_n`.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::DomainTooLarge`] if the field has no multiplicative subgroup
+    /// of size `2^log_n`.
+    pub fn with_radix(log_n: u64, radix: FftRadix) -> Result<Self, MultiplicationError> {
+        if log_n > F::TWO_ADICITY {
+            return Err(MultiplicationError::DomainTooLarge {
+                log_n,
+                two_adicity: F::TWO_ADICITY,
+            });
+        }
+        let twiddle_error = |e| MultiplicationError::TwiddleGeneration(format!("{:?}", e));
+        let twiddles = get_twiddles::<F>(log_n, RootsConfig::BitReverse).map_err(twiddle_error)?;
+        let inv_twiddles =
+            get_twiddles::<F>(log_n, RootsConfig::BitReverseInversed).map_err(twiddle_error)?;
+        let (roots, inv_roots) = match radix {
+            FftRadix::Radix2 => (Vec::new(), Vec::new()),
+            FftRadix::Radix4 => {
+                let n = 1usize << log_n;
+                (
+                    get_powers_of_primitive_root::<F>(log_n, n, RootsConfig::Natural)
+                        .map_err(twiddle_error)?,
+                    get_powers_of_primitive_root::<F>(log_n, n, RootsConfig::NaturalInversed)
+                        .map_err(twiddle_error)?,
+                )
+            }
+        };
+        Ok(Self::from_parts(
+            log_n,
+            radix,
+            twiddles,
+            inv_twiddles,
+            roots,
+            inv_roots,
+        ))
+    }
+
+    /// Plans radix-2 FFTs on the smallest domain that holds the product `p1 * p2`, as chosen by
+    /// [`DomainPlan`].
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::DomainTooLarge`] if the product is too large for the field's
+    /// two-adicity.
+    pub fn for_product(
+        p1: &Polynomial<FieldElement<F>>,
+        p2: &Polynomial<FieldElement<F>>,
+    ) -> Result<Self, MultiplicationError> {
+        Self::new(DomainPlan::new(p1, p2).log_size())
+    }
+
+    /// Builds a radix-2 plan on a domain of size `2^log_n` from precomputed bit-reversed twiddle
+    /// tables, such as a loaded `TwiddleSet`, which hold `2^log_n / 2` factors each: none for the
+    /// single point of `log_n = 0`.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::DomainTooLarge`] if the field has no multiplicative subgroup
+    /// of size `2^log_n`, or [`MultiplicationError::TwiddleLengthMismatch`] if either table has
+    /// the wrong length for it. Twiddles of the right length but for the wrong root of unity
+    /// cannot be detected.
+    pub fn from_twiddles(
+        log_n: u64,
+        twiddles: Vec<FieldElement<F>>,
+        inv_twiddles: Vec<FieldElement<F>>,
+    ) -> Result<Self, MultiplicationError> {
+        if log_n > F::TWO_ADICITY {
+            return Err(MultiplicationError::DomainTooLarge {
+                log_n,
+                two_adicity: F::TWO_ADICITY,
+            });
+        }
+        let expected = (1usize << log_n) / 2;
+        for got in [twiddles.len(), inv_twiddles.len()] {
+            if got != expected {
+                return Err(MultiplicationError::TwiddleLengthMismatch { expected, got });
+            }
+        }
+        Ok(Self::from_parts(
+            log_n,
+            FftRadix::Radix2,
+            twiddles,
+            inv_twiddles,
+            Vec::new(),
+            Vec::new(),
+        ))
+    }
+
+    fn from_parts(
+        log_n: u64,
+        radix: FftRadix,
+        twiddles: Vec<FieldElement<F>>,
+        inv_twiddles: Vec<FieldElement<F>>,
+        roots: Vec<FieldElement<F>>,
+        inv_roots: Vec<FieldElement<F>>,
+    ) -> Self {
+        let n = 1usize << log_n;
+        // Each pair of positions whose indices are bit reversals of one another, listed once.
+        let swaps = (0..n)
+            .filter_map(|i| {
+                let j = i.reverse_bits().checked_shr(usize::BITS - log_n as u32)?;
+                (i < j).then_some((i, j))
+            })
+            .collect();
+        let n_inv = FieldElement::<F>::from(n as u64)
+            .inv()
+            .expect("Inverse of N should exist in the field.");
+        let scaled_inv_twiddles = inv_twiddles.iter().map(|w| w * &n_inv).collect();
+        Self {
+            log_n,
+            radix,
+            twiddles,
+            inv_twiddles,
+            roots,
+            inv_roots,
+            scaled_inv_twiddles,
+            swaps,
+            n_inv,
+        }
+    }
+
+    /// Returns the number of points `n` of the domain.
+    pub fn size(&self) -> usize {
+        1 << self.log_n
+    }
+
+    /// Returns `log2(n)`.
+    pub fn log_size(&self) -> u64 {
+        self.log_n
+    }
+
+    /// Returns the radix of the butterflies.
+    pub fn radix(&self) -> FftRadix {
+        self.radix
+    }
+
+    /// Returns the `n / 2` bit-reversed roots of unity of the radix-2 forward FFT.
+    pub fn twiddles(&self) -> &[FieldElement<F>] {
+        &self.twiddles
+    }
+
+    /// Returns the `n / 2` bit-reversed inverse roots of unity of the radix-2 inverse FFT.
+    pub fn inv_twiddles(&self) -> &[FieldElement<F>] {
+        &self.inv_twiddles
+    }
+
+    /// Returns the `n / 2` bit-reversed inverse roots of unity multiplied by `1/n`, which the
+    /// last layer of [`crate::in_place_nr_2radix_ifft_scaled`] uses.
+    pub fn scaled_inv_twiddles(&self) -> &[FieldElement<F>] {
+        &self.scaled_inv_twiddles
+    }
+
+    /// Evaluates the coefficients in `buf` at `w^0, ..., w^(n-1)` in place, in natural order.
+    ///
+    /// # Panics
+    /// Panics if `buf` does not have exactly `n` entries.
+    pub fn forward(&self, buf: &mut [FieldElement<F>]) {
+        self.forward_unordered(buf);
+        if self.radix == FftRadix::Radix2 {
+            self.bit_reverse(buf);
+        }
+    }
+
+    /// Interpolates the natural-order evaluations in `buf` back into coefficients in place,
+    /// including the division by `n`, undoing [`FftPlan::forward`].
+    ///
+    /// # Panics
+    /// Panics if `buf` does not have exactly `n` entries.
+    pub fn inverse(&self, buf: &mut [FieldElement<F>]) {
+        if self.radix == FftRadix::Radix2 {
+            self.bit_reverse(buf);
+        }
+        self.inverse_unordered(buf);
+    }
+
+    /// Evaluates the coefficients in `buf` in place, leaving the evaluations in the radix's own
+    /// order: bit-reversed for radix 2, natural for radix 4. Only pointwise operations between
+    /// such vectors are meaningful before [`FftPlan::inverse_unordered`].
+    ///
+    /// A radix-4 transform is not in place, so it copies `buf` first.
+    ///
+    /// # Panics
+    /// Panics if `buf` does not have exactly `n` entries.
+    pub fn forward_unordered(&self, buf: &mut [FieldElement<F>]) {
+        self.check_len(buf);
+        match self.radix {
+            FftRadix::Radix2 => in_place_nr_2radix_fft(buf, &self.twiddles),
+            FftRadix::Radix4 => {
+                let input = buf.to_vec();
+                radix4_transform(&input, 1, buf, &self.roots, 1);
+            }
+        }
+    }
+
+    /// Interpolates evaluations in the order [`FftPlan::forward_unordered`] leaves them back into
+    /// natural-order coefficients in place, including the division by `n`.
+    ///
+    /// # Panics
+    /// Panics if `buf` does not have exactly `n` entries.
+    pub fn inverse_unordered(&self, buf: &mut [FieldElement<F>]) {
+        self.check_len(buf);
+        match self.radix {
+            FftRadix::Radix2 => in_place_rn_2radix_ifft(buf, &self.inv_twiddles),
+            FftRadix::Radix4 => {
+                let input = buf.to_vec();
+                radix4_transform(&input, 1, buf, &self.inv_roots, 1);
+            }
+        }
+        for c in buf.iter_mut() {
+            *c = &*c * &self.n_inv;
+        }
+    }
+
+    /// Applies the bit-reversal permutation to `buf` in place, from the precomputed swap table.
+    ///
+    /// # Panics
+    /// Panics if `buf` does not have exactly `n` entries.
+    pub fn bit_reverse(&self, buf: &mut [FieldElement<F>]) {
+        self.check_len(buf);
+        for &(i, j) in &self.swaps {
+            buf.swap(i, j);
+        }
+    }
+
+    fn check_len(&self, buf: &[FieldElement<F>]) {
+        assert_eq!(
+            buf.len(),
+            self.size(),
+            "Buffer length must match the plan size."
+        );
+    }
+}
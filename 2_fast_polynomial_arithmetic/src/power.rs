@@ -0,0 +1,73 @@
+//! Polynomial exponentiation by repeated squaring.
+//!
+//! Scanning the bits of `k` from the top, every bit squares the running power and every set bit
+//! multiplies it by `p` once more,
+//!
+//! ```text
+//! p^(2m)     = (p^m)^2
+//! p^(2m + 1) = (p^m)^2 * p
+//! ```
+//!
+//! so `p^k` takes about `log2(k)` squarings and at most as many products, all done with the FFT.
+//! The last squaring dominates: the result has `k * deg(p) + 1` coefficients. When only the
+//! coefficients up to some degree are needed, as for the first terms of a generating function,
+//! every intermediate power is truncated to that degree, which bounds all transforms by it.
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{
+    multiply_polynomials_fft_cached, required_domain_size, square_polynomial_fft,
+    MultiplicationError, TwiddleCache,
+};
+
+/// Raises `p` to the `k`-th power, optionally keeping only the terms up to `max_degree`.
+///
+/// # Arguments
+/// * `p` - The base polynomial.
+/// * `k` - The exponent. `p^0` is the constant `1`, even for the zero polynomial.
+/// * `max_degree` - If set, the result is `p^k mod x^(max_degree + 1)`.
+///
+/// # Returns
+/// The polynomial `p^k`, truncated to degree `max_degree` if one is given.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if an intermediate product is too large for the
+/// field's two-adicity.
+pub fn pow_polynomial<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    k: u64,
+    max_degree: Option<usize>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    let truncate = |q: Polynomial<FieldElement<F>>| match max_degree {
+        Some(d) if d < q.coefficients.len() => Polynomial::new(&q.coefficients[..=d]),
+        _ => q,
+    };
+    if k == 0 {
+        return Ok(Polynomial::new(&[FieldElement::one()]));
+    }
+    let base = truncate(p.clone());
+    if base.coefficients.is_empty() {
+        return Ok(base);
+    }
+
+    let mut cache = TwiddleCache::new();
+    let mut result = base.clone();
+    for bit in (0..k.ilog2()).rev() {
+        result = truncate(square_cached(&result, &mut cache)?);
+        if (k >> bit) & 1 == 1 {
+            result = truncate(multiply_polynomials_fft_cached(&result, &base, &mut cache)?);
+        }
+    }
+    Ok(result)
+}
+
+/// Squares `p` with a single forward FFT, taking the plan from `cache`.
+fn square_cached<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    cache: &mut TwiddleCache<F>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    let log_n = required_domain_size(p, p).trailing_zeros() as u64;
+    square_polynomial_fft(p, cache.plan(log_n)?)
+}
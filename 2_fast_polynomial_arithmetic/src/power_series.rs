@@ -0,0 +1,66 @@
+//! Truncated power series over the field.
+//!
+//! A polynomial with a non-zero constant term is invertible modulo `x^n` for every `n`: there is
+//! a unique `g` of degree below `n` with `p g = 1 mod x^n`. Newton iteration finds it by doubling
+//! the precision at each step,
+//!
+//! ```text
+//! g_(2k) = g_k (2 - p g_k) mod x^(2k)
+//! ```
+//!
+//! so the cost is dominated by the last two multiplications and stays O(N log N) with the FFT.
+//! Power-series inversion underlies fast division, fast interpolation, and manipulations of
+//! generating functions.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{mul_low, MultiplicationError};
+
+/// Returns the inverse of `p` as a power series modulo `x^n`: the unique polynomial `g` of degree
+/// below `n` with `p * g = 1 mod x^n`. For `n = 0` the result is the zero polynomial.
+///
+/// # Errors
+/// Returns [`MultiplicationError::ZeroConstantTerm`] if `p(0) = 0`, since `p` then has no inverse
+/// modulo `x^n` for any `n >= 1`.
+pub fn inverse_mod_xn<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    n: usize,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if n == 0 {
+        return Ok(Polynomial::new(&[]));
+    }
+    let a0 = p
+        .coefficients
+        .first()
+        .and_then(|a0| a0.inv().ok())
+        .ok_or(MultiplicationError::ZeroConstantTerm)?;
+
+    let mut g = Polynomial::new(&[a0]);
+    let mut precision = 1;
+    while precision < n {
+        precision = (2 * precision).min(n);
+        let p_trunc = Polynomial::new(&truncated(p, precision));
+        // e = 2 - p g mod x^precision
+        let mut e: Vec<FieldElement<F>> = truncated(&mul_low(&p_trunc, &g, precision), precision)
+            .iter()
+            .map(|c| -c)
+            .collect();
+        e[0] = &e[0] + FieldElement::<F>::from(2);
+        g = mul_low(&g, &Polynomial::new(&e), precision);
+    }
+    Ok(g)
+}
+
+/// Returns the first `k` coefficients of `p`, zero-padded to exactly `k` entries.
+pub(crate) fn truncated<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    k: usize,
+) -> Vec<FieldElement<F>> {
+    let mut coeffs: Vec<FieldElement<F>> = p.coefficients.iter().take(k).cloned().collect();
+    coeffs.resize(k, FieldElement::zero());
+    coeffs
+}
@@ -0,0 +1,48 @@
+//! Products of many polynomials via a balanced product tree.
+//!
+//! Folding `p_1 * p_2 * ... * p_k` sequentially multiplies an ever-growing accumulator by small
+//! factors, so the FFT never gets balanced operands. Multiplying neighbours pairwise instead, level
+//! by level, keeps both operands of every product the same size: with `k` linear factors the tree
+//! has `log2(k)` levels, each costing O(k log k), for O(k log^2 k) in total. This is how the
+//! zerofier `Z(x) = (x - a_1)(x - a_2)...(x - a_k)` of a large domain is built.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{multiply_polynomials_fft_cached, MultiplicationError, TwiddleCache};
+
+/// Multiplies all polynomials in `polys` using a balanced product tree, with every pairwise
+/// product computed by the FFT. The twiddles for each level are generated once and shared.
+///
+/// The product of an empty slice is the constant polynomial `1`.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the full product is too large for the
+/// field's two-adicity.
+pub fn multiply_many<F: IsFFTField>(
+    polys: &[Polynomial<FieldElement<F>>],
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    let mut cache = TwiddleCache::new();
+    let mut level = polys.to_vec();
+    if level.is_empty() {
+        return Ok(Polynomial::new(&[FieldElement::one()]));
+    }
+
+    // Replace each pair of neighbours by their product until one polynomial is left. An odd one
+    // out at the end of a level is carried up unchanged.
+    while level.len() > 1 {
+        let mut next = Vec::with_capacity(level.len().div_ceil(2));
+        for pair in level.chunks(2) {
+            match pair {
+                [p1, p2] => next.push(multiply_polynomials_fft_cached(p1, p2, &mut cache)?),
+                [p] => next.push(p.clone()),
+                _ => unreachable!("chunks(2) yields one or two polynomials"),
+            }
+        }
+        level = next;
+    }
+    Ok(level.pop().expect("one polynomial is left"))
+}
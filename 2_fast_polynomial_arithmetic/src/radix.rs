@@ -0,0 +1,117 @@
+//! Selectable FFT radix for multiplication.
+//!
+//! The radix-2 FFT splits a size-`n` transform into two halves and spends `n / 2` twiddle
+//! multiplications per level. A radix-4 step splits into four quarters at once, replacing two
+//! radix-2 levels: its butterfly
+//!
+//! ```text
+//! X[k]          = (t0 + t2) +   (t1 + t3)
+//! X[k + n/4]    = (t0 - t2) + i (t1 - t3)
+//! X[k + n/2]    = (t0 + t2) -   (t1 + t3)
+//! X[k + 3n/4]   = (t0 - t2) - i (t1 - t3)
+//! ```
+//!
+//! with `t_r = w^(rk) s_r[k]` needs three twiddle multiplications per four outputs, plus one
+//! multiplication by the fixed fourth root of unity `i`. Over the two levels it replaces, radix-4
+//! performs `3n/4` twiddle multiplications against radix-2's `n`. When `log2(n)` is odd, the
+//! recursion ends with a single radix-2 step.
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{
+    multiply_polynomials_fft, multiply_polynomials_fft_auto, DomainPlan, FftPlan,
+    MultiplicationError,
+};
+
+/// The butterfly used by [`multiply_polynomials_fft_with_radix`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FftRadix {
+    /// lambdaworks' radix-2 FFT, as used by [`crate::multiply_polynomials_fft`].
+    #[default]
+    Radix2,
+    /// The radix-4 FFT of this module, with a final radix-2 step when `log2(n)` is odd. Its
+    /// evaluations come out in natural order.
+    Radix4,
+}
+
+/// Multiplies two polynomials using the FFT with the given radix. The domain size and roots of
+/// unity are computed internally, as in [`multiply_polynomials_fft_auto`].
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the product is too large for the field's
+/// two-adicity.
+pub fn multiply_polynomials_fft_with_radix<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    radix: FftRadix,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    match radix {
+        FftRadix::Radix2 => multiply_polynomials_fft_auto(p1, p2),
+        FftRadix::Radix4 => multiply_polynomials_radix4(p1, p2),
+    }
+}
+
+fn multiply_polynomials_radix4<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::new(&[]));
+    }
+    let plan = FftPlan::with_radix(DomainPlan::new(p1, p2).log_size(), FftRadix::Radix4)?;
+    multiply_polynomials_fft(p1, p2, &plan)
+}
+
+/// Computes `out[k] = sum_j input[j * stride] * w^(jk)` for `k < n = out.len()`, where `w` is a
+/// primitive `n`-th root of unity taken from `roots`, the powers of a primitive `N`-th root with
+/// `N = n * root_stride`.
+///
+/// The four quarter-size transforms are written into the four quarters of `out`, and each
+/// butterfly reads and writes the same four positions, so the combination happens in place.
+pub(crate) fn radix4_transform<F: IsFFTField>(
+    input: &[FieldElement<F>],
+    stride: usize,
+    out: &mut [FieldElement<F>],
+    roots: &[FieldElement<F>],
+    root_stride: usize,
+) {
+    let n = out.len();
+    match n {
+        1 => out[0] = input[0].clone(),
+        2 => {
+            let (a, b) = (&input[0], &input[stride]);
+            out[0] = a + b;
+            out[1] = a - b;
+        }
+        _ => {
+            let q = n / 4;
+            for (r, quarter) in out.chunks_mut(q).enumerate() {
+                radix4_transform(
+                    &input[r * stride..],
+                    4 * stride,
+                    quarter,
+                    roots,
+                    4 * root_stride,
+                );
+            }
+
+            // The primitive fourth root of unity `i = w^(n/4)`.
+            let i_unit = &roots[q * root_stride];
+            for k in 0..q {
+                let t0 = out[k].clone();
+                let t1 = &out[q + k] * &roots[k * root_stride];
+                let t2 = &out[2 * q + k] * &roots[2 * k * root_stride];
+                let t3 = &out[3 * q + k] * &roots[3 * k * root_stride];
+
+                let (sum02, diff02) = (&t0 + &t2, &t0 - &t2);
+                let (sum13, diff13) = (&t1 + &t3, (&t1 - &t3) * i_unit);
+                out[k] = &sum02 + &sum13;
+                out[q + k] = &diff02 + &diff13;
+                out[2 * q + k] = &sum02 - &sum13;
+                out[3 * q + k] = &diff02 - &diff13;
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Resultants and the composed product and sum of two polynomials.
+//!
+//! The resultant of `p` of degree `m` with roots `a_i` and `q` of degree `n` is
+//!
+//! ```text
+//! Res(p, q) = lc(p)^n prod_i q(a_i)
+//! ```
+//!
+//! and vanishes exactly when `p` and `q` share a root. It never needs the roots: one step of the
+//! Euclidean algorithm, `p = s q + r` with `k = deg(r)`, gives `Res(p, q) = (-1)^(mn) lc(q)^(m-k)
+//! Res(q, r)`, down to a constant `q = c` with `Res(p, c) = c^m`.
+//!
+//! The composed product and sum are the monic polynomials whose `mn` roots are every product
+//! `a_i b_j` and every sum `a_i + b_j` of a root of `p` and a root of `q`. Their values at any `x`
+//! are resultants in a second variable `y`,
+//!
+//! ```text
+//! (p (x) q)(x) = prod_(i,j) (x - a_i b_j)   = Res_y(p(y), y^n q(x/y)) / (lc(p)^n lc(q)^m)
+//! (p (+) q)(x) = prod_(i,j) (x - a_i - b_j) = Res_y(p(y), q(x - y))   / (lc(p)^n lc(q)^m)
+//! ```
+//!
+//! so both are computed by evaluation and interpolation: one resultant at each point of an FFT
+//! domain of more than `mn` points, then an inverse FFT. For the composed product, `lc(p)^n`
+//! becomes `lc(p)^deg_y`, where `deg_y` is the degree of `y^n q(x/y)` in `y`, which drops below
+//! `n` when `q(0) = 0`. The roots themselves may live in an extension field; the results always
+//! have coefficients in `F`.
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use lambdaworks_math::fft::cpu::roots_of_unity::get_powers_of_primitive_root;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::{IsFFTField, RootsConfig};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{divide_polynomials_fast, scale, shift, FftPlan, MultiplicationError};
+
+/// A pair `(p, q)` of operands.
+type PolynomialPair<F> = (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>);
+
+/// Returns the resultant `Res(p, q)`, computed with the Euclidean algorithm.
+///
+/// The resultant is zero if either polynomial is zero, and `c^deg(q)` for a constant `p = c`.
+pub fn resultant<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    q: &Polynomial<FieldElement<F>>,
+) -> FieldElement<F> {
+    // Trim the operands so their last coefficients are the true leading ones.
+    let mut a = Polynomial::new(&p.coefficients);
+    let mut b = Polynomial::new(&q.coefficients);
+    if a.coefficients.is_empty() || b.coefficients.is_empty() {
+        return FieldElement::zero();
+    }
+
+    let mut result = FieldElement::<F>::one();
+    loop {
+        let m = (a.coefficients.len() - 1) as u64;
+        let n = (b.coefficients.len() - 1) as u64;
+        let lc_b = b.coefficients[b.coefficients.len() - 1].clone();
+        if n == 0 {
+            return result * lc_b.pow(m);
+        }
+        let (_, r) = divide_polynomials_fast(&a, &b).expect("b is not the zero polynomial");
+        if r.coefficients.is_empty() {
+            // a and b share a factor of positive degree.
+            return FieldElement::zero();
+        }
+        let k = (r.coefficients.len() - 1) as u64;
+        result *= lc_b.pow(m - k);
+        if m * n % 2 == 1 {
+            result = -result;
+        }
+        a = b;
+        b = r;
+    }
+}
+
+/// Returns the composed product of `p` and `q`: the monic polynomial of degree
+/// `deg(p) * deg(q)` whose roots are the products `a * b` of a root `a` of `p` and a root `b` of
+/// `q`, with multiplicity.
+///
+/// # Errors
+/// Returns [`MultiplicationError::ZeroPolynomial`] if either operand is zero, and
+/// [`MultiplicationError::DomainTooLarge`] if `deg(p) * deg(q)` is too large for the field's
+/// two-adicity.
+pub fn composed_product<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    q: &Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    let (p, q) = nonzero_pair(p, q)?;
+    let n = q.coefficients.len() - 1;
+    // The degree of y^n q(x/y) in y, which is the same at every x != 0.
+    let deg_y = n - q
+        .coefficients
+        .iter()
+        .take_while(|c| **c == FieldElement::zero())
+        .count();
+
+    interpolate_resultants(&p, &q, deg_y as u64, |x| {
+        // y^n q(x/y) has the coefficients of q(x y) in reverse order.
+        let mut coeffs = scale(&q, x).coefficients;
+        coeffs.resize(n + 1, FieldElement::zero());
+        coeffs.reverse();
+        Polynomial::new(&coeffs)
+    })
+}
+
+/// Returns the composed sum of `p` and `q`: the monic polynomial of degree `deg(p) * deg(q)`
+/// whose roots are the sums `a + b` of a root `a` of `p` and a root `b` of `q`, with
+/// multiplicity.
+///
+/// # Errors
+/// Returns [`MultiplicationError::ZeroPolynomial`] if either operand is zero, and
+/// [`MultiplicationError::DomainTooLarge`] if `deg(p) * deg(q)` is too large for the field's
+/// two-adicity.
+///
+/// # Panics
+/// Panics if the degree of `q` is at least the characteristic of the field, as for [`shift`].
+pub fn composed_sum<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    q: &Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    let (p, q) = nonzero_pair(p, q)?;
+    let n = (q.coefficients.len() - 1) as u64;
+    // q(x - y) is q(-y) shifted by -x.
+    let q_neg = scale(&q, &-FieldElement::<F>::one());
+
+    interpolate_resultants(&p, &q, n, |x| shift(&q_neg, &-x))
+}
+
+/// Returns `p` and `q` trimmed.
+///
+/// # Errors
+/// Returns [`MultiplicationError::ZeroPolynomial`] if either of them is zero.
+fn nonzero_pair<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    q: &Polynomial<FieldElement<F>>,
+) -> Result<PolynomialPair<F>, MultiplicationError> {
+    let p = Polynomial::new(&p.coefficients);
+    let q = Polynomial::new(&q.coefficients);
+    if p.coefficients.is_empty() || q.coefficients.is_empty() {
+        return Err(MultiplicationError::ZeroPolynomial);
+    }
+    Ok((p, q))
+}
+
+/// Interpolates the monic polynomial of degree `deg(p) * deg(q)` whose value at every `x` is
+/// `Res_y(p, g(x)) / (lc(p)^deg_y lc(q)^deg(p))`, where `g(x)` has degree `deg_y` in `y`.
+fn interpolate_resultants<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    q: &Polynomial<FieldElement<F>>,
+    deg_y: u64,
+    mut g: impl FnMut(&FieldElement<F>) -> Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    let m = p.coefficients.len() - 1;
+    let n = q.coefficients.len() - 1;
+    if m == 0 || n == 0 {
+        // A constant has no roots, so neither has the result.
+        return Ok(Polynomial::new(&[FieldElement::one()]));
+    }
+
+    // 1. Plan an FFT domain with more points than the result has degree.
+    let len = (m * n + 1).next_power_of_two();
+    let plan = FftPlan::<F>::new(len.trailing_zeros() as u64)?;
+    let points = get_powers_of_primitive_root::<F>(plan.log_size(), len, RootsConfig::Natural)
+        .map_err(|e| MultiplicationError::TwiddleGeneration(format!("{:?}", e)))?;
+
+    // 2. Evaluate the normalized resultant at every point.
+    let lc_p = &p.coefficients[m];
+    let lc_q = &q.coefficients[n];
+    let norm = (lc_p.pow(deg_y) * lc_q.pow(m as u64))
+        .inv()
+        .expect("the leading coefficients are non-zero");
+    let mut evals: Vec<FieldElement<F>> =
+        points.iter().map(|x| resultant(p, &g(x)) * &norm).collect();
+
+    // 3. Interpolate.
+    plan.inverse(&mut evals);
+    Ok(Polynomial::new(&evals))
+}
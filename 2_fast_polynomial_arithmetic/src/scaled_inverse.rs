@@ -0,0 +1,145 @@
+//! Inverse FFT with the `1/N` scaling folded into the last butterfly layer.
+//!
+//! [`crate::multiply_polynomials_fft`] ends with a separate pass that multiplies every
+//! coefficient by `1/N`, `N` multiplications and one more trip through memory. The scaling cannot
+//! be pushed into all of the inverse twiddles, since a butterfly only multiplies one of its two
+//! inputs by the twiddle, but it can be folded into the last layer, where every output is
+//! produced exactly once:
+//!
+//! ```text
+//! (a, b) -> (a + w b, a - w b)               standard butterfly
+//! (a, b) -> (a/N + (w/N) b, a/N - (w/N) b)   last layer, with w/N precomputed
+//! ```
+//!
+//! The `N / 2` last-layer butterflies now cost two multiplications each, which replaces the `N / 2`
+//! twiddle multiplications of the last layer plus the `N` of the scaling pass. The premultiplied
+//! table `w/N` comes from [`FftPlan::scaled_inv_twiddles`], or [`get_scaled_inv_twiddles`] without
+//! a plan; the earlier layers keep using the plain inverse twiddles.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::fft::cpu::roots_of_unity::get_twiddles;
+use lambdaworks_math::fft::errors::FFTError;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::{IsFFTField, RootsConfig};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{validate_plan, FftPlan, MultiplicationError};
+
+/// Returns the bit-reversed inverse twiddles for a domain of size `N = 2^log_n`, each multiplied
+/// by `1/N`.
+///
+/// The first entry is `1/N` itself, since the first inverse twiddle is `1`.
+///
+/// # Errors
+/// Returns an `FFTError` if the field has no subgroup of size `2^log_n`.
+pub fn get_scaled_inv_twiddles<F: IsFFTField>(
+    log_n: u64,
+) -> Result<Vec<FieldElement<F>>, FFTError> {
+    // `get_twiddles` rejects an oversized `log_n` before `1 << log_n` can overflow.
+    let inv_twiddles = get_twiddles::<F>(log_n, RootsConfig::BitReverseInversed)?;
+    let n_inv = FieldElement::<F>::from(1u64 << log_n)
+        .inv()
+        .expect("Inverse of N should exist in the field.");
+    Ok(inv_twiddles.iter().map(|w| w * &n_inv).collect())
+}
+
+/// Runs the inverse radix-2 FFT on `input` in place, natural order in and bit-reversed order out,
+/// including the division by `N`.
+///
+/// # Arguments
+/// * `input` - The `N` values to transform, with `N` a power of two.
+/// * `inv_twiddles` - The bit-reversed inverse twiddles, as from `get_twiddles`.
+/// * `scaled_inv_twiddles` - The same twiddles multiplied by `1/N`, as from
+///   [`get_scaled_inv_twiddles`] or [`FftPlan::scaled_inv_twiddles`].
+pub fn in_place_nr_2radix_ifft_scaled<F: IsFFTField>(
+    input: &mut [FieldElement<F>],
+    inv_twiddles: &[FieldElement<F>],
+    scaled_inv_twiddles: &[FieldElement<F>],
+) {
+    let n = input.len();
+    if n < 2 {
+        // A single point is its own transform, and 1/N = 1.
+        return;
+    }
+
+    // 1. Run every layer but the last, as in `in_place_nr_2radix_fft`: group `g` of each layer
+    // uses the twiddle `inv_twiddles[g]`.
+    let mut group_count = 1;
+    let mut group_size = n;
+    while group_size > 2 {
+        let half = group_size / 2;
+        for (group, w) in inv_twiddles.iter().enumerate().take(group_count) {
+            let first = group * group_size;
+            for i in first..first + half {
+                let wi = w * &input[i + half];
+                let (y0, y1) = (&input[i] + &wi, &input[i] - &wi);
+                input[i] = y0;
+                input[i + half] = y1;
+            }
+        }
+        group_count *= 2;
+        group_size = half;
+    }
+
+    // 2. The last layer pairs adjacent positions, with the scaling folded in.
+    let n_inv = &scaled_inv_twiddles[0];
+    for (pair, w) in input.chunks_exact_mut(2).zip(scaled_inv_twiddles) {
+        let a = &pair[0] * n_inv;
+        let wb = w * &pair[1];
+        pair[0] = &a + &wb;
+        pair[1] = &a - &wb;
+    }
+}
+
+/// Multiplies two polynomials using the FFT, with the inverse transform's `1/N` scaling folded
+/// into the plan's scaled inverse twiddles.
+///
+/// # Arguments
+/// * `p1`, `p2`, `plan` - As for [`crate::multiply_polynomials_fft`]; the inverse transform is this
+///   module's rather than the plan's.
+///
+/// # Returns
+/// A new `Polynomial` representing the product `p1 * p2`.
+///
+/// # Errors
+/// Returns a [`MultiplicationError`] under the same conditions as
+/// [`crate::multiply_polynomials_fft`].
+pub fn multiply_polynomials_fft_scaled<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    plan: &FftPlan<F>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::new(&[]));
+    }
+    validate_plan(p1.degree() + p2.degree() + 1, plan)?;
+    let n = plan.size();
+
+    // 1. Evaluate both operands, in natural order.
+    let evaluate = |p: &Polynomial<FieldElement<F>>| {
+        let mut evals = p.coefficients.to_vec();
+        evals.resize(n, FieldElement::zero());
+        plan.forward(&mut evals);
+        evals
+    };
+    let p1_evals = evaluate(p1);
+    let p2_evals = evaluate(p2);
+
+    // 2. Multiply pointwise.
+    let mut c_coeffs: Vec<FieldElement<F>> = p1_evals
+        .iter()
+        .zip(p2_evals.iter())
+        .map(|(y1, y2)| y1 * y2)
+        .collect();
+
+    // 3. Interpolate; the result is already scaled, so only the permutation remains.
+    in_place_nr_2radix_ifft_scaled(
+        &mut c_coeffs,
+        plan.inv_twiddles(),
+        plan.scaled_inv_twiddles(),
+    );
+    plan.bit_reverse(&mut c_coeffs);
+
+    Ok(Polynomial::new(&c_coeffs))
+}
@@ -0,0 +1,101 @@
+//! Allocation-free FFT multiplication with caller-provided buffers.
+//!
+//! [`crate::multiply_polynomials_fft`] allocates two padded operand vectors, the product
+//! evaluations, and the scaled coefficients on every call. For small and medium sizes in a tight
+//! loop, those allocations cost as much as the arithmetic. [`FftScratch`] owns the working buffers
+//! and the FFT plans, so after the first call at a given size no further allocation happens.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{DomainPlan, MultiplicationError, TwiddleCache};
+
+/// Reusable working memory for [`multiply_polynomials_fft_into`]: two operand buffers, which keep
+/// their capacity between calls, and a [`TwiddleCache`].
+///
+/// The field defaults to Babybear.
+#[derive(Debug, Clone)]
+pub struct FftScratch<F: IsFFTField = Babybear31PrimeField> {
+    lhs: Vec<FieldElement<F>>,
+    rhs: Vec<FieldElement<F>>,
+    plans: TwiddleCache<F>,
+}
+
+impl<F: IsFFTField> Default for FftScratch<F> {
+    fn default() -> Self {
+        Self {
+            lhs: Vec::new(),
+            rhs: Vec::new(),
+            plans: TwiddleCache::new(),
+        }
+    }
+}
+
+impl<F: IsFFTField> FftScratch<F> {
+    /// Creates empty scratch space; buffers grow on first use.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates scratch space with buffers preallocated for domains of up to `n` points.
+    pub fn with_capacity(n: usize) -> Self {
+        Self {
+            lhs: Vec::with_capacity(n),
+            rhs: Vec::with_capacity(n),
+            plans: TwiddleCache::new(),
+        }
+    }
+}
+
+/// Multiplies two polynomials using the FFT, writing the product's coefficients into `out` and
+/// doing all intermediate work in `scratch`.
+///
+/// `out` is cleared first and receives the coefficients in ascending order of power, without
+/// trailing zeros, exactly as `multiply_polynomials_fft(p1, p2, ...).coefficients` would be. Its
+/// capacity is reused, so passing the same vector in a loop avoids reallocating it.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the product is too large for the field's
+/// two-adicity.
+pub fn multiply_polynomials_fft_into<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    scratch: &mut FftScratch<F>,
+    out: &mut Vec<FieldElement<F>>,
+) -> Result<(), MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        out.clear();
+        return Ok(());
+    }
+    let domain = DomainPlan::new(p1, p2);
+    let FftScratch { lhs, rhs, plans } = scratch;
+    let plan = plans.plan(domain.log_size())?;
+
+    // 1. Copy the zero-padded operands into the scratch buffers and evaluate them in place. The
+    // evaluations stay in the plan's own order, as in `multiply_polynomials_fft`.
+    for (buffer, p) in [(&mut *lhs, p1), (&mut *rhs, p2)] {
+        buffer.clear();
+        buffer.extend_from_slice(&p.coefficients);
+        buffer.resize(domain.size(), FieldElement::zero());
+        plan.forward_unordered(buffer);
+    }
+
+    // 2. Multiply pointwise into the left buffer and interpolate it in place.
+    for (y1, y2) in lhs.iter_mut().zip(rhs.iter()) {
+        *y1 = &*y1 * y2;
+    }
+    plan.inverse_unordered(lhs);
+
+    // 3. Copy the coefficients into `out`, dropping the zero coefficients past the product's
+    // degree.
+    out.clear();
+    out.extend_from_slice(&lhs[..domain.product_len()]);
+    while out.last().is_some_and(|c| *c == FieldElement::zero()) {
+        out.pop();
+    }
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! Serde support for Babybear polynomials and twiddle tables.
+//!
+//! `lambdaworks` types do not implement `Serialize`, so this module wraps the two things worth
+//! persisting: random benchmark inputs, and the twiddle tables for large domains, which take as
+//! long to generate as a multiplication. Both serialize their field elements as canonical `u32`
+//! representatives, so the files are portable across machines and independent of the internal
+//! Montgomery form.
+//!
+//! ```text
+//! SerializablePolynomial -> [c_0, c_1, ..., c_(n-1)]
+//! TwiddleSet             -> { "log_n": k, "twiddles": [...], "inv_twiddles": [...] }
+//! ```
+//!
+//! Deserialization reduces every value modulo `p` and does not re-derive the roots of unity, so
+//! only load twiddle tables from trusted sources.
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use lambdaworks_math::fft::cpu::roots_of_unity::get_twiddles;
+use lambdaworks_math::field::traits::{IsFFTField, RootsConfig};
+use lambdaworks_math::polynomial::Polynomial;
+use serde::{Deserialize, Serialize};
+
+use crate::{MultiplicationError, F, FE};
+
+/// A Babybear polynomial that serializes as its coefficients, constant term first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "Vec<u32>", into = "Vec<u32>")]
+pub struct SerializablePolynomial(pub Polynomial<FE>);
+
+impl From<Vec<u32>> for SerializablePolynomial {
+    fn from(coeffs: Vec<u32>) -> Self {
+        Self(Polynomial::new(&from_canonical(&coeffs)))
+    }
+}
+
+impl From<SerializablePolynomial> for Vec<u32> {
+    fn from(poly: SerializablePolynomial) -> Self {
+        to_canonical(&poly.0.coefficients)
+    }
+}
+
+/// The forward and inverse bit-reversed twiddles for a domain of size `2^log_n`, in the layout
+/// expected by [`crate::FftPlan::from_twiddles`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "TwiddleSetData", into = "TwiddleSetData")]
+pub struct TwiddleSet {
+    /// `log2` of the domain size.
+    pub log_n: u64,
+    /// The bit-reversed roots of unity for the forward FFT.
+    pub twiddles: Vec<FE>,
+    /// The bit-reversed inverse roots of unity for the inverse FFT.
+    pub inv_twiddles: Vec<FE>,
+}
+
+impl TwiddleSet {
+    /// Computes the twiddles for a domain of size `2^log_n`.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::DomainTooLarge`] if `log_n` exceeds the two-adicity of
+    /// Babybear.
+    pub fn new(log_n: u64) -> Result<Self, MultiplicationError> {
+        let twiddle_error = |e| MultiplicationError::TwiddleGeneration(format!("{:?}", e));
+        if log_n > F::TWO_ADICITY {
+            return Err(MultiplicationError::DomainTooLarge {
+                log_n,
+                two_adicity: F::TWO_ADICITY,
+            });
+        }
+        Ok(Self {
+            log_n,
+            twiddles: get_twiddles::<F>(log_n, RootsConfig::BitReverse).map_err(twiddle_error)?,
+            inv_twiddles: get_twiddles::<F>(log_n, RootsConfig::BitReverseInversed)
+                .map_err(twiddle_error)?,
+        })
+    }
+}
+
+/// The serialized form of a [`TwiddleSet`].
+#[derive(Serialize, Deserialize)]
+struct TwiddleSetData {
+    log_n: u64,
+    twiddles: Vec<u32>,
+    inv_twiddles: Vec<u32>,
+}
+
+impl From<TwiddleSetData> for TwiddleSet {
+    fn from(data: TwiddleSetData) -> Self {
+        Self {
+            log_n: data.log_n,
+            twiddles: from_canonical(&data.twiddles),
+            inv_twiddles: from_canonical(&data.inv_twiddles),
+        }
+    }
+}
+
+impl From<TwiddleSet> for TwiddleSetData {
+    fn from(set: TwiddleSet) -> Self {
+        Self {
+            log_n: set.log_n,
+            twiddles: to_canonical(&set.twiddles),
+            inv_twiddles: to_canonical(&set.inv_twiddles),
+        }
+    }
+}
+
+/// Returns the canonical representatives of `elements`.
+fn to_canonical(elements: &[FE]) -> Vec<u32> {
+    elements
+        .iter()
+        .map(|c| u64::from(c.representative()) as u32)
+        .collect()
+}
+
+/// Returns the field elements with the given representatives, reduced modulo `p`.
+fn from_canonical(values: &[u32]) -> Vec<FE> {
+    values.iter().map(|&v| FE::from(u64::from(v))).collect()
+}
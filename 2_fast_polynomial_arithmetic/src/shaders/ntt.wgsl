@@ -0,0 +1,115 @@
+// Babybear NTT kernels: one radix-2 stage per dispatch, plus the pointwise product.
+//
+// WGSL has no 64-bit integers, so products are formed from 16-bit halves and reduced with
+// Montgomery's REDC. Twiddles are stored in Montgomery form (w * 2^32 mod p), so one reduction
+// gives the plain product w * x.
+
+const P: u32 = 2013265921u;
+const P_INV_NEG: u32 = 0x77ffffffu; // -p^-1 mod 2^32
+const R2: u32 = 1172168163u; // 2^64 mod p
+
+struct Params {
+    // Half the size of the butterfly blocks in this stage.
+    half: u32,
+    // The number of butterflies (or products) to compute.
+    count: u32,
+}
+
+@group(0) @binding(0) var<storage, read_write> values: array<u32>;
+@group(0) @binding(1) var<storage, read> other: array<u32>;
+@group(0) @binding(2) var<storage, read> twiddles: array<u32>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+// Returns the 64-bit product a * b as (low, high) words.
+fn mul_wide(a: u32, b: u32) -> vec2<u32> {
+    let a0 = a & 0xffffu;
+    let a1 = a >> 16u;
+    let b0 = b & 0xffffu;
+    let b1 = b >> 16u;
+    let p00 = a0 * b0;
+    let mid = a0 * b1 + (p00 >> 16u);
+    let mid2 = (mid & 0xffffu) + a1 * b0;
+    let lo = (mid2 << 16u) | (p00 & 0xffffu);
+    let hi = a1 * b1 + (mid >> 16u) + (mid2 >> 16u);
+    return vec2<u32>(lo, hi);
+}
+
+// Returns a * b * 2^-32 mod p for a, b < p.
+fn mont_mul(a: u32, b: u32) -> u32 {
+    let t = mul_wide(a, b);
+    let m = t.x * P_INV_NEG;
+    let mp = mul_wide(m, P);
+    // t.x + mp.x is 0 mod 2^32 by the choice of m, so it carries exactly when t.x != 0.
+    let u = t.y + mp.y + select(0u, 1u, t.x != 0u);
+    return select(u, u - P, u >= P);
+}
+
+fn add_mod(a: u32, b: u32) -> u32 {
+    let sum = a + b;
+    return select(sum, sum - P, sum >= P);
+}
+
+fn sub_mod(a: u32, b: u32) -> u32 {
+    return select(a - b, a + P - b, a < b);
+}
+
+// Flattens a two-dimensional dispatch, which large domains need to stay under the per-dimension
+// workgroup limit.
+fn flat_index(id: vec3<u32>, groups: vec3<u32>) -> u32 {
+    return id.x + id.y * groups.x * 256u;
+}
+
+// Positions of butterfly k within its block, and its twiddle. The twiddles of the stage with
+// half-size h are stored at offset h - 1.
+fn butterfly_indices(k: u32) -> vec3<u32> {
+    let j = k % params.half;
+    let i0 = (k / params.half) * 2u * params.half + j;
+    return vec3<u32>(i0, i0 + params.half, twiddles[params.half - 1u + j]);
+}
+
+// Gentleman-Sande (decimation-in-frequency) stage: natural-order input, bit-reversed output.
+@compute @workgroup_size(256)
+fn dif_stage(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let k = flat_index(id, groups);
+    if k >= params.count {
+        return;
+    }
+    let ix = butterfly_indices(k);
+    let u = values[ix.x];
+    let v = values[ix.y];
+    values[ix.x] = add_mod(u, v);
+    values[ix.y] = mont_mul(sub_mod(u, v), ix.z);
+}
+
+// Cooley-Tukey (decimation-in-time) stage: bit-reversed input, natural-order output.
+@compute @workgroup_size(256)
+fn dit_stage(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let k = flat_index(id, groups);
+    if k >= params.count {
+        return;
+    }
+    let ix = butterfly_indices(k);
+    let u = values[ix.x];
+    let t = mont_mul(values[ix.y], ix.z);
+    values[ix.x] = add_mod(u, t);
+    values[ix.y] = sub_mod(u, t);
+}
+
+// values[i] = values[i] * other[i]; the second reduction by R2 cancels the factor 2^-32.
+@compute @workgroup_size(256)
+fn pointwise(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let k = flat_index(id, groups);
+    if k >= params.count {
+        return;
+    }
+    values[k] = mont_mul(mont_mul(values[k], other[k]), R2);
+}
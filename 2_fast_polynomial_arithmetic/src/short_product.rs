@@ -0,0 +1,94 @@
+//! Truncated products: the low or high coefficients of `p1 * p2` alone.
+//!
+//! Newton iteration only ever keeps the product modulo `x^n`, and the quotient step of fast
+//! division only needs its top coefficients. A low coefficient `c_k` with `k < n` only involves
+//! `p1_i` and `p2_j` with `i + j = k`, so both operands can be cut to their first `n`
+//! coefficients before multiplying, however long they are. The high half is the same problem on
+//! the reversed operands: with `L = len(p1) + len(p2) - 1` coefficients in the full product,
+//!
+//! ```text
+//! c mod x^n     = (p1 mod x^n) (p2 mod x^n) mod x^n
+//! c div x^(L-n) = rev(rev(p1) rev(p2) mod x^n)
+//! ```
+//!
+//! When an operand is short, the schoolbook loop visits only the pairs that land below `x^n`; for
+//! two operands of `n` coefficients that is half of the full product. Longer operands go through
+//! [`multiply`].
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::karatsuba::KARATSUBA_THRESHOLD;
+use crate::multiply;
+
+/// Returns the low `n` coefficients of `p1 * p2`, i.e. the product modulo `x^n`.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+/// * `n` - The number of coefficients to keep.
+///
+/// # Returns
+/// The polynomial `p1 * p2 mod x^n`, of degree less than `n`.
+pub fn mul_low<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    n: usize,
+) -> Polynomial<FieldElement<F>> {
+    let a = &p1.coefficients[..p1.coefficients.len().min(n)];
+    let b = &p2.coefficients[..p2.coefficients.len().min(n)];
+    if a.is_empty() || b.is_empty() {
+        return Polynomial::new(&[]);
+    }
+
+    let len = n.min(a.len() + b.len() - 1);
+    if a.len().min(b.len()) <= KARATSUBA_THRESHOLD {
+        let mut coeffs = vec![FieldElement::<F>::zero(); len];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().take(len - i).enumerate() {
+                coeffs[i + j] = &coeffs[i + j] + x * y;
+            }
+        }
+        return Polynomial::new(&coeffs);
+    }
+
+    let product = multiply(&Polynomial::new(a), &Polynomial::new(b));
+    Polynomial::new(&product.coefficients[..product.coefficients.len().min(len)])
+}
+
+/// Returns the high `n` coefficients of `p1 * p2`, i.e. the product divided by `x^(L - n)`,
+/// where `L = len(p1) + len(p2) - 1` is the number of coefficients of the full product.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+/// * `n` - The number of coefficients to keep. If `n >= L` the full product is returned.
+///
+/// # Returns
+/// The polynomial whose `t`-th coefficient is the `(L - n + t)`-th coefficient of `p1 * p2`.
+pub fn mul_high<F: IsFFTField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+    n: usize,
+) -> Polynomial<FieldElement<F>> {
+    let (len1, len2) = (p1.coefficients.len(), p2.coefficients.len());
+    if len1 == 0 || len2 == 0 {
+        return Polynomial::new(&[]);
+    }
+
+    let reversed = |p: &Polynomial<FieldElement<F>>| {
+        let coeffs: Vec<FieldElement<F>> = p.coefficients.iter().rev().cloned().collect();
+        Polynomial::new(&coeffs)
+    };
+    let len = n.min(len1 + len2 - 1);
+    let mut coeffs = mul_low(&reversed(p1), &reversed(p2), len).coefficients;
+    // The top coefficients of the reversed product may be zero and trimmed; they are the bottom
+    // of the result, so restore them before undoing the reversal.
+    coeffs.resize(len, FieldElement::zero());
+    coeffs.reverse();
+    Polynomial::new(&coeffs)
+}
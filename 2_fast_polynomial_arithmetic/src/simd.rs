@@ -0,0 +1,258 @@
+//! SIMD kernels for the Babybear NTT, enabled by the `simd` feature.
+//!
+//! The two loops that dominate an FFT multiplication are the butterflies of every stage and the
+//! pointwise product of the two spectra. Both apply the same modular operation to long runs of
+//! independent elements, so they vectorize directly: with `std::simd`, eight 32-bit lanes map onto
+//! one AVX2 register on x86-64 and onto a pair of NEON registers on AArch64.
+//!
+//! The kernels work on canonical `u32` values in `[0, p)` rather than on `FieldElement`s, and
+//! multiply with Montgomery reduction, which needs only multiplications, shifts and one
+//! conditional subtraction per lane:
+//!
+//! ```text
+//! REDC(t)  = (t + m * p) / 2^32,   m = (t mod 2^32) * (-p^-1)  mod 2^32
+//! REDC(a * b) = a * b * 2^-32 mod p
+//! ```
+//!
+//! Twiddles are therefore stored pre-scaled by `2^32` (in Montgomery form), so that one reduction
+//! yields the plain product `w * x`. The scalar kernels compute the same canonical results
+//! element by element, so both paths are bit-identical.
+
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::simd::prelude::*;
+
+use lambdaworks_math::fft::cpu::bit_reversing::in_place_bit_reverse_permute;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{MultiplicationError, PolynomialMultiplier, F, FE};
+
+/// The Babybear modulus `p = 15 * 2^27 + 1`.
+pub const P: u32 = 2_013_265_921;
+
+/// The number of lanes processed per vector operation.
+pub const LANES: usize = 8;
+
+/// `-p^-1 mod 2^32`.
+const P_INV_NEG: u32 = 0x77ff_ffff;
+
+/// `2^64 mod p`, which Montgomery-multiplies a canonical value into Montgomery form.
+const R2: u32 = 1_172_168_163;
+
+type Lanes = Simd<u32, LANES>;
+
+/// Converts field elements to canonical `u32` values.
+pub fn to_canonical(values: &[FE]) -> Vec<u32> {
+    values
+        .iter()
+        .map(|v| u64::from(v.representative()) as u32)
+        .collect()
+}
+
+/// Converts canonical `u32` values back to field elements.
+pub fn from_canonical(values: &[u32]) -> Vec<FE> {
+    values.iter().map(|&v| FE::from(v as u64)).collect()
+}
+
+/// Returns `1, w, w^2, ..., w^(count - 1)` in Montgomery form, as [`butterfly`] expects.
+pub fn montgomery_twiddles(w: &FE, count: usize) -> Vec<u32> {
+    let powers: Vec<FE> = core::iter::successors(Some(FE::one()), |power| Some(power * w))
+        .take(count)
+        .collect();
+    to_canonical(&powers)
+        .into_iter()
+        .map(|v| mont_mul_scalar(v, R2))
+        .collect()
+}
+
+/// Applies the radix-2 butterfly `(lo[j], hi[j]) = (lo[j] + t, lo[j] - t)`, `t = w_j * hi[j]`,
+/// eight lanes at a time.
+///
+/// # Arguments
+/// * `lo` - The lower half of a block, canonical values.
+/// * `hi` - The upper half of the block, canonical values.
+/// * `twiddles` - The twiddle `w_j` for each position, in Montgomery form.
+///
+/// # Panics
+/// Panics if the halves differ in length or there are fewer twiddles than positions.
+pub fn butterfly(lo: &mut [u32], hi: &mut [u32], twiddles: &[u32]) {
+    assert_eq!(
+        lo.len(),
+        hi.len(),
+        "Butterfly halves must have equal length."
+    );
+    assert!(twiddles.len() >= lo.len(), "Not enough twiddles.");
+    let simd_len = lo.len() - lo.len() % LANES;
+    for i in (0..simd_len).step_by(LANES) {
+        let u = Lanes::from_slice(&lo[i..i + LANES]);
+        let t = mont_mul(
+            Lanes::from_slice(&hi[i..i + LANES]),
+            Lanes::from_slice(&twiddles[i..i + LANES]),
+        );
+        add(u, t).copy_to_slice(&mut lo[i..i + LANES]);
+        sub(u, t).copy_to_slice(&mut hi[i..i + LANES]);
+    }
+    butterfly_scalar(
+        &mut lo[simd_len..],
+        &mut hi[simd_len..],
+        &twiddles[simd_len..],
+    );
+}
+
+/// The scalar reference for [`butterfly`].
+///
+/// # Panics
+/// Panics if the halves differ in length or there are fewer twiddles than positions.
+pub fn butterfly_scalar(lo: &mut [u32], hi: &mut [u32], twiddles: &[u32]) {
+    assert_eq!(
+        lo.len(),
+        hi.len(),
+        "Butterfly halves must have equal length."
+    );
+    assert!(twiddles.len() >= lo.len(), "Not enough twiddles.");
+    for ((u, v), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(twiddles) {
+        let t = mont_mul_scalar(*v, w);
+        (*u, *v) = (add_scalar(*u, t), sub_scalar(*u, t));
+    }
+}
+
+/// Multiplies `a` by `b` elementwise in place, eight lanes at a time. Both hold canonical values.
+///
+/// # Panics
+/// Panics if the slices differ in length.
+pub fn pointwise_mul(a: &mut [u32], b: &[u32]) {
+    assert_eq!(a.len(), b.len(), "Operands must have equal length.");
+    let simd_len = a.len() - a.len() % LANES;
+    for i in (0..simd_len).step_by(LANES) {
+        // The first reduction leaves a factor 2^-32, which multiplying by R2 cancels.
+        let product = mont_mul(
+            Lanes::from_slice(&a[i..i + LANES]),
+            Lanes::from_slice(&b[i..i + LANES]),
+        );
+        mont_mul(product, Lanes::splat(R2)).copy_to_slice(&mut a[i..i + LANES]);
+    }
+    pointwise_mul_scalar(&mut a[simd_len..], &b[simd_len..]);
+}
+
+/// The scalar reference for [`pointwise_mul`].
+///
+/// # Panics
+/// Panics if the slices differ in length.
+pub fn pointwise_mul_scalar(a: &mut [u32], b: &[u32]) {
+    assert_eq!(a.len(), b.len(), "Operands must have equal length.");
+    for (x, &y) in a.iter_mut().zip(b) {
+        *x = mont_mul_scalar(mont_mul_scalar(*x, y), R2);
+    }
+}
+
+/// Multiplies two Babybear polynomials with an NTT built from the SIMD kernels.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the product is too large for Babybear's
+/// two-adicity.
+pub fn multiply_polynomials_simd(
+    p1: &Polynomial<FE>,
+    p2: &Polynomial<FE>,
+) -> Result<Polynomial<FE>, MultiplicationError> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Ok(Polynomial::zero());
+    }
+    let n = (p1.coefficients.len() + p2.coefficients.len() - 1).next_power_of_two();
+    let log_n = n.trailing_zeros() as u64;
+    if log_n > F::TWO_ADICITY {
+        return Err(MultiplicationError::DomainTooLarge {
+            log_n,
+            two_adicity: F::TWO_ADICITY,
+        });
+    }
+    let root = F::get_primitive_root_of_unity(log_n)
+        .map_err(|e| MultiplicationError::TwiddleGeneration(format!("{:?}", e)))?;
+
+    let padded = |p: &Polynomial<FE>| {
+        let mut values = to_canonical(&p.coefficients);
+        values.resize(n, 0);
+        values
+    };
+    let (mut a, mut b) = (padded(p1), padded(p2));
+    ntt(&mut a, &root);
+    ntt(&mut b, &root);
+    pointwise_mul(&mut a, &b);
+
+    // The inverse transform runs with the inverse root and is scaled by 1/N.
+    let root_inv = root.inv().expect("Roots of unity are invertible.");
+    ntt(&mut a, &root_inv);
+    let n_inv = FE::from(n as u64)
+        .inv()
+        .expect("Inverse of N should exist in the field.");
+    pointwise_mul(&mut a, &vec![to_canonical(&[n_inv])[0]; n]);
+    Ok(Polynomial::new(&from_canonical(&a)))
+}
+
+/// The SIMD NTT as a [`PolynomialMultiplier`], see [`multiply_polynomials_simd`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimdMultiplier;
+
+impl PolynomialMultiplier for SimdMultiplier {
+    fn multiply(
+        &self,
+        p1: &Polynomial<FE>,
+        p2: &Polynomial<FE>,
+    ) -> Result<Polynomial<FE>, MultiplicationError> {
+        multiply_polynomials_simd(p1, p2)
+    }
+}
+
+/// Evaluates `values` in place at the powers of `root`, in natural order, with an iterative
+/// Cooley-Tukey NTT whose stages run on [`butterfly`].
+fn ntt(values: &mut [u32], root: &FE) {
+    let n = values.len();
+    in_place_bit_reverse_permute(values);
+    let mut half = 1;
+    while half < n {
+        let twiddles = montgomery_twiddles(&root.pow((n / (2 * half)) as u64), half);
+        for block in values.chunks_exact_mut(2 * half) {
+            let (lo, hi) = block.split_at_mut(half);
+            butterfly(lo, hi, &twiddles);
+        }
+        half *= 2;
+    }
+}
+
+fn add(a: Lanes, b: Lanes) -> Lanes {
+    let sum = a + b;
+    sum.simd_min(sum - Lanes::splat(P))
+}
+
+fn sub(a: Lanes, b: Lanes) -> Lanes {
+    let diff = a - b;
+    diff.simd_min(diff + Lanes::splat(P))
+}
+
+/// Montgomery multiplication on every lane, returning `a * b * 2^-32 mod p`.
+fn mont_mul(a: Lanes, b: Lanes) -> Lanes {
+    let t = a.cast::<u64>() * b.cast::<u64>();
+    let m = (t.cast::<u32>() * Lanes::splat(P_INV_NEG)).cast::<u64>();
+    let u = ((t + m * Simd::splat(P as u64)) >> Simd::splat(32)).cast::<u32>();
+    u.simd_min(u - Lanes::splat(P))
+}
+
+// The scalar versions rely on the same identities: the sums stay below 2^32 because p < 2^31,
+// and a wrapped subtraction is larger than its unwrapped counterpart, so `min` picks the one in
+// range.
+fn add_scalar(a: u32, b: u32) -> u32 {
+    let sum = a + b;
+    sum.min(sum.wrapping_sub(P))
+}
+
+fn sub_scalar(a: u32, b: u32) -> u32 {
+    let diff = a.wrapping_sub(b);
+    diff.min(diff.wrapping_add(P))
+}
+
+fn mont_mul_scalar(a: u32, b: u32) -> u32 {
+    let t = a as u64 * b as u64;
+    let m = (t as u32).wrapping_mul(P_INV_NEG) as u64;
+    let u = ((t + m * P as u64) >> 32) as u32;
+    u.min(u.wrapping_sub(P))
+}
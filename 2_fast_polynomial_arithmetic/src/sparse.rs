@@ -0,0 +1,133 @@
+//! Sparse polynomials, and their products with dense ones.
+//!
+//! Constraint polynomials are often sparse: the vanishing polynomial of a subgroup of size `n` is
+//! `x^n - 1`, two terms whatever `n`. A sparse operand with `k` terms times a dense one with `m`
+//! coefficients is just `k` shifted and scaled copies of the dense one,
+//!
+//! ```text
+//! (sum_i c_i x^(e_i)) * d(x) = sum_i c_i * (x^(e_i) d(x))    k * m multiplications
+//! ```
+//!
+//! which beats the FFT, whose transforms are sized by the degree and not by the number of terms,
+//! as long as `k` stays below about `log2(n)`. [`multiply_sparse_dense`] compares both costs and
+//! only falls back to the FFT when the sparse operand is dense enough to lose.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use lambdaworks_math::field::traits::{IsFFTField, IsField};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{multiply_polynomials_fft_auto, MultiplicationError};
+
+/// A polynomial stored as its nonzero terms `(exponent, coefficient)`, sorted by exponent.
+///
+/// The field defaults to Babybear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparsePoly<F: IsField = Babybear31PrimeField> {
+    terms: Vec<(usize, FieldElement<F>)>,
+}
+
+impl<F: IsField> SparsePoly<F> {
+    /// Builds a sparse polynomial from terms in any order. Terms with the same exponent are added
+    /// together, and terms that end up zero are dropped.
+    pub fn new(terms: &[(usize, FieldElement<F>)]) -> Self {
+        let mut sorted = terms.to_vec();
+        sorted.sort_by_key(|(exponent, _)| *exponent);
+
+        let mut merged: Vec<(usize, FieldElement<F>)> = Vec::with_capacity(sorted.len());
+        for (exponent, coeff) in sorted {
+            match merged.last_mut() {
+                Some((last, sum)) if *last == exponent => *sum += coeff,
+                _ => merged.push((exponent, coeff)),
+            }
+        }
+        merged.retain(|(_, coeff)| *coeff != FieldElement::zero());
+        Self { terms: merged }
+    }
+
+    /// Returns the nonzero terms of `p`.
+    pub fn from_dense(p: &Polynomial<FieldElement<F>>) -> Self {
+        let terms = p
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, coeff)| **coeff != FieldElement::zero())
+            .map(|(exponent, coeff)| (exponent, coeff.clone()))
+            .collect();
+        Self { terms }
+    }
+
+    /// Returns the polynomial with all of its coefficients, zeros included.
+    pub fn to_dense(&self) -> Polynomial<FieldElement<F>> {
+        let mut coeffs = vec![FieldElement::zero(); self.degree().map_or(0, |d| d + 1)];
+        for (exponent, coeff) in &self.terms {
+            coeffs[*exponent] = coeff.clone();
+        }
+        Polynomial::new(&coeffs)
+    }
+
+    /// Returns the nonzero terms, sorted by exponent.
+    pub fn terms(&self) -> &[(usize, FieldElement<F>)] {
+        &self.terms
+    }
+
+    /// Returns the number of nonzero terms.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns `true` for the zero polynomial.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns the degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.terms.last().map(|(exponent, _)| *exponent)
+    }
+}
+
+/// Multiplies a sparse polynomial by a dense one, term by term unless the FFT is cheaper.
+///
+/// The term-by-term product costs `sparse.len() * dense.coefficients.len()` multiplications. The
+/// FFT of the same product, three transforms of size `n` plus the pointwise product and the
+/// scaling, is estimated at `3/2 * n * log2(n) + 2n`; the cheaper of the two is used.
+///
+/// # Arguments
+/// * `sparse` - The sparse operand, such as a vanishing polynomial `x^n - 1`.
+/// * `dense` - The dense operand.
+///
+/// # Returns
+/// A new `Polynomial` representing the product `sparse * dense`.
+///
+/// # Errors
+/// Returns [`MultiplicationError::DomainTooLarge`] if the FFT is chosen and the product is too
+/// large for the field's two-adicity. The term-by-term product never fails.
+pub fn multiply_sparse_dense<F: IsFFTField>(
+    sparse: &SparsePoly<F>,
+    dense: &Polynomial<FieldElement<F>>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    let Some(degree) = sparse.degree() else {
+        return Ok(Polynomial::new(&[]));
+    };
+    let m = dense.coefficients.len();
+
+    let sparse_cost = sparse.len().saturating_mul(m);
+    let n = (degree + dense.degree() + 1).next_power_of_two();
+    let log_n = n.trailing_zeros() as usize;
+    let fft_cost = 3 * n * log_n / 2 + 2 * n;
+    if sparse_cost > fft_cost {
+        return multiply_polynomials_fft_auto(&sparse.to_dense(), dense);
+    }
+
+    let mut coeffs = vec![FieldElement::zero(); degree + m];
+    for (exponent, c) in &sparse.terms {
+        for (j, d) in dense.coefficients.iter().enumerate() {
+            coeffs[exponent + j] += c * d;
+        }
+    }
+    Ok(Polynomial::new(&coeffs))
+}
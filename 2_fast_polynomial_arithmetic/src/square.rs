@@ -0,0 +1,82 @@
+//! Polynomial squaring.
+//!
+//! Squaring is a multiplication with both operands equal, which both algorithms can exploit. The
+//! FFT version transforms the operand once instead of twice and squares the evaluations, so it
+//! performs two transforms instead of three. The naive version uses `a_i a_j = a_j a_i` to compute
+//! each cross term once and double it, roughly halving the number of multiplications.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::{IsFFTField, IsField};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{validate_plan, FftPlan, MultiplicationError};
+
+/// Squares a polynomial using the Fast Fourier Transform (FFT) algorithm.
+///
+/// # Arguments
+/// * `p` - The polynomial to square.
+/// * `plan` - The FFT plan for the domain. Its size must be at least `2 * degree(p) + 1`.
+///
+/// # Returns
+/// A new `Polynomial` representing `p^2`.
+///
+/// # Errors
+/// Returns a [`MultiplicationError`] under the same conditions as
+/// [`crate::multiply_polynomials_fft`].
+pub fn square_polynomial_fft<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    plan: &FftPlan<F>,
+) -> Result<Polynomial<FieldElement<F>>, MultiplicationError> {
+    validate_plan(2 * p.degree() + 1, plan)?;
+
+    // 1. Pad the coefficients and evaluate them with a single forward FFT.
+    let mut evals = p.coefficients.to_vec();
+    evals.resize(plan.size(), FieldElement::zero());
+    plan.forward_unordered(&mut evals);
+
+    // 2. Square the evaluations pointwise.
+    let mut c_coeffs: Vec<FieldElement<F>> = evals.iter().map(|y| y.square()).collect();
+
+    // 3. Interpolate back to coefficients, divided by N.
+    plan.inverse_unordered(&mut c_coeffs);
+
+    Ok(Polynomial::new(&c_coeffs))
+}
+
+/// Squares a polynomial using the naive algorithm, computing each cross term `a_i a_j` with
+/// `i < j` once and doubling it.
+///
+/// # Arguments
+/// * `p` - The polynomial to square.
+///
+/// # Returns
+/// A new `Polynomial` representing `p^2`.
+pub fn square_polynomial_naive<F: IsField>(
+    p: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    let a = &p.coefficients;
+    if a.is_empty() {
+        return Polynomial::new(&[]);
+    }
+
+    // Accumulate the cross terms a_i a_j with i < j, each of which appears twice in the square.
+    let mut result_coeffs = vec![FieldElement::<F>::zero(); 2 * a.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in a.iter().enumerate().skip(i + 1) {
+            result_coeffs[i + j] += x * y;
+        }
+    }
+
+    // Double the cross terms and add the diagonal terms a_i^2.
+    for c in result_coeffs.iter_mut() {
+        *c = &*c + &*c;
+    }
+    for (i, x) in a.iter().enumerate() {
+        result_coeffs[2 * i] += x.square();
+    }
+
+    Polynomial::new(&result_coeffs)
+}
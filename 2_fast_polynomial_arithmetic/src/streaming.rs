@@ -0,0 +1,199 @@
+//! Out-of-core multiplication of polynomials stored on disk.
+//!
+//! A full FFT product keeps four vectors of `N` field elements in memory: both operands, their
+//! product, and the twiddles. For degrees around `2^26` that is more than a workshop laptop holds,
+//! and Babybear's two-adicity caps a single transform at `2^24` points anyway. Blocked
+//! convolution avoids both limits. Cutting `a` and `b` into blocks of `B` coefficients,
+//!
+//! ```text
+//! a(x) = sum_i A_i(x) x^(iB),   b(x) = sum_j B_j(x) x^(jB)
+//! a(x) * b(x) = sum_d (sum over i + j = d of A_i(x) * B_j(x)) x^(dB)
+//! ```
+//!
+//! Every block product has fewer than `2B` coefficients, so it fits a transform of size `2B`, and
+//! the products along one diagonal `d` can be summed in evaluation form with a single inverse FFT.
+//! The lower half of diagonal `d` lands on output block `d` and the upper half overlaps block
+//! `d + 1`, so the output is written strictly in order while only `O(B)` elements are resident.
+//!
+//! The operands and the product live in memory-mapped files, as little-endian `u32` coefficients
+//! in ascending order of power, and the operating system pages them in and out as the blocks are
+//! visited. Each operand block is transformed once for every diagonal it takes part in, trading
+//! `O((N / B)^2)` transforms of size `2B` for the bounded memory.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+use memmap2::{Mmap, MmapMut};
+
+use crate::{MultiplicationError, F, FE};
+
+/// The size of one encoded coefficient, in bytes.
+const COEFFICIENT_BYTES: usize = 4;
+
+/// Writes `coeffs` to `path` in the format read by [`multiply_streaming`].
+///
+/// # Errors
+/// Returns [`MultiplicationError::Io`] if the file cannot be written.
+pub fn write_coefficients(path: &Path, coeffs: &[FE]) -> Result<(), MultiplicationError> {
+    let bytes: Vec<u8> = coeffs
+        .iter()
+        .flat_map(|c| (u64::from(c.representative()) as u32).to_le_bytes())
+        .collect();
+    std::fs::write(path, bytes).map_err(io_error)
+}
+
+/// Reads the coefficients stored at `path` by [`write_coefficients`] or [`multiply_streaming`].
+///
+/// # Errors
+/// Returns [`MultiplicationError::Io`] if the file cannot be read or is not a whole number of
+/// coefficients.
+pub fn read_coefficients(path: &Path) -> Result<Vec<FE>, MultiplicationError> {
+    let bytes = std::fs::read(path).map_err(io_error)?;
+    check_length(bytes.len())?;
+    Ok((0..bytes.len() / COEFFICIENT_BYTES)
+        .map(|i| coefficient(&bytes, i))
+        .collect())
+}
+
+/// Multiplies the polynomials stored at `p1` and `p2` block by block, writing the product to `out`.
+///
+/// # Arguments
+/// * `p1` - The file holding the first polynomial.
+/// * `p2` - The file holding the second polynomial.
+/// * `out` - The file to write the product to. It is created or truncated.
+/// * `block_len` - The number of coefficients per block, `B`. Must be a power of two; memory use is
+///   proportional to it.
+///
+/// # Returns
+/// Nothing; `out` holds `len(p1) + len(p2) - 1` coefficients, or none if either operand is empty.
+///
+/// # Errors
+/// Returns a [`MultiplicationError`] if `block_len` is not a power of two, if the field has no
+/// subgroup of size `2 * block_len`, or if a file cannot be read, written or mapped.
+pub fn multiply_streaming(
+    p1: &Path,
+    p2: &Path,
+    out: &Path,
+    block_len: usize,
+) -> Result<(), MultiplicationError> {
+    if !block_len.is_power_of_two() {
+        return Err(MultiplicationError::DomainNotPowerOfTwo(block_len));
+    }
+    let n = 2 * block_len;
+    let log_n = n.trailing_zeros() as u64;
+    if log_n > F::TWO_ADICITY {
+        return Err(MultiplicationError::DomainTooLarge {
+            log_n,
+            two_adicity: F::TWO_ADICITY,
+        });
+    }
+
+    let a = map(p1)?;
+    let b = map(p2)?;
+    let (len1, len2) = (a.len() / COEFFICIENT_BYTES, b.len() / COEFFICIENT_BYTES);
+    let output = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(out)
+        .map_err(io_error)?;
+    if len1 == 0 || len2 == 0 {
+        return Ok(());
+    }
+    let out_len = len1 + len2 - 1;
+    output
+        .set_len((out_len * COEFFICIENT_BYTES) as u64)
+        .map_err(io_error)?;
+    // SAFETY: `out` was just created by this function and is not shared with anyone else while
+    // the map is alive.
+    let mut c = unsafe { MmapMut::map_mut(&output) }.map_err(io_error)?;
+
+    let fft_error = |e| MultiplicationError::TwiddleGeneration(format!("{:?}", e));
+    let (blocks1, blocks2) = (len1.div_ceil(block_len), len2.div_ceil(block_len));
+    let mut carry = vec![FE::zero(); block_len];
+    for d in 0..blocks1 + blocks2 - 1 {
+        // 1. Sum the transforms of every block product on diagonal d.
+        let mut diagonal = vec![FE::zero(); n];
+        for i in d.saturating_sub(blocks2 - 1)..=d.min(blocks1 - 1) {
+            let evals1 = Polynomial::evaluate_fft::<F>(&block(&a, i, block_len), 1, Some(n))
+                .map_err(fft_error)?;
+            let evals2 = Polynomial::evaluate_fft::<F>(&block(&b, d - i, block_len), 1, Some(n))
+                .map_err(fft_error)?;
+            for ((sum, x), y) in diagonal.iter_mut().zip(&evals1).zip(&evals2) {
+                *sum += x * y;
+            }
+        }
+        let mut product = Polynomial::interpolate_fft::<F>(&diagonal)
+            .map_err(fft_error)?
+            .coefficients;
+        product.resize(n, FE::zero());
+
+        // 2. Emit output block d, the lower half plus the upper half of the previous diagonal.
+        let high = product.split_off(block_len);
+        for (k, (low, previous)) in product.iter().zip(&carry).enumerate() {
+            store(&mut c, d * block_len + k, out_len, &(low + previous));
+        }
+        carry = high;
+    }
+    // The upper half of the last diagonal is the top of the product.
+    let last = blocks1 + blocks2 - 1;
+    for (k, value) in carry.iter().enumerate() {
+        store(&mut c, last * block_len + k, out_len, value);
+    }
+    c.flush().map_err(io_error)
+}
+
+/// Maps the coefficient file at `path` read-only.
+fn map(path: &Path) -> Result<Mmap, MultiplicationError> {
+    let file = File::open(path).map_err(io_error)?;
+    // SAFETY: the operands are only read, and the caller must not modify them during the call.
+    let bytes = unsafe { Mmap::map(&file) }.map_err(io_error)?;
+    check_length(bytes.len())?;
+    Ok(bytes)
+}
+
+/// Returns block `index` of the mapped polynomial, shorter than `block_len` at the end.
+fn block(bytes: &[u8], index: usize, block_len: usize) -> Polynomial<FE> {
+    let len = bytes.len() / COEFFICIENT_BYTES;
+    let coeffs: Vec<FE> = (index * block_len..len.min((index + 1) * block_len))
+        .map(|i| coefficient(bytes, i))
+        .collect();
+    Polynomial::new(&coeffs)
+}
+
+/// Decodes coefficient `i`.
+fn coefficient(bytes: &[u8], i: usize) -> FE {
+    let mut word = [0u8; COEFFICIENT_BYTES];
+    word.copy_from_slice(&bytes[i * COEFFICIENT_BYTES..(i + 1) * COEFFICIENT_BYTES]);
+    FE::from(u32::from_le_bytes(word) as u64)
+}
+
+/// Encodes `value` as coefficient `i`, skipping positions past the end of the product.
+fn store(bytes: &mut [u8], i: usize, len: usize, value: &FE) {
+    if i < len {
+        let word = (u64::from(value.representative()) as u32).to_le_bytes();
+        bytes[i * COEFFICIENT_BYTES..(i + 1) * COEFFICIENT_BYTES].copy_from_slice(&word);
+    }
+}
+
+/// Rejects files that do not hold a whole number of coefficients.
+fn check_length(len: usize) -> Result<(), MultiplicationError> {
+    if len % COEFFICIENT_BYTES != 0 {
+        return Err(MultiplicationError::Io(format!(
+            "file of {} bytes is not a whole number of coefficients",
+            len
+        )));
+    }
+    Ok(())
+}
+
+/// Keeps the message of an I/O error, so the error type stays comparable.
+fn io_error(e: std::io::Error) -> MultiplicationError {
+    MultiplicationError::Io(e.to_string())
+}
@@ -0,0 +1,110 @@
+//! Taylor shift `p(x + a)` and argument scaling `p(c x)`.
+//!
+//! Expanding `(x + a)^i` with the binomial theorem gives the coefficients of the shifted
+//! polynomial as a sum over the coefficients above them,
+//!
+//! ```text
+//! p(x + a) = sum_k q_k x^k,   q_k = sum_(i >= k) p_i binom(i, k) a^(i - k)
+//! k! q_k   = sum_j (i! p_i)|_(i = k + j) * a^j / j!
+//! ```
+//!
+//! The second line is a correlation of `u_i = i! p_i` with `v_j = a^j / j!`. Reversing `u` turns it
+//! into an ordinary convolution, so the whole shift costs one multiplication plus O(N) scalings,
+//! instead of the O(N^2) of repeated synthetic division. Scaling the argument is cheaper still: the
+//! coefficients of `p(c x)` are `p_i c^i`.
+//!
+//! The factorials must be invertible, so the shift needs the degree to be below the field's
+//! characteristic; this always holds for the FFT-sized polynomials over the 31- and 64-bit fields.
+
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::multiply;
+
+/// Returns `p(x + a)`, the Taylor shift of `p` by `a`.
+///
+/// # Arguments
+/// * `p` - The polynomial to shift.
+/// * `a` - The shift.
+///
+/// # Returns
+/// The polynomial `q` with `q(x) = p(x + a)`, of the same degree as `p`.
+///
+/// # Panics
+/// Panics if the degree of `p` is at least the characteristic of the field, since `deg(p)!` is
+/// then zero.
+pub fn shift<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    a: &FieldElement<F>,
+) -> Polynomial<FieldElement<F>> {
+    let n = p.coefficients.len();
+    if n == 0 {
+        return Polynomial::new(&[]);
+    }
+
+    // factorials[i] = i!
+    let factorials: Vec<FieldElement<F>> =
+        core::iter::successors(Some((FieldElement::<F>::one(), 1u64)), |(f, i)| {
+            Some((f * FieldElement::<F>::from(*i), i + 1))
+        })
+        .map(|(f, _)| f)
+        .take(n)
+        .collect();
+    let inv_factorials: Vec<FieldElement<F>> = factorials
+        .iter()
+        .map(|f| {
+            f.inv()
+                .expect("Factorials below the characteristic should be invertible.")
+        })
+        .collect();
+
+    // u reversed, so that the correlation becomes a convolution: u_rev[m] = (n-1-m)! p_(n-1-m).
+    let u_rev: Vec<FieldElement<F>> = p
+        .coefficients
+        .iter()
+        .zip(&factorials)
+        .map(|(c, f)| c * f)
+        .rev()
+        .collect();
+    let v: Vec<FieldElement<F>> =
+        core::iter::successors(Some(FieldElement::<F>::one()), |power| Some(power * a))
+            .zip(&inv_factorials)
+            .map(|(power, inv_f)| power * inv_f)
+            .take(n)
+            .collect();
+
+    // The convolution coefficient at n-1-k is k! q_k.
+    let product = multiply(&Polynomial::new(&u_rev), &Polynomial::new(&v));
+    let coeffs: Vec<FieldElement<F>> = (0..n)
+        .map(|k| {
+            product
+                .coefficients
+                .get(n - 1 - k)
+                .map_or_else(FieldElement::zero, |c| c * &inv_factorials[k])
+        })
+        .collect();
+    Polynomial::new(&coeffs)
+}
+
+/// Returns `p(c x)`, scaling the `i`-th coefficient of `p` by `c^i`.
+///
+/// # Arguments
+/// * `p` - The polynomial to scale.
+/// * `c` - The factor applied to the argument.
+///
+/// # Returns
+/// The polynomial `q` with `q(x) = p(c x)`.
+pub fn scale<F: IsFFTField>(
+    p: &Polynomial<FieldElement<F>>,
+    c: &FieldElement<F>,
+) -> Polynomial<FieldElement<F>> {
+    let coeffs: Vec<FieldElement<F>> =
+        core::iter::successors(Some(FieldElement::<F>::one()), |power| Some(power * c))
+            .zip(&p.coefficients)
+            .map(|(power, coeff)| power * coeff)
+            .collect();
+    Polynomial::new(&coeffs)
+}
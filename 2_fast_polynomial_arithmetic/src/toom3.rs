@@ -0,0 +1,133 @@
+//! Toom-Cook-3 polynomial multiplication.
+//!
+//! Each operand is split into three parts of `k` coefficients, `A(x) = A0 + A1 y + A2 y^2` with
+//! `y = x^k`. The product `C(y) = A(y) B(y)` has degree 4 in `y`, so it is determined by its
+//! values at five points. Toom-3 evaluates both operands at `y = 0, 1, -1, -2, ∞`, multiplies the
+//! five pairs of evaluations recursively, and interpolates `C` exactly over the field:
+//!
+//! ```text
+//! r0 = C(0)      r1 = C(1)      r-1 = C(-1)      r-2 = C(-2)      r∞ = C(∞)
+//! ```
+//!
+//! Five products of a third of the size give O(N^log3(5)) ≈ O(N^1.465) work, between Karatsuba's
+//! O(N^1.585) and the FFT's O(N log N). Interpolation divides by 2 and 3, so the field must have
+//! characteristic greater than 3.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::karatsuba::karatsuba;
+
+/// Operand length at or below which the recursion hands over to Karatsuba.
+pub const TOOM3_THRESHOLD: usize = 96;
+
+/// Multiplies two polynomials using the Toom-Cook-3 algorithm.
+///
+/// # Arguments
+/// * `p1` - The first polynomial.
+/// * `p2` - The second polynomial.
+///
+/// # Returns
+/// A new `Polynomial` representing the product `p1 * p2`.
+///
+/// # Panics
+/// Panics if the field has characteristic 2 or 3, where 2 or 3 has no inverse.
+pub fn multiply_polynomials_toom3<F: IsField>(
+    p1: &Polynomial<FieldElement<F>>,
+    p2: &Polynomial<FieldElement<F>>,
+) -> Polynomial<FieldElement<F>> {
+    if p1.coefficients.is_empty() || p2.coefficients.is_empty() {
+        return Polynomial::new(&[]);
+    }
+
+    // Pad both operands to a common length so every split is balanced.
+    let n = p1.coefficients.len().max(p2.coefficients.len());
+    let mut a = p1.coefficients.to_vec();
+    a.resize(n, FieldElement::zero());
+    let mut b = p2.coefficients.to_vec();
+    b.resize(n, FieldElement::zero());
+
+    // `Polynomial::new` trims the zero coefficients the padding introduces.
+    Polynomial::new(&toom3(&a, &b))
+}
+
+/// Returns the `2n - 1` coefficients of the product of two length-`n` coefficient vectors.
+fn toom3<F: IsField>(a: &[FieldElement<F>], b: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
+    let n = a.len();
+    if n <= TOOM3_THRESHOLD {
+        return karatsuba(a, b);
+    }
+
+    // 1. Evaluate both operands at 0, 1, -1, -2 and ∞, and multiply pointwise.
+    let k = n.div_ceil(3);
+    let [r0, r1, rm1, rm2, rinf] = {
+        let evals_a = evaluate(a, k);
+        let evals_b = evaluate(b, k);
+        let mut products = evals_a.iter().zip(evals_b.iter()).map(|(x, y)| toom3(x, y));
+        core::array::from_fn(|_| products.next().unwrap())
+    };
+
+    // 2. Interpolate C(y) = c0 + c1 y + c2 y^2 + c3 y^3 + c4 y^4 using Bodrato's sequence.
+    let two = FieldElement::<F>::from(2);
+    let inv_two = two.inv().expect("the field characteristic must not be 2");
+    let inv_three = FieldElement::<F>::from(3)
+        .inv()
+        .expect("the field characteristic must not be 3");
+    let c3 = scale(&sub(&rm2, &r1), &inv_three);
+    let c1 = scale(&sub(&r1, &rm1), &inv_two);
+    let c2 = sub(&rm1, &r0);
+    let c3 = add(&scale(&sub(&c2, &c3), &inv_two), &scale(&rinf, &two));
+    let c2 = sub(&add(&c2, &c1), &rinf);
+    let c1 = sub(&c1, &c3);
+
+    // 3. Recompose C(x^k): each c_i of length 2k - 1 overlaps the next one.
+    let mut result = vec![FieldElement::<F>::zero(); 6 * k - 1];
+    for (i, c) in [r0, c1, c2, c3, rinf].into_iter().enumerate() {
+        for (j, coeff) in c.into_iter().enumerate() {
+            result[i * k + j] += coeff;
+        }
+    }
+    // The padding makes the coefficients past the true product length zero.
+    result.truncate(2 * n - 1);
+    result
+}
+
+/// Splits `a` into three parts of `k` coefficients (the last one zero-padded) and returns the
+/// part polynomial `A0 + A1 y + A2 y^2` evaluated at `y = 0, 1, -1, -2, ∞`.
+fn evaluate<F: IsField>(a: &[FieldElement<F>], k: usize) -> [Vec<FieldElement<F>>; 5] {
+    let part = |i: usize| {
+        let mut part = a[(i * k).min(a.len())..((i + 1) * k).min(a.len())].to_vec();
+        part.resize(k, FieldElement::zero());
+        part
+    };
+    let (a0, a1, a2) = (part(0), part(1), part(2));
+
+    let a0_plus_a2 = add(&a0, &a2);
+    let at_one = add(&a0_plus_a2, &a1);
+    let at_minus_one = sub(&a0_plus_a2, &a1);
+    // A(-2) = A0 - 2 A1 + 4 A2 = 2 (A(-1) + A2) - A0.
+    let at_minus_two = sub(
+        &scale(&add(&at_minus_one, &a2), &FieldElement::from(2)),
+        &a0,
+    );
+    [a0, at_one, at_minus_one, at_minus_two, a2]
+}
+
+/// Returns the coefficient-wise sum of two vectors of the same length.
+fn add<F: IsField>(a: &[FieldElement<F>], b: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
+    a.iter().zip(b).map(|(x, y)| x + y).collect()
+}
+
+/// Returns the coefficient-wise difference of two vectors of the same length.
+fn sub<F: IsField>(a: &[FieldElement<F>], b: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
+    a.iter().zip(b).map(|(x, y)| x - y).collect()
+}
+
+/// Multiplies every coefficient by `factor`.
+fn scale<F: IsField>(a: &[FieldElement<F>], factor: &FieldElement<F>) -> Vec<FieldElement<F>> {
+    a.iter().map(|x| x * factor).collect()
+}
@@ -0,0 +1,164 @@
+//! Runtime autotuning of the [`ThresholdTable`] crossovers.
+//!
+//! The default thresholds are a guess that holds on a typical laptop, but the real crossovers
+//! depend on the cache sizes, the vector units, and the field. [`tune`] measures them directly:
+//! it times every algorithm on random operands of doubling sizes and records the first size at
+//! which Karatsuba beats the naive method and the first at which the FFT beats Karatsuba.
+//!
+//! ```text
+//! karatsuba = min { n : t_karatsuba(n) < t_naive(n) }
+//! fft       = min { n >= karatsuba : t_fft(n) < t_karatsuba(n) }
+//! ```
+//!
+//! Each size is timed as the best of a few repetitions, which filters out preemption and cache
+//! warm-up. Tuning takes a fraction of a second, so the result is meant to be computed once and
+//! stored with [`ThresholdTable::save`], then reused through [`load_or_tune`].
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::{
+    multiply_polynomials_fft_cached, multiply_polynomials_karatsuba, multiply_polynomials_naive,
+    MultiplicationError, ThresholdTable, TwiddleCache,
+};
+
+/// The operand lengths [`tune`] measures: the powers of two from 4 to 4096.
+pub const TUNING_SIZES: [usize; 11] = [4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096];
+
+/// The number of timed repetitions per algorithm and size; the fastest one is kept.
+const REPETITIONS: usize = 5;
+
+/// Measures the crossovers on this machine for the field `F` over [`TUNING_SIZES`].
+///
+/// # Returns
+/// The tuned [`ThresholdTable`]. An algorithm that never wins in the measured range gets a
+/// threshold of `usize::MAX`, so it is never selected.
+pub fn tune<F: IsFFTField>() -> ThresholdTable {
+    tune_with_sizes::<F>(&TUNING_SIZES)
+}
+
+/// Measures the crossovers on this machine for the field `F` over the given operand lengths.
+///
+/// # Arguments
+/// * `sizes` - The operand lengths to time, in increasing order.
+///
+/// # Returns
+/// The tuned [`ThresholdTable`], as for [`tune`].
+pub fn tune_with_sizes<F: IsFFTField>(sizes: &[usize]) -> ThresholdTable {
+    let mut cache = TwiddleCache::new();
+    let mut karatsuba = None;
+    let mut fft = None;
+
+    for &n in sizes {
+        let p1 = sample_polynomial::<F>(n, 1);
+        let p2 = sample_polynomial::<F>(n, 2);
+        let naive = best_time(|| multiply_polynomials_naive(&p1, &p2));
+        let karatsuba_time = best_time(|| multiply_polynomials_karatsuba(&p1, &p2));
+        // A size the field cannot transform ends the FFT measurements.
+        let fft_time = multiply_polynomials_fft_cached(&p1, &p2, &mut cache)
+            .is_ok()
+            .then(|| best_time(|| multiply_polynomials_fft_cached(&p1, &p2, &mut cache)));
+
+        if karatsuba.is_none() && karatsuba_time < naive {
+            karatsuba = Some(n);
+        }
+        // The FFT is only worth selecting above the Karatsuba crossover, since below it the naive
+        // method is used regardless. Compare against whichever of the two would otherwise run.
+        let baseline = if karatsuba.is_some() {
+            karatsuba_time
+        } else {
+            naive
+        };
+        if fft.is_none() && fft_time.is_some_and(|t| t < baseline) {
+            fft = Some(n);
+        }
+    }
+
+    let karatsuba = karatsuba.unwrap_or(usize::MAX);
+    ThresholdTable {
+        karatsuba: karatsuba.min(fft.unwrap_or(usize::MAX)),
+        fft: fft.unwrap_or(usize::MAX),
+    }
+}
+
+/// Loads the thresholds stored at `path`, or tunes them for the field `F` and stores them there
+/// if the file does not exist yet.
+///
+/// # Errors
+/// Returns [`MultiplicationError::Io`] if an existing file cannot be read or parsed, or if the
+/// tuned table cannot be written.
+pub fn load_or_tune<F: IsFFTField>(path: &Path) -> Result<ThresholdTable, MultiplicationError> {
+    if path.exists() {
+        return ThresholdTable::load(path);
+    }
+    let thresholds = tune::<F>();
+    thresholds.save(path)?;
+    Ok(thresholds)
+}
+
+impl ThresholdTable {
+    /// Writes the table to `path` as `key = value` lines, one per threshold.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::Io`] if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), MultiplicationError> {
+        let contents = format!("karatsuba = {}\nfft = {}\n", self.karatsuba, self.fft);
+        std::fs::write(path, contents).map_err(|e| MultiplicationError::Io(e.to_string()))
+    }
+
+    /// Reads a table written by [`ThresholdTable::save`].
+    ///
+    /// Missing thresholds keep their [`ThresholdTable::default`] values.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::Io`] if the file cannot be read or contains a line that is
+    /// not a known threshold.
+    pub fn load(path: &Path) -> Result<Self, MultiplicationError> {
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| MultiplicationError::Io(e.to_string()))?;
+        let mut thresholds = Self::default();
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let malformed = || MultiplicationError::Io(format!("malformed threshold: {:?}", line));
+            let (key, value) = line.split_once('=').ok_or_else(malformed)?;
+            let value: usize = value.trim().parse().map_err(|_| malformed())?;
+            match key.trim() {
+                "karatsuba" => thresholds.karatsuba = value,
+                "fft" => thresholds.fft = value,
+                _ => return Err(malformed()),
+            }
+        }
+        Ok(thresholds)
+    }
+}
+
+/// Returns the fastest of [`REPETITIONS`] runs of `f`.
+fn best_time<T>(mut f: impl FnMut() -> T) -> Duration {
+    (0..REPETITIONS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+/// Returns a polynomial with `n` pseudo-random coefficients, reproducible from `seed`.
+fn sample_polynomial<F: IsFFTField>(n: usize, seed: u64) -> Polynomial<FieldElement<F>> {
+    // A multiplicative congruential generator is plenty for timing inputs.
+    let coeffs: Vec<FieldElement<F>> = core::iter::successors(Some(seed), |x| {
+        Some(x.wrapping_mul(6_364_136_223_846_793_005))
+    })
+    .map(|x| FieldElement::from(x >> 33))
+    .take(n)
+    .collect();
+    Polynomial::new(&coeffs)
+}
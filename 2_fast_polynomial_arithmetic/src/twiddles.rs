@@ -0,0 +1,63 @@
+//! Memoized FFT plans for repeated FFT multiplications.
+//!
+//! Generating the bit-reversed roots of unity costs O(N) field multiplications plus a
+//! permutation, which is as much work as a pointwise product. Callers that multiply many
+//! polynomials of similar size should build each [`FftPlan`] once and reuse it.
+
+use alloc::collections::btree_map::Entry;
+use alloc::collections::BTreeMap;
+
+use lambdaworks_math::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+use lambdaworks_math::field::traits::IsFFTField;
+
+use crate::{FftPlan, MultiplicationError};
+
+/// A lazily filled cache of radix-2 [`FftPlan`]s, keyed by `log2(n)`.
+///
+/// The field defaults to Babybear.
+#[derive(Debug, Clone)]
+pub struct TwiddleCache<F: IsFFTField = Babybear31PrimeField> {
+    plans: BTreeMap<u64, FftPlan<F>>,
+}
+
+impl<F: IsFFTField> Default for TwiddleCache<F> {
+    fn default() -> Self {
+        Self {
+            plans: BTreeMap::new(),
+        }
+    }
+}
+
+impl<F: IsFFTField> TwiddleCache<F> {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the plan for a domain of size `2^log_n`, building and storing it on first use.
+    ///
+    /// # Errors
+    /// Returns [`MultiplicationError::DomainTooLarge`] if the field has no subgroup of size
+    /// `2^log_n`.
+    pub fn plan(&mut self, log_n: u64) -> Result<&FftPlan<F>, MultiplicationError> {
+        Ok(match self.plans.entry(log_n) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(FftPlan::new(log_n)?),
+        })
+    }
+
+    /// Returns the number of domain sizes currently cached.
+    pub fn len(&self) -> usize {
+        self.plans.len()
+    }
+
+    /// Returns `true` if no plans have been built yet.
+    pub fn is_empty(&self) -> bool {
+        self.plans.is_empty()
+    }
+
+    /// Drops all cached plans.
+    pub fn clear(&mut self) {
+        self.plans.clear();
+    }
+}
@@ -0,0 +1,24 @@
+//! Fuzzes the decoder of the compact proof encoding with arbitrary bytes.
+//!
+//! The proptest round trips start from proofs, so they only feed the decoder encodings and their
+//! damaged copies; the fuzzer also reaches the lengths and varints no encoder writes. Decoding must
+//! never panic, and whatever it accepts must encode back to exactly the bytes it came from.
+//!
+//! ```text
+//! cargo +nightly fuzz run compact_decoding
+//! ```
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use polynomial_commitment_scheme::types::FriProof;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(proof) = FriProof::from_compact_bytes(data) {
+        assert_eq!(
+            proof.to_compact_bytes(),
+            data,
+            "a decoded proof does not encode back to its bytes"
+        );
+    }
+});
@@ -0,0 +1,185 @@
+//! This binary runs the Prover and the Verifier as separate processes, which share nothing but
+//! the files passed between them: the Prover ends with the proof, and the Verifier starts from it
+//! and the public parameters alone.
+//!
+//! ```text
+//! cargo run --bin fri -- prove --poly coeffs.json --out proof.bin [--params params.json]
+//! cargo run --bin fri -- verify --proof proof.bin --params params.json
+//! ```
+//!
+//! `coeffs.json` holds the polynomial's coefficients, constant term first, as integers reduced
+//! modulo Babybear's prime, so `[2, -3, 0, 1]` is `x^3 - 3x + 2`. `params.json` holds the
+//! arguments of `FriParameters::new` and the Merkle cap height:
+//!
+//! ```text
+//! {"claimed_degree": 3, "blowup_factor": 8, "num_queries": 7, "last_layer_degree_bound": 1,
+//!  "offset": 31, "merkle_cap_height": 1}
+//! ```
+//!
+//! Without `--params`, `prove` picks 20 bits of security for the polynomial's degree, as the
+//! demo does, and writes those parameters to `params.json` beside the proof. The proof is
+//! written in the text encoding of `FriProof::encode`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use lambdaworks_math::polynomial::Polynomial;
+use polynomial_commitment_scheme::prover::Prover;
+use polynomial_commitment_scheme::types::{FriParameters, FriProof};
+use polynomial_commitment_scheme::verifier::Verifier;
+use polynomial_commitment_scheme::FE;
+use serde::{Deserialize, Serialize};
+
+/// The security level of the parameters `prove` picks when none are given.
+const DEFAULT_TARGET_BITS: usize = 20;
+
+/// The blowup factor of the parameters `prove` picks when none are given.
+const DEFAULT_BLOWUP_FACTOR: usize = 8;
+
+const USAGE: &str = "usage:
+  fri prove --poly <coeffs.json> --out <proof.bin> [--params <params.json>]
+  fri verify --proof <proof.bin> --params <params.json>";
+
+/// The contents of `params.json`.
+#[derive(Debug, Serialize, Deserialize)]
+struct ParametersFile {
+    claimed_degree: usize,
+    blowup_factor: usize,
+    num_queries: usize,
+    last_layer_degree_bound: usize,
+    offset: u64,
+    merkle_cap_height: usize,
+}
+
+impl ParametersFile {
+    /// The parameters `prove` picks for a polynomial of `num_coefficients` coefficients: the
+    /// smallest domain that fits them, 20 bits of security, folding down to degree 1 and Merkle
+    /// caps of 2 nodes.
+    fn default_for(num_coefficients: usize) -> Result<Self, String> {
+        let claimed_degree = num_coefficients.max(1).next_power_of_two() - 1;
+        let params = FriParameters::with_security(
+            claimed_degree,
+            DEFAULT_BLOWUP_FACTOR,
+            DEFAULT_TARGET_BITS,
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(Self {
+            claimed_degree,
+            blowup_factor: DEFAULT_BLOWUP_FACTOR,
+            num_queries: params.num_queries,
+            last_layer_degree_bound: 1,
+            offset: 31,
+            merkle_cap_height: 1,
+        })
+    }
+
+    fn to_parameters(&self) -> Result<FriParameters, String> {
+        FriParameters::new(
+            self.claimed_degree,
+            self.blowup_factor,
+            self.num_queries,
+            self.last_layer_degree_bound,
+            FE::from(self.offset),
+        )
+        .map(|params| params.with_merkle_cap_height(self.merkle_cap_height))
+        .map_err(|e| format!("invalid parameters: {}", e))
+    }
+}
+
+/// Returns the value following `flag` in `args`, if any.
+fn flag<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+}
+
+/// Returns the value following `name` in `args`, or an error naming the missing flag.
+fn required<'a>(args: &'a [String], name: &str) -> Result<&'a str, String> {
+    flag(args, name).ok_or_else(|| format!("missing {}\n{}", name, USAGE))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+fn read_parameters(path: &Path) -> Result<ParametersFile, String> {
+    serde_json::from_str(&read(path)?).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Reads the coefficients of `coeffs.json`, where a negative integer stands for its residue.
+fn read_polynomial(path: &Path) -> Result<Polynomial<FE>, String> {
+    let integers: Vec<i64> =
+        serde_json::from_str(&read(path)?).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let coefficients: Vec<FE> = integers
+        .iter()
+        .map(|&c| match u64::try_from(c) {
+            Ok(c) => FE::from(c),
+            Err(_) => -FE::from(c.unsigned_abs()),
+        })
+        .collect();
+    Ok(Polynomial::new(&coefficients))
+}
+
+/// Runs `prove`, returning what it wrote.
+fn prove(args: &[String]) -> Result<String, String> {
+    let poly_path = Path::new(required(args, "--poly")?);
+    let out = PathBuf::from(required(args, "--out")?);
+    let poly = read_polynomial(poly_path)?;
+
+    let mut report = String::new();
+    let params_file = match flag(args, "--params") {
+        Some(path) => read_parameters(Path::new(path))?,
+        None => {
+            let params_file = ParametersFile::default_for(poly.coefficients.len())?;
+            let path = out.with_file_name("params.json");
+            let json = serde_json::to_string_pretty(&params_file).map_err(|e| e.to_string())?;
+            write(&path, &(json + "\n"))?;
+            report.push_str(&format!("Wrote the parameters to {}\n", path.display()));
+            params_file
+        }
+    };
+
+    let proof = Prover::new(poly, params_file.to_parameters()?)
+        .prove()
+        .map_err(|e| format!("could not prove: {}", e))?;
+    write(&out, &proof.encode())?;
+    report.push_str(&format!(
+        "Wrote a proof of {} bytes to {}",
+        proof.size_in_bytes(),
+        out.display()
+    ));
+    Ok(report)
+}
+
+/// Runs `verify`, returning the verdict if the proof is accepted.
+fn verify(args: &[String]) -> Result<String, String> {
+    let proof_path = Path::new(required(args, "--proof")?);
+    let params = read_parameters(Path::new(required(args, "--params")?))?.to_parameters()?;
+    let proof = FriProof::decode(&read(proof_path)?)
+        .map_err(|e| format!("{}: {}", proof_path.display(), e))?;
+    Verifier::new(params)
+        .verify(&proof)
+        .map_err(|e| format!("proof rejected: {}", e))?;
+    Ok(format!("Proof {} verified", proof_path.display()))
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let outcome = match args.first().map(String::as_str) {
+        Some("prove") => prove(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+    match outcome {
+        Ok(report) => println!("{}", report),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use lambdaworks_crypto::fiat_shamir::default_transcript::DefaultTranscript;
+use lambdaworks_crypto::fiat_shamir::is_transcript::IsTranscript;
+use lambdaworks_math::traits::AsBytes;
+
+use crate::types::{evaluation_claim_bytes, DomainSeparator, FriParameters};
+use crate::{F, FE};
+
+/// The Fiat-Shamir transcript of a proof, which both the Prover and the Verifier drive through the
+/// same methods so that they absorb and squeeze in the same order and with the same encodings.
+///
+/// A proof of [`crate::prover::Prover`] absorbs and squeezes, in order:
+///
+/// 1. the domain separator and the parameters, on creation;
+/// 2. the claim `(commitment, z, y)`, for the quotient of an evaluation proof only;
+/// 3. the cap of the first layer;
+/// 4. for each folding round, its challenge `beta`, then the cap of the layer it folds into, except
+///    after the last round, whose layer is not committed;
+/// 5. the coefficients of the last layer, one by one;
+/// 6. the query indices.
+///
+/// Any departure from this order on one side, or in how a value is encoded, leaves the two sides
+/// with different challenges and silently breaks verification, hence the single implementation.
+pub struct FriChallenger {
+    transcript: DefaultTranscript<F>,
+}
+
+impl FriChallenger {
+    /// Creates the transcript both parties start from, bound to `separator` and `params`.
+    pub fn new(separator: &DomainSeparator, params: &FriParameters) -> Self {
+        Self::from_transcript(separator.transcript(params))
+    }
+
+    /// Wraps a transcript whose initial absorptions were made elsewhere.
+    pub fn from_transcript(transcript: DefaultTranscript<F>) -> Self {
+        Self { transcript }
+    }
+
+    /// Absorbs raw bytes, such as a label.
+    pub fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.transcript.append_bytes(bytes);
+    }
+
+    /// Absorbs every node of a Merkle cap, in order.
+    pub fn absorb_commitment(&mut self, cap: &[[u8; 32]]) {
+        for node in cap {
+            self.transcript.append_bytes(node);
+        }
+    }
+
+    /// Absorbs field elements, each as its canonical bytes, in order.
+    pub fn absorb_elements(&mut self, elements: &[FE]) {
+        for element in elements {
+            self.transcript.append_bytes(&element.as_bytes());
+        }
+    }
+
+    /// Absorbs the claim that the polynomial committed to by `commitment` evaluates to `y` at `z`.
+    pub fn absorb_evaluation_claim(&mut self, commitment: &[[u8; 32]], z: &FE, y: &FE) {
+        self.transcript
+            .append_bytes(&evaluation_claim_bytes(commitment, z, y));
+    }
+
+    /// Samples a field element, such as a folding challenge.
+    pub fn sample_element(&mut self) -> FE {
+        self.transcript.sample_field_element()
+    }
+
+    /// Samples 32 raw bytes, for challenges in another field than Babybear, such as the scalars
+    /// of a curve.
+    pub fn sample_bytes(&mut self) -> [u8; 32] {
+        self.transcript.sample()
+    }
+
+    /// Samples a field element outside every one of `domains`, drawing again as long as it falls
+    /// in one.
+    pub fn sample_out_of_domain(&mut self, domains: &[&[FE]]) -> FE {
+        loop {
+            let z = self.sample_element();
+            if domains.iter().all(|domain| !domain.contains(&z)) {
+                return z;
+            }
+        }
+    }
+
+    /// Samples an index below `max_value`: the first 8 bytes of a sample, read as a big-endian
+    /// `u64`, reduced modulo `max_value`.
+    pub fn sample_index(&mut self, max_value: usize) -> usize {
+        let sample_bytes: [u8; 8] = self.transcript.sample()[..8].try_into().unwrap();
+        (u64::from_be_bytes(sample_bytes) % max_value as u64) as usize
+    }
+
+    /// Samples `count` indices below `max_value`. A repeated index would only repeat its
+    /// decommitment, so each is kept once, in ascending order.
+    pub fn sample_query_indices(&mut self, count: usize, max_value: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..count).map(|_| self.sample_index(max_value)).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::four_query_params;
+
+    fn challenger() -> FriChallenger {
+        let params = four_query_params();
+        FriChallenger::new(&DomainSeparator::default(), &params)
+    }
+
+    #[test]
+    fn absorbs_and_squeezes_as_the_transcript_it_wraps() {
+        let params = four_query_params();
+        let cap = [[1; 32], [2; 32]];
+        let coefficients = [FE::from(5), FE::from(7)];
+
+        let mut challenger = challenger();
+        challenger.absorb_commitment(&cap);
+        let beta = challenger.sample_element();
+        challenger.absorb_elements(&coefficients);
+        let index = challenger.sample_index(32);
+
+        let mut transcript = DomainSeparator::default().transcript(&params);
+        transcript.append_bytes(&[1; 32]);
+        transcript.append_bytes(&[2; 32]);
+        assert_eq!(beta, transcript.sample_field_element());
+        transcript.append_bytes(&FE::from(5).as_bytes());
+        transcript.append_bytes(&FE::from(7).as_bytes());
+        let bytes: [u8; 8] = transcript.sample()[..8].try_into().unwrap();
+        assert_eq!(index as u64, u64::from_be_bytes(bytes) % 32);
+    }
+
+    #[test]
+    fn challenges_depend_on_the_order_of_absorption() {
+        let (a, b) = ([1; 32], [2; 32]);
+
+        let mut forward = challenger();
+        forward.absorb_commitment(&[a]);
+        forward.absorb_commitment(&[b]);
+        let mut backward = challenger();
+        backward.absorb_commitment(&[b]);
+        backward.absorb_commitment(&[a]);
+        assert_ne!(forward.sample_element(), backward.sample_element());
+
+        // Sampling before absorbing a commitment is not the same as sampling after it.
+        let mut early = challenger();
+        let beta_early = early.sample_element();
+        let mut late = challenger();
+        late.absorb_commitment(&[a]);
+        assert_ne!(beta_early, late.sample_element());
+    }
+
+    #[test]
+    fn query_indices_are_distinct_sorted_and_in_range() {
+        let indices = challenger().sample_query_indices(64, 16);
+        assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(indices.iter().all(|&i| i < 16));
+
+        // The same transcript always yields the same indices.
+        assert_eq!(challenger().sample_query_indices(64, 16), indices);
+    }
+}
@@ -0,0 +1,374 @@
+//! The compact binary encoding of a [`FriProof`], whose length is what a proof actually costs to
+//! send.
+//!
+//! The text encoding of [`crate::encoding`] spells every element and hash in hex and is meant for
+//! people and channels; this one is meant for the wire. A proof is encoded as:
+//!
+//! ```text
+//! proof         := version commitments last_layer decommitments multi_proofs
+//! version       := 0x01
+//! commitments   := varint(count) cap*            cap          := varint(count) hash*
+//! last_layer    := varint(count) element*
+//! decommitments := varint(count) decommitment*   decommitment := varint(count) (element element)*
+//! multi_proofs  := varint(count) multi_proof*    multi_proof  := varint(count) hash*
+//! hash          := 32 bytes
+//! element       := 4 bytes, the canonical representative below p, little-endian
+//! varint        := unsigned LEB128, 7 bits per byte from the lowest, in as few bytes as possible
+//! ```
+//!
+//! A decommitment's count is its number of layers, each a pair of `f(x)` and `f(-x)`. Every value
+//! has exactly one encoding: the decoder rejects non-canonical elements, padded varints and
+//! trailing bytes, so encoding a decoded proof gives back the same bytes.
+//!
+//! With the `zstd` feature, [`FriProof::to_compressed_bytes`] also compresses the encoding. The
+//! hashes are incompressible, so this saves little beyond what the varints already do, but it
+//! shows how little there is to gain.
+
+use crate::error::FriError;
+use crate::types::{FriProof, QueryDecommitment};
+use crate::FE;
+
+/// The version byte that starts every encoding.
+const FORMAT_VERSION: u8 = 1;
+
+/// The bytes of a field element.
+const ELEMENT_BYTES: usize = 4;
+
+/// The zstd level of [`FriProof::to_compressed_bytes`].
+#[cfg(feature = "zstd")]
+const COMPRESSION_LEVEL: i32 = 19;
+
+/// The largest encoding [`FriProof::from_compressed_bytes`] decompresses, far above any proof of
+/// this crate, so that a small input cannot claim an unbounded one.
+#[cfg(feature = "zstd")]
+const MAX_DECOMPRESSED_BYTES: usize = 1 << 24;
+
+impl FriProof {
+    /// Encodes the proof in the compact binary format of this module.
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        let mut writer = Writer(vec![FORMAT_VERSION]);
+        writer.list(&self.layer_commitments, |w, cap| w.hashes(cap));
+        writer.list(&self.last_layer_coefficients, Writer::element);
+        writer.list(&self.query_decommitments, |w, decommitment| {
+            let layers: Vec<(&FE, &FE)> = decommitment
+                .layer_evaluations
+                .iter()
+                .zip(&decommitment.layer_evaluations_sym)
+                .collect();
+            w.list(&layers, |w, (value, value_sym)| {
+                w.element(value);
+                w.element(value_sym);
+            });
+        });
+        writer.list(&self.layer_multi_proofs, |w, nodes| w.hashes(nodes));
+        writer.0
+    }
+
+    /// Decodes a proof from the bytes produced by [`FriProof::to_compact_bytes`].
+    ///
+    /// Decoding only checks the encoding; [`FriProof::validate_shape`] checks the proof against
+    /// its parameters.
+    ///
+    /// # Errors
+    /// Returns [`FriError::MalformedProof`] if the bytes are not the encoding of a proof.
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self, FriError> {
+        let mut reader = Reader(bytes);
+        let version = reader.byte()?;
+        if version != FORMAT_VERSION {
+            return Err(malformed(format!("unknown format version {}", version)));
+        }
+        let proof = FriProof {
+            layer_commitments: reader.list(Reader::hashes)?,
+            last_layer_coefficients: reader.list(Reader::element)?,
+            query_decommitments: reader.list(|r| {
+                let mut decommitment = QueryDecommitment::default();
+                for _ in 0..r.count()? {
+                    decommitment.layer_evaluations.push(r.element()?);
+                    decommitment.layer_evaluations_sym.push(r.element()?);
+                }
+                Ok(decommitment)
+            })?,
+            layer_multi_proofs: reader.list(Reader::hashes)?,
+        };
+        if !reader.0.is_empty() {
+            return Err(malformed(format!(
+                "{} trailing bytes after the proof",
+                reader.0.len()
+            )));
+        }
+        Ok(proof)
+    }
+
+    /// Encodes the proof with [`FriProof::to_compact_bytes`] and compresses it with zstd.
+    #[cfg(feature = "zstd")]
+    pub fn to_compressed_bytes(&self) -> Vec<u8> {
+        zstd::bulk::compress(&self.to_compact_bytes(), COMPRESSION_LEVEL)
+            .expect("compressing into memory does not fail")
+    }
+
+    /// Decodes a proof from the bytes produced by [`FriProof::to_compressed_bytes`].
+    ///
+    /// # Errors
+    /// Returns [`FriError::MalformedProof`] if the bytes do not decompress, or do not decompress
+    /// to the encoding of a proof.
+    #[cfg(feature = "zstd")]
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self, FriError> {
+        let encoded = zstd::bulk::decompress(bytes, MAX_DECOMPRESSED_BYTES)
+            .map_err(|e| malformed(format!("not a zstd frame: {}", e)))?;
+        Self::from_compact_bytes(&encoded)
+    }
+}
+
+/// The bytes of an encoding so far.
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn varint(&mut self, mut value: usize) {
+        while value >= 0x80 {
+            self.0.push((value & 0x7f) as u8 | 0x80);
+            value >>= 7;
+        }
+        self.0.push(value as u8);
+    }
+
+    fn element(&mut self, element: &FE) {
+        // The representative is below p < 2^31, so its low 4 bytes hold all of it.
+        let value = element.representative().limbs[0] as u32;
+        self.0.extend(value.to_le_bytes());
+    }
+
+    fn hashes(&mut self, hashes: &[[u8; 32]]) {
+        self.varint(hashes.len());
+        for hash in hashes {
+            self.0.extend(hash);
+        }
+    }
+
+    /// Writes the count of `items`, then each with `item`.
+    fn list<T>(&mut self, items: &[T], mut item: impl FnMut(&mut Self, &T)) {
+        self.varint(items.len());
+        for x in items {
+            item(self, x);
+        }
+    }
+}
+
+/// The bytes of an encoding still to decode.
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8], FriError> {
+        if self.0.len() < len {
+            return Err(malformed("the encoding ends early".to_string()));
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> Result<u8, FriError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> Result<usize, FriError> {
+        let mut value = 0usize;
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.byte()?;
+            let bits = usize::from(byte & 0x7f);
+            if bits << shift >> shift != bits {
+                return Err(malformed("a varint overflows".to_string()));
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                // A final zero byte after the first would only pad the varint.
+                if byte == 0 && shift > 0 {
+                    return Err(malformed("a varint is padded".to_string()));
+                }
+                return Ok(value);
+            }
+        }
+        Err(malformed("a varint overflows".to_string()))
+    }
+
+    /// Reads the count of a list. Every item takes at least a byte, so a count beyond the bytes
+    /// left is rejected before anything is read, or allocated, for it.
+    fn count(&mut self) -> Result<usize, FriError> {
+        let count = self.varint()?;
+        if count > self.0.len() {
+            return Err(malformed(format!(
+                "a count of {} with {} bytes left",
+                count,
+                self.0.len()
+            )));
+        }
+        Ok(count)
+    }
+
+    fn element(&mut self) -> Result<FE, FriError> {
+        let bytes: [u8; ELEMENT_BYTES] = self.take(ELEMENT_BYTES)?.try_into().unwrap();
+        let value = u32::from_le_bytes(bytes);
+        let element = FE::from(u64::from(value));
+        if element.representative().limbs[0] != u64::from(value) {
+            return Err(malformed(format!("{} is not a canonical element", value)));
+        }
+        Ok(element)
+    }
+
+    fn hash(&mut self) -> Result<[u8; 32], FriError> {
+        Ok(self.take(32)?.try_into().unwrap())
+    }
+
+    fn hashes(&mut self) -> Result<Vec<[u8; 32]>, FriError> {
+        (0..self.count()?).map(|_| self.hash()).collect()
+    }
+
+    /// Reads the count of a list, then each item with `item`.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, FriError>,
+    ) -> Result<Vec<T>, FriError> {
+        (0..self.count()?).map(|_| item(self)).collect()
+    }
+}
+
+fn malformed(reason: String) -> FriError {
+    FriError::MalformedProof { reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::prover::Prover;
+    use crate::test_utils::demo_poly;
+    use crate::types::FriParameters;
+    use crate::verifier::Verifier;
+
+    fn arb_element() -> impl Strategy<Value = FE> {
+        any::<u64>().prop_map(FE::from)
+    }
+
+    fn arb_hashes() -> impl Strategy<Value = Vec<[u8; 32]>> {
+        vec(any::<u8>().prop_map(|byte| [byte; 32]), 0..4)
+    }
+
+    /// Generates proofs of any shape, including ones no Prover would send, since the encoding must
+    /// carry whatever it is given.
+    fn arb_proof() -> impl Strategy<Value = FriProof> {
+        let decommitment = vec((arb_element(), arb_element()), 0..5).prop_map(|pairs| {
+            let (layer_evaluations, layer_evaluations_sym) = pairs.into_iter().unzip();
+            QueryDecommitment {
+                layer_evaluations,
+                layer_evaluations_sym,
+            }
+        });
+        (
+            vec(arb_hashes(), 0..4),
+            vec(arb_element(), 0..200),
+            vec(decommitment, 0..4),
+            vec(arb_hashes(), 0..4),
+        )
+            .prop_map(
+                |(caps, coefficients, decommitments, multi_proofs)| FriProof {
+                    layer_commitments: caps,
+                    last_layer_coefficients: coefficients,
+                    query_decommitments: decommitments,
+                    layer_multi_proofs: multi_proofs,
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn proofs_survive_compact_encoding(proof in arb_proof()) {
+            prop_assert_eq!(FriProof::from_compact_bytes(&proof.to_compact_bytes()), Ok(proof));
+        }
+
+        #[test]
+        fn decoding_arbitrary_bytes_never_panics(bytes in vec(any::<u8>(), 0..256)) {
+            // The bytes rarely form a proof, but one that does has exactly the encoding it came
+            // from.
+            if let Ok(proof) = FriProof::from_compact_bytes(&bytes) {
+                prop_assert_eq!(proof.to_compact_bytes(), bytes);
+            }
+        }
+
+        #[test]
+        fn decoding_never_panics_on_a_damaged_proof(
+            proof in arb_proof(),
+            position in any::<usize>(),
+            byte in any::<u8>(),
+        ) {
+            let mut bytes = proof.to_compact_bytes();
+            let position = position % bytes.len();
+            bytes[position] = byte;
+            if let Ok(decoded) = FriProof::from_compact_bytes(&bytes) {
+                prop_assert_eq!(decoded.to_compact_bytes(), bytes);
+            }
+            prop_assert!(FriProof::from_compact_bytes(&bytes[..position]).is_err());
+        }
+    }
+
+    #[test]
+    fn decoded_proofs_verify_and_cost_what_the_breakdown_counts() {
+        let poly = demo_poly();
+        let params = FriParameters::new(3, 8, 7, 1, FE::from(31))
+            .unwrap()
+            .with_merkle_cap_height(1);
+        let proof = Prover::new(poly, params.clone()).prove().unwrap();
+
+        let bytes = proof.to_compact_bytes();
+        let decoded = FriProof::from_compact_bytes(&bytes).unwrap();
+        assert_eq!(decoded, proof);
+        assert_eq!(Verifier::new(params).verify(&decoded), Ok(()));
+        // The lengths, one byte each at these sizes, and the version byte are all the encoding
+        // adds to the payload.
+        assert!(bytes.len() > proof.size_in_bytes());
+        assert!(bytes.len() < proof.size_in_bytes() + 32);
+        assert!(bytes.len() < proof.encode().len() / 2);
+    }
+
+    #[test]
+    fn rejects_non_canonical_encodings() {
+        let proof = FriProof {
+            layer_commitments: vec![vec![[7; 32]]],
+            last_layer_coefficients: vec![FE::from(5)],
+            query_decommitments: Vec::new(),
+            layer_multi_proofs: vec![Vec::new()],
+        };
+        let bytes = proof.to_compact_bytes();
+        // The layout is: version, 1 cap of 1 hash, 1 coefficient, no decommitment, 1 empty
+        // multi-proof.
+        assert_eq!(bytes.len(), 1 + 2 + 32 + 1 + 4 + 1 + 2);
+
+        let mut unknown_version = bytes.clone();
+        unknown_version[0] = 2;
+        let mut above_the_modulus = bytes.clone();
+        above_the_modulus[36..40].copy_from_slice(&u32::MAX.to_le_bytes());
+        let mut padded_varint = bytes.clone();
+        padded_varint.splice(1..2, [0x81, 0x00]);
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        for encoding in [unknown_version, above_the_modulus, padded_varint, trailing] {
+            assert!(matches!(
+                FriProof::from_compact_bytes(&encoding),
+                Err(FriError::MalformedProof { .. })
+            ));
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn proofs_survive_compression() {
+        let poly = demo_poly();
+        let params = FriParameters::new(3, 8, 7, 1, FE::from(31)).unwrap();
+        let proof = Prover::new(poly, params).prove().unwrap();
+        assert_eq!(
+            FriProof::from_compressed_bytes(&proof.to_compressed_bytes()),
+            Ok(proof)
+        );
+        assert!(FriProof::from_compressed_bytes(b"not zstd").is_err());
+    }
+}
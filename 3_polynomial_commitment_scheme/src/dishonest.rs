@@ -0,0 +1,155 @@
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::prover::Prover;
+use crate::trace::Observer;
+use crate::types::{FriParameters, QueryDecommitment};
+use crate::FE;
+
+/// Hooks into the steps of a [`Prover`], through which a dishonest Prover departs from the
+/// protocol. Every hook does nothing by default, so an implementation only overrides the step it
+/// attacks.
+pub trait Tamper {
+    /// Returns the challenge the Prover folds with, given the one it sampled from the transcript
+    /// and the challenges it folded the earlier rounds with.
+    fn challenge(&mut self, _earlier: &[FE], sampled: FE) -> FE {
+        sampled
+    }
+
+    /// Alters the evaluations of layer `layer`, at least 1, right after they are folded and before
+    /// they are committed or, for the last layer, interpolated.
+    fn layer(&mut self, _layer: usize, _evaluations: &mut [FE]) {}
+
+    /// Alters the decommitment of the query at original index `query` before it is sent.
+    fn decommitment(&mut self, _query: usize, _decommitment: &mut QueryDecommitment) {}
+}
+
+/// The unit hook tampers with nothing, and is the default of every Prover.
+impl Tamper for () {}
+
+/// The attacks a cheating Prover can mount, each of which the Verifier must reject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Attack {
+    /// Adds one to every evaluation of layer `layer`, so that the committed layer is no longer the
+    /// fold of the one before. Layer 0 has no layer before it, so `layer` must be at least 1.
+    CorruptLayer { layer: usize },
+    /// Swaps `f(x)` and `f(-x)` in the decommitments of layer `layer`, so that the opened values
+    /// no longer match their committed leaf.
+    SwapSymmetricValues { layer: usize },
+    /// Folds round `round`, at least 1, with the challenge of the round before instead of the one
+    /// sampled after the latest commitment.
+    StaleChallenge { round: usize },
+}
+
+impl Tamper for Attack {
+    fn challenge(&mut self, earlier: &[FE], sampled: FE) -> FE {
+        match *self {
+            Attack::StaleChallenge { round } if round > 0 && earlier.len() == round => {
+                earlier[round - 1]
+            }
+            _ => sampled,
+        }
+    }
+
+    fn layer(&mut self, layer: usize, evaluations: &mut [FE]) {
+        if *self == (Attack::CorruptLayer { layer }) {
+            for evaluation in evaluations {
+                *evaluation += FE::one();
+            }
+        }
+    }
+
+    fn decommitment(&mut self, _query: usize, decommitment: &mut QueryDecommitment) {
+        if let Attack::SwapSymmetricValues { layer } = *self {
+            if layer < decommitment.layer_evaluations.len() {
+                std::mem::swap(
+                    &mut decommitment.layer_evaluations[layer],
+                    &mut decommitment.layer_evaluations_sym[layer],
+                );
+            }
+        }
+    }
+}
+
+impl<O: Observer> Prover<O> {
+    /// Runs every proof of this Prover through `tamper`, making it a dishonest Prover.
+    pub fn with_tamper(mut self, tamper: impl Tamper + 'static) -> Self {
+        self.tamper = Box::new(tamper);
+        self
+    }
+}
+
+/// Returns a polynomial of degree `2 * claimed_degree + 1`, above the degree `params` claim but
+/// still small enough to evaluate on their domain.
+pub fn high_degree_polynomial(params: &FriParameters) -> Polynomial<FE> {
+    let coefficients: Vec<FE> = (1..=2 * (params.claimed_degree as u64 + 1))
+        .map(FE::from)
+        .collect();
+    Polynomial::new(&coefficients)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::FriError;
+    use crate::test_utils::{degree_seven_params, degree_seven_poly};
+    use crate::verifier::Verifier;
+
+    /// Proves `poly` with `tamper` and returns the Verifier's verdict.
+    fn verdict(
+        poly: Polynomial<FE>,
+        params: FriParameters,
+        tamper: impl Tamper + 'static,
+    ) -> Result<(), FriError> {
+        let proof = Prover::new(poly, params.clone())
+            .with_tamper(tamper)
+            .prove()
+            .unwrap();
+        Verifier::new(params).verify(&proof)
+    }
+
+    #[test]
+    fn honest_prover_is_accepted() {
+        let (poly, params) = (degree_seven_poly(), degree_seven_params());
+        assert_eq!(verdict(poly, params, ()), Ok(()));
+    }
+
+    #[test]
+    fn rejects_a_polynomial_above_the_claimed_degree() {
+        let params = degree_seven_params();
+        let poly = high_degree_polynomial(&params);
+        assert!(matches!(
+            verdict(poly, params, ()),
+            Err(FriError::LastLayerDegreeTooHigh { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_a_corrupted_layer() {
+        let (poly, params) = (degree_seven_poly(), degree_seven_params());
+        let attack = Attack::CorruptLayer { layer: 2 };
+        assert!(matches!(
+            verdict(poly, params, attack),
+            Err(FriError::InconsistentFolding { layer: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_swapped_symmetric_values() {
+        let (poly, params) = (degree_seven_poly(), degree_seven_params());
+        let attack = Attack::SwapSymmetricValues { layer: 1 };
+        assert_eq!(
+            verdict(poly, params, attack),
+            Err(FriError::InvalidMerkleProof)
+        );
+    }
+
+    #[test]
+    fn rejects_a_stale_challenge() {
+        let (poly, params) = (degree_seven_poly(), degree_seven_params());
+        let attack = Attack::StaleChallenge { round: 1 };
+        assert!(matches!(
+            verdict(poly, params, attack),
+            Err(FriError::InconsistentFolding { layer: 1, .. })
+        ));
+    }
+}
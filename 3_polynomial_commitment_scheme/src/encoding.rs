@@ -0,0 +1,297 @@
+//! The text encoding of the protocol's messages, and of proofs as the messages carrying them.
+//!
+//! Every message is a single line of whitespace-separated tokens, which the
+//! [`crate::interactive`] channels send as is. A [`FriProof`] is encoded as the Prover's
+//! messages of the interactive protocol, one per line: each layer's commitment, then the last
+//! layer, then the decommitments, then each layer's multi-proof. Nothing here depends on the
+//! platform, so a proof made by the demo can be decoded and verified wherever the Verifier runs, a
+//! browser included.
+
+use std::iter::Peekable;
+use std::str::SplitWhitespace;
+
+use crate::error::FriError;
+use crate::trace::ProtocolEvent;
+use crate::types::{FriProof, QueryDecommitment};
+use crate::FE;
+
+/// A message of the interactive protocol.
+///
+/// The Prover's messages are what a [`crate::prover::Prover`] appends to its transcript, and the
+/// Verifier's are what it samples from it instead.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    /// Prover to Verifier: the Merkle cap of a layer.
+    Commitment(Vec<[u8; 32]>),
+    /// Verifier to Prover: the folding challenge of a round.
+    Challenge(FE),
+    /// Prover to Verifier: the coefficients of the last layer's polynomial.
+    LastLayer(Vec<FE>),
+    /// Verifier to Prover: the query indices, distinct and in ascending order.
+    Queries(Vec<usize>),
+    /// Prover to Verifier: the decommitment of each query, in the order of the indices.
+    Decommitments(Vec<QueryDecommitment>),
+    /// Prover to Verifier: the Merkle multi-proof of a layer's queried leaves, after the
+    /// decommitments.
+    MultiProof(Vec<[u8; 32]>),
+}
+
+impl Message {
+    /// Encodes the message as a single line of whitespace-separated tokens: its kind, then its
+    /// hashes and field elements in hex and its indices and lengths in decimal.
+    pub fn encode(&self) -> String {
+        let mut tokens = Vec::new();
+        match self {
+            Message::Commitment(cap) => {
+                tokens.push("commitment".to_string());
+                tokens.extend(cap.iter().map(hex::encode));
+            }
+            Message::Challenge(beta) => {
+                tokens.push("challenge".to_string());
+                tokens.push(ProtocolEvent::element(beta));
+            }
+            Message::LastLayer(coefficients) => {
+                tokens.push("last_layer".to_string());
+                tokens.extend(coefficients.iter().map(ProtocolEvent::element));
+            }
+            Message::Queries(indices) => {
+                tokens.push("queries".to_string());
+                tokens.extend(indices.iter().map(usize::to_string));
+            }
+            Message::Decommitments(decommitments) => {
+                tokens.push("decommitments".to_string());
+                // Each decommitment is its number of layers, then both evaluations of each layer.
+                for decommitment in decommitments {
+                    tokens.push(decommitment.layer_evaluations.len().to_string());
+                    let layers = decommitment
+                        .layer_evaluations
+                        .iter()
+                        .zip(&decommitment.layer_evaluations_sym);
+                    for (value, value_sym) in layers {
+                        tokens.push(ProtocolEvent::element(value));
+                        tokens.push(ProtocolEvent::element(value_sym));
+                    }
+                }
+            }
+            Message::MultiProof(nodes) => {
+                tokens.push("multi_proof".to_string());
+                tokens.extend(nodes.iter().map(hex::encode));
+            }
+        }
+        tokens.join(" ")
+    }
+
+    /// Decodes a message from a line produced by [`Message::encode`].
+    ///
+    /// # Errors
+    /// Returns [`FriError::ChannelError`] if the line is not a well-formed message.
+    pub fn decode(line: &str) -> Result<Self, FriError> {
+        let mut tokens = Tokens(line.split_whitespace().peekable());
+        let message = match tokens.next()? {
+            "commitment" => Message::Commitment(tokens.rest(Tokens::hash)?),
+            "challenge" => Message::Challenge(tokens.element()?),
+            "last_layer" => Message::LastLayer(tokens.rest(Tokens::element)?),
+            "queries" => Message::Queries(tokens.rest(Tokens::number)?),
+            "decommitments" => Message::Decommitments(tokens.rest(|tokens| {
+                let mut decommitment = QueryDecommitment::default();
+                for _ in 0..tokens.number()? {
+                    decommitment.layer_evaluations.push(tokens.element()?);
+                    decommitment.layer_evaluations_sym.push(tokens.element()?);
+                }
+                Ok(decommitment)
+            })?),
+            "multi_proof" => Message::MultiProof(tokens.rest(Tokens::hash)?),
+            kind => return Err(malformed(format!("unknown message kind {}", kind))),
+        };
+        if tokens.0.peek().is_some() {
+            return Err(malformed("trailing tokens after the message".to_string()));
+        }
+        Ok(message)
+    }
+}
+
+/// The whitespace-separated tokens of an encoded [`Message`].
+struct Tokens<'a>(Peekable<SplitWhitespace<'a>>);
+
+impl<'a> Tokens<'a> {
+    fn next(&mut self) -> Result<&'a str, FriError> {
+        self.0
+            .next()
+            .ok_or_else(|| malformed("the message ends early".to_string()))
+    }
+
+    fn number(&mut self) -> Result<usize, FriError> {
+        let token = self.next()?;
+        token
+            .parse()
+            .map_err(|_| malformed(format!("{} is not a number", token)))
+    }
+
+    fn element(&mut self) -> Result<FE, FriError> {
+        let token = self.next()?;
+        FE::from_hex(token).map_err(|_| malformed(format!("{} is not a field element", token)))
+    }
+
+    fn hash(&mut self) -> Result<[u8; 32], FriError> {
+        let token = self.next()?;
+        hex::decode(token)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| malformed(format!("{} is not a 32-byte hash", token)))
+    }
+
+    /// Reads items with `item` until the tokens run out.
+    fn rest<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, FriError>,
+    ) -> Result<Vec<T>, FriError> {
+        let mut items = Vec::new();
+        while self.0.peek().is_some() {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+}
+
+fn malformed(reason: String) -> FriError {
+    FriError::ChannelError(format!("malformed message: {}", reason))
+}
+
+impl FriProof {
+    /// Encodes the proof as the Prover's messages carrying it, one [`Message::encode`]d line
+    /// each.
+    pub fn encode(&self) -> String {
+        self.layer_commitments
+            .iter()
+            .cloned()
+            .map(Message::Commitment)
+            .chain([
+                Message::LastLayer(self.last_layer_coefficients.clone()),
+                Message::Decommitments(self.query_decommitments.clone()),
+            ])
+            .chain(
+                self.layer_multi_proofs
+                    .iter()
+                    .cloned()
+                    .map(Message::MultiProof),
+            )
+            .map(|message| message.encode() + "\n")
+            .collect()
+    }
+
+    /// Decodes a proof from the lines produced by [`FriProof::encode`], ignoring blank lines.
+    ///
+    /// Decoding only checks the encoding; [`FriProof::validate_shape`] checks the proof against
+    /// its parameters.
+    ///
+    /// # Errors
+    /// Returns [`FriError::MalformedProof`] if a line is not a well-formed message, or if the
+    /// messages are not commitments followed by the last layer, the decommitments and the
+    /// multi-proofs.
+    pub fn decode(text: &str) -> Result<Self, FriError> {
+        let mut proof = FriProof {
+            layer_commitments: Vec::new(),
+            last_layer_coefficients: Vec::new(),
+            query_decommitments: Vec::new(),
+            layer_multi_proofs: Vec::new(),
+        };
+        let mut messages = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                Message::decode(line).map_err(|e| FriError::MalformedProof {
+                    reason: format!("line {}: {}", i + 1, e),
+                })
+            });
+        let out_of_order = |expected: &str| FriError::MalformedProof {
+            reason: format!("expected {} next", expected),
+        };
+        loop {
+            match messages.next().transpose()? {
+                Some(Message::Commitment(cap)) => proof.layer_commitments.push(cap),
+                Some(Message::LastLayer(coefficients)) => {
+                    proof.last_layer_coefficients = coefficients;
+                    break;
+                }
+                _ => return Err(out_of_order("a commitment or the last layer")),
+            }
+        }
+        match messages.next().transpose()? {
+            Some(Message::Decommitments(decommitments)) => {
+                proof.query_decommitments = decommitments
+            }
+            _ => return Err(out_of_order("the decommitments")),
+        }
+        for message in messages {
+            match message? {
+                Message::MultiProof(nodes) => proof.layer_multi_proofs.push(nodes),
+                _ => return Err(out_of_order("a multi-proof or the end of the proof")),
+            }
+        }
+        Ok(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prover::Prover;
+    use crate::test_utils::demo_poly;
+    use crate::types::FriParameters;
+    use crate::verifier::Verifier;
+
+    #[test]
+    fn messages_survive_encoding() {
+        let decommitment = QueryDecommitment {
+            layer_evaluations: vec![FE::from(3), FE::from(5)],
+            layer_evaluations_sym: vec![FE::from(4), FE::from(6)],
+        };
+        let messages = [
+            Message::Commitment(vec![[7; 32], [8; 32]]),
+            Message::Challenge(FE::from(123456)),
+            Message::LastLayer(vec![FE::from(1), FE::from(2)]),
+            Message::Queries(vec![3, 17]),
+            Message::Decommitments(vec![decommitment.clone(), decommitment]),
+            Message::MultiProof(vec![[1; 32], [2; 32]]),
+            Message::MultiProof(vec![]),
+        ];
+        for message in messages {
+            assert_eq!(Message::decode(&message.encode()), Ok(message));
+        }
+    }
+
+    #[test]
+    fn decoded_proofs_verify() {
+        let poly = demo_poly();
+        let params = FriParameters::new(3, 8, 4, 1, FE::from(31))
+            .unwrap()
+            .with_merkle_cap_height(1);
+        let proof = Prover::new(poly, params.clone()).prove().unwrap();
+
+        let decoded = FriProof::decode(&proof.encode()).unwrap();
+        assert_eq!(decoded, proof);
+        assert_eq!(Verifier::new(params).verify(&decoded), Ok(()));
+    }
+
+    #[test]
+    fn rejects_proofs_with_messages_out_of_order() {
+        let encoded = FriProof {
+            layer_commitments: vec![vec![[7; 32]]],
+            last_layer_coefficients: vec![FE::from(1)],
+            query_decommitments: vec![QueryDecommitment::default()],
+            layer_multi_proofs: vec![vec![[1; 32]]],
+        }
+        .encode();
+        let mut lines: Vec<&str> = encoded.lines().collect();
+        lines.swap(1, 2);
+        assert!(matches!(
+            FriProof::decode(&lines.join("\n")),
+            Err(FriError::MalformedProof { .. })
+        ));
+        assert!(matches!(
+            FriProof::decode("commitment not-a-hash"),
+            Err(FriError::MalformedProof { .. })
+        ));
+    }
+}
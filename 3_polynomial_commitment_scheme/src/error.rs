@@ -12,6 +12,50 @@ pub enum FriError {
         expected: String,
         got: String,
     },
+    /// Interpolating the last layer's polynomial from its evaluations failed.
+    LastLayerInterpolationError(String),
+    /// The evaluation domain size `(claimed_degree + 1) * blowup_factor` is not a power of 2.
+    DomainNotPowerOfTwo(usize),
+    /// The blowup factor is below 2, so the evaluation domain adds no redundancy.
+    BlowupFactorTooSmall(usize),
+    /// The Verifier would make no queries, so the proof would check nothing.
+    NoQueries,
+    /// The field has no multiplicative subgroup of size `2^log_size`.
+    DomainTooLarge { log_size: u64, two_adicity: u64 },
+    /// A zero coset offset collapses the evaluation domain to a single point.
+    ZeroOffset,
+    /// The proof does not hold one decommitment per distinct query index.
+    WrongNumberOfDecommitments { expected: usize, got: usize },
+    /// The evaluation point lies in the domain, where the quotient `(P(x) - y) / (x - z)` cannot
+    /// be computed from the evaluations of `P`.
+    EvaluationPointInDomain,
+    /// An opening of the polynomial disagrees with the quotient's evaluation at a domain index.
+    InconsistentQuotient { index: usize },
+    /// The last layer's polynomial has a higher degree than the folding rounds leave.
+    LastLayerDegreeTooHigh { degree: usize, bound: usize },
+    /// Evaluating the initial polynomial on the domain failed.
+    EvaluationError(String),
+    /// Sending or receiving an interactive message failed, or a message could not be decoded.
+    ChannelError(String),
+    /// The other party sent a different message than the protocol expects at this step.
+    UnexpectedMessage { expected: &'static str },
+    /// The STIR folding factor is not a power of two of at least 4 within the initial domain, or
+    /// the offset lets the folded domain meet the next domain.
+    InvalidFoldingFactor(usize),
+    /// The proof does not hold one entry per round of the parameters.
+    WrongNumberOfRounds { expected: usize, got: usize },
+    /// No number of queries reaches the target security level, which the commit phase caps at
+    /// `max_bits`.
+    SecurityTargetTooHigh { target_bits: usize, max_bits: usize },
+    /// The proof does not have the shape the parameters require.
+    MalformedProof { reason: String },
+    /// The polynomial has a higher degree than the trusted setup has powers of `τ` for.
+    DegreeAboveSetup { degree: usize, max_degree: usize },
+    /// The pairings of a KZG opening disagree, so the polynomial does not take the claimed value.
+    PairingCheckFailed,
+    /// The commitment an IPA opening accumulates does not open to its last coefficient, so the
+    /// polynomial does not take the claimed value.
+    InnerProductCheckFailed,
 }
 
 impl fmt::Display for FriError {
@@ -30,6 +74,68 @@ impl fmt::Display for FriError {
                 "Inconsistent folding at layer {}: expected {}, got {}",
                 layer, expected, got
             ),
+            FriError::LastLayerInterpolationError(msg) => {
+                write!(f, "Last layer interpolation failed: {}", msg)
+            }
+            FriError::DomainNotPowerOfTwo(size) => {
+                write!(f, "Evaluation domain size {} is not a power of 2", size)
+            }
+            FriError::BlowupFactorTooSmall(blowup) => {
+                write!(f, "Blowup factor {} is below the minimum of 2", blowup)
+            }
+            FriError::NoQueries => write!(f, "The number of queries must be positive"),
+            FriError::DomainTooLarge {
+                log_size,
+                two_adicity,
+            } => write!(
+                f,
+                "Evaluation domain of size 2^{} exceeds the field's two-adicity {}",
+                log_size, two_adicity
+            ),
+            FriError::ZeroOffset => write!(f, "The coset offset must be non-zero"),
+            FriError::WrongNumberOfDecommitments { expected, got } => {
+                write!(f, "Expected {} query decommitments, got {}", expected, got)
+            }
+            FriError::EvaluationPointInDomain => {
+                write!(f, "The evaluation point must lie outside the domain")
+            }
+            FriError::InconsistentQuotient { index } => write!(
+                f,
+                "The polynomial opening at index {} is inconsistent with the quotient",
+                index
+            ),
+            FriError::LastLayerDegreeTooHigh { degree, bound } => write!(
+                f,
+                "Last layer polynomial has degree {}, above the bound {}",
+                degree, bound
+            ),
+            FriError::EvaluationError(msg) => write!(f, "Evaluation on the domain failed: {}", msg),
+            FriError::ChannelError(msg) => write!(f, "Channel error: {}", msg),
+            FriError::UnexpectedMessage { expected } => {
+                write!(f, "Expected a {} message from the other party", expected)
+            }
+            FriError::InvalidFoldingFactor(k) => {
+                write!(f, "Folding factor {} is not valid for these domains", k)
+            }
+            FriError::WrongNumberOfRounds { expected, got } => {
+                write!(f, "Expected {} rounds in the proof, got {}", expected, got)
+            }
+            FriError::SecurityTargetTooHigh {
+                target_bits,
+                max_bits,
+            } => write!(
+                f,
+                "A target of {} bits exceeds the {} bits the field allows over this domain",
+                target_bits, max_bits
+            ),
+            FriError::MalformedProof { reason } => write!(f, "Malformed proof: {}", reason),
+            FriError::DegreeAboveSetup { degree, max_degree } => write!(
+                f,
+                "Polynomial of degree {} exceeds the setup's maximum degree {}",
+                degree, max_degree
+            ),
+            FriError::PairingCheckFailed => write!(f, "KZG pairing check failed"),
+            FriError::InnerProductCheckFailed => write!(f, "IPA inner-product check failed"),
         }
     }
 }
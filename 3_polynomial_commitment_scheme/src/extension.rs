@@ -0,0 +1,616 @@
+use lambdaworks_crypto::merkle_tree::merkle::MerkleTree;
+use lambdaworks_math::fft::cpu::bit_reversing::reverse_index;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::quartic_babybear::Degree4BabyBearExtensionField;
+use lambdaworks_math::polynomial::Polynomial;
+use lambdaworks_math::traits::AsBytes;
+use tracing::{debug, info, info_span};
+
+use crate::challenges::FriChallenger;
+use crate::error::FriError;
+use crate::prover::Prover;
+use crate::trace::ProtocolEvent;
+use crate::types::{
+    build_cap_trees, cap_multi_proof, verify_cap_multi_proof, DomainSeparator, FriLayer,
+    FriParameters, SecurityReport,
+};
+use crate::verifier::Verifier;
+use crate::{FriBackend, FE};
+
+/// The label absorbed after the parameters, which keeps these transcripts apart from those of
+/// base-field challenges.
+const EXTENSION_LABEL: &[u8] = b"challenges in GF(p^4)";
+
+/// An element `a0 + a1 u + a2 u^2 + a3 u^3` of Babybear's quartic extension
+/// `GF(p^4) = GF(p)[u] / (u^4 - 11)`, as lambdaworks implements it, with its components in
+/// ascending order of power. `u^4 - 11` is irreducible because 11 is not a square modulo `p` and
+/// `p = 1 mod 4`.
+pub type Fp4 = FieldElement<Degree4BabyBearExtensionField>;
+
+/// Returns the hex representatives of the components, as the other field elements of a trace are
+/// written.
+fn to_hex(y: &Fp4) -> String {
+    let components: Vec<String> = y.value().iter().map(ProtocolEvent::element).collect();
+    format!("[{}]", components.join(", "))
+}
+
+/// Returns the canonical bytes of the components, in order.
+fn as_bytes(y: &Fp4) -> Vec<u8> {
+    y.value().iter().flat_map(AsBytes::as_bytes).collect()
+}
+
+/// Samples an element from the transcript, one component at a time, so that it carries four
+/// times the bits of a base-field challenge.
+fn sample(challenger: &mut FriChallenger) -> Fp4 {
+    Fp4::new(std::array::from_fn(|_| challenger.sample_element()))
+}
+
+impl FriParameters {
+    /// Estimates the soundness of a proof with these parameters and folding challenges drawn from
+    /// the quartic extension, which lifts the commit phase's bound to about
+    /// `4 log2|F| - log2(n)` bits and leaves the queries to set the security level.
+    pub fn extension_security_report(&self) -> SecurityReport {
+        let base = self.security_report();
+        self.security_report_for_challenges(4.0 * base.field_bits)
+    }
+}
+
+/// The decommitment of a query to a proof with extension challenges. The Merkle proofs of all
+/// queries are in the proof's [`ExtensionFriProof::layer_multi_proofs`].
+#[derive(Debug, Clone)]
+pub struct ExtensionDecommitment {
+    /// The evaluation at the query index in the first layer, the polynomial's own in the base
+    /// field.
+    pub first_layer_evaluation: FE,
+    /// The evaluation at the symmetric index in the first layer.
+    pub first_layer_evaluation_sym: FE,
+    /// The evaluation at the query index of each committed folded layer, from layer 1 on.
+    pub layer_evaluations: Vec<Fp4>,
+    /// The evaluation at the symmetric index of each committed folded layer.
+    pub layer_evaluations_sym: Vec<Fp4>,
+}
+
+/// A FRI proof whose folding challenges are drawn from the quartic extension.
+///
+/// The polynomial, its domain and its first layer stay in the base field; every layer folded
+/// with an extension challenge, and so the last layer's coefficients, lie in the extension.
+#[derive(Debug, Clone)]
+pub struct ExtensionFriProof {
+    /// The Merkle cap of each committed layer, starting with the base-field first layer.
+    pub layer_commitments: Vec<Vec<[u8; 32]>>,
+    /// The coefficients of the last layer's polynomial.
+    pub last_layer_coefficients: Vec<Fp4>,
+    /// The decommitments for each distinct query index, in ascending order of index.
+    pub query_decommitments: Vec<ExtensionDecommitment>,
+    /// The Merkle multi-proof of each committed layer, for the leaves the queries open in it, as
+    /// in [`crate::types::FriProof::layer_multi_proofs`].
+    pub layer_multi_proofs: Vec<Vec<[u8; 32]>>,
+}
+
+impl ExtensionFriProof {
+    /// Checks that the proof has the shape `params` give it, as
+    /// [`crate::types::FriProof::validate_shape`] does for base-field challenges.
+    ///
+    /// # Errors
+    /// Returns [`FriError::MalformedProof`] naming the first part out of shape.
+    pub fn validate_shape(&self, params: &FriParameters) -> Result<(), FriError> {
+        let malformed = |reason: String| Err(FriError::MalformedProof { reason });
+        let num_layers = params.num_folding_rounds().max(1);
+        if self.layer_commitments.len() != num_layers {
+            return malformed(format!(
+                "{} layer commitments, expected {}",
+                self.layer_commitments.len(),
+                num_layers
+            ));
+        }
+        if self.layer_multi_proofs.len() != num_layers {
+            return malformed(format!(
+                "{} layer multi-proofs, expected {}",
+                self.layer_multi_proofs.len(),
+                num_layers
+            ));
+        }
+        if self.query_decommitments.len() > params.num_queries {
+            return malformed(format!(
+                "{} query decommitments for {} queries",
+                self.query_decommitments.len(),
+                params.num_queries
+            ));
+        }
+        for (q, decommitment) in self.query_decommitments.iter().enumerate() {
+            let lengths = [
+                decommitment.layer_evaluations.len(),
+                decommitment.layer_evaluations_sym.len(),
+            ];
+            if lengths.iter().any(|&len| len != num_layers - 1) {
+                return malformed(format!(
+                    "decommitment {} opens {:?} folded layers, expected {}",
+                    q,
+                    lengths,
+                    num_layers - 1
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A committed layer folded with an extension challenge.
+struct ExtensionLayer {
+    evaluations: Vec<Fp4>,
+    merkle_trees: Vec<MerkleTree<FriBackend>>,
+}
+
+impl ExtensionLayer {
+    /// Commits to `evaluations`, in bit-reversed order, with a Merkle cap of `2^cap_height`
+    /// nodes. Leaf `k` holds the components of `evaluations[2k]` followed by those of
+    /// `evaluations[2k + 1]`.
+    fn new(evaluations: Vec<Fp4>, cap_height: usize, layer: usize) -> Result<Self, FriError> {
+        let leaves: Vec<Vec<FE>> = evaluations
+            .chunks(2)
+            .map(|pair| leaf(&pair[0], &pair[1]))
+            .collect();
+        let merkle_trees = build_cap_trees(&leaves, cap_height).ok_or_else(|| {
+            FriError::MerkleTreeConstructionError(format!(
+                "Failed to build Merkle tree for layer {}",
+                layer
+            ))
+        })?;
+        Ok(Self {
+            evaluations,
+            merkle_trees,
+        })
+    }
+
+    fn cap(&self) -> Vec<[u8; 32]> {
+        self.merkle_trees.iter().map(|tree| tree.root).collect()
+    }
+
+    fn multi_proof(&self, leaf_indices: &[usize]) -> Vec<[u8; 32]> {
+        cap_multi_proof(&self.merkle_trees, self.evaluations.len() / 2, leaf_indices)
+    }
+}
+
+/// The Prover of FRI with folding challenges drawn from the quartic extension.
+pub struct ExtensionProver {
+    inner: Prover,
+    params: FriParameters,
+    challenger: FriChallenger,
+}
+
+impl ExtensionProver {
+    /// Creates a new Prover, under the crate's default [`DomainSeparator`].
+    pub fn new(poly: Polynomial<FE>, params: FriParameters) -> Self {
+        Self::with_domain_separator(poly, params, DomainSeparator::default())
+    }
+
+    /// Creates a new Prover whose transcripts start from `separator`.
+    pub fn with_domain_separator(
+        poly: Polynomial<FE>,
+        params: FriParameters,
+        separator: DomainSeparator,
+    ) -> Self {
+        let mut challenger = FriChallenger::new(&separator, &params);
+        challenger.absorb_bytes(EXTENSION_LABEL);
+        Self {
+            inner: Prover::with_domain_separator(poly, params.clone(), separator),
+            params,
+            challenger,
+        }
+    }
+
+    /// Generates the proof.
+    ///
+    /// The first layer is committed in the base field as usual. Each fold then combines
+    /// `f(x)` and `f(-x)` with a challenge `beta` in the extension,
+    /// `(f(x) + f(-x)) / 2 + beta (f(x) - f(-x)) / (2x)`, so every later layer lies in the
+    /// extension while the domain points `x` stay in the base field.
+    pub fn prove(&mut self) -> Result<ExtensionFriProof, FriError> {
+        let _span = info_span!("prove_extension").entered();
+        info!("--- Prover: Starting proof generation with extension challenges ---");
+        let first_layer = self.inner.evaluation_layer()?;
+        self.challenger.absorb_commitment(&first_layer.cap());
+        let mut layer_commitments = vec![first_layer.cap()];
+
+        let num_rounds = self.params.num_folding_rounds();
+        let mut evaluations: Vec<Fp4> = first_layer
+            .evaluations
+            .iter()
+            .map(|y| y.to_extension())
+            .collect();
+        let mut domain = first_layer.domain.clone();
+        let mut layers: Vec<ExtensionLayer> = Vec::new();
+        for i in 0..num_rounds {
+            let beta = sample(&mut self.challenger);
+            debug!(
+                "  > Round {}: Sampled challenge beta = {}",
+                i,
+                to_hex(&beta)
+            );
+            (evaluations, domain) = fold_evaluations(&evaluations, &domain, &beta);
+            if i + 1 == num_rounds {
+                break;
+            }
+            let layer = ExtensionLayer::new(evaluations, self.params.cap_height(i + 1), i + 1)?;
+            self.challenger.absorb_commitment(&layer.cap());
+            layer_commitments.push(layer.cap());
+            evaluations = layer.evaluations.clone();
+            layers.push(layer);
+        }
+
+        let last_layer_coefficients = interpolate_last_layer(&evaluations, &domain)?;
+        for coefficient in &last_layer_coefficients {
+            self.challenger.absorb_bytes(&as_bytes(coefficient));
+        }
+
+        let query_indices = self
+            .challenger
+            .sample_query_indices(self.params.num_queries, self.params.domain.len());
+        debug!("  > Sampled query indices: {:?}", query_indices);
+        let query_decommitments = query_indices
+            .iter()
+            .map(|&query| decommit(&first_layer, &layers, query))
+            .collect();
+        // Queries that meet at a layer open the same leaf, which the multi-proof covers once.
+        let layer_multi_proofs = (0..=layers.len())
+            .map(|i| {
+                let leaf_indices = leaf_indices(&query_indices, i);
+                match i {
+                    0 => first_layer.multi_proof(&leaf_indices),
+                    _ => layers[i - 1].multi_proof(&leaf_indices),
+                }
+            })
+            .collect();
+
+        info!("--- Prover: Proof generation complete ---");
+        Ok(ExtensionFriProof {
+            layer_commitments,
+            last_layer_coefficients,
+            query_decommitments,
+            layer_multi_proofs,
+        })
+    }
+}
+
+/// The Verifier of FRI with folding challenges drawn from the quartic extension.
+pub struct ExtensionVerifier {
+    params: FriParameters,
+    challenger: FriChallenger,
+}
+
+impl ExtensionVerifier {
+    /// Creates a new Verifier, under the crate's default [`DomainSeparator`].
+    pub fn new(params: FriParameters) -> Self {
+        Self::with_domain_separator(params, DomainSeparator::default())
+    }
+
+    /// Creates a new Verifier that only accepts proofs made under `separator`.
+    pub fn with_domain_separator(params: FriParameters, separator: DomainSeparator) -> Self {
+        let mut challenger = FriChallenger::new(&separator, &params);
+        challenger.absorb_bytes(EXTENSION_LABEL);
+        Self { params, challenger }
+    }
+
+    /// Verifies the proof, replaying the extension challenges from its commitments.
+    pub fn verify(&mut self, proof: &ExtensionFriProof) -> Result<(), FriError> {
+        let _span = info_span!("verify_extension").entered();
+        info!("--- Verifier: Starting verification with extension challenges ---");
+        proof.validate_shape(&self.params)?;
+
+        self.challenger
+            .absorb_commitment(&proof.layer_commitments[0]);
+        let betas: Vec<Fp4> = (0..self.params.num_folding_rounds())
+            .map(|i| {
+                let beta = sample(&mut self.challenger);
+                if let Some(cap) = proof.layer_commitments.get(i + 1) {
+                    self.challenger.absorb_commitment(cap);
+                }
+                beta
+            })
+            .collect();
+        for coefficient in &proof.last_layer_coefficients {
+            self.challenger.absorb_bytes(&as_bytes(coefficient));
+        }
+        let query_indices = self
+            .challenger
+            .sample_query_indices(self.params.num_queries, self.params.domain.len());
+
+        let bound = self.params.last_layer_degree();
+        let num_coefficients = proof
+            .last_layer_coefficients
+            .iter()
+            .rposition(|c| *c != Fp4::zero())
+            .map_or(0, |i| i + 1);
+        if num_coefficients > bound + 1 {
+            return Err(FriError::LastLayerDegreeTooHigh {
+                degree: num_coefficients - 1,
+                bound,
+            });
+        }
+        if proof.query_decommitments.len() != query_indices.len() {
+            return Err(FriError::WrongNumberOfDecommitments {
+                expected: query_indices.len(),
+                got: proof.query_decommitments.len(),
+            });
+        }
+        for layer in 0..proof.layer_commitments.len() {
+            self.verify_multi_proof(proof, layer, &query_indices)?;
+        }
+        for (&query, decommitment) in query_indices.iter().zip(&proof.query_decommitments) {
+            self.verify_query(proof, &betas, query, decommitment)?;
+            debug!("  > Query at index {} is consistent", query);
+        }
+
+        info!("--- Verifier: Proof accepted ---");
+        Ok(())
+    }
+
+    /// Checks the leaves every query opens in layer `layer` against the layer's cap, with the
+    /// layer's multi-proof.
+    fn verify_multi_proof(
+        &self,
+        proof: &ExtensionFriProof,
+        layer: usize,
+        query_indices: &[usize],
+    ) -> Result<(), FriError> {
+        let mut leaves: Vec<(usize, Vec<FE>)> = query_indices
+            .iter()
+            .zip(&proof.query_decommitments)
+            .map(|(&query, decommitment)| {
+                let idx = query >> layer;
+                // The first layer's leaves hold base-field elements, the others' components.
+                if layer == 0 {
+                    let (leaf_idx, leaf) = <Verifier>::leaf(
+                        idx,
+                        decommitment.first_layer_evaluation,
+                        decommitment.first_layer_evaluation_sym,
+                    );
+                    return (leaf_idx, leaf.to_vec());
+                }
+                let y = &decommitment.layer_evaluations[layer - 1];
+                let y_sym = &decommitment.layer_evaluations_sym[layer - 1];
+                match idx % 2 {
+                    0 => (idx / 2, leaf(y, y_sym)),
+                    _ => (idx / 2, leaf(y_sym, y)),
+                }
+            })
+            .collect();
+        // Queries that meet at this layer must open the same leaf.
+        leaves.sort_by_key(|(leaf_idx, _)| *leaf_idx);
+        leaves.dedup();
+        if leaves.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(FriError::InvalidMerkleProof);
+        }
+        verify_cap_multi_proof(
+            &proof.layer_commitments[layer],
+            self.params.cap_height(layer),
+            (self.params.domain.len() >> layer) / 2,
+            &leaves,
+            &proof.layer_multi_proofs[layer],
+        )?;
+        Ok(())
+    }
+
+    /// Checks every fold of the query at original index `query`, from the first layer down to the
+    /// last layer's polynomial; the openings themselves are checked by
+    /// [`ExtensionVerifier::verify_multi_proof`].
+    fn verify_query(
+        &self,
+        proof: &ExtensionFriProof,
+        betas: &[Fp4],
+        query: usize,
+        decommitment: &ExtensionDecommitment,
+    ) -> Result<(), FriError> {
+        // The value and its symmetric one at each layer, starting with the first.
+        let pairs: Vec<(Fp4, Fp4)> = std::iter::once((
+            decommitment.first_layer_evaluation.to_extension(),
+            decommitment.first_layer_evaluation_sym.to_extension(),
+        ))
+        .chain(
+            decommitment
+                .layer_evaluations
+                .iter()
+                .cloned()
+                .zip(decommitment.layer_evaluations_sym.iter().cloned()),
+        )
+        .collect();
+
+        // Each fold must land on the next layer's value, and the last one on the last layer's
+        // polynomial. Without any folding, the first layer is itself the last one.
+        let num_rounds = betas.len();
+        let last_value = evaluate(
+            &proof.last_layer_coefficients,
+            &self.domain_point(num_rounds, query),
+        );
+        for i in 0..num_rounds.max(1) {
+            let (y, y_sym) = &pairs[i];
+            let (expected, got) = if num_rounds == 0 {
+                (last_value.clone(), y.clone())
+            } else {
+                let x = self.domain_point(i, query);
+                let child = pairs.get(i + 1).map_or(&last_value, |pair| &pair.0);
+                (fold(y, y_sym, &x, &betas[i]), child.clone())
+            };
+            if expected != got {
+                return Err(FriError::InconsistentFolding {
+                    layer: i,
+                    expected: to_hex(&expected),
+                    got: to_hex(&got),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the point of layer `layer`'s domain at which the query for the original index
+    /// `query` lands: the `2^layer`-th power of the first domain's point at the natural index
+    /// that the query's position `query >> layer` stands for in the layer's bit-reversed order.
+    fn domain_point(&self, layer: usize, query: usize) -> FE {
+        let layer_size = self.params.domain.len() >> layer;
+        let natural_idx = reverse_index(query >> layer, layer_size as u64);
+        self.params.domain[natural_idx].pow(1_u64 << layer)
+    }
+}
+
+/// Folds `f(x)` and `f(-x)` into `(f(x) + f(-x)) / 2 + beta (f(x) - f(-x)) / (2x)`.
+fn fold(y: &Fp4, y_sym: &Fp4, x: &FE, beta: &Fp4) -> Fp4 {
+    let two_inv = FE::from(2).inv().unwrap();
+    let f_even = (y + y_sym) * two_inv.to_extension();
+    let f_odd = (y - y_sym) * (two_inv * x.inv().unwrap()).to_extension();
+    f_even + beta * f_odd
+}
+
+/// Folds a layer's evaluations with `beta`, returning the next layer's evaluations and domain,
+/// all in bit-reversed order.
+fn fold_evaluations(evaluations: &[Fp4], domain: &[FE], beta: &Fp4) -> (Vec<Fp4>, Vec<FE>) {
+    evaluations
+        .chunks(2)
+        .zip(domain.iter().step_by(2))
+        .map(|(pair, x)| (fold(&pair[0], &pair[1], x, beta), x.square()))
+        .unzip()
+}
+
+/// Recovers the last layer's polynomial component by component, each an inverse FFT over the
+/// base-field coset, and drops its trailing zero coefficients.
+fn interpolate_last_layer(evaluations: &[Fp4], domain: &[FE]) -> Result<Vec<Fp4>, FriError> {
+    let mut components = Vec::with_capacity(4);
+    for c in 0..4 {
+        let values: Vec<FE> = evaluations.iter().map(|y| y.value()[c]).collect();
+        components.push(<Prover>::interpolate_last_layer(&values, domain)?.coefficients);
+    }
+    let mut coefficients: Vec<Fp4> = (0..evaluations.len())
+        .map(|k| {
+            Fp4::new(std::array::from_fn(|c| {
+                components[c].get(k).copied().unwrap_or_else(FE::zero)
+            }))
+        })
+        .collect();
+    while coefficients.last() == Some(&Fp4::zero()) {
+        coefficients.pop();
+    }
+    Ok(coefficients)
+}
+
+/// Evaluates the polynomial of extension `coefficients` at a base-field point, by Horner's rule.
+fn evaluate(coefficients: &[Fp4], x: &FE) -> Fp4 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Fp4::zero(), |acc, c| acc * x.to_extension() + c)
+}
+
+/// Returns the decommitment of the query at original index `query`.
+fn decommit(
+    first_layer: &FriLayer,
+    layers: &[ExtensionLayer],
+    query: usize,
+) -> ExtensionDecommitment {
+    let (idx, sym_idx) = indices(0, query);
+    let mut decommitment = ExtensionDecommitment {
+        first_layer_evaluation: first_layer.evaluations[idx],
+        first_layer_evaluation_sym: first_layer.evaluations[sym_idx],
+        layer_evaluations: Vec::new(),
+        layer_evaluations_sym: Vec::new(),
+    };
+    for (i, layer) in layers.iter().enumerate() {
+        let (idx, sym_idx) = indices(i + 1, query);
+        decommitment
+            .layer_evaluations
+            .push(layer.evaluations[idx].clone());
+        decommitment
+            .layer_evaluations_sym
+            .push(layer.evaluations[sym_idx].clone());
+    }
+    decommitment
+}
+
+/// Returns the leaves the queries `query_indices` open in layer `layer`, distinct and in
+/// ascending order.
+fn leaf_indices(query_indices: &[usize], layer: usize) -> Vec<usize> {
+    let mut leaf_indices: Vec<usize> = query_indices
+        .iter()
+        .map(|&query| indices(layer, query).0 / 2)
+        .collect();
+    leaf_indices.dedup();
+    leaf_indices
+}
+
+/// Returns the index at which the query for the original index `query` lands in layer `layer`,
+/// and its symmetric index, its neighbour in bit-reversed order.
+fn indices(layer: usize, query: usize) -> (usize, usize) {
+    let idx = query >> layer;
+    (idx, idx ^ 1)
+}
+
+/// Returns the Merkle leaf of `y` and `y_sym`: the components of each, in order.
+fn leaf(y: &Fp4, y_sym: &Fp4) -> Vec<FE> {
+    y.value().iter().chain(y_sym.value()).copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dishonest::high_degree_polynomial;
+    use crate::test_utils::{degree_seven_params, degree_seven_poly};
+
+    #[test]
+    fn extension_arithmetic() {
+        let a = Fp4::new([FE::from(3), FE::from(1), FE::from(4), FE::from(1)]);
+        let b = Fp4::new([FE::from(5), FE::from(9), FE::from(2), FE::from(6)]);
+        assert_eq!(&a * a.inv().unwrap(), Fp4::one());
+        assert_eq!((&a + &b) * &b, &a * &b + &b * &b);
+        // u^4 reduces to 11, the constant of lambdaworks' extension.
+        let u = Fp4::new([FE::zero(), FE::one(), FE::zero(), FE::zero()]);
+        assert_eq!(u.pow(4_u64), FE::from(11).to_extension());
+        assert!(Fp4::zero().inv().is_err());
+    }
+
+    #[test]
+    fn honest_proof_verifies() {
+        let (poly, params) = (
+            degree_seven_poly(),
+            degree_seven_params().with_merkle_cap_height(1),
+        );
+        let proof = ExtensionProver::new(poly, params.clone()).prove().unwrap();
+        // Folding with extension challenges leaves the base field after the first layer.
+        assert!(proof
+            .query_decommitments
+            .iter()
+            .flat_map(|d| &d.layer_evaluations)
+            .any(|y| y.value()[1..].iter().any(|c| *c != FE::zero())));
+        assert_eq!(ExtensionVerifier::new(params).verify(&proof), Ok(()));
+    }
+
+    #[test]
+    fn rejects_a_polynomial_above_the_claimed_degree() {
+        let params = degree_seven_params().with_merkle_cap_height(1);
+        let poly = high_degree_polynomial(&params);
+        let proof = ExtensionProver::new(poly, params.clone()).prove().unwrap();
+        assert!(matches!(
+            ExtensionVerifier::new(params).verify(&proof),
+            Err(FriError::LastLayerDegreeTooHigh { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_a_tampered_folded_evaluation() {
+        let (poly, params) = (
+            degree_seven_poly(),
+            degree_seven_params().with_merkle_cap_height(1),
+        );
+        let mut proof = ExtensionProver::new(poly, params.clone()).prove().unwrap();
+        let mut truncated = proof.clone();
+        truncated.layer_multi_proofs.pop();
+        assert!(matches!(
+            ExtensionVerifier::new(params.clone()).verify(&truncated),
+            Err(FriError::MalformedProof { .. })
+        ));
+
+        let y = &mut proof.query_decommitments[0].layer_evaluations[0];
+        *y = &*y + Fp4::one();
+        assert_eq!(
+            ExtensionVerifier::new(params).verify(&proof),
+            Err(FriError::InvalidMerkleProof)
+        );
+    }
+}
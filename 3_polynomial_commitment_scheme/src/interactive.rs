@@ -0,0 +1,420 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use lambdaworks_math::polynomial::Polynomial;
+use rand::rngs::ThreadRng;
+use rand::Rng;
+use tracing::{debug, info, info_span};
+
+pub use crate::encoding::Message;
+use crate::error::FriError;
+use crate::prover::Prover;
+use crate::types::{FriParameters, FriProof, QueryDecommitment};
+use crate::verifier::Verifier;
+use crate::FE;
+
+/// A two-way link between the Prover and the Verifier of the interactive protocol.
+pub trait Channel {
+    /// Sends `message` to the other party.
+    fn send(&mut self, message: Message) -> Result<(), FriError>;
+    /// Waits for the next message from the other party.
+    fn receive(&mut self) -> Result<Message, FriError>;
+}
+
+/// One end of an in-memory channel, for parties running on two threads of the same process.
+pub struct MemoryChannel {
+    sender: Sender<Message>,
+    receiver: Receiver<Message>,
+}
+
+impl MemoryChannel {
+    /// Creates a channel and returns both of its ends, one for each party.
+    pub fn pair() -> (Self, Self) {
+        let (a_sender, b_receiver) = mpsc::channel();
+        let (b_sender, a_receiver) = mpsc::channel();
+        (
+            Self {
+                sender: a_sender,
+                receiver: a_receiver,
+            },
+            Self {
+                sender: b_sender,
+                receiver: b_receiver,
+            },
+        )
+    }
+}
+
+impl Channel for MemoryChannel {
+    fn send(&mut self, message: Message) -> Result<(), FriError> {
+        self.sender
+            .send(message)
+            .map_err(|_| FriError::ChannelError("the other party hung up".to_string()))
+    }
+
+    fn receive(&mut self) -> Result<Message, FriError> {
+        self.receiver
+            .recv()
+            .map_err(|_| FriError::ChannelError("the other party hung up".to_string()))
+    }
+}
+
+/// A channel over a TCP connection, carrying each message as one [`Message::encode`]d line.
+pub struct TcpChannel {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl TcpChannel {
+    /// Wraps a connected stream.
+    pub fn new(stream: TcpStream) -> Result<Self, FriError> {
+        let writer = stream.try_clone().map_err(io_error)?;
+        Ok(Self {
+            reader: BufReader::new(stream),
+            writer,
+        })
+    }
+}
+
+impl Channel for TcpChannel {
+    fn send(&mut self, message: Message) -> Result<(), FriError> {
+        writeln!(self.writer, "{}", message.encode()).map_err(io_error)
+    }
+
+    fn receive(&mut self) -> Result<Message, FriError> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line).map_err(io_error)? == 0 {
+            return Err(FriError::ChannelError(
+                "the connection was closed".to_string(),
+            ));
+        }
+        Message::decode(&line)
+    }
+}
+
+fn io_error(e: std::io::Error) -> FriError {
+    FriError::ChannelError(e.to_string())
+}
+
+/// The Prover of the interactive protocol, answering a Verifier over a [`Channel`].
+///
+/// It sends the same commitments as a [`Prover`], but waits for each challenge from the Verifier
+/// where that Prover samples it from its transcript. Hashing the transcript in place of the
+/// Verifier is the Fiat-Shamir transformation, which turns this exchange into a single proof.
+pub struct InteractiveProver<C: Channel> {
+    prover: Prover,
+    params: FriParameters,
+    channel: C,
+}
+
+impl<C: Channel> InteractiveProver<C> {
+    /// Creates a Prover of `poly` that talks to the Verifier over `channel`.
+    pub fn new(poly: Polynomial<FE>, params: FriParameters, channel: C) -> Self {
+        Self {
+            prover: Prover::new(poly, params.clone()),
+            params,
+            channel,
+        }
+    }
+
+    /// Runs the protocol to its end.
+    pub fn run(&mut self) -> Result<(), FriError> {
+        let _span = info_span!("interactive_prove").entered();
+        info!("--- Interactive Prover: Starting ---");
+
+        // 1. Commit to the evaluations on the LDE domain.
+        let initial_layer = self.prover.evaluation_layer()?;
+        self.channel
+            .send(Message::Commitment(initial_layer.cap()))?;
+        let mut evaluations = initial_layer.evaluations.clone();
+        let mut domain = initial_layer.domain.clone();
+        let mut layers = vec![initial_layer];
+
+        // 2. Fold with each challenge, committing to every layer but the last.
+        let num_rounds = self.params.num_folding_rounds();
+        for i in 0..num_rounds {
+            let Message::Challenge(beta) = self.channel.receive()? else {
+                return Err(FriError::UnexpectedMessage {
+                    expected: "challenge",
+                });
+            };
+            debug!(
+                "  > Round {}: Received challenge beta = {}",
+                i,
+                beta.representative()
+            );
+            (evaluations, domain) = <Prover>::fold_evaluations(&evaluations, &domain, &beta);
+            if i + 1 == num_rounds {
+                break;
+            }
+            let layer = self
+                .prover
+                .build_layer(evaluations.clone(), domain.clone(), i + 1)?;
+            self.channel.send(Message::Commitment(layer.cap()))?;
+            layers.push(layer);
+        }
+        let last_poly = <Prover>::interpolate_last_layer(&evaluations, &domain)?;
+        self.channel
+            .send(Message::LastLayer(last_poly.coefficients))?;
+
+        // 3. Open every layer at the queried indices.
+        let Message::Queries(query_indices) = self.channel.receive()? else {
+            return Err(FriError::UnexpectedMessage {
+                expected: "queries",
+            });
+        };
+        debug!("  > Received queries at indices: {:?}", query_indices);
+        self.check_queries(&query_indices)?;
+        let mut decommitments = vec![QueryDecommitment::default(); query_indices.len()];
+        let multi_proofs: Vec<Vec<[u8; 32]>> = layers
+            .iter()
+            .enumerate()
+            .map(|(i, layer)| <Prover>::open_layer(layer, i, &query_indices, &mut decommitments))
+            .collect();
+        self.channel.send(Message::Decommitments(decommitments))?;
+        for multi_proof in multi_proofs {
+            self.channel.send(Message::MultiProof(multi_proof))?;
+        }
+
+        info!("--- Interactive Prover: Done ---");
+        Ok(())
+    }
+
+    /// Checks that the Verifier's queries can be opened: the Verifier may be a peer over the
+    /// network, and opening assumes distinct indices of the LDE domain in ascending order.
+    ///
+    /// The honest Verifier drops repeated indices, so there may be fewer than `num_queries`, but
+    /// never more.
+    fn check_queries(&self, query_indices: &[usize]) -> Result<(), FriError> {
+        let malformed = |reason: String| Err(FriError::MalformedProof { reason });
+        if query_indices.is_empty() || query_indices.len() > self.params.num_queries {
+            return malformed(format!(
+                "{} queries, expected between 1 and {}",
+                query_indices.len(),
+                self.params.num_queries
+            ));
+        }
+        let domain_size = self.params.domain.len();
+        if let Some(index) = query_indices.iter().find(|&&index| index >= domain_size) {
+            return malformed(format!(
+                "query index {} outside the domain of size {}",
+                index, domain_size
+            ));
+        }
+        if query_indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return malformed("query indices are not distinct and ascending".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Draws a uniformly random element of Babybear, with no bias towards the lower residues that
+/// reducing a random `u64` would leave.
+fn random_element<R: Rng>(rng: &mut R) -> FE {
+    FE::from(rng.gen_range(0..BABYBEAR_MODULUS))
+}
+
+/// The prime of the Babybear field.
+const BABYBEAR_MODULUS: u64 = 2_013_265_921;
+
+/// The Verifier of the interactive protocol, challenging a Prover over a [`Channel`].
+///
+/// Its challenges and queries are fresh random coins rather than transcript samples; once the
+/// Prover has answered them, it runs the same checks as a [`Verifier`].
+pub struct InteractiveVerifier<C: Channel, R: Rng = ThreadRng> {
+    verifier: Verifier,
+    params: FriParameters,
+    channel: C,
+    rng: R,
+}
+
+impl<C: Channel> InteractiveVerifier<C> {
+    /// Creates a Verifier that talks to the Prover over `channel`, drawing its coins from the
+    /// thread's random number generator.
+    pub fn new(params: FriParameters, channel: C) -> Self {
+        Self {
+            verifier: Verifier::new(params.clone()),
+            params,
+            channel,
+            rng: rand::thread_rng(),
+        }
+    }
+}
+
+impl<C: Channel, R: Rng> InteractiveVerifier<C, R> {
+    /// Draws the Verifier's coins from `rng` instead.
+    pub fn with_rng<S: Rng>(self, rng: S) -> InteractiveVerifier<C, S> {
+        InteractiveVerifier {
+            verifier: self.verifier,
+            params: self.params,
+            channel: self.channel,
+            rng,
+        }
+    }
+
+    /// Runs the protocol to its end, accepting if the Prover's answers pass every check.
+    pub fn run(&mut self) -> Result<(), FriError> {
+        let _span = info_span!("interactive_verify").entered();
+        info!("--- Interactive Verifier: Starting ---");
+
+        // 1. Receive each commitment, answering all but the last with a random challenge.
+        let num_rounds = self.params.num_folding_rounds();
+        let mut layer_commitments = vec![self.receive_commitment()?];
+        let mut betas = Vec::with_capacity(num_rounds);
+        for i in 0..num_rounds {
+            let beta = random_element(&mut self.rng);
+            debug!(
+                "  > Round {}: Sent challenge beta = {}",
+                i,
+                beta.representative()
+            );
+            self.channel.send(Message::Challenge(beta))?;
+            betas.push(beta);
+            if i + 1 < num_rounds {
+                layer_commitments.push(self.receive_commitment()?);
+            }
+        }
+        let Message::LastLayer(last_layer_coefficients) = self.channel.receive()? else {
+            return Err(FriError::UnexpectedMessage {
+                expected: "last layer",
+            });
+        };
+
+        // 2. Query random indices of the LDE domain.
+        let domain_size = self.params.domain.len() as u64;
+        let mut query_indices: Vec<usize> = (0..self.params.num_queries)
+            .map(|_| self.rng.gen_range(0..domain_size) as usize)
+            .collect();
+        query_indices.sort_unstable();
+        query_indices.dedup();
+        self.channel.send(Message::Queries(query_indices.clone()))?;
+        let Message::Decommitments(query_decommitments) = self.channel.receive()? else {
+            return Err(FriError::UnexpectedMessage {
+                expected: "decommitments",
+            });
+        };
+        let layer_multi_proofs = (0..layer_commitments.len())
+            .map(|_| match self.channel.receive()? {
+                Message::MultiProof(nodes) => Ok(nodes),
+                _ => Err(FriError::UnexpectedMessage {
+                    expected: "multi-proof",
+                }),
+            })
+            .collect::<Result<_, _>>()?;
+
+        // 3. Check the answers as they would be checked in a proof.
+        let proof = FriProof {
+            layer_commitments,
+            last_layer_coefficients,
+            query_decommitments,
+            layer_multi_proofs,
+        };
+        self.verifier
+            .check_queries(&proof, &betas, &query_indices)?;
+        info!("--- Interactive Verifier: Accepted ---");
+        Ok(())
+    }
+
+    fn receive_commitment(&mut self) -> Result<Vec<[u8; 32]>, FriError> {
+        match self.channel.receive()? {
+            Message::Commitment(cap) => Ok(cap),
+            _ => Err(FriError::UnexpectedMessage {
+                expected: "commitment",
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::thread;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::test_utils::{degree_seven_poly, demo_params, demo_poly};
+
+    #[test]
+    fn honest_prover_convinces_the_verifier_in_memory() {
+        let (poly, params) = (demo_poly(), demo_params().with_merkle_cap_height(1));
+        let (prover_end, verifier_end) = MemoryChannel::pair();
+
+        let prover_params = params.clone();
+        let prover =
+            thread::spawn(move || InteractiveProver::new(poly, prover_params, prover_end).run());
+        let verdict = InteractiveVerifier::new(params, verifier_end)
+            .with_rng(StdRng::seed_from_u64(7))
+            .run();
+
+        assert_eq!(prover.join().unwrap(), Ok(()));
+        assert_eq!(verdict, Ok(()));
+    }
+
+    #[test]
+    fn honest_prover_convinces_the_verifier_over_tcp() {
+        let (poly, params) = (demo_poly(), demo_params().with_merkle_cap_height(1));
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let prover_params = params.clone();
+        let prover = thread::spawn(move || {
+            let channel = TcpChannel::new(TcpStream::connect(address).unwrap())?;
+            InteractiveProver::new(poly, prover_params, channel).run()
+        });
+        let (stream, _) = listener.accept().unwrap();
+        let verdict = InteractiveVerifier::new(params, TcpChannel::new(stream).unwrap()).run();
+
+        assert_eq!(prover.join().unwrap(), Ok(()));
+        assert_eq!(verdict, Ok(()));
+    }
+
+    #[test]
+    fn rejects_a_polynomial_above_the_claimed_degree() {
+        let params = demo_params().with_merkle_cap_height(1);
+        let poly = degree_seven_poly();
+        let (prover_end, verifier_end) = MemoryChannel::pair();
+
+        let prover_params = params.clone();
+        let prover =
+            thread::spawn(move || InteractiveProver::new(poly, prover_params, prover_end).run());
+        let verdict = InteractiveVerifier::new(params, verifier_end).run();
+
+        prover.join().unwrap().unwrap();
+        assert!(verdict.is_err());
+    }
+
+    /// Runs an honest Prover against a Verifier that sends `queries`, and returns the Prover's
+    /// outcome.
+    fn answer_queries(queries: Vec<usize>) -> Result<(), FriError> {
+        let (poly, params) = (demo_poly(), demo_params().with_merkle_cap_height(1));
+        let (prover_end, mut verifier_end) = MemoryChannel::pair();
+        for _ in 0..params.num_folding_rounds() {
+            verifier_end.send(Message::Challenge(FE::from(3))).unwrap();
+        }
+        verifier_end.send(Message::Queries(queries)).unwrap();
+        InteractiveProver::new(poly, params, prover_end).run()
+    }
+
+    #[test]
+    fn prover_rejects_queries_it_cannot_open() {
+        let params = demo_params().with_merkle_cap_height(1);
+        let domain_size = params.domain.len();
+        assert_eq!(answer_queries(vec![0, domain_size - 1]), Ok(()));
+
+        for queries in [
+            vec![domain_size],
+            vec![5, 2],
+            vec![2, 2],
+            vec![],
+            vec![0, 1, 2],
+        ] {
+            assert!(matches!(
+                answer_queries(queries),
+                Err(FriError::MalformedProof { .. })
+            ));
+        }
+    }
+}
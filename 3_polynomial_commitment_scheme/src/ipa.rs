@@ -0,0 +1,407 @@
+//! A transparent polynomial commitment from the inner-product argument (IPA) of Bulletproofs, in
+//! the form Halo uses to open polynomials.
+//!
+//! The setup is `n` generators `G_i` of BLS12-381's first group and one more, `U`, all hashed to
+//! the curve, so that no one knows a discrete logarithm between any two and there is no toxic
+//! waste. The commitment to `P` with coefficients `a` is the Pedersen vector commitment
+//! `C = <a, G>`. To prove `P(z) = y = <a, b>` for `b = (1, z, z^2, ...)`, the Prover halves `a`,
+//! `b` and `G` in each of `log n` rounds: it sends the cross terms
+//! `L = <a_lo, G_hi> + <a_lo, b_hi> U` and `R = <a_hi, G_lo> + <a_hi, b_lo> U`, draws a challenge
+//! `u`, and folds `a' = u a_lo + u^-1 a_hi`, `b' = u^-1 b_lo + u b_hi` and
+//! `G' = u^-1 G_lo + u G_hi`. Each round keeps `C + y U + Σ (u^2 L + u^-2 R) = <a', G'> + <a', b'>
+//! U`, so after the last one the Prover only sends the coefficient left.
+//!
+//! The opening holds `2 log n` points, between KZG's single point and FRI's Merkle paths, but the
+//! Verifier must fold all `n` generators itself: its work is linear in the degree, where KZG's is
+//! a pairing check and FRI's is polylogarithmic.
+
+use lambdaworks_crypto::fiat_shamir::default_transcript::DefaultTranscript;
+use lambdaworks_math::cyclic_group::IsGroup;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::{
+    BLS12381Curve, BLS12381FieldElement,
+};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement;
+use lambdaworks_math::elliptic_curve::traits::IsEllipticCurve;
+use lambdaworks_math::polynomial::Polynomial;
+use lambdaworks_math::traits::ByteConversion;
+
+use crate::challenges::FriChallenger;
+use crate::error::FriError;
+use crate::kzg::{G1Point, G1_POINT_BYTES, SCALAR_BYTES};
+
+/// The label the generators are hashed from.
+const GENERATORS_LABEL: &[u8] = b"Educational IPA generators";
+
+/// The label that starts the transcript of every opening.
+const TRANSCRIPT_LABEL: &[u8] = b"Educational IPA";
+
+/// The cofactor of BLS12-381's first group, which takes a point of the curve into the subgroup of
+/// prime order.
+const G1_COFACTOR: u128 = 0x396c_8c00_5555_e156_8c00_aaab_0000_aaab;
+
+/// A proof that the committed polynomial evaluates to `value` at a point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpaOpening {
+    /// The claimed evaluation `y = P(z)`.
+    pub value: FrElement,
+    /// The cross term `L` of each round.
+    pub left: Vec<G1Point>,
+    /// The cross term `R` of each round.
+    pub right: Vec<G1Point>,
+    /// The single coefficient left after the last round.
+    pub coefficient: FrElement,
+}
+
+impl IpaOpening {
+    /// Returns the size of the opening in bytes, with its points compressed.
+    pub fn size_in_bytes(&self) -> usize {
+        (self.left.len() + self.right.len()) * G1_POINT_BYTES + 2 * SCALAR_BYTES
+    }
+}
+
+/// The IPA scheme under a transparent setup, which commits to, opens and verifies polynomials of
+/// degree at most the setup's.
+pub struct Ipa {
+    generators: Vec<G1Point>,
+    u: G1Point,
+}
+
+impl Ipa {
+    /// Creates the scheme for polynomials of degree at most `max_degree`, which it rounds up so
+    /// that the number of coefficients is a power of two.
+    ///
+    /// The generators are drawn one after the other from the same transcript, so a larger setup
+    /// extends a smaller one and their commitments agree.
+    pub fn new(max_degree: usize) -> Self {
+        let n = (max_degree + 1).next_power_of_two();
+        let mut challenger =
+            FriChallenger::from_transcript(DefaultTranscript::new(GENERATORS_LABEL));
+        let u = hash_to_curve(&mut challenger);
+        let generators = (0..n).map(|_| hash_to_curve(&mut challenger)).collect();
+        Self { generators, u }
+    }
+
+    /// Returns the highest degree the setup commits to.
+    pub fn max_degree(&self) -> usize {
+        self.generators.len() - 1
+    }
+
+    /// Commits to `poly`.
+    ///
+    /// # Errors
+    /// Returns [`FriError::DegreeAboveSetup`] if `poly` has more coefficients than the setup
+    /// has generators.
+    pub fn commit(&self, poly: &Polynomial<FrElement>) -> Result<G1Point, FriError> {
+        self.check_degree(poly)?;
+        Ok(multi_scalar_mul(&poly.coefficients, &self.generators))
+    }
+
+    /// Proves the evaluation of `poly` at `z`.
+    ///
+    /// # Errors
+    /// Returns [`FriError::DegreeAboveSetup`] as [`Ipa::commit`] does.
+    pub fn open(
+        &self,
+        poly: &Polynomial<FrElement>,
+        z: &FrElement,
+    ) -> Result<IpaOpening, FriError> {
+        let commitment = self.commit(poly)?;
+        let mut a = poly.coefficients.clone();
+        a.resize(self.generators.len(), FrElement::zero());
+        let mut b = powers(z, a.len());
+        let mut g = self.generators.clone();
+        let value = inner_product(&a, &b);
+
+        let mut challenger = Self::challenger(&commitment, z, &value);
+        let u = self
+            .u
+            .operate_with_self(sample_scalar(&mut challenger).representative());
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (g_lo, g_hi) = g.split_at(half);
+            let l = multi_scalar_mul(a_lo, g_hi)
+                .operate_with(&u.operate_with_self(inner_product(a_lo, b_hi).representative()));
+            let r = multi_scalar_mul(a_hi, g_lo)
+                .operate_with(&u.operate_with_self(inner_product(a_hi, b_lo).representative()));
+
+            let (x, x_inv) = Self::round_challenge(&mut challenger, &l, &r);
+            a = fold_scalars(a_lo, a_hi, &x, &x_inv);
+            b = fold_scalars(b_lo, b_hi, &x_inv, &x);
+            g = fold_points(g_lo, g_hi, &x_inv, &x);
+            left.push(l);
+            right.push(r);
+        }
+
+        Ok(IpaOpening {
+            value,
+            left,
+            right,
+            coefficient: a[0],
+        })
+    }
+
+    /// Verifies that the polynomial committed to by `commitment` evaluates to `opening.value` at
+    /// `z`.
+    ///
+    /// The Verifier folds `b` and the generators as the Prover did, which takes a linear number of
+    /// scalar multiplications, and checks that the commitment it accumulates opens to the last
+    /// coefficient.
+    ///
+    /// # Errors
+    /// Returns [`FriError::WrongNumberOfRounds`] if the opening does not hold one `L` and one `R`
+    /// per halving of the generators, and [`FriError::InnerProductCheckFailed`] if the final
+    /// check fails.
+    pub fn verify(
+        &self,
+        commitment: &G1Point,
+        z: &FrElement,
+        opening: &IpaOpening,
+    ) -> Result<(), FriError> {
+        let rounds = self.generators.len().trailing_zeros() as usize;
+        for got in [opening.left.len(), opening.right.len()] {
+            if got != rounds {
+                return Err(FriError::WrongNumberOfRounds {
+                    expected: rounds,
+                    got,
+                });
+            }
+        }
+
+        let mut challenger = Self::challenger(commitment, z, &opening.value);
+        let u = self
+            .u
+            .operate_with_self(sample_scalar(&mut challenger).representative());
+        // P = C + y U, to which each round adds its cross terms.
+        let mut p = commitment.operate_with(&u.operate_with_self(opening.value.representative()));
+        let mut b = powers(z, self.generators.len());
+        let mut g = self.generators.clone();
+        for (l, r) in opening.left.iter().zip(&opening.right) {
+            let (x, x_inv) = Self::round_challenge(&mut challenger, l, r);
+            p = p
+                .operate_with(&l.operate_with_self(x.square().representative()))
+                .operate_with(&r.operate_with_self(x_inv.square().representative()));
+            let half = b.len() / 2;
+            b = fold_scalars(&b[..half], &b[half..], &x_inv, &x);
+            g = fold_points(&g[..half], &g[half..], &x_inv, &x);
+        }
+
+        let a = &opening.coefficient;
+        let expected = g[0]
+            .operate_with_self(a.representative())
+            .operate_with(&u.operate_with_self((a * b[0]).representative()));
+        if p == expected {
+            Ok(())
+        } else {
+            Err(FriError::InnerProductCheckFailed)
+        }
+    }
+
+    /// Starts the transcript of an opening of `commitment` to `value` at `z`.
+    fn challenger(commitment: &G1Point, z: &FrElement, value: &FrElement) -> FriChallenger {
+        let mut challenger =
+            FriChallenger::from_transcript(DefaultTranscript::new(TRANSCRIPT_LABEL));
+        challenger.absorb_bytes(&point_bytes(commitment));
+        challenger.absorb_bytes(&z.to_bytes_be());
+        challenger.absorb_bytes(&value.to_bytes_be());
+        challenger
+    }
+
+    /// Absorbs the cross terms of a round and draws its challenge `u`, returned with its inverse.
+    fn round_challenge(
+        challenger: &mut FriChallenger,
+        l: &G1Point,
+        r: &G1Point,
+    ) -> (FrElement, FrElement) {
+        challenger.absorb_bytes(&point_bytes(l));
+        challenger.absorb_bytes(&point_bytes(r));
+        let x = sample_scalar(challenger);
+        let x_inv = x.inv().unwrap();
+        (x, x_inv)
+    }
+
+    fn check_degree(&self, poly: &Polynomial<FrElement>) -> Result<(), FriError> {
+        if poly.coefficients.len() > self.generators.len() {
+            return Err(FriError::DegreeAboveSetup {
+                degree: poly.degree(),
+                max_degree: self.max_degree(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Hashes the next samples of `challenger` to a point of the prime-order subgroup, trying each as
+/// an x-coordinate until `x^3 + 4` has a square root.
+fn hash_to_curve(challenger: &mut FriChallenger) -> G1Point {
+    loop {
+        let x = BLS12381FieldElement::from_hex(&hex::encode(challenger.sample_bytes())).unwrap();
+        let Some((y, _)) = (x.pow(3_u64) + BLS12381FieldElement::from(4)).sqrt() else {
+            continue;
+        };
+        let point = BLS12381Curve::create_point_from_affine(x, y)
+            .unwrap()
+            .operate_with_self(G1_COFACTOR);
+        if !point.is_neutral_element() {
+            return point;
+        }
+    }
+}
+
+/// Samples a nonzero scalar from 31 bytes of a sample, which stay below the group order.
+fn sample_scalar(challenger: &mut FriChallenger) -> FrElement {
+    loop {
+        let scalar = FrElement::from_hex(&hex::encode(&challenger.sample_bytes()[..31])).unwrap();
+        if scalar != FrElement::zero() {
+            return scalar;
+        }
+    }
+}
+
+/// Returns the affine coordinates of `point`, which are the same however it was computed.
+fn point_bytes(point: &G1Point) -> Vec<u8> {
+    let affine = point.to_affine();
+    [affine.x().to_bytes_be(), affine.y().to_bytes_be()].concat()
+}
+
+/// Returns `1, z, z^2, ..., z^(n-1)`.
+fn powers(z: &FrElement, n: usize) -> Vec<FrElement> {
+    let mut power = FrElement::one();
+    (0..n)
+        .map(|_| {
+            let current = power;
+            power *= z;
+            current
+        })
+        .collect()
+}
+
+fn inner_product(a: &[FrElement], b: &[FrElement]) -> FrElement {
+    a.iter().zip(b).map(|(a_i, b_i)| a_i * b_i).sum()
+}
+
+/// Returns `Σ scalars_i points_i`, over the shorter of the two.
+fn multi_scalar_mul(scalars: &[FrElement], points: &[G1Point]) -> G1Point {
+    scalars
+        .iter()
+        .zip(points)
+        .fold(G1Point::neutral_element(), |acc, (scalar, point)| {
+            acc.operate_with(&point.operate_with_self(scalar.representative()))
+        })
+}
+
+/// Returns `lo_i * x_lo + hi_i * x_hi` for each `i`.
+fn fold_scalars(
+    lo: &[FrElement],
+    hi: &[FrElement],
+    x_lo: &FrElement,
+    x_hi: &FrElement,
+) -> Vec<FrElement> {
+    lo.iter()
+        .zip(hi)
+        .map(|(l, h)| l * x_lo + h * x_hi)
+        .collect()
+}
+
+/// Returns `x_lo lo_i + x_hi hi_i` for each `i`.
+fn fold_points(lo: &[G1Point], hi: &[G1Point], x_lo: &FrElement, x_hi: &FrElement) -> Vec<G1Point> {
+    lo.iter()
+        .zip(hi)
+        .map(|(l, h)| {
+            l.operate_with_self(x_lo.representative())
+                .operate_with(&h.operate_with_self(x_hi.representative()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kzg::lift;
+    use crate::test_utils::demo_poly;
+    use crate::FE;
+
+    #[test]
+    fn opens_the_lifted_polynomial_to_its_evaluations() {
+        let (ipa, poly) = (Ipa::new(3), lift(&demo_poly()));
+        let commitment = ipa.commit(&poly).unwrap();
+        for z in [0, 1, 5] {
+            let z = FrElement::from(z);
+            let opening = ipa.open(&poly, &z).unwrap();
+            assert_eq!(opening.value, poly.evaluate(&z));
+            assert_eq!(ipa.verify(&commitment, &z, &opening), Ok(()));
+        }
+        // Two rounds halve the 4 generators down to one.
+        let opening = ipa.open(&poly, &FrElement::from(5)).unwrap();
+        assert_eq!(opening.value, FrElement::from(112));
+        assert_eq!(
+            opening.size_in_bytes(),
+            4 * G1_POINT_BYTES + 2 * SCALAR_BYTES
+        );
+    }
+
+    #[test]
+    fn rejects_other_values_points_and_cross_terms() {
+        let (ipa, poly) = (Ipa::new(3), lift(&demo_poly()));
+        let commitment = ipa.commit(&poly).unwrap();
+        let z = FrElement::from(5);
+        let opening = ipa.open(&poly, &z).unwrap();
+
+        let tamperings: [fn(&mut IpaOpening); 3] = [
+            |o| o.value += FrElement::one(),
+            |o| o.coefficient += FrElement::one(),
+            |o| o.left.swap(0, 1),
+        ];
+        for tamper in tamperings {
+            let mut tampered = opening.clone();
+            tamper(&mut tampered);
+            assert_eq!(
+                ipa.verify(&commitment, &z, &tampered),
+                Err(FriError::InnerProductCheckFailed)
+            );
+        }
+        assert_eq!(
+            ipa.verify(&commitment, &FrElement::from(6), &opening),
+            Err(FriError::InnerProductCheckFailed)
+        );
+
+        let mut truncated = opening;
+        truncated.right.pop();
+        assert_eq!(
+            ipa.verify(&commitment, &z, &truncated),
+            Err(FriError::WrongNumberOfRounds {
+                expected: 2,
+                got: 1
+            })
+        );
+    }
+
+    #[test]
+    fn larger_setups_extend_smaller_ones() {
+        let (small, poly) = (Ipa::new(3), lift(&demo_poly()));
+        let large = Ipa::new(7);
+        assert_eq!(large.max_degree(), 7);
+        assert_eq!(large.generators[..4], small.generators[..]);
+        assert_eq!(large.commit(&poly), small.commit(&poly));
+
+        // The larger setup opens the same commitment, with one more round.
+        let z = FrElement::from(5);
+        let opening = large.open(&poly, &z).unwrap();
+        assert_eq!(opening.left.len(), 3);
+        assert_eq!(
+            large.verify(&small.commit(&poly).unwrap(), &z, &opening),
+            Ok(())
+        );
+
+        let too_large = lift(&Polynomial::new(&[FE::one(); 9]));
+        assert_eq!(
+            large.commit(&too_large),
+            Err(FriError::DegreeAboveSetup {
+                degree: 8,
+                max_degree: 7
+            })
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! The KZG polynomial commitment scheme over BLS12-381, for contrast with FRI.
+//!
+//! A trusted setup publishes `[τ^i]_1` for `i = 0..=max_degree` and `[τ]_2`, for a secret `τ`
+//! that must then be destroyed. The commitment to `P` is the single point `C = [P(τ)]_1`. The
+//! proof that `P(z) = y` is the single point `π = [q(τ)]_1` of the quotient
+//! `q(x) = (P(x) - y) / (x - z)`, and the Verifier checks `e(C - [y]_1, [1]_2) = e(π, [τ - z]_2)`.
+//! Both are a constant 48 bytes, whatever the degree, where a FRI proof grows with it. In return,
+//! anyone who knows `τ` can open a commitment to any value, the Verifier computes pairings, and
+//! the discrete logarithms it relies on fall to a quantum computer.
+//!
+//! Pairing-friendly curves have a group order of more than 250 bits, so the polynomials are over
+//! BLS12-381's scalar field rather than Babybear. [`lift`] carries a polynomial of this crate
+//! across.
+
+use lambdaworks_crypto::commitments::kzg::{KateZaveruchaGoldberg, StructuredReferenceString};
+use lambdaworks_crypto::commitments::traits::IsCommitmentScheme;
+use lambdaworks_math::cyclic_group::IsGroup;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{
+    FrElement, FrField,
+};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::pairing::BLS12381AtePairing;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::twist::BLS12381TwistCurve;
+use lambdaworks_math::elliptic_curve::traits::{IsEllipticCurve, IsPairing};
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::error::FriError;
+use crate::FE;
+
+/// A point of BLS12-381's first group, in which commitments and proofs live.
+pub type G1Point = <BLS12381AtePairing as IsPairing>::G1Point;
+
+/// A point of BLS12-381's second group, in which the setup publishes `[1]_2` and `[τ]_2`.
+pub type G2Point = <BLS12381AtePairing as IsPairing>::G2Point;
+
+/// The structured reference string of the trusted setup.
+pub type Srs = StructuredReferenceString<G1Point, G2Point>;
+
+/// The size of a compressed point of the first group.
+pub const G1_POINT_BYTES: usize = 48;
+
+/// The size of an element of the scalar field.
+pub const SCALAR_BYTES: usize = 32;
+
+/// Runs the trusted setup for polynomials of degree at most `max_degree`, from the toxic waste
+/// `tau`.
+///
+/// Whoever knows `tau` can forge openings, so a real setup derives it from many parties'
+/// contributions, any one of whom forgetting theirs keeps it secret.
+pub fn trusted_setup(max_degree: usize, tau: &FrElement) -> Srs {
+    let g1 = BLS12381Curve::generator();
+    let g2 = BLS12381TwistCurve::generator();
+    let mut power = FrElement::one();
+    let powers_main_group: Vec<G1Point> = (0..=max_degree)
+        .map(|_| {
+            let point = g1.operate_with_self(power.representative());
+            power *= tau;
+            point
+        })
+        .collect();
+    let powers_secondary_group = [g2.clone(), g2.operate_with_self(tau.representative())];
+    Srs::new(&powers_main_group, &powers_secondary_group)
+}
+
+/// Returns `poly` over BLS12-381's scalar field, with each coefficient the integer of smallest
+/// absolute value it stands for modulo Babybear's prime.
+///
+/// A polynomial with small integer coefficients, such as `x^3 - 3x + 2`, is then the same
+/// polynomial in both fields, and agrees with itself at every small integer point.
+pub fn lift(poly: &Polynomial<FE>) -> Polynomial<FrElement> {
+    let coefficients: Vec<FrElement> = poly
+        .coefficients
+        .iter()
+        .map(|c| {
+            let (positive, negative) =
+                (c.representative().limbs[0], (-*c).representative().limbs[0]);
+            if negative < positive {
+                -FrElement::from(negative)
+            } else {
+                FrElement::from(positive)
+            }
+        })
+        .collect();
+    Polynomial::new(&coefficients)
+}
+
+/// A proof that the committed polynomial evaluates to `value` at a point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KzgOpening {
+    /// The claimed evaluation `y = P(z)`.
+    pub value: FrElement,
+    /// The commitment `[q(τ)]_1` to the quotient `(P(x) - y) / (x - z)`.
+    pub witness: G1Point,
+}
+
+impl KzgOpening {
+    /// Returns the size of the opening in bytes, with its point compressed: the same for every
+    /// polynomial.
+    pub fn size_in_bytes(&self) -> usize {
+        SCALAR_BYTES + G1_POINT_BYTES
+    }
+}
+
+/// The KZG scheme under a trusted setup, which commits to, opens and verifies polynomials of
+/// degree at most the setup's.
+pub struct Kzg {
+    scheme: KateZaveruchaGoldberg<FrField, BLS12381AtePairing>,
+    max_degree: usize,
+}
+
+impl Kzg {
+    /// Creates the scheme for the setup `srs`, which holds one point per coefficient it can
+    /// commit to.
+    pub fn new(srs: Srs) -> Self {
+        let max_degree = srs.powers_main_group.len().saturating_sub(1);
+        Self {
+            scheme: KateZaveruchaGoldberg::new(srs),
+            max_degree,
+        }
+    }
+
+    /// Commits to `poly`.
+    ///
+    /// # Errors
+    /// Returns [`FriError::DegreeAboveSetup`] if `poly` has more coefficients than the setup
+    /// has powers of `τ`.
+    pub fn commit(&self, poly: &Polynomial<FrElement>) -> Result<G1Point, FriError> {
+        self.check_degree(poly)?;
+        Ok(self.scheme.commit(poly))
+    }
+
+    /// Proves the evaluation of `poly` at `z`.
+    ///
+    /// # Errors
+    /// Returns [`FriError::DegreeAboveSetup`] as [`Kzg::commit`] does.
+    pub fn open(
+        &self,
+        poly: &Polynomial<FrElement>,
+        z: &FrElement,
+    ) -> Result<KzgOpening, FriError> {
+        self.check_degree(poly)?;
+        let value = poly.evaluate(z);
+        let witness = self.scheme.open(z, &value, poly);
+        Ok(KzgOpening { value, witness })
+    }
+
+    /// Verifies that the polynomial committed to by `commitment` evaluates to `opening.value` at
+    /// `z`, with a single pairing check.
+    ///
+    /// # Errors
+    /// Returns [`FriError::PairingCheckFailed`] if the pairings disagree.
+    pub fn verify(
+        &self,
+        commitment: &G1Point,
+        z: &FrElement,
+        opening: &KzgOpening,
+    ) -> Result<(), FriError> {
+        if self
+            .scheme
+            .verify(z, &opening.value, commitment, &opening.witness)
+        {
+            Ok(())
+        } else {
+            Err(FriError::PairingCheckFailed)
+        }
+    }
+
+    fn check_degree(&self, poly: &Polynomial<FrElement>) -> Result<(), FriError> {
+        if poly.coefficients.len() > self.max_degree + 1 {
+            return Err(FriError::DegreeAboveSetup {
+                degree: poly.degree(),
+                max_degree: self.max_degree,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::demo_poly;
+
+    /// The demo's polynomial, lifted from Babybear, under a setup for degree 3.
+    fn setup() -> (Kzg, Polynomial<FrElement>) {
+        let kzg = Kzg::new(trusted_setup(3, &FrElement::from(0x5eed)));
+        (kzg, lift(&demo_poly()))
+    }
+
+    #[test]
+    fn opens_the_lifted_polynomial_to_its_evaluations() {
+        let (kzg, poly) = setup();
+        let commitment = kzg.commit(&poly).unwrap();
+        let z = FrElement::from(5);
+        let opening = kzg.open(&poly, &z).unwrap();
+        // 5^3 - 3 * 5 + 2 over the integers, as over Babybear.
+        assert_eq!(opening.value, FrElement::from(112));
+        assert_eq!(kzg.verify(&commitment, &z, &opening), Ok(()));
+        assert_eq!(opening.size_in_bytes(), 80);
+    }
+
+    #[test]
+    fn rejects_other_values_points_and_commitments() {
+        let (kzg, poly) = setup();
+        let commitment = kzg.commit(&poly).unwrap();
+        let z = FrElement::from(5);
+        let opening = kzg.open(&poly, &z).unwrap();
+
+        let mut wrong_value = opening.clone();
+        wrong_value.value += FrElement::one();
+        assert_eq!(
+            kzg.verify(&commitment, &z, &wrong_value),
+            Err(FriError::PairingCheckFailed)
+        );
+        assert_eq!(
+            kzg.verify(&commitment, &FrElement::from(6), &opening),
+            Err(FriError::PairingCheckFailed)
+        );
+        let shifted = kzg.commit(&lift(&Polynomial::new(&[FE::from(1)]))).unwrap();
+        assert_eq!(
+            kzg.verify(&commitment.operate_with(&shifted), &z, &opening),
+            Err(FriError::PairingCheckFailed)
+        );
+    }
+
+    #[test]
+    fn rejects_polynomials_above_the_setup_degree() {
+        let (kzg, _) = setup();
+        let poly = lift(&Polynomial::new(&[FE::one(); 5]));
+        assert_eq!(
+            kzg.commit(&poly),
+            Err(FriError::DegreeAboveSetup {
+                degree: 4,
+                max_degree: 3
+            })
+        );
+    }
+}
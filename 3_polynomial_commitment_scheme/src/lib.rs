@@ -0,0 +1,99 @@
+//! # Educational FRI Protocol Implementation
+//!
+//! This code provides a simplified, educational implementation of the FRI (Fast Reed-Solomon
+//! Interactive Oracle Proof of Proximity) protocol in Rust. It is designed for teaching
+//! purposes to demonstrate the core concepts of FRI, which is a foundational component in
+//! many modern STARK (Scalable Transparent Argument of Knowledge) systems.
+//!
+//! The implementation uses the `lambdaworks` library for finite field arithmetic, polynomials,
+//! and Merkle trees.
+//!
+//! ## Protocol Flow Overview
+//!
+//! 1. **COMMIT**: The Prover evaluates a polynomial `P(x)` over a large domain (a Low-Degree
+//!    Extension or LDE). It then commits to these evaluations using a Merkle tree, whose leaves
+//!    pair each evaluation at `x` with the one at `-x`.
+//!
+//! 2. **FOLD**: The Prover and Verifier engage in a recursive process. In each round:
+//!     - The Verifier sends a random challenge, `beta`.
+//!     - The Prover uses `beta` to "fold" the current set of evaluations into a smaller set,
+//!       representing a new polynomial of half the degree.
+//!     - The Prover commits to the new evaluations and the process repeats.
+//!
+//! 3. **LAST LAYER**: This folding continues until the polynomial's degree is at most a small
+//!    bound. The Prover sends the coefficients of this last polynomial to the Verifier, which
+//!    evaluates it directly.
+//!
+//! 4. **QUERY**: The Verifier asks the Prover to reveal the evaluations of the polynomial at
+//!    specific random points and their symmetric points from the initial domain, along with one
+//!    Merkle multi-proof per layer for the leaves holding them, which shares the nodes the queries'
+//!    authentication paths have in common.
+//!
+//! 5. **VERIFY**: The Verifier checks two things:
+//!     - **Merkle Paths**: That the revealed evaluations are consistent with the commitments.
+//!     - **Folding Consistency**: That the folding process was performed correctly at each step for
+//!       the queried points. This ensures the Prover didn't cheat during the folding phase.
+//!
+//! ## Interactive and Non-Interactive FRI
+//!
+//! FRI is an interactive protocol, and the `interactive` module runs it as one: the Verifier
+//! sends fresh random challenges and queries over a [`interactive::Channel`], in memory or over
+//! TCP. The [`prover::Prover`] and [`verifier::Verifier`] instead derive those messages from a
+//! hash of the transcript so far: the Fiat-Shamir transformation, which turns the exchange into a
+//! single proof that anyone can check.
+//!
+//! ## Evaluation Proofs
+//!
+//! On its own, FRI only shows that a committed function is close to a low-degree polynomial. To
+//! prove an evaluation `P(z) = y`, the Prover runs FRI on the quotient `(P(x) - y) / (x - z)`,
+//! which is a polynomial exactly when the claim holds, and opens `P` at the queried points so the
+//! Verifier can check that the quotient was derived from the committed `P`.
+//!
+//! ## Other Schemes
+//!
+//! The [`kzg`] module implements KZG over BLS12-381, which commits to and opens the same
+//! polynomials with a single curve point each, under a trusted setup. The [`ipa`] module proves
+//! the same evaluations with the inner-product argument, which needs no trusted setup but opens
+//! with a logarithmic number of points and leaves the Verifier linear work.
+//!
+//! ## Features
+//!
+//! The default `std` feature adds the [`interactive`] module, whose channels and random coins
+//! need threads, sockets and the operating system's randomness. Without it, the Prover, the
+//! Verifier and the proof [`encoding`] only need what `wasm32-unknown-unknown` provides, and the
+//! `wasm` feature exports the Verifier to JavaScript from the `wasm` module. The `zstd` feature
+//! adds the compression of the [`compact`] proof encoding.
+
+use lambdaworks_crypto::merkle_tree::backends::types::BatchKeccak256Backend;
+use lambdaworks_math::field::element::FieldElement;
+use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+
+pub mod challenges;
+pub mod compact;
+pub mod dishonest;
+pub mod encoding;
+pub mod error;
+pub mod extension;
+#[cfg(feature = "std")]
+pub mod interactive;
+pub mod ipa;
+pub mod kzg;
+pub mod prover;
+pub mod stir;
+#[cfg(test)]
+mod test_utils;
+pub mod trace;
+pub mod types;
+pub mod verifier;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/// The prime field for our computations (Babybear).
+pub type F = Babybear31PrimeField;
+/// A field element in the Babybear field.
+pub type FE = FieldElement<F>;
+/// The backend for our Merkle Tree, using Keccak256 for hashing. Each leaf holds the pair of
+/// evaluations `f(x)` and `f(-x)` that a fold combines.
+pub type FriBackend = BatchKeccak256Backend<F>;
+/// The name of the protocol, used for initializing the transcript.
+const PROTOCOL_ID: &[u8] = b"Educational FRI";
@@ -1,76 +1,251 @@
-//! # Educational FRI Protocol Implementation
+//! The demo: runs the FRI protocol end to end on a small polynomial, from a proof and its
+//! verification to an evaluation proof, the interactive protocol, a comparison with STIR, a
+//! proof with extension-field challenges and the same evaluation proved with KZG and with the
+//! inner-product argument.
 //!
-//! This code provides a simplified, educational implementation of the FRI (Fast Reed-Solomon
-//! Interactive Oracle Proof of Proximity) protocol in Rust. It is designed for teaching
-//! purposes to demonstrate the core concepts of FRI, which is a foundational component in
-//! many modern STARK (Scalable Transparent Argument of Knowledge) systems.
-//!
-//! The implementation uses the `lambdaworks` library for finite field arithmetic, polynomials,
-//! and Merkle trees.
-//!
-//! ## Protocol Flow Overview
-//!
-//! 1. **COMMIT**: The Prover evaluates a polynomial `P(x)` over a large domain (a Low-Degree
-//!    Extension or LDE). It then commits to these evaluations using a Merkle tree.
-//!
-//! 2. **FOLD**: The Prover and Verifier engage in a recursive process. In each round:
-//!     - The Verifier sends a random challenge, `beta`.
-//!     - The Prover uses `beta` to "fold" the current set of evaluations into a smaller set,
-//!       representing a new polynomial of half the degree.
-//!     - The Prover commits to the new evaluations and the process repeats.
-//!
-//! 3. **LAST LAYER**: This folding continues until the polynomial is reduced to a constant. The
-//!    Prover sends this constant value to the Verifier.
-//!
-//! 4. **QUERY**: The Verifier asks the Prover to reveal the evaluations of the polynomial at
-//!    specific random points from the initial domain, along with their Merkle authentication paths
-//!    for all layers.
-//!
-//! 5. **VERIFY**: The Verifier checks two things:
-//!     - **Merkle Paths**: That the revealed evaluations are consistent with the commitments.
-//!     - **Folding Consistency**: That the folding process was performed correctly at each step for
-//!       the queried points. This ensures the Prover didn't cheat during the folding phase.
-use lambdaworks_crypto::merkle_tree::backends::types::Keccak256Backend;
-use lambdaworks_math::field::element::FieldElement;
-use lambdaworks_math::field::fields::fft_friendly::babybear::Babybear31PrimeField;
-use lambdaworks_math::polynomial::Polynomial;
+//! `cargo run -p polynomial_commitment_scheme` prints the outcome of each step. Pass `--verbose`
+//! to follow the protocol as it runs: every commitment, challenge, query and check is logged.
+//! Pass `--trace <dir>` to also record the same steps, as the Prover took them and as the
+//! Verifier replayed them, in `prover_trace.json` and `verifier_trace.json`.
+use std::path::Path;
+use std::{fs, thread};
 
-use crate::prover::Prover;
-use crate::types::FriParameters;
-use crate::verifier::Verifier;
-
-pub mod error;
-pub mod prover;
-pub mod types;
-pub mod verifier;
-
-/// The prime field for our computations (Babybear).
-type F = Babybear31PrimeField;
-/// A field element in the Babybear field.
-type FE = FieldElement<F>;
-/// The backend for our Merkle Tree, using Keccak256 for hashing.
-type FriBackend = Keccak256Backend<F>;
-/// The name of the protocol, used for initializing the transcript.
-const PROTOCOL_ID: &[u8] = b"Educational FRI";
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement;
+use lambdaworks_math::polynomial::Polynomial;
+use polynomial_commitment_scheme::extension::{ExtensionProver, ExtensionVerifier};
+use polynomial_commitment_scheme::interactive::{
+    InteractiveProver, InteractiveVerifier, MemoryChannel,
+};
+use polynomial_commitment_scheme::ipa::Ipa;
+use polynomial_commitment_scheme::kzg::{self, Kzg, G1_POINT_BYTES};
+use polynomial_commitment_scheme::prover::Prover;
+use polynomial_commitment_scheme::stir::{StirParameters, StirProver, StirVerifier};
+use polynomial_commitment_scheme::trace::ProtocolTrace;
+use polynomial_commitment_scheme::types::FriParameters;
+use polynomial_commitment_scheme::verifier::Verifier;
+use polynomial_commitment_scheme::FE;
+use rand::Rng;
+use tracing::Level;
 
 fn main() {
+    // The step-by-step narration of the protocol is logged through `tracing`, and only shown
+    // with `--verbose`.
+    let args: Vec<String> = std::env::args().collect();
+    let verbose = args.iter().any(|arg| arg == "--verbose" || arg == "-v");
+    // `--trace <dir>` dumps every event of the proof and of its verification as JSON.
+    let trace_dir = args
+        .iter()
+        .position(|arg| arg == "--trace")
+        .and_then(|i| args.get(i + 1));
+    tracing_subscriber::fmt()
+        .with_max_level(if verbose { Level::DEBUG } else { Level::WARN })
+        .without_time()
+        .with_target(false)
+        .with_level(false)
+        .init();
+
     // 1. SETUP
     // The polynomial we want to prove knowledge of: P(x) = x^3 - 3x + 2
     let poly = Polynomial::new(&[FE::from(2), -FE::from(3), FE::from(0), FE::from(1)]);
     let claimed_degree = 3;
-    // Parameters: degree 3, blowup factor 4 (domain size 16), 2 queries.
-    let params = FriParameters::new(claimed_degree, 8, 2);
+    // Parameters: degree 3, blowup factor 8 (domain size 32), as many queries as 20 bits of
+    // security need, folding stops once the polynomial has degree at most 1, and the domain is
+    // the coset of the subgroup shifted by the field's multiplicative generator 31. Each layer is
+    // committed by a Merkle cap of 2 nodes.
+    let target_bits = 20;
+    let params = FriParameters::with_security(claimed_degree, 8, target_bits)
+        .unwrap()
+        .with_last_layer_degree_bound(1)
+        .with_merkle_cap_height(1);
+
+    // The demo parameters are far too weak for real use; a higher target or a larger blowup
+    // factor raise the security level, up to what the field allows.
+    let security = params.security_report();
+    println!(
+        "Security: {:.1} bits conjectured, {:.1} bits proven, {:.1} bits proven with DEEP \
+         (rate {}, {} queries, {} grinding bits, {}-bit field)",
+        security.conjectured_bits,
+        security.proven_bits,
+        security.deep_proven_bits,
+        security.rate,
+        security.num_queries,
+        security.grinding_bits,
+        security.field_bits
+    );
+    let hand_picked = FriParameters::new(claimed_degree, 8, 2, 1, FE::from(31)).unwrap();
+    println!(
+        "{} queries reach {} bits; 2 hand-picked queries would need {} bits of grinding",
+        params.num_queries,
+        target_bits,
+        hand_picked.suggested_grinding_bits(target_bits)
+    );
 
     // 2. PROVE
     // The Prover generates a proof that it knows a polynomial of degree <= 3.
-    let mut prover = Prover::new(poly, params.clone());
+    let mut prover = Prover::new(poly.clone(), params.clone()).with_observer(ProtocolTrace::new());
     let proof = prover.prove().unwrap();
+    let size = proof.size_breakdown();
+    println!(
+        "Proof size: {} bytes ({} in commitments, {} in authentication paths, {} in evaluations, \
+         {} in the last layer)",
+        size.total(),
+        size.commitments,
+        size.auth_paths,
+        size.evaluations,
+        size.last_layer
+    );
+    println!(
+        "Compact encoding: {} bytes, against {} in the text encoding",
+        proof.to_compact_bytes().len(),
+        proof.encode().len()
+    );
+    #[cfg(feature = "zstd")]
+    println!(
+        "Compressed with zstd: {} bytes",
+        proof.to_compressed_bytes().len()
+    );
 
     // 3. VERIFY
     // The Verifier checks the proof.
-    let mut verifier = Verifier::new(params);
+    let mut verifier = Verifier::new(params.clone()).with_observer(ProtocolTrace::new());
     match verifier.verify(&proof) {
         Ok(_) => println!("\n✅ SUCCESS: Proof verified successfully!"),
         Err(e) => println!("\n❌ FAILURE: Proof verification failed: {}", e),
     }
+    if let Some(dir) = trace_dir {
+        for (file, trace) in [
+            ("prover_trace.json", &prover.observer),
+            ("verifier_trace.json", &verifier.observer),
+        ] {
+            let path = Path::new(dir).join(file);
+            match fs::write(&path, trace.to_json()) {
+                Ok(()) => println!("Wrote {} events to {}", trace.events.len(), path.display()),
+                Err(e) => println!("Could not write {}: {}", path.display(), e),
+            }
+        }
+    }
+
+    // 4. OPEN
+    // As a commitment scheme: the Prover commits to P and proves its evaluation at a point z
+    // outside the domain, P(5) = 112, by running FRI on the quotient (P(x) - 112) / (x - 5).
+    let z = FE::from(5);
+    let commitment = prover.commit().unwrap();
+    let evaluation_proof = prover.open(&z).unwrap();
+    match verifier.verify_eval(&commitment, &z, &evaluation_proof.value, &evaluation_proof) {
+        Ok(_) => println!(
+            "\n✅ SUCCESS: P({}) = {} verified against the commitment!",
+            z.representative(),
+            evaluation_proof.value.representative()
+        ),
+        Err(e) => println!("\n❌ FAILURE: Evaluation proof failed: {}", e),
+    }
+
+    // With DEEP-FRI, the point comes from the transcript of the commitment instead, which makes
+    // each query of the quotient's proof count for more.
+    let deep_proof = prover.prove_deep().unwrap();
+    match verifier.verify_deep(&commitment, &deep_proof) {
+        Ok(_) => println!("\n✅ SUCCESS: DEEP-FRI proof verified!"),
+        Err(e) => println!("\n❌ FAILURE: DEEP-FRI proof failed: {}", e),
+    }
+
+    // 5. INTERACT
+    // The same protocol without Fiat-Shamir: the Prover runs on its own thread and receives each
+    // challenge from the Verifier over a channel, instead of hashing its transcript.
+    let (prover_end, verifier_end) = MemoryChannel::pair();
+    let prover_params = params.clone();
+    let interactive_poly = poly.clone();
+    let interactive_prover = thread::spawn(move || {
+        InteractiveProver::new(interactive_poly, prover_params, prover_end).run()
+    });
+    let verdict = InteractiveVerifier::new(params.clone(), verifier_end).run();
+    if let Err(e) = interactive_prover.join().unwrap() {
+        println!("\n❌ FAILURE: Interactive Prover failed: {}", e);
+    }
+    match verdict {
+        Ok(_) => println!("\n✅ SUCCESS: Interactive proof accepted!"),
+        Err(e) => println!("\n❌ FAILURE: Interactive proof rejected: {}", e),
+    }
+
+    // 6. COMPARE
+    // STIR folds 4 evaluations into one per round but only halves the domain, so each round tests
+    // a code of lower rate and needs fewer queries. Both are compared at 20 bits for a polynomial
+    // of degree 255 with a blowup factor of 4.
+    let coefficients: Vec<FE> = (1..=256).map(FE::from).collect();
+    let stir_params = StirParameters::new(255, 4, 4, 20, FE::from(31)).unwrap();
+    let fri = stir_params.fri_query_complexity();
+    let stir = stir_params.query_complexity();
+    println!(
+        "\nFRI:  {} queries per round, {} Merkle openings, {} evaluations revealed",
+        fri.queries_per_round[0], fri.openings, fri.revealed_evaluations
+    );
+    println!(
+        "STIR: {:?} queries per round, {} Merkle openings, {} evaluations revealed",
+        stir.queries_per_round, stir.openings, stir.revealed_evaluations
+    );
+    let stir_proof = StirProver::new(Polynomial::new(&coefficients), stir_params.clone())
+        .prove()
+        .unwrap();
+    match StirVerifier::new(stir_params).verify(&stir_proof) {
+        Ok(_) => println!("\n✅ SUCCESS: STIR proof verified!"),
+        Err(e) => println!("\n❌ FAILURE: STIR proof failed: {}", e),
+    }
+
+    // 7. EXTEND
+    // Babybear has only 31 bits, which caps the commit phase's soundness well below what the
+    // queries can give. Drawing each folding challenge from the quartic extension lifts that cap
+    // to about 120 bits, while the polynomial and its first layer stay in the base field.
+    let extension_security = params.extension_security_report();
+    println!(
+        "\nExtension challenges: {:.1} bits conjectured, {:.1} bits proven ({}-bit challenges)",
+        extension_security.conjectured_bits,
+        extension_security.proven_bits,
+        extension_security.field_bits
+    );
+    let extension_proof = ExtensionProver::new(poly.clone(), params.clone())
+        .prove()
+        .unwrap();
+    match ExtensionVerifier::new(params).verify(&extension_proof) {
+        Ok(_) => println!("\n✅ SUCCESS: Proof with extension challenges verified!"),
+        Err(e) => println!(
+            "\n❌ FAILURE: Proof with extension challenges failed: {}",
+            e
+        ),
+    }
+
+    // 8. CONTRAST
+    // KZG proves the same evaluation P(5) = 112 with a single curve point, whatever the degree,
+    // but only under a trusted setup whose secret must be destroyed.
+    let kzg = Kzg::new(kzg::trusted_setup(
+        3,
+        &FrElement::from(rand::thread_rng().gen::<u64>()),
+    ));
+    let lifted = kzg::lift(&poly);
+    let kzg_commitment = kzg.commit(&lifted).unwrap();
+    let kzg_z = FrElement::from(5);
+    let kzg_opening = kzg.open(&lifted, &kzg_z).unwrap();
+    println!(
+        "\nKZG: a {}-byte commitment and an {}-byte opening, against {} bytes for FRI's quotient proof",
+        G1_POINT_BYTES,
+        kzg_opening.size_in_bytes(),
+        evaluation_proof.quotient_proof.size_in_bytes()
+    );
+    match kzg.verify(&kzg_commitment, &kzg_z, &kzg_opening) {
+        Ok(_) => println!("\n✅ SUCCESS: KZG opening verified!"),
+        Err(e) => println!("\n❌ FAILURE: KZG opening failed: {}", e),
+    }
+
+    // The inner-product argument needs no trusted setup, at the cost of an opening that grows
+    // with the logarithm of the degree and a Verifier that folds every generator.
+    let ipa = Ipa::new(3);
+    let ipa_commitment = ipa.commit(&lifted).unwrap();
+    let ipa_opening = ipa.open(&lifted, &kzg_z).unwrap();
+    println!(
+        "\nIPA: a {}-byte commitment and a {}-byte opening, with no trusted setup but a Verifier linear in the degree",
+        G1_POINT_BYTES,
+        ipa_opening.size_in_bytes()
+    );
+    match ipa.verify(&ipa_commitment, &kzg_z, &ipa_opening) {
+        Ok(_) => println!("\n✅ SUCCESS: IPA opening verified!"),
+        Err(e) => println!("\n❌ FAILURE: IPA opening failed: {}", e),
+    }
 }
@@ -1,188 +1,533 @@
-use lambdaworks_crypto::fiat_shamir::default_transcript::DefaultTranscript;
-use lambdaworks_crypto::fiat_shamir::is_transcript::IsTranscript;
-use lambdaworks_crypto::merkle_tree::merkle::MerkleTree;
+use lambdaworks_math::fft::cpu::bit_reversing::in_place_bit_reverse_permute;
 use lambdaworks_math::polynomial::Polynomial;
-use lambdaworks_math::traits::AsBytes;
+use tracing::{debug, info, info_span};
 
+use crate::challenges::FriChallenger;
+use crate::dishonest::Tamper;
 use crate::error::FriError;
-use crate::types::{FriLayer, FriParameters, FriProof, QueryDecommitment};
-use crate::{FriBackend, F, FE, PROTOCOL_ID};
+use crate::trace::{Observer, ProtocolEvent};
+use crate::types::{
+    deep_point, DomainSeparator, EvaluationProof, FriLayer, FriParameters, FriProof,
+    PolynomialOpening, QueryDecommitment,
+};
+use crate::{F, FE};
+
+/// How the Prover trades memory for time between the fold and query phases.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProverMode {
+    /// Keeps every committed layer, with its evaluations and Merkle trees, until the queries are
+    /// answered: about `2N` field elements plus the trees of every layer for a domain of `N`
+    /// points.
+    #[default]
+    Fast,
+    /// Keeps only the Merkle caps and the folding challenges, and recomputes each layer in turn
+    /// once the query indices are known, so no more than a couple of layers are alive at once.
+    /// The proof is the same, for about twice the work.
+    MemoryLean,
+}
+
+/// The layers a fold phase committed to, and what the query phase needs of them.
+struct Folded {
+    /// The Merkle cap of every committed layer.
+    caps: Vec<Vec<[u8; 32]>>,
+    /// Every committed layer, kept only in [`ProverMode::Fast`].
+    layers: Vec<FriLayer>,
+    /// The folding challenge of every round.
+    betas: Vec<FE>,
+    /// The coefficients of the last layer's polynomial.
+    last_layer_coefficients: Vec<FE>,
+}
+
+/// What a query phase opened of the committed layers.
+struct Openings {
+    /// The decommitment of every query.
+    decommitments: Vec<QueryDecommitment>,
+    /// The Merkle multi-proof of every committed layer.
+    multi_proofs: Vec<Vec<[u8; 32]>>,
+}
 
 /// The Prover entity for the FRI protocol.
-pub struct Prover {
+pub struct Prover<O: Observer = ()> {
     poly: Polynomial<FE>,
     params: FriParameters,
-    transcript: DefaultTranscript<F>,
+    separator: DomainSeparator,
+    challenger: FriChallenger,
+    mode: ProverMode,
+    /// The hooks through which a dishonest Prover departs from the protocol.
+    pub(crate) tamper: Box<dyn Tamper>,
+    /// The observer notified of every step of the proof.
+    pub observer: O,
 }
 
 impl Prover {
-    /// Creates a new Prover.
+    /// Creates a new Prover, under the crate's default [`DomainSeparator`].
     pub fn new(poly: Polynomial<FE>, params: FriParameters) -> Self {
+        Self::with_domain_separator(poly, params, DomainSeparator::default())
+    }
+
+    /// Creates a new Prover whose transcript is seeded with `seed`, under the default labels, so
+    /// that the proof is the same on every run and differs from seed to seed.
+    pub fn with_seed(poly: Polynomial<FE>, params: FriParameters, seed: u64) -> Self {
+        Self::with_domain_separator(poly, params, DomainSeparator::default().with_seed(seed))
+    }
+
+    /// Creates a new Prover whose transcripts start from `separator`, so that its proofs only
+    /// verify under the same protocol label and application context.
+    pub fn with_domain_separator(
+        poly: Polynomial<FE>,
+        params: FriParameters,
+        separator: DomainSeparator,
+    ) -> Self {
+        // Bind the labels and the public parameters before any commitment.
+        let challenger = FriChallenger::new(&separator, &params);
         Self {
             poly,
             params,
-            transcript: DefaultTranscript::new(PROTOCOL_ID),
+            separator,
+            challenger,
+            mode: ProverMode::default(),
+            tamper: Box::new(()),
+            observer: (),
+        }
+    }
+}
+
+impl<O: Observer> Prover<O> {
+    /// Notifies `observer` of every step of the proofs this Prover generates.
+    pub fn with_observer<P: Observer>(self, observer: P) -> Prover<P> {
+        Prover {
+            poly: self.poly,
+            params: self.params,
+            separator: self.separator,
+            challenger: self.challenger,
+            mode: self.mode,
+            tamper: self.tamper,
+            observer,
         }
     }
 
+    /// Sets how this Prover trades memory for time, [`ProverMode::Fast`] by default.
+    pub fn with_mode(mut self, mode: ProverMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Executes the entire proving process.
     pub fn prove(&mut self) -> Result<FriProof, FriError> {
-        println!("--- Prover: Starting proof generation ---");
+        self.prove_with_queries().map(|(proof, _)| proof)
+    }
+
+    /// Commits to the polynomial with the Merkle cap of its evaluations on the LDE domain, the
+    /// same commitment that opens a proof of it.
+    pub fn commit(&self) -> Result<Vec<[u8; 32]>, FriError> {
+        Ok(self.evaluation_layer()?.cap())
+    }
+
+    /// Proves the evaluation `P(z) = y` against the commitment of [`Prover::commit`], for a point
+    /// `z` outside the domain.
+    ///
+    /// `P(z) = y` exactly when `x - z` divides `P(x) - y`, so the Prover runs FRI on the quotient
+    /// `q(x) = (P(x) - y) / (x - z)`, of degree one less than `P`, and opens `P` at every point FRI
+    /// queries. The Verifier recomputes `q` there from the openings, which ties the low-degree
+    /// quotient to the committed polynomial.
+    pub fn open(&self, z: &FE) -> Result<EvaluationProof, FriError> {
+        let _span = info_span!("open").entered();
+        info!(
+            "--- Prover: Opening the commitment at z = {} ---",
+            z.representative()
+        );
+        if self.params.domain.contains(z) {
+            return Err(FriError::EvaluationPointInDomain);
+        }
+        self.open_committed(&self.evaluation_layer()?, z)
+    }
+
+    /// Proves with DEEP-FRI that the committed function is close to a polynomial of the claimed
+    /// degree: rather than a point of the Prover's choosing, the transcript of the commitment picks
+    /// an out-of-domain point `z`, and the Prover opens the commitment there as
+    /// [`Prover::open`] does.
+    ///
+    /// Beyond the unique-decoding radius, many low-degree polynomials can each agree with a
+    /// function on part of the domain, and a cheating Prover could answer each query from a
+    /// different one. The quotient by `x - z` only has low degree for those taking the claimed
+    /// value `P(z)` at the random `z`, which pins the Prover to a single one of them, so each
+    /// query catches more of the functions far from the code.
+    pub fn prove_deep(&self) -> Result<EvaluationProof, FriError> {
+        let _span = info_span!("prove_deep").entered();
+        let layer = self.evaluation_layer()?;
+        let z = deep_point(&self.params, &self.separator, &layer.cap());
+        info!(
+            "--- Prover: Sampled the DEEP point z = {} ---",
+            z.representative()
+        );
+        self.open_committed(&layer, &z)
+    }
+
+    /// Opens the committed first layer `layer` at `z`, which lies outside the domain.
+    fn open_committed(&self, layer: &FriLayer, z: &FE) -> Result<EvaluationProof, FriError> {
+        let value = self.poly.evaluate(z);
+
+        // q(x) = (P(x) - y) / (x - z), exact since z is a root of P(x) - y.
+        let constant = self
+            .poly
+            .coefficients
+            .first()
+            .copied()
+            .unwrap_or_else(FE::zero);
+        let shifted: Vec<FE> = std::iter::once(constant - value)
+            .chain(self.poly.coefficients.iter().skip(1).copied())
+            .collect();
+        let quotient = Polynomial::new(&shifted).ruffini_division(z);
+
+        // The quotient's transcript starts from the claim it proves.
+        let mut quotient_prover = Prover::with_domain_separator(
+            quotient,
+            self.params.quotient_parameters(),
+            self.separator.clone(),
+        )
+        .with_mode(self.mode);
+        quotient_prover
+            .challenger
+            .absorb_evaluation_claim(&layer.cap(), z, &value);
+        let (quotient_proof, query_indices) = quotient_prover.prove_with_queries()?;
+
+        // Open P at each point, and its symmetric one, that FRI queried on the quotient.
+        let openings = query_indices
+            .into_iter()
+            .map(|query_idx| PolynomialOpening {
+                evaluation: layer.evaluations[query_idx],
+                evaluation_sym: layer.evaluations[query_idx ^ 1],
+                auth_path: layer.auth_path(query_idx / 2),
+            })
+            .collect();
+
+        Ok(EvaluationProof {
+            value,
+            quotient_proof,
+            openings,
+        })
+    }
+
+    /// Executes the entire proving process, also returning the query indices it decommitted.
+    fn prove_with_queries(&mut self) -> Result<(FriProof, Vec<usize>), FriError> {
+        let _span = info_span!("prove").entered();
+        info!("--- Prover: Starting proof generation ---");
 
         // 1. Commit Phase: Evaluate the polynomial and commit to the evaluations.
         let initial_layer = self.commit_phase()?;
-        // 2. Fold Phase: Recursively fold the polynomial until it's a constant.
-        let (layers, last_value) = self.fold_phase(initial_layer)?;
+        // 2. Fold Phase: Recursively fold the polynomial until its degree is under the bound.
+        let folded = self.fold_phase(initial_layer)?;
         // 3. Query Phase: Generate decommitments for random queries.
-        let query_decommitments = self.query_phase(&layers);
+        let query_indices = self.sample_query_indices();
+        let Openings {
+            decommitments: mut query_decommitments,
+            multi_proofs: layer_multi_proofs,
+        } = match self.mode {
+            ProverMode::Fast => self.query_phase(&folded.layers, &query_indices),
+            ProverMode::MemoryLean => {
+                self.query_phase_lean(&folded.betas, folded.caps.len(), &query_indices)?
+            }
+        };
+        for (&query, decommitment) in query_indices.iter().zip(&mut query_decommitments) {
+            self.tamper.decommitment(query, decommitment);
+        }
+        self.record_openings(&query_indices, &query_decommitments);
 
-        println!("--- Prover: Proof generation complete ---\n");
-        Ok(FriProof {
-            layer_commitments: layers.iter().map(|l| l.merkle_tree.root).collect(),
-            last_layer_value: last_value,
+        info!("--- Prover: Proof generation complete ---");
+        let proof = FriProof {
+            layer_commitments: folded.caps,
+            last_layer_coefficients: folded.last_layer_coefficients,
             query_decommitments,
-        })
+            layer_multi_proofs,
+        };
+        Ok((proof, query_indices))
+    }
+
+    /// Evaluates the polynomial on the LDE domain and builds the Merkle trees of the first layer,
+    /// without touching the transcript.
+    pub(crate) fn evaluation_layer(&self) -> Result<FriLayer, FriError> {
+        let (evaluations, domain) = self.first_layer_evaluations()?;
+        self.build_layer(evaluations, domain, 0)
     }
 
     /// Phase 1: Commit to the initial polynomial evaluations on the LDE domain.
     fn commit_phase(&mut self) -> Result<FriLayer, FriError> {
-        println!("[Prover] Phase 1: COMMIT");
+        info!("[Prover] Phase 1: COMMIT");
         // Evaluate the polynomial on the large domain (LDE).
-        let evaluations = self.poly.evaluate_slice(&self.params.domain);
-        // Build a Merkle tree from the evaluations to commit to them.
-        let merkle_tree = MerkleTree::<FriBackend>::build(&evaluations).ok_or_else(|| {
-            FriError::MerkleTreeConstructionError("Failed to build initial Merkle tree".to_string())
-        })?;
-
-        // Add the Merkle root to the transcript to make it part of the public record.
-        self.transcript.append_bytes(&merkle_tree.root);
-        println!(
-            "  > Layer 0 committed with root: 0x{}",
-            hex::encode(merkle_tree.root)
-        );
+        let (evaluations, domain) = self.first_layer_evaluations()?;
+        self.commit_layer(evaluations, domain, 0)
+    }
 
-        Ok(FriLayer {
-            evaluations,
-            merkle_tree,
-            domain: self.params.domain.to_owned(),
-        })
+    /// Evaluates the polynomial on the LDE domain, returning the evaluations and the domain in
+    /// the bit-reversed order the layers store them in.
+    fn first_layer_evaluations(&self) -> Result<(Vec<FE>, Vec<FE>), FriError> {
+        let mut evaluations = self.evaluate_on_domain()?;
+        let mut domain = self.params.domain.to_owned();
+        in_place_bit_reverse_permute(&mut evaluations);
+        in_place_bit_reverse_permute(&mut domain);
+        Ok((evaluations, domain))
     }
 
-    /// Phase 2: Interactively fold the polynomial evaluations until a constant is reached.
-    fn fold_phase(&mut self, initial_layer: FriLayer) -> Result<(Vec<FriLayer>, FE), FriError> {
-        println!("[Prover] Phase 2: FOLD");
-        let mut layers = vec![initial_layer];
+    /// Evaluates the polynomial on the LDE domain with a coset FFT, in `O(n log n)` rather than
+    /// the `O(n d)` of evaluating at each point in turn.
+    fn evaluate_on_domain(&self) -> Result<Vec<FE>, FriError> {
+        let domain_size = self.params.domain.len();
+        if self.poly.coefficients.len() > domain_size {
+            return Err(FriError::EvaluationError(format!(
+                "{} coefficients do not fit a domain of {} points",
+                self.poly.coefficients.len(),
+                domain_size
+            )));
+        }
+        // The domain is `offset * <w>` in natural order, which is exactly what the coset FFT
+        // evaluates on.
+        Polynomial::evaluate_offset_fft::<F>(&self.poly, 1, Some(domain_size), &self.params.offset)
+            .map_err(|e| FriError::EvaluationError(format!("{:?}", e)))
+    }
 
-        // Continue folding until the polynomial becomes a constant (evaluations list has 1 element)
-        while layers.last().unwrap().evaluations.len() > 1 {
-            let i = layers.len() - 1;
+    /// Phase 2: Interactively fold the polynomial evaluations until the layer polynomial has degree
+    /// at most `last_layer_degree_bound`, then interpolate that last layer.
+    ///
+    /// Every layer but the last is committed; the last one is sent as its coefficients instead.
+    /// A [`ProverMode::MemoryLean`] Prover keeps only the layer it is folding.
+    fn fold_phase(&mut self, initial_layer: FriLayer) -> Result<Folded, FriError> {
+        info!("[Prover] Phase 2: FOLD");
+        let num_rounds = self.params.num_folding_rounds();
+        let mut folded = Folded {
+            caps: Vec::new(),
+            layers: Vec::new(),
+            betas: Vec::with_capacity(num_rounds),
+            last_layer_coefficients: Vec::new(),
+        };
+        let (mut evaluations, mut domain) = self.retire_layer(initial_layer, &mut folded);
+
+        for i in 0..num_rounds {
             // Get a random challenge `beta` from the transcript.
-            let beta: FE = self.transcript.sample_field_element();
-            println!(
+            let beta = self.challenger.sample_element();
+            debug!(
                 "  > Round {}: Sampled challenge beta = {}",
                 i,
                 beta.representative()
             );
+            self.observer.on_event(ProtocolEvent::ChallengeSampled {
+                round: i,
+                beta: ProtocolEvent::element(&beta),
+            });
+            let beta = self.tamper.challenge(&folded.betas, beta);
+            folded.betas.push(beta);
 
-            let previous_layer = layers.last().unwrap();
             // Fold the evaluations and domain for the next layer.
-            let (next_evaluations, next_domain) =
-                Self::fold_evaluations(&previous_layer.evaluations, &previous_layer.domain, &beta);
+            (evaluations, domain) = Self::fold_evaluations(&evaluations, &domain, &beta);
+            self.tamper.layer(i + 1, &mut evaluations);
+            if i + 1 == num_rounds {
+                break;
+            }
 
             // Commit to the new evaluations.
-            let next_merkle_tree =
-                MerkleTree::<FriBackend>::build(&next_evaluations).ok_or_else(|| {
-                    FriError::MerkleTreeConstructionError(format!(
-                        "Failed to build Merkle tree for layer {}",
-                        i + 1
-                    ))
-                })?;
-
-            // Add the new Merkle root to the transcript.
-            self.transcript.append_bytes(&next_merkle_tree.root);
-            println!(
-                "    - Layer {} committed with root: 0x{}",
-                i + 1,
-                hex::encode(next_merkle_tree.root)
-            );
-
-            layers.push(FriLayer {
-                evaluations: next_evaluations,
-                merkle_tree: next_merkle_tree,
-                domain: next_domain,
-            });
+            let layer = self.commit_layer(evaluations, domain, i + 1)?;
+            (evaluations, domain) = self.retire_layer(layer, &mut folded);
         }
 
-        // The final layer contains a single evaluation, which is the constant value.
-        let last_value = layers.last().unwrap().evaluations[0].clone();
-        self.transcript.append_bytes(&last_value.as_bytes());
-        println!(
-            "  > Folding complete. Final value: {}",
-            last_value.representative()
+        let last_poly = Self::interpolate_last_layer(&evaluations, &domain)?;
+        self.challenger.absorb_elements(&last_poly.coefficients);
+        debug!(
+            "  > Folding complete. Last layer polynomial: {}",
+            last_poly.print_as_sage_poly(None)
         );
+        self.observer.on_event(ProtocolEvent::LastLayerSent {
+            coefficients: last_poly
+                .coefficients
+                .iter()
+                .map(ProtocolEvent::element)
+                .collect(),
+        });
+
+        folded.last_layer_coefficients = last_poly.coefficients;
+        Ok(folded)
+    }
 
-        Ok((layers, last_value))
+    /// Recovers the polynomial of the last layer from its evaluations, to be sent in full.
+    pub(crate) fn interpolate_last_layer(
+        evaluations: &[FE],
+        domain: &[FE],
+    ) -> Result<Polynomial<FE>, FriError> {
+        // The last layer's domain is a coset starting at its offset, which bit reversal leaves in
+        // place, so an inverse FFT over the evaluations in natural order recovers its polynomial.
+        let mut natural = evaluations.to_vec();
+        in_place_bit_reverse_permute(&mut natural);
+        Polynomial::interpolate_offset_fft::<F>(&natural, &domain[0])
+            .map_err(|e| FriError::LastLayerInterpolationError(format!("{:?}", e)))
     }
 
-    /// Phase 3: Generate decommitments for random queries issued by the verifier.
-    fn query_phase(&mut self, layers: &[FriLayer]) -> Vec<QueryDecommitment> {
-        println!("[Prover] Phase 3: QUERY");
-        // Sample random indices from the transcript for the queries.
-        let query_indices: Vec<usize> = (0..self.params.num_queries)
-            .map(|_| self.sample_index(self.params.domain.len()))
+    /// Records the cap of a committed layer, keeping the layer itself for the query phase only
+    /// in [`ProverMode::Fast`], and returns its evaluations and domain to fold next.
+    fn retire_layer(&self, layer: FriLayer, folded: &mut Folded) -> (Vec<FE>, Vec<FE>) {
+        folded.caps.push(layer.cap());
+        match self.mode {
+            ProverMode::Fast => {
+                let next = (layer.evaluations.clone(), layer.domain.clone());
+                folded.layers.push(layer);
+                next
+            }
+            // Dropping the Merkle trees here leaves a single layer's evaluations alive.
+            ProverMode::MemoryLean => (layer.evaluations, layer.domain),
+        }
+    }
+
+    /// Phase 3: Generate decommitments for random queries issued by the verifier, along with the
+    /// Merkle multi-proof of each layer.
+    fn query_phase(&mut self, layers: &[FriLayer], query_indices: &[usize]) -> Openings {
+        info!("[Prover] Phase 3: QUERY");
+        let mut decommitments = self.start_query_phase(query_indices);
+        let multi_proofs = layers
+            .iter()
+            .enumerate()
+            .map(|(i, layer)| Self::open_layer(layer, i, query_indices, &mut decommitments))
             .collect();
+        Openings {
+            decommitments,
+            multi_proofs,
+        }
+    }
 
-        println!(
+    /// Phase 3 for a [`ProverMode::MemoryLean`] Prover, which kept none of the `num_layers`
+    /// committed layers: each is recomputed from the polynomial and the challenges `betas`, opened,
+    /// and dropped before the next.
+    fn query_phase_lean(
+        &mut self,
+        betas: &[FE],
+        num_layers: usize,
+        query_indices: &[usize],
+    ) -> Result<Openings, FriError> {
+        info!("[Prover] Phase 3: QUERY (recomputing the layers)");
+        let mut decommitments = self.start_query_phase(query_indices);
+        let mut multi_proofs = Vec::with_capacity(num_layers);
+        let (mut evaluations, mut domain) = self.first_layer_evaluations()?;
+        for i in 0..num_layers {
+            if i > 0 {
+                (evaluations, domain) =
+                    Self::fold_evaluations(&evaluations, &domain, &betas[i - 1]);
+                self.tamper.layer(i, &mut evaluations);
+            }
+            let layer = self.build_layer(evaluations, domain, i)?;
+            multi_proofs.push(Self::open_layer(
+                &layer,
+                i,
+                query_indices,
+                &mut decommitments,
+            ));
+            (evaluations, domain) = (layer.evaluations, layer.domain);
+        }
+        Ok(Openings {
+            decommitments,
+            multi_proofs,
+        })
+    }
+
+    /// Announces the query indices and returns an empty decommitment for each.
+    fn start_query_phase(&mut self, query_indices: &[usize]) -> Vec<QueryDecommitment> {
+        debug!(
             "  > Generating decommitments for queries at indices: {:?}",
             query_indices
         );
+        self.observer.on_event(ProtocolEvent::QueriesSampled {
+            indices: query_indices.to_vec(),
+        });
+        vec![QueryDecommitment::default(); query_indices.len()]
+    }
 
-        query_indices
-            .into_iter()
-            .map(|mut query_idx| {
-                let mut decommitment = QueryDecommitment {
-                    layer_evaluations: Vec::new(),
-                    layer_auth_paths: Vec::new(),
-                    layer_evaluations_sym: Vec::new(),
-                    layer_auth_paths_sym: Vec::new(),
-                };
-
-                // For each layer, provide the evaluation and its Merkle proof.
-                for layer in layers {
-                    let domain_size = layer.domain.len();
-                    // The symmetric index corresponds to f(-x).
-                    let sym_idx = (query_idx + domain_size / 2) % domain_size;
-
-                    // Provide evaluation and auth path for f(x).
-                    decommitment
-                        .layer_evaluations
-                        .push(layer.evaluations[query_idx].clone());
-                    decommitment.layer_auth_paths.push(
-                        layer
-                            .merkle_tree
-                            .get_proof_by_pos(query_idx)
-                            .unwrap()
-                            .merkle_path,
-                    );
-
-                    // Provide evaluation and auth path for f(-x).
-                    decommitment
-                        .layer_evaluations_sym
-                        .push(layer.evaluations[sym_idx].clone());
-                    decommitment.layer_auth_paths_sym.push(
-                        layer
-                            .merkle_tree
-                            .get_proof_by_pos(sym_idx)
-                            .unwrap()
-                            .merkle_path,
-                    );
-
-                    // The index for the next layer is `query_idx mod (domain_size / 2)`.
-                    query_idx %= (domain_size / 2).max(1);
-                }
-                decommitment
-            })
-            .collect()
+    /// Adds, for each query, both evaluations of `layer`, the layer at index `layer_idx`, to its
+    /// decommitment, and returns the Merkle multi-proof of their leaves.
+    pub(crate) fn open_layer(
+        layer: &FriLayer,
+        layer_idx: usize,
+        query_indices: &[usize],
+        decommitments: &mut [QueryDecommitment],
+    ) -> Vec<[u8; 32]> {
+        let mut leaf_indices = Vec::with_capacity(query_indices.len());
+        for (&query, decommitment) in query_indices.iter().zip(decommitments) {
+            // Each fold merges adjacent positions, so the query lands on its position shifted
+            // right once per fold.
+            let query_idx = query >> layer_idx;
+            // The symmetric index, its neighbour, corresponds to f(-x).
+            let sym_idx = query_idx ^ 1;
+
+            decommitment
+                .layer_evaluations
+                .push(layer.evaluations[query_idx]);
+            decommitment
+                .layer_evaluations_sym
+                .push(layer.evaluations[sym_idx]);
+            // f(x) and f(-x) share the leaf of their pair.
+            leaf_indices.push(query_idx / 2);
+        }
+        // Queries that meet at this layer share their leaf.
+        leaf_indices.sort_unstable();
+        leaf_indices.dedup();
+        layer.multi_proof(&leaf_indices)
+    }
+
+    /// Notifies the observer of every opened evaluation, query by query.
+    fn record_openings(&mut self, query_indices: &[usize], decommitments: &[QueryDecommitment]) {
+        for (&query, decommitment) in query_indices.iter().zip(decommitments) {
+            let values = decommitment
+                .layer_evaluations
+                .iter()
+                .zip(&decommitment.layer_evaluations_sym);
+            for (i, (value, value_sym)) in values.enumerate() {
+                self.observer.on_event(ProtocolEvent::QueryOpened {
+                    query,
+                    layer: i,
+                    index: query >> i,
+                    value: ProtocolEvent::element(value),
+                    value_sym: ProtocolEvent::element(value_sym),
+                });
+            }
+        }
+    }
+
+    /// Builds the Merkle trees of layer `layer_idx` over its evaluations.
+    pub(crate) fn build_layer(
+        &self,
+        evaluations: Vec<FE>,
+        domain: Vec<FE>,
+        layer_idx: usize,
+    ) -> Result<FriLayer, FriError> {
+        FriLayer::new(evaluations, domain, self.params.cap_height(layer_idx)).ok_or_else(|| {
+            FriError::MerkleTreeConstructionError(format!(
+                "Failed to build Merkle tree for layer {}",
+                layer_idx
+            ))
+        })
+    }
+
+    /// Commits to the evaluations of layer `layer_idx` with a Merkle cap, and adds the cap to the
+    /// transcript to make it part of the public record.
+    fn commit_layer(
+        &mut self,
+        evaluations: Vec<FE>,
+        domain: Vec<FE>,
+        layer_idx: usize,
+    ) -> Result<FriLayer, FriError> {
+        let layer = self.build_layer(evaluations, domain, layer_idx)?;
+
+        let cap = layer.cap();
+        self.challenger.absorb_commitment(&cap);
+        let cap_hex: Vec<String> = cap
+            .iter()
+            .map(|node| format!("0x{}", hex::encode(node)))
+            .collect();
+        debug!(
+            "  > Layer {} committed with cap: [{}]",
+            layer_idx,
+            cap_hex.join(", ")
+        );
+        self.observer.on_event(ProtocolEvent::LayerCommitted {
+            layer: layer_idx,
+            cap: ProtocolEvent::cap(&cap),
+        });
+
+        Ok(layer)
     }
 
     /// Folds a layer of evaluations based on a challenge `beta`.
@@ -190,21 +535,27 @@ impl Prover {
     ///
     /// It takes a polynomial `f(x)` represented by its evaluations over a domain `D`,
     /// and computes the evaluations of a new, smaller polynomial `f_next(x^2)` over `D^2`.
+    /// Both are in bit-reversed order, so `f(x)` and `f(-x)` are adjacent and each pair folds
+    /// into the next layer's position of half their own.
     ///
     /// The formula is: `f_next(x^2) = (f(x) + f(-x))/2 + beta * (f(x) - f(-x))/(2x)`
     /// where `(f(x) + f(-x))/2` is the even part of `f` and `(f(x) - f(-x))/(2x)` is the odd part.
-    fn fold_evaluations(evaluations: &[FE], domain: &[FE], beta: &FE) -> (Vec<FE>, Vec<FE>) {
-        let next_domain_size = domain.len() / 2;
+    pub(crate) fn fold_evaluations(
+        evaluations: &[FE],
+        domain: &[FE],
+        beta: &FE,
+    ) -> (Vec<FE>, Vec<FE>) {
         let two_inv = FE::from(2).inv().unwrap();
 
-        let next_evaluations = (0..next_domain_size)
-            .map(|i| {
+        let next_evaluations = evaluations
+            .chunks(2)
+            .zip(domain.iter().step_by(2))
+            .map(|(pair, x)| {
                 // Get the evaluation at a point x and its symmetric counterpart -x
-                let y = &evaluations[i];
-                let y_symmetric = &evaluations[i + next_domain_size]; // Corresponds to -x
+                let y = &pair[0];
+                let y_symmetric = &pair[1]; // Corresponds to -x
 
-                // Get the domain value x and its inverse
-                let x = &domain[i];
+                // Get the inverse of the domain value x
                 let x_inv = x.inv().unwrap();
 
                 // Calculate the even and odd components of the polynomial
@@ -216,20 +567,119 @@ impl Prover {
             })
             .collect();
 
-        // The next domain consists of the squares of the first half of the current domain
-        let next_domain = domain
-            .iter()
-            .take(next_domain_size)
-            .map(|x| x.square())
-            .collect();
+        // The next domain consists of the squares of every other point of the current domain,
+        // which are already in the next domain's bit-reversed order
+        let next_domain = domain.iter().step_by(2).map(|x| x.square()).collect();
 
         (next_evaluations, next_domain)
     }
 
-    /// Samples a random index from the transcript.
-    fn sample_index(&mut self, max_value: usize) -> usize {
-        // Use 8 bytes from the transcript for a u64, then get a value in range.
-        let sample_bytes: [u8; 8] = self.transcript.sample()[..8].try_into().unwrap();
-        (u64::from_be_bytes(sample_bytes) % max_value as u64) as usize
+    /// Samples the query indices from the transcript.
+    fn sample_query_indices(&mut self) -> Vec<usize> {
+        self.challenger
+            .sample_query_indices(self.params.num_queries, self.params.domain.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+    use crate::test_utils::{demo_params, demo_poly, four_query_params};
+    use crate::verifier::Verifier;
+
+    /// Checks `proof` against the golden vector `golden/<name>.txt`, which holds the proof
+    /// encoded by [`FriProof::encode`].
+    ///
+    /// The vectors are only written when `BLESS_GOLDEN` is set, after a change that is meant to
+    /// alter proofs; a missing one fails the test, so that it cannot pass against itself.
+    fn assert_golden(name: &str, proof: &FriProof) {
+        let encoded = proof.encode();
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("golden")
+            .join(format!("{}.txt", name));
+        if std::env::var_os("BLESS_GOLDEN").is_some() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, &encoded).unwrap();
+        }
+        let Ok(golden) = fs::read_to_string(&path) else {
+            panic!(
+                "missing golden vector {}; run with BLESS_GOLDEN=1",
+                path.display()
+            );
+        };
+        assert_eq!(
+            golden,
+            encoded,
+            "the proof differs from {}; rerun with BLESS_GOLDEN=1 if the change is intended",
+            path.display()
+        );
+    }
+
+    #[test]
+    fn seeded_proofs_are_reproducible() {
+        let (poly, params) = (demo_poly(), demo_params().with_merkle_cap_height(1));
+        let proof = Prover::with_seed(poly.clone(), params.clone(), 1)
+            .prove()
+            .unwrap();
+        let again = Prover::with_seed(poly.clone(), params.clone(), 1)
+            .prove()
+            .unwrap();
+        let other = Prover::with_seed(poly, params.clone(), 2).prove().unwrap();
+        assert_eq!(proof, again);
+        assert_ne!(proof, other);
+
+        assert_eq!(
+            Verifier::with_seed(params.clone(), 1).verify(&proof),
+            Ok(())
+        );
+        assert!(Verifier::with_seed(params, 2).verify(&proof).is_err());
+    }
+
+    #[test]
+    fn seeded_proofs_match_their_golden_vectors() {
+        let (poly, params) = (demo_poly(), demo_params().with_merkle_cap_height(1));
+        for seed in [0, 1] {
+            let proof = Prover::with_seed(poly.clone(), params.clone(), seed)
+                .prove()
+                .unwrap();
+            assert_golden(&format!("fri_proof_seed_{}", seed), &proof);
+        }
+    }
+
+    #[test]
+    fn fft_evaluation_matches_pointwise_evaluation() {
+        let coefficients: Vec<FE> = (1..=6).map(FE::from).collect();
+        let poly = Polynomial::new(&coefficients);
+        let params = FriParameters::new(7, 4, 1, 0, FE::from(31)).unwrap();
+        let expected = poly.evaluate_slice(&params.domain);
+
+        let prover = Prover::new(poly, params);
+        assert_eq!(prover.evaluate_on_domain().unwrap(), expected);
+    }
+
+    #[test]
+    fn layers_keep_each_point_next_to_its_negation() {
+        let (poly, params) = (demo_poly(), demo_params().with_merkle_cap_height(1));
+        let (evaluations, domain) = Prover::new(poly, params).first_layer_evaluations().unwrap();
+        let (_, folded) = <Prover>::fold_evaluations(&evaluations, &domain, &FE::from(7));
+        for layer_domain in [&domain, &folded] {
+            assert!(layer_domain.chunks(2).all(|pair| pair[1] == -pair[0]));
+        }
+    }
+
+    #[test]
+    fn memory_lean_proof_matches_fast_proof() {
+        let poly = demo_poly();
+        let params = four_query_params().with_merkle_cap_height(1);
+
+        let fast = Prover::new(poly.clone(), params.clone()).prove().unwrap();
+        let lean = Prover::new(poly, params)
+            .with_mode(ProverMode::MemoryLean)
+            .prove()
+            .unwrap();
+        assert_eq!(fast, lean);
     }
 }
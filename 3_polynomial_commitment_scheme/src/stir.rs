@@ -0,0 +1,651 @@
+//! STIR, a low-degree test that needs fewer queries than FRI for the same security.
+//!
+//! A STIR round folds the committed polynomial `f` by a factor `k` with a challenge into `g`, and
+//! commits to `g` on a domain only half the size of `f`'s, where FRI would shrink it by `k`. The
+//! Verifier learns `g` at an out-of-domain point and, from `f`'s openings, at the fibres it
+//! queries. The next round tests the quotient of `g` by the vanishing polynomial of those points,
+//! which only has low degree if `g` agrees with all of them. Each round thus lowers the rate of
+//! the code being tested, and every query of a lower-rate code rejects a far polynomial with
+//! higher probability, so later rounds need fewer queries than FRI's, which keep the initial
+//! rate throughout.
+
+use lambdaworks_crypto::fiat_shamir::default_transcript::DefaultTranscript;
+use lambdaworks_crypto::merkle_tree::merkle::MerkleTree;
+use lambdaworks_crypto::merkle_tree::proof::Proof;
+use lambdaworks_math::fft::cpu::roots_of_unity::get_powers_of_primitive_root_coset;
+use lambdaworks_math::field::traits::IsFFTField;
+use lambdaworks_math::polynomial::Polynomial;
+use lambdaworks_math::traits::AsBytes;
+use tracing::{debug, info, info_span};
+
+use crate::challenges::FriChallenger;
+use crate::error::FriError;
+use crate::types::FriParameters;
+use crate::{FriBackend, F, FE, PROTOCOL_ID};
+
+/// Parameters of the STIR variant, in which each round folds `folding_factor` evaluations into
+/// one but only halves the domain, so every round tests a code of lower rate than the last.
+#[derive(Debug, Clone)]
+pub struct StirParameters {
+    /// The maximum degree the Prover claims for the polynomial.
+    pub claimed_degree: usize,
+    /// The ratio of the initial domain's size to the number of coefficients.
+    pub blowup_factor: usize,
+    /// The number `k` of evaluations each fold combines, a power of two of at least 4.
+    pub folding_factor: usize,
+    /// The conjectured security level the query counts are chosen for.
+    pub security_bits: usize,
+    /// The offset of every domain, each a coset of a subgroup of half the size of the last.
+    pub offset: FE,
+    /// The number of rounds that commit to a folded function, before the final fold is sent in
+    /// the clear.
+    pub num_rounds: usize,
+    /// The number of queries of each of the `num_rounds + 1` folds.
+    pub num_queries: Vec<usize>,
+}
+
+impl StirParameters {
+    /// Creates parameters for a polynomial of degree at most `claimed_degree`, folded
+    /// `folding_factor` ways in each round, and queried enough in each round for
+    /// `security_bits` bits of conjectured security.
+    ///
+    /// The initial domain is the one of [`FriParameters::new`] for the same degree, blowup factor
+    /// and offset, and the rounds continue until at most `folding_factor` coefficients are left.
+    ///
+    /// # Errors
+    /// Returns the errors of [`FriParameters::new`], [`FriError::NoQueries`] for a zero security
+    /// level, and [`FriError::InvalidFoldingFactor`] if `folding_factor` is not a power of two of
+    /// at least 4, exceeds the initial domain, or lets a folded domain meet the next domain.
+    pub fn new(
+        claimed_degree: usize,
+        blowup_factor: usize,
+        folding_factor: usize,
+        security_bits: usize,
+        offset: FE,
+    ) -> Result<Self, FriError> {
+        let fri = FriParameters::new(claimed_degree, blowup_factor, 1, 0, offset)?;
+        if security_bits == 0 {
+            return Err(FriError::NoQueries);
+        }
+        let domain_size = fri.domain.len();
+        if !folding_factor.is_power_of_two() || folding_factor < 4 || folding_factor > domain_size {
+            return Err(FriError::InvalidFoldingFactor(folding_factor));
+        }
+        // The folded domain offset^k * <w^k> and the next domain offset * <w^2> meet exactly when
+        // offset^(k-1) has an order that is a power of two.
+        let separation = offset.pow((folding_factor - 1) as u64);
+        if separation.pow(1_u64 << F::TWO_ADICITY) == FE::one() {
+            return Err(FriError::InvalidFoldingFactor(folding_factor));
+        }
+
+        // Fold until at most `k` coefficients are left, stopping early if the domain would no
+        // longer hold a fibre of `k` points.
+        let mut num_coefficients = claimed_degree + 1;
+        let mut num_rounds = 0;
+        while num_coefficients > folding_factor
+            && (domain_size >> (num_rounds + 1)) >= folding_factor
+        {
+            num_coefficients = num_coefficients.div_ceil(folding_factor);
+            num_rounds += 1;
+        }
+
+        let mut params = Self {
+            claimed_degree,
+            blowup_factor,
+            folding_factor,
+            security_bits,
+            offset,
+            num_rounds,
+            num_queries: Vec::new(),
+        };
+        // Each query of a fold catches a function far from its code except with probability about
+        // the code's rate, so lower-rate rounds need fewer queries.
+        params.num_queries = (0..=num_rounds)
+            .map(|i| {
+                let rate = params.nominal_coefficients(i) as f64 / params.domain_size(i) as f64;
+                let queries = (security_bits as f64 / -rate.log2()).ceil() as usize;
+                queries.clamp(1, params.domain_size(i) / folding_factor)
+            })
+            .collect();
+        Ok(params)
+    }
+
+    /// Returns the size of the domain of round `round`, half that of the round before.
+    pub fn domain_size(&self, round: usize) -> usize {
+        ((self.claimed_degree + 1) * self.blowup_factor) >> round
+    }
+
+    /// Returns the domain of round `round`.
+    pub fn domain(&self, round: usize) -> Vec<FE> {
+        let size = self.domain_size(round);
+        get_powers_of_primitive_root_coset::<F>(size.trailing_zeros() as u64, size, &self.offset)
+            .expect("the initial domain is within the field's two-adicity")
+    }
+
+    /// Returns the number of coefficients of the function folded in round `round`, before the
+    /// out-of-domain and query points of the earlier rounds are divided out.
+    fn nominal_coefficients(&self, round: usize) -> usize {
+        (0..round).fold(self.claimed_degree + 1, |n, _| {
+            n.div_ceil(self.folding_factor)
+        })
+    }
+
+    /// Returns the query complexity of a proof with these parameters.
+    pub fn query_complexity(&self) -> QueryComplexity {
+        let openings: usize = self.num_queries.iter().sum();
+        QueryComplexity {
+            queries_per_round: self.num_queries.clone(),
+            openings,
+            revealed_evaluations: openings * self.folding_factor + self.num_rounds,
+        }
+    }
+
+    /// Returns the query complexity of vanilla FRI at the same security level, for the same
+    /// polynomial and blowup factor, folding down to a constant.
+    pub fn fri_query_complexity(&self) -> QueryComplexity {
+        let rate = 1.0 / self.blowup_factor as f64;
+        let num_queries = (self.security_bits as f64 / -rate.log2()).ceil() as usize;
+        let fri = FriParameters::new(
+            self.claimed_degree,
+            self.blowup_factor,
+            num_queries,
+            0,
+            self.offset,
+        )
+        .expect("the same domain was accepted for STIR");
+        // Every query opens one leaf, holding a pair of evaluations, of each committed layer.
+        let num_layers = fri.num_folding_rounds().max(1);
+        QueryComplexity {
+            queries_per_round: vec![num_queries; num_layers],
+            openings: num_queries * num_layers,
+            revealed_evaluations: 2 * num_queries * num_layers,
+        }
+    }
+
+    /// Returns the challenger both parties start from, bound to the parameters.
+    fn challenger(&self) -> FriChallenger {
+        let mut challenger = FriChallenger::from_transcript(DefaultTranscript::new(PROTOCOL_ID));
+        let mut bytes: Vec<u8> = [
+            self.claimed_degree,
+            self.blowup_factor,
+            self.folding_factor,
+            self.security_bits,
+        ]
+        .iter()
+        .flat_map(|&n| (n as u64).to_be_bytes())
+        .collect();
+        bytes.extend(self.offset.as_bytes());
+        challenger.absorb_bytes(&bytes);
+        challenger
+    }
+}
+
+/// The work of the Verifier for one proof, in queries and openings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryComplexity {
+    /// The number of queries of each round.
+    pub queries_per_round: Vec<usize>,
+    /// The number of Merkle authentication paths the Verifier checks.
+    pub openings: usize,
+    /// The number of field elements the Prover reveals to answer the Verifier.
+    pub revealed_evaluations: usize,
+}
+
+/// The `k` evaluations of a committed function on a fibre `{x * z^t}`, where `z` is a `k`-th root
+/// of unity, together with the Merkle proof of the leaf holding them.
+#[derive(Debug, Clone)]
+pub struct FibreOpening {
+    /// The evaluations at `x * z^t`, for `t` from 0 to `k - 1`.
+    pub values: Vec<FE>,
+    /// The Merkle authentication path of the fibre's leaf.
+    pub auth_path: Vec<[u8; 32]>,
+}
+
+/// A round of a STIR proof.
+#[derive(Debug, Clone)]
+pub struct StirRound {
+    /// The Merkle root of the round's folded polynomial `g`, evaluated on the round's domain.
+    pub root: [u8; 32],
+    /// The value of `g` at the round's out-of-domain point.
+    pub ood_answer: FE,
+    /// The openings of the previous committed function at the round's queried fibres.
+    pub openings: Vec<FibreOpening>,
+}
+
+/// A STIR proof that a committed function is close to a polynomial of the claimed degree.
+#[derive(Debug, Clone)]
+pub struct StirProof {
+    /// The Merkle root of the polynomial's evaluations on the initial domain.
+    pub initial_root: [u8; 32],
+    /// The committed rounds.
+    pub rounds: Vec<StirRound>,
+    /// The coefficients of the final fold.
+    pub final_coefficients: Vec<FE>,
+    /// The openings of the last committed function at the final queried fibres.
+    pub final_openings: Vec<FibreOpening>,
+}
+
+/// The Prover of the STIR variant.
+pub struct StirProver {
+    poly: Polynomial<FE>,
+    params: StirParameters,
+}
+
+impl StirProver {
+    /// Creates a new Prover.
+    pub fn new(poly: Polynomial<FE>, params: StirParameters) -> Self {
+        Self { poly, params }
+    }
+
+    /// Generates the proof.
+    ///
+    /// Each round folds the current polynomial `f` with a challenge into `g`, of `k` times fewer
+    /// coefficients, and commits to `g` on a domain only half the size of `f`'s. The Verifier can
+    /// compute `g` on the fibres it queries from `f`'s openings, and learns `g` at an
+    /// out-of-domain point; the next `f` is `g` divided by the vanishing polynomial of all those
+    /// points, which only has low degree if `g` agrees with them.
+    pub fn prove(&self) -> Result<StirProof, FriError> {
+        self.prove_answering(|g, r_out| g.evaluate(r_out))
+    }
+
+    /// Generates the proof, sending `answer(g, r_out)` as each round's out-of-domain answer.
+    fn prove_answering(
+        &self,
+        answer: impl Fn(&Polynomial<FE>, &FE) -> FE,
+    ) -> Result<StirProof, FriError> {
+        let _span = info_span!("stir_prove").entered();
+        info!("--- STIR Prover: Starting proof generation ---");
+        let params = &self.params;
+        let k = params.folding_factor;
+        let mut challenger = params.challenger();
+
+        let mut f = self.poly.clone();
+        let mut domain = params.domain(0);
+        let mut evaluations = evaluate(&f, &domain)?;
+        let mut tree = commit(&evaluations, k)?;
+        challenger.absorb_commitment(&[tree.root]);
+        let initial_root = tree.root;
+
+        let mut rounds = Vec::with_capacity(params.num_rounds);
+        for i in 0..params.num_rounds {
+            let r_fold = challenger.sample_element();
+            let g = fold_polynomial(&f, &r_fold, k);
+            let next_domain = params.domain(i + 1);
+            let next_evaluations = evaluate(&g, &next_domain)?;
+            let next_tree = commit(&next_evaluations, k)?;
+            challenger.absorb_commitment(&[next_tree.root]);
+
+            let folded_domain = fold_domain(&domain, k);
+            let r_out = challenger.sample_out_of_domain(&[&next_domain, &folded_domain]);
+            let ood_answer = answer(&g, &r_out);
+            challenger.absorb_elements(&[ood_answer]);
+
+            let query_indices =
+                challenger.sample_query_indices(params.num_queries[i], folded_domain.len());
+            debug!(
+                "  > Round {}: {} coefficients left, queries at fibres {:?}",
+                i,
+                g.coefficients.len(),
+                query_indices
+            );
+            rounds.push(StirRound {
+                root: next_tree.root,
+                ood_answer,
+                openings: open_fibres(&evaluations, &tree, &query_indices, k),
+            });
+
+            // The next polynomial is g divided by the points at which the Verifier learns it.
+            let points: Vec<FE> = std::iter::once(r_out)
+                .chain(query_indices.iter().map(|&b| folded_domain[b]))
+                .collect();
+            f = quotient(&g, &points)?;
+            (domain, evaluations, tree) = (next_domain, next_evaluations, next_tree);
+        }
+
+        // The final fold is small enough to send in the clear.
+        let r_fold = challenger.sample_element();
+        let final_poly = fold_polynomial(&f, &r_fold, k);
+        challenger.absorb_elements(&final_poly.coefficients);
+        let query_indices = challenger
+            .sample_query_indices(params.num_queries[params.num_rounds], domain.len() / k);
+        let final_openings = open_fibres(&evaluations, &tree, &query_indices, k);
+
+        info!("--- STIR Prover: Proof generation complete ---");
+        Ok(StirProof {
+            initial_root,
+            rounds,
+            final_coefficients: final_poly.coefficients,
+            final_openings,
+        })
+    }
+}
+
+/// The Verifier of the STIR variant.
+pub struct StirVerifier {
+    params: StirParameters,
+}
+
+impl StirVerifier {
+    /// Creates a new Verifier.
+    pub fn new(params: StirParameters) -> Self {
+        Self { params }
+    }
+
+    /// Verifies the proof.
+    pub fn verify(&self, proof: &StirProof) -> Result<(), FriError> {
+        let _span = info_span!("stir_verify").entered();
+        info!("--- STIR Verifier: Starting verification ---");
+        let params = &self.params;
+        let k = params.folding_factor;
+        if proof.rounds.len() != params.num_rounds {
+            return Err(FriError::WrongNumberOfRounds {
+                expected: params.num_rounds,
+                got: proof.rounds.len(),
+            });
+        }
+        let mut challenger = params.challenger();
+        challenger.absorb_commitment(&[proof.initial_root]);
+
+        let mut root = proof.initial_root;
+        let mut domain = params.domain(0);
+        // How the function folded in the current round derives from the committed one, and how
+        // many coefficients it may have.
+        let mut claim: Option<QuotientClaim> = None;
+        let mut num_coefficients = params.claimed_degree + 1;
+
+        for (i, round) in proof.rounds.iter().enumerate() {
+            let r_fold = challenger.sample_element();
+            challenger.absorb_commitment(&[round.root]);
+            let next_domain = params.domain(i + 1);
+            let folded_domain = fold_domain(&domain, k);
+            let r_out = challenger.sample_out_of_domain(&[&next_domain, &folded_domain]);
+            challenger.absorb_elements(&[round.ood_answer]);
+            let query_indices =
+                challenger.sample_query_indices(params.num_queries[i], folded_domain.len());
+
+            let folded = fold_openings(
+                &root,
+                &domain,
+                claim.as_ref(),
+                &r_fold,
+                &query_indices,
+                &round.openings,
+                k,
+            )?;
+            debug!("  > Round {}: fibre openings are valid", i);
+
+            // The folded polynomial must take these values, and the next function is its
+            // quotient by them.
+            let points: Vec<FE> = std::iter::once(r_out)
+                .chain(query_indices.iter().map(|&b| folded_domain[b]))
+                .collect();
+            let values: Vec<FE> = std::iter::once(round.ood_answer).chain(folded).collect();
+            let folded_coefficients = num_coefficients.div_ceil(k);
+            let quotient_claim = QuotientClaim::new(points, &values)?;
+            // With at least as many points as coefficients, the quotient is zero and the values
+            // must determine the folded polynomial on their own.
+            if quotient_claim.answer.coefficients.len() > folded_coefficients {
+                return Err(FriError::LastLayerDegreeTooHigh {
+                    degree: quotient_claim.answer.degree(),
+                    bound: folded_coefficients.saturating_sub(1),
+                });
+            }
+            num_coefficients = folded_coefficients.saturating_sub(quotient_claim.points.len());
+            claim = Some(quotient_claim);
+            root = round.root;
+            domain = next_domain;
+        }
+
+        // The final fold must have low degree and match the last committed function.
+        let r_fold = challenger.sample_element();
+        let bound = num_coefficients.div_ceil(k);
+        let final_poly = Polynomial::new(&proof.final_coefficients);
+        if final_poly.coefficients.len() > bound {
+            return Err(FriError::LastLayerDegreeTooHigh {
+                degree: final_poly.degree(),
+                bound: bound.saturating_sub(1),
+            });
+        }
+        challenger.absorb_elements(&proof.final_coefficients);
+        let folded_domain = fold_domain(&domain, k);
+        let query_indices = challenger
+            .sample_query_indices(params.num_queries[params.num_rounds], folded_domain.len());
+        let folded = fold_openings(
+            &root,
+            &domain,
+            claim.as_ref(),
+            &r_fold,
+            &query_indices,
+            &proof.final_openings,
+            k,
+        )?;
+        for (&b, value) in query_indices.iter().zip(folded) {
+            let expected = final_poly.evaluate(&folded_domain[b]);
+            if expected != value {
+                return Err(FriError::InconsistentFolding {
+                    layer: params.num_rounds,
+                    expected: expected.representative().to_hex(),
+                    got: value.representative().to_hex(),
+                });
+            }
+        }
+
+        info!("--- STIR Verifier: Proof accepted ---");
+        Ok(())
+    }
+}
+
+/// The points a committed function `g` must agree with, turning it into the function
+/// `(g - answer) / vanishing` that the next round folds.
+struct QuotientClaim {
+    points: Vec<FE>,
+    /// The polynomial interpolating the claimed values at the points.
+    answer: Polynomial<FE>,
+}
+
+impl QuotientClaim {
+    fn new(points: Vec<FE>, values: &[FE]) -> Result<Self, FriError> {
+        let answer = Polynomial::interpolate(&points, values)
+            .map_err(|e| FriError::LastLayerInterpolationError(format!("{:?}", e)))?;
+        Ok(Self { points, answer })
+    }
+
+    /// Returns the quotient at `x`, a point of the next domain, from the committed value `g_x`.
+    fn apply(&self, x: &FE, g_x: FE) -> FE {
+        let vanishing = self
+            .points
+            .iter()
+            .fold(FE::one(), |acc, point| acc * (x - point));
+        (g_x - self.answer.evaluate(x))
+            * vanishing
+                .inv()
+                .expect("the domain is disjoint from the quotient's points")
+    }
+}
+
+/// Evaluates `poly` on a coset `domain` in natural order.
+fn evaluate(poly: &Polynomial<FE>, domain: &[FE]) -> Result<Vec<FE>, FriError> {
+    Polynomial::evaluate_offset_fft::<F>(poly, 1, Some(domain.len()), &domain[0])
+        .map_err(|e| FriError::EvaluationError(format!("{:?}", e)))
+}
+
+/// Commits to `evaluations` with one leaf per fibre of `k` points.
+///
+/// For a domain of `n` points, the fibre of index `b < n / k` holds the points `b + t * n / k`,
+/// whose `k`-th powers all equal that of point `b`.
+fn commit(evaluations: &[FE], k: usize) -> Result<MerkleTree<FriBackend>, FriError> {
+    let num_fibres = evaluations.len() / k;
+    let leaves: Vec<Vec<FE>> = (0..num_fibres)
+        .map(|b| (0..k).map(|t| evaluations[b + t * num_fibres]).collect())
+        .collect();
+    MerkleTree::<FriBackend>::build(&leaves).ok_or_else(|| {
+        FriError::MerkleTreeConstructionError("Failed to build a STIR Merkle tree".to_string())
+    })
+}
+
+/// Opens the fibres `query_indices` of the function committed to by `tree`.
+fn open_fibres(
+    evaluations: &[FE],
+    tree: &MerkleTree<FriBackend>,
+    query_indices: &[usize],
+    k: usize,
+) -> Vec<FibreOpening> {
+    let num_fibres = evaluations.len() / k;
+    query_indices
+        .iter()
+        .map(|&b| FibreOpening {
+            values: (0..k).map(|t| evaluations[b + t * num_fibres]).collect(),
+            auth_path: tree.get_proof_by_pos(b).unwrap().merkle_path,
+        })
+        .collect()
+}
+
+/// Checks the openings of the fibres `query_indices` of a committed function against `root`, and
+/// returns the fold with `r_fold` of the function they determine at each fibre.
+///
+/// The folded function is the committed one itself in the first round, and its quotient by
+/// `claim` in every later one.
+fn fold_openings(
+    root: &[u8; 32],
+    domain: &[FE],
+    claim: Option<&QuotientClaim>,
+    r_fold: &FE,
+    query_indices: &[usize],
+    openings: &[FibreOpening],
+    k: usize,
+) -> Result<Vec<FE>, FriError> {
+    if openings.len() != query_indices.len() {
+        return Err(FriError::WrongNumberOfDecommitments {
+            expected: query_indices.len(),
+            got: openings.len(),
+        });
+    }
+    let num_fibres = domain.len() / k;
+    query_indices
+        .iter()
+        .zip(openings)
+        .map(|(&b, opening)| {
+            let path = Proof {
+                merkle_path: opening.auth_path.clone(),
+            };
+            if opening.values.len() != k || !path.verify::<FriBackend>(root, b, &opening.values) {
+                return Err(FriError::InvalidMerkleProof);
+            }
+            let points: Vec<FE> = (0..k).map(|t| domain[b + t * num_fibres]).collect();
+            let values: Vec<FE> = match claim {
+                Some(claim) => points
+                    .iter()
+                    .zip(&opening.values)
+                    .map(|(x, &g_x)| claim.apply(x, g_x))
+                    .collect(),
+                None => opening.values.clone(),
+            };
+            fold_fibre(&points, &values, r_fold)
+        })
+        .collect()
+}
+
+/// Folds `f = sum_j x^j f_j(x^k)` into `g = sum_j r^j f_j`, with `k` times fewer coefficients.
+fn fold_polynomial(f: &Polynomial<FE>, r: &FE, k: usize) -> Polynomial<FE> {
+    let coefficients: Vec<FE> = f
+        .coefficients
+        .chunks(k)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .rev()
+                .fold(FE::zero(), |acc, &coefficient| acc * r + coefficient)
+        })
+        .collect();
+    Polynomial::new(&coefficients)
+}
+
+/// Returns the fold with `r` at `x^k` of a function taking `values` on the fibre `points` of `x`.
+///
+/// On the fibre, `x^k` is fixed, so `f` is the polynomial `h(X) = sum_j X^j f_j(x^k)` of degree
+/// less than `k`, and the fold is `h(r)`.
+fn fold_fibre(points: &[FE], values: &[FE], r: &FE) -> Result<FE, FriError> {
+    Polynomial::interpolate(points, values)
+        .map(|h| h.evaluate(r))
+        .map_err(|e| FriError::LastLayerInterpolationError(format!("{:?}", e)))
+}
+
+/// Returns the `k`-th power of the first point of each fibre of `domain`, which is the domain of
+/// the folded function.
+fn fold_domain(domain: &[FE], k: usize) -> Vec<FE> {
+    domain[..domain.len() / k]
+        .iter()
+        .map(|x| x.pow(k as u64))
+        .collect()
+}
+
+/// Returns `g` divided by the polynomial vanishing on `points`, after subtracting the polynomial
+/// interpolating `g` there, so that the division is exact.
+fn quotient(g: &Polynomial<FE>, points: &[FE]) -> Result<Polynomial<FE>, FriError> {
+    let values: Vec<FE> = points.iter().map(|point| g.evaluate(point)).collect();
+    let answer = QuotientClaim::new(points.to_vec(), &values)?.answer;
+    let len = g.coefficients.len().max(answer.coefficients.len());
+    let coefficient =
+        |p: &Polynomial<FE>, i: usize| p.coefficients.get(i).copied().unwrap_or_else(FE::zero);
+    let numerator: Vec<FE> = (0..len)
+        .map(|i| coefficient(g, i) - coefficient(&answer, i))
+        .collect();
+    Ok(points.iter().fold(Polynomial::new(&numerator), |p, point| {
+        p.ruffini_division(point)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A polynomial of degree 255, under a claimed degree of 255 and a blowup factor of 4.
+    fn setup() -> (Polynomial<FE>, StirParameters) {
+        let coefficients: Vec<FE> = (1..=256).map(FE::from).collect();
+        let params = StirParameters::new(255, 4, 4, 20, FE::from(31)).unwrap();
+        (Polynomial::new(&coefficients), params)
+    }
+
+    #[test]
+    fn honest_proof_verifies() {
+        let (poly, params) = setup();
+        let proof = StirProver::new(poly, params.clone()).prove().unwrap();
+        assert_eq!(proof.rounds.len(), 3);
+        assert_eq!(StirVerifier::new(params).verify(&proof), Ok(()));
+    }
+
+    #[test]
+    fn rejects_polynomial_above_the_claimed_degree() {
+        let (_, params) = setup();
+        let coefficients: Vec<FE> = (1..=512).map(FE::from).collect();
+        let proof = StirProver::new(Polynomial::new(&coefficients), params.clone())
+            .prove()
+            .unwrap();
+        assert!(matches!(
+            StirVerifier::new(params).verify(&proof),
+            Err(FriError::LastLayerDegreeTooHigh { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_a_wrong_out_of_domain_answer() {
+        let (poly, params) = setup();
+        let proof = StirProver::new(poly, params.clone())
+            .prove_answering(|g, r_out| g.evaluate(r_out) + FE::one())
+            .unwrap();
+        assert!(matches!(
+            StirVerifier::new(params).verify(&proof),
+            Err(FriError::LastLayerDegreeTooHigh { .. })
+        ));
+    }
+
+    #[test]
+    fn needs_fewer_openings_than_fri() {
+        let (_, params) = setup();
+        let stir = params.query_complexity();
+        let fri = params.fri_query_complexity();
+        // Later rounds test codes of lower rate, so they need fewer queries.
+        assert!(stir.queries_per_round.windows(2).all(|w| w[0] >= w[1]));
+        assert!(stir.openings < fri.openings);
+    }
+}
@@ -0,0 +1,33 @@
+//! The fixtures shared by the unit tests.
+
+use lambdaworks_math::polynomial::Polynomial;
+
+use crate::types::FriParameters;
+use crate::FE;
+
+/// P(x) = x^3 - 3x + 2, the polynomial of the demo.
+pub(crate) fn demo_poly() -> Polynomial<FE> {
+    Polynomial::new(&[FE::from(2), -FE::from(3), FE::from(0), FE::from(1)])
+}
+
+/// The parameters of the demo for [`demo_poly`], under single roots.
+pub(crate) fn demo_params() -> FriParameters {
+    FriParameters::new(3, 8, 2, 1, FE::from(31)).unwrap()
+}
+
+/// The parameters of [`demo_poly`] with four queries and no grinding, under single roots.
+pub(crate) fn four_query_params() -> FriParameters {
+    FriParameters::new(3, 8, 4, 0, FE::from(31)).unwrap()
+}
+
+/// A polynomial of degree 7, whose coefficients are 1 to 8.
+pub(crate) fn degree_seven_poly() -> Polynomial<FE> {
+    let coefficients: Vec<FE> = (1..=8).map(FE::from).collect();
+    Polynomial::new(&coefficients)
+}
+
+/// The parameters that fold [`degree_seven_poly`] in three rounds down to a constant, under
+/// single roots.
+pub(crate) fn degree_seven_params() -> FriParameters {
+    FriParameters::new(7, 4, 4, 0, FE::from(31)).unwrap()
+}
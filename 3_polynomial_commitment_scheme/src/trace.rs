@@ -0,0 +1,101 @@
+use serde::Serialize;
+
+use crate::FE;
+
+/// A step of the protocol, as the Prover performs it or the Verifier replays it from a proof.
+///
+/// Hashes are hex encoded and field elements are their hex representatives, so a trace reads the
+/// same in JSON as in the demo's log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ProtocolEvent {
+    /// A layer was committed to with its Merkle cap.
+    LayerCommitted { layer: usize, cap: Vec<String> },
+    /// The folding challenge of a round was sampled from the transcript.
+    ChallengeSampled { round: usize, beta: String },
+    /// The coefficients of the last layer's polynomial were sent.
+    LastLayerSent { coefficients: Vec<String> },
+    /// The query indices were sampled from the transcript.
+    QueriesSampled { indices: Vec<usize> },
+    /// For the query of original index `query`, a layer's evaluations at `index` and at its
+    /// symmetric index were opened.
+    QueryOpened {
+        query: usize,
+        layer: usize,
+        index: usize,
+        value: String,
+        value_sym: String,
+    },
+}
+
+impl ProtocolEvent {
+    /// Returns the hex encoding of a Merkle cap.
+    pub(crate) fn cap(cap: &[[u8; 32]]) -> Vec<String> {
+        cap.iter().map(hex::encode).collect()
+    }
+
+    /// Returns the hex representative of a field element.
+    pub(crate) fn element(x: &FE) -> String {
+        x.representative().to_hex()
+    }
+}
+
+/// A hook notified of every protocol event by a [`crate::prover::Prover`] or
+/// [`crate::verifier::Verifier`].
+pub trait Observer {
+    fn on_event(&mut self, event: ProtocolEvent);
+}
+
+/// The unit observer ignores every event, and is the default of both parties.
+impl Observer for () {
+    fn on_event(&mut self, _event: ProtocolEvent) {}
+}
+
+/// An observer recording every event, in order, to be dumped as JSON.
+///
+/// The Prover's trace of an honest run and the Verifier's trace of checking it agree event for
+/// event, so diffing two traces shows exactly where a cheating run departs from an honest one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ProtocolTrace {
+    pub events: Vec<ProtocolEvent>,
+}
+
+impl ProtocolTrace {
+    /// Creates an empty trace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the trace as a pretty-printed JSON array of events, each tagged by its `event`.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.events).expect("a trace always serializes")
+    }
+}
+
+impl Observer for ProtocolTrace {
+    fn on_event(&mut self, event: ProtocolEvent) {
+        self.events.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prover::Prover;
+    use crate::test_utils::{demo_poly, four_query_params};
+    use crate::verifier::Verifier;
+
+    #[test]
+    fn verifier_replays_the_provers_trace() {
+        let poly = demo_poly();
+        let params = four_query_params();
+
+        let mut prover = Prover::new(poly, params.clone()).with_observer(ProtocolTrace::new());
+        let proof = prover.prove().unwrap();
+        let mut verifier = Verifier::new(params).with_observer(ProtocolTrace::new());
+        verifier.verify(&proof).unwrap();
+
+        assert!(!prover.observer.events.is_empty());
+        assert_eq!(prover.observer, verifier.observer);
+    }
+}
@@ -1,16 +1,41 @@
+use lambdaworks_crypto::fiat_shamir::default_transcript::DefaultTranscript;
+use lambdaworks_crypto::fiat_shamir::is_transcript::IsTranscript;
 use lambdaworks_crypto::merkle_tree::merkle::MerkleTree;
-use lambdaworks_math::fft::cpu::roots_of_unity::get_powers_of_primitive_root;
-use lambdaworks_math::field::traits::RootsConfig;
+use lambdaworks_crypto::merkle_tree::proof::Proof;
+use lambdaworks_crypto::merkle_tree::traits::IsMerkleTreeBackend;
+use lambdaworks_math::fft::cpu::roots_of_unity::get_powers_of_primitive_root_coset;
+use lambdaworks_math::field::traits::{IsFFTField, IsPrimeField};
+use lambdaworks_math::traits::AsBytes;
 
-use crate::{FriBackend, F, FE};
+use crate::challenges::FriChallenger;
+use crate::error::FriError;
+use crate::{FriBackend, F, FE, PROTOCOL_ID};
+
+/// The bits of proof-of-work the Prover grinds before the query indices are sampled, each of
+/// which doubles the work of a Prover retrying for favourable queries. This implementation does
+/// not grind.
+pub const GRINDING_BITS: usize = 0;
 
 /// Shared parameters for the FRI protocol, agreed upon by the Prover and Verifier.
 #[derive(Debug, Clone)]
 pub struct FriParameters {
-    /// The initial evaluation domain (LDE).
+    /// The initial evaluation domain (LDE), the coset `offset * <w>` of the subgroup generated by
+    /// a primitive root of unity `w`, in natural order. The layers store their evaluations in
+    /// bit-reversed order instead; see [`FriLayer`].
     pub domain: Vec<FE>,
-    /// How many queries the Verifier will make to check the proof.
+    /// The coset offset of the domain. An offset of 1 gives the subgroup itself.
+    pub offset: FE,
+    /// How many query indices the Verifier samples. Repeated indices are queried once.
     pub num_queries: usize,
+    /// The claimed degree of the initial polynomial.
+    pub claimed_degree: usize,
+    /// Folding stops once the layer polynomial has at most this degree, and the Prover sends its
+    /// coefficients instead of committing to it.
+    pub last_layer_degree_bound: usize,
+    /// Each layer is committed to by the `2^merkle_cap_height` nodes at this depth of its Merkle
+    /// tree (its "cap") rather than by the root alone, which shortens every authentication path
+    /// by as many hashes. A height of 0 commits to the root.
+    pub merkle_cap_height: usize,
 }
 
 impl FriParameters {
@@ -21,54 +46,819 @@ impl FriParameters {
     /// * `blowup_factor`: How much larger the evaluation domain is than the number of coefficients.
     ///   A larger factor provides more security.
     /// * `num_queries`: The number of queries to perform. More queries also increase security.
-    pub fn new(claimed_degree: usize, blowup_factor: usize, num_queries: usize) -> Self {
-        // The Low-Degree Extension (LDE) domain size.
-        let domain_size = (claimed_degree + 1) * blowup_factor;
-        // The domain is a multiplicative subgroup, so its size must be a power of 2.
+    /// * `last_layer_degree_bound`: The degree at which folding stops. A bound of 0 folds all the
+    ///   way down to a constant.
+    /// * `offset`: The coset offset of the domain. An offset outside the subgroup keeps the domain
+    ///   clear of the subgroup's points, where quotient denominators such as `x^n - 1` vanish.
+    ///
+    /// # Errors
+    /// * [`FriError::BlowupFactorTooSmall`] if `blowup_factor` is below 2.
+    /// * [`FriError::NoQueries`] if `num_queries` is 0.
+    /// * [`FriError::ZeroOffset`] if `offset` is zero.
+    /// * [`FriError::DomainNotPowerOfTwo`] if `(claimed_degree + 1) * blowup_factor` is not a power
+    ///   of 2.
+    /// * [`FriError::DomainTooLarge`] if the field has no multiplicative subgroup of that size.
+    pub fn new(
+        claimed_degree: usize,
+        blowup_factor: usize,
+        num_queries: usize,
+        last_layer_degree_bound: usize,
+        offset: FE,
+    ) -> Result<Self, FriError> {
+        if blowup_factor < 2 {
+            return Err(FriError::BlowupFactorTooSmall(blowup_factor));
+        }
+        if num_queries == 0 {
+            return Err(FriError::NoQueries);
+        }
+        if offset == FE::zero() {
+            return Err(FriError::ZeroOffset);
+        }
+
+        // The Low-Degree Extension (LDE) domain size. A size that overflows is certainly too
+        // large for the field.
+        let domain_size = claimed_degree
+            .checked_add(1)
+            .and_then(|n| n.checked_mul(blowup_factor))
+            .ok_or(FriError::DomainTooLarge {
+                log_size: usize::BITS as u64,
+                two_adicity: F::TWO_ADICITY,
+            })?;
+        // The domain is a coset of a multiplicative subgroup, so its size must be a power of 2.
+        if !domain_size.is_power_of_two() {
+            return Err(FriError::DomainNotPowerOfTwo(domain_size));
+        }
         let root_order = domain_size.trailing_zeros() as u64;
+        if root_order > F::TWO_ADICITY {
+            return Err(FriError::DomainTooLarge {
+                log_size: root_order,
+                two_adicity: F::TWO_ADICITY,
+            });
+        }
 
-        let domain =
-            get_powers_of_primitive_root::<F>(root_order, domain_size, RootsConfig::Natural)
-                .unwrap();
+        let domain = get_powers_of_primitive_root_coset::<F>(root_order, domain_size, &offset)
+            .expect("the domain size is within the field's two-adicity");
 
-        Self {
+        Ok(Self {
             domain,
+            offset,
             num_queries,
+            claimed_degree,
+            last_layer_degree_bound,
+            merkle_cap_height: 0,
+        })
+    }
+
+    /// Creates parameters that reach `target_bits` of conjectured security with as few queries
+    /// as possible, for a polynomial of degree at most `claimed_degree` and a domain
+    /// `blowup_factor` times larger.
+    ///
+    /// Each query adds `log2(blowup_factor)` bits on top of the grinding, so the number of queries
+    /// is the rest of the target divided by that, rounded up. Folding goes all the way down to a
+    /// constant, and the domain is the coset of the field's multiplicative generator 31; the
+    /// builders adjust the rest.
+    ///
+    /// # Errors
+    /// Returns the errors of [`FriParameters::new`], with [`FriError::NoQueries`] for a target of
+    /// zero bits, and [`FriError::SecurityTargetTooHigh`] if the target exceeds what the field
+    /// allows over this domain, whatever the number of queries.
+    pub fn with_security(
+        claimed_degree: usize,
+        blowup_factor: usize,
+        target_bits: usize,
+    ) -> Result<Self, FriError> {
+        let mut params = Self::new(claimed_degree, blowup_factor, 1, 0, FE::from(31))?;
+        let bits_per_query = (blowup_factor as f64).log2();
+        params.num_queries =
+            (target_bits.saturating_sub(GRINDING_BITS) as f64 / bits_per_query).ceil() as usize;
+        if params.num_queries == 0 {
+            return Err(FriError::NoQueries);
+        }
+        let max_bits = params.commit_phase_bits();
+        if target_bits as f64 > max_bits {
+            return Err(FriError::SecurityTargetTooHigh {
+                target_bits,
+                max_bits: max_bits.max(0.0) as usize,
+            });
+        }
+        Ok(params)
+    }
+
+    /// Stops folding once the layer polynomial has at most `degree_bound` degree.
+    pub fn with_last_layer_degree_bound(mut self, degree_bound: usize) -> Self {
+        self.last_layer_degree_bound = degree_bound;
+        self
+    }
+
+    /// Commits to each layer with a Merkle cap of `2^cap_height` nodes instead of a single root.
+    pub fn with_merkle_cap_height(mut self, cap_height: usize) -> Self {
+        self.merkle_cap_height = cap_height;
+        self
+    }
+
+    /// Returns the cap height of layer `layer`'s Merkle tree: `merkle_cap_height`, limited to the
+    /// height of the tree itself, whose `n / 2^(layer + 1)` leaves each hold a pair of
+    /// evaluations.
+    pub fn cap_height(&self, layer: usize) -> usize {
+        let num_leaves = (self.domain.len() >> layer) / 2;
+        self.merkle_cap_height
+            .min(num_leaves.trailing_zeros() as usize)
+    }
+
+    /// Returns the canonical encoding of the parameters that the Prover and Verifier absorb into
+    /// the transcript before anything else, so proofs for different parameters draw different
+    /// challenges.
+    ///
+    /// The claimed degree, blowup factor, domain size, number of queries, last-layer degree bound
+    /// and Merkle cap height are each encoded as a big-endian `u64`, followed by the bytes of the
+    /// offset.
+    pub fn to_transcript_bytes(&self) -> Vec<u8> {
+        let domain_size = self.domain.len();
+        let blowup_factor = domain_size / (self.claimed_degree + 1);
+        let mut bytes: Vec<u8> = [
+            self.claimed_degree,
+            blowup_factor,
+            domain_size,
+            self.num_queries,
+            self.last_layer_degree_bound,
+            self.merkle_cap_height,
+        ]
+        .iter()
+        .flat_map(|&n| (n as u64).to_be_bytes())
+        .collect();
+        bytes.extend(self.offset.as_bytes());
+        bytes
+    }
+
+    /// Returns the parameters of the FRI proof for an evaluation quotient `(P(x) - y) / (x - z)`:
+    /// the same domain and queries, but a claimed degree one less than `P`'s.
+    pub fn quotient_parameters(&self) -> Self {
+        Self {
+            claimed_degree: self.claimed_degree.saturating_sub(1),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the conjectured security level of the parameters in bits; see
+    /// [`FriParameters::security_report`].
+    pub fn security_bits(&self) -> f64 {
+        self.security_report().conjectured_bits
+    }
+
+    /// Estimates the soundness of a proof with these parameters.
+    ///
+    /// With rate `rho = (claimed_degree + 1) / n`, each query catches a function far from every
+    /// low-degree polynomial except with probability about `rho` under the commonly used
+    /// conjecture, and `(1 + rho) / 2` provably within the unique-decoding radius. Either way,
+    /// the `num_queries` queries and the grinding multiply the Prover's work, while a folding
+    /// challenge of `log2|F|` bits, drawn over a domain of `n` points, bounds the security of the
+    /// commit phase at about `log2|F| - log2(n)` bits. With DEEP-FRI, the proof holds up to the
+    /// Johnson bound instead, where a query fails with probability about `sqrt(rho)`.
+    pub fn security_report(&self) -> SecurityReport {
+        self.security_report_for_challenges(field_bits())
+    }
+
+    /// Estimates the soundness of a proof with these parameters whose folding challenges carry
+    /// `challenge_bits` bits, such as those of an extension field.
+    pub(crate) fn security_report_for_challenges(&self, challenge_bits: f64) -> SecurityReport {
+        let rate = (self.claimed_degree + 1) as f64 / self.domain.len() as f64;
+        let commit_phase_bits = challenge_bits - (self.domain.len() as f64).log2();
+        let queries = self.num_queries as f64;
+        let grinding = GRINDING_BITS as f64;
+
+        let conjectured_query_bits = queries * -rate.log2() + grinding;
+        let proven_query_bits = queries * -((1.0 + rate) / 2.0).log2() + grinding;
+        let deep_query_bits = queries * -rate.sqrt().log2() + grinding;
+        SecurityReport {
+            rate,
+            num_queries: self.num_queries,
+            grinding_bits: GRINDING_BITS,
+            field_bits: challenge_bits,
+            conjectured_bits: conjectured_query_bits.min(commit_phase_bits),
+            proven_bits: proven_query_bits.min(commit_phase_bits),
+            deep_proven_bits: deep_query_bits.min(commit_phase_bits),
+        }
+    }
+
+    /// Returns the bits of grinding that would lift the conjectured security of these queries to
+    /// `target_bits`, or 0 if they already reach it.
+    ///
+    /// Grinding costs the Prover `2^bits` hashes once, where each query it replaces costs a
+    /// Merkle path per layer in every proof, so a few bits of it are a common way to round the
+    /// number of queries down. The target is still capped by the commit phase.
+    pub fn suggested_grinding_bits(&self, target_bits: usize) -> usize {
+        let rate = (self.claimed_degree + 1) as f64 / self.domain.len() as f64;
+        let query_bits = self.num_queries as f64 * -rate.log2();
+        (target_bits as f64 - query_bits).ceil().max(0.0) as usize
+    }
+
+    /// Returns the bound on the security of the commit phase, `log2|F| - log2(n)` bits.
+    fn commit_phase_bits(&self) -> f64 {
+        field_bits() - (self.domain.len() as f64).log2()
+    }
+
+    /// Returns the number of folding rounds, each halving the number of coefficients, needed to
+    /// bring the claimed degree down to `last_layer_degree_bound`.
+    pub fn num_folding_rounds(&self) -> usize {
+        self.fold_claimed_degree().0
+    }
+
+    /// Returns the degree the last layer's polynomial can have after the folding rounds, at most
+    /// `last_layer_degree_bound`.
+    pub fn last_layer_degree(&self) -> usize {
+        self.fold_claimed_degree().1 - 1
+    }
+
+    /// Halves the claimed number of coefficients until it is within the last-layer bound,
+    /// returning the number of rounds and the number of coefficients left.
+    fn fold_claimed_degree(&self) -> (usize, usize) {
+        let mut num_coefficients = self.claimed_degree + 1;
+        let mut rounds = 0;
+        while num_coefficients > self.last_layer_degree_bound + 1 {
+            num_coefficients = num_coefficients.div_ceil(2);
+            rounds += 1;
         }
+        (rounds, num_coefficients)
     }
 }
 
+/// Returns a lower bound on `log2` of the field size. `field_bit_size` rounds it up, so one bit
+/// less is safe.
+fn field_bits() -> f64 {
+    (F::field_bit_size() - 1) as f64
+}
+
+/// The estimated soundness of a set of FRI parameters, from [`FriParameters::security_report`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityReport {
+    /// The code rate `(claimed_degree + 1) / n`, the inverse of the blowup factor.
+    pub rate: f64,
+    /// The number of queries the Verifier samples.
+    pub num_queries: usize,
+    /// The bits of proof-of-work ground by the Prover.
+    pub grinding_bits: usize,
+    /// A lower bound on `log2` of the size of the field the challenges are drawn from.
+    pub field_bits: f64,
+    /// The security level in bits under the conjecture that each query is as strong as the
+    /// rate allows.
+    pub conjectured_bits: f64,
+    /// The security level in bits that is proven for the unique-decoding regime.
+    pub proven_bits: f64,
+    /// The security level in bits that is proven for DEEP-FRI, up to the Johnson bound.
+    pub deep_proven_bits: f64,
+}
+
 /// Represents a single layer in the FRI protocol's commitment-folding process.
+///
+/// The evaluations and the domain are stored in bit-reversed order: position `j` holds the point
+/// at natural index `reverse(j)`. Since `-x` lies half a domain away from `x`, and reversing the
+/// bits turns the top bit into the lowest, `f(x)` and `f(-x)` sit side by side at positions `2k`
+/// and `2k + 1`. Folding is then a sequential scan over adjacent pairs, whose results land at
+/// position `k` of the next layer in its own bit-reversed order, so a query at position `q` of the
+/// first layer lands at `q >> i` in layer `i`.
 #[derive(Clone)]
 pub struct FriLayer {
-    /// The evaluations of the polynomial for this layer.
+    /// The evaluations of the polynomial for this layer, in bit-reversed order.
     pub evaluations: Vec<FE>,
-    /// The Merkle tree committing to the evaluations.
-    pub merkle_tree: MerkleTree<FriBackend>,
-    /// The domain over which the evaluations were made.
+    /// The Merkle trees committing to the evaluations, one for each node of the cap, over
+    /// consecutive runs of leaves. Leaf `k` holds the pair `[evaluations[2k], evaluations[2k +
+    /// 1]]`, that is `f(x)` and `f(-x)`.
+    pub merkle_trees: Vec<MerkleTree<FriBackend>>,
+    /// The domain over which the evaluations were made, in bit-reversed order.
     pub domain: Vec<FE>,
 }
 
-/// A decommitment for a single query, providing evaluations and Merkle paths for each layer.
-#[derive(Debug, Clone)]
+impl FriLayer {
+    /// Commits to `evaluations` with a Merkle cap of `2^cap_height` nodes.
+    ///
+    /// The node of the cap above a run of leaves is the root of a Merkle tree over that run alone,
+    /// so the layer keeps one tree per node. Returns `None` if a tree cannot be built, such as for
+    /// a cap wider than the number of leaves.
+    pub fn new(evaluations: Vec<FE>, domain: Vec<FE>, cap_height: usize) -> Option<Self> {
+        let merkle_trees = build_cap_trees(&leaf_pairs(&evaluations), cap_height)?;
+        Some(Self {
+            evaluations,
+            merkle_trees,
+            domain,
+        })
+    }
+
+    /// Returns the Merkle cap committing to this layer.
+    pub fn cap(&self) -> Vec<[u8; 32]> {
+        self.merkle_trees.iter().map(|tree| tree.root).collect()
+    }
+
+    /// Returns the authentication path of leaf `leaf_idx` up to its node of the cap.
+    pub fn auth_path(&self, leaf_idx: usize) -> Vec<[u8; 32]> {
+        cap_auth_path(&self.merkle_trees, self.evaluations.len() / 2, leaf_idx)
+    }
+
+    /// Returns the Merkle multi-proof of the leaves `leaf_indices`, distinct and in ascending
+    /// order, up to the cap; see [`cap_multi_proof`].
+    pub fn multi_proof(&self, leaf_indices: &[usize]) -> Vec<[u8; 32]> {
+        cap_multi_proof(&self.merkle_trees, self.evaluations.len() / 2, leaf_indices)
+    }
+}
+
+/// Builds the Merkle trees under a cap of `2^cap_height` nodes, one over each run of consecutive
+/// `leaves`. Returns `None` if a tree cannot be built, such as for a cap wider than the number of
+/// leaves.
+pub(crate) fn build_cap_trees(
+    leaves: &[Vec<FE>],
+    cap_height: usize,
+) -> Option<Vec<MerkleTree<FriBackend>>> {
+    let run_length = leaves.len() >> cap_height;
+    if run_length == 0 {
+        return None;
+    }
+    leaves
+        .chunks(run_length)
+        .map(MerkleTree::<FriBackend>::build)
+        .collect()
+}
+
+/// Returns the authentication path of leaf `leaf_idx`, out of `num_leaves`, up to its node of
+/// the cap of `trees`.
+pub(crate) fn cap_auth_path(
+    trees: &[MerkleTree<FriBackend>],
+    num_leaves: usize,
+    leaf_idx: usize,
+) -> Vec<[u8; 32]> {
+    let run_length = num_leaves / trees.len();
+    trees[leaf_idx / run_length]
+        .get_proof_by_pos(leaf_idx % run_length)
+        .unwrap()
+        .merkle_path
+}
+
+/// Returns the Merkle multi-proof of the leaves `leaf_indices`, out of `num_leaves`, up to the
+/// cap of `trees`: the nodes their authentication paths need that cannot be computed from the
+/// leaves themselves.
+///
+/// `leaf_indices` must be distinct and in ascending order. Going up the trees one level at a time,
+/// the proof holds the sibling of each known node whose sibling is not known too, in ascending
+/// order of position; the parents of the known nodes are known at the next level. Paths that meet
+/// below the cap share their nodes above the meeting point, and two sibling leaves need none at
+/// their level, so the proof is never longer than the paths taken one by one.
+pub(crate) fn cap_multi_proof(
+    trees: &[MerkleTree<FriBackend>],
+    num_leaves: usize,
+    leaf_indices: &[usize],
+) -> Vec<[u8; 32]> {
+    let paths: Vec<Vec<[u8; 32]>> = leaf_indices
+        .iter()
+        .map(|&leaf_idx| cap_auth_path(trees, num_leaves, leaf_idx))
+        .collect();
+    let path_len = paths.first().map_or(0, Vec::len);
+
+    // Each known node is its position at the current level and a leaf below it, whose path
+    // holds the node's sibling.
+    let mut known: Vec<(usize, usize)> = leaf_indices.iter().copied().zip(0..).collect();
+    let mut nodes = Vec::new();
+    for level in 0..path_len {
+        let mut parents = Vec::with_capacity(known.len());
+        let mut k = 0;
+        while k < known.len() {
+            let (position, leaf) = known[k];
+            if known
+                .get(k + 1)
+                .is_some_and(|&(next, _)| next == position ^ 1)
+            {
+                k += 1;
+            } else {
+                nodes.push(paths[leaf][level]);
+            }
+            parents.push((position >> 1, leaf));
+            k += 1;
+        }
+        known = parents;
+    }
+    nodes
+}
+
+/// Checks that each of `leaves`, pairs of a leaf's index out of `num_leaves` and the leaf itself,
+/// lies under the Merkle cap `cap` of height `cap_height`, given their multi-proof
+/// [`cap_multi_proof`].
+///
+/// The leaves must be in ascending order of index, which must be distinct. The proof must hold
+/// exactly the nodes the leaves need, no fewer and no more.
+pub(crate) fn verify_cap_multi_proof(
+    cap: &[[u8; 32]],
+    cap_height: usize,
+    num_leaves: usize,
+    leaves: &[(usize, Vec<FE>)],
+    multi_proof: &[[u8; 32]],
+) -> Result<(), FriError> {
+    if cap.len() != 1 << cap_height {
+        return Err(FriError::InvalidMerkleProof);
+    }
+    // Each node of the cap covers `2^path_len` leaves.
+    let path_len = num_leaves.trailing_zeros() as usize - cap_height;
+    let mut proof_nodes = multi_proof.iter();
+    let mut known: Vec<(usize, [u8; 32])> = leaves
+        .iter()
+        .map(|(leaf_idx, leaf)| (*leaf_idx, FriBackend::hash_data(leaf)))
+        .collect();
+    for _ in 0..path_len {
+        let mut parents = Vec::with_capacity(known.len());
+        let mut k = 0;
+        while k < known.len() {
+            let (position, node) = known[k];
+            let sibling = match known.get(k + 1) {
+                Some(&(next, sibling)) if next == position ^ 1 => {
+                    k += 1;
+                    sibling
+                }
+                _ => *proof_nodes.next().ok_or(FriError::InvalidMerkleProof)?,
+            };
+            let parent = if position % 2 == 0 {
+                FriBackend::hash_new_parent(&node, &sibling)
+            } else {
+                FriBackend::hash_new_parent(&sibling, &node)
+            };
+            parents.push((position >> 1, parent));
+            k += 1;
+        }
+        known = parents;
+    }
+    if proof_nodes.next().is_some() {
+        return Err(FriError::InvalidMerkleProof);
+    }
+    if known
+        .iter()
+        .any(|(position, node)| cap.get(*position) != Some(node))
+    {
+        return Err(FriError::InvalidMerkleProof);
+    }
+    Ok(())
+}
+
+/// Checks that `leaf` is leaf `leaf_idx`, out of `num_leaves`, under the Merkle cap `cap` of
+/// height `cap_height`, given its authentication path up to the cap.
+pub(crate) fn verify_cap_path(
+    cap: &[[u8; 32]],
+    cap_height: usize,
+    num_leaves: usize,
+    leaf_idx: usize,
+    leaf: &[FE],
+    auth_path: &[[u8; 32]],
+) -> Result<(), FriError> {
+    if cap.len() != 1 << cap_height {
+        return Err(FriError::InvalidMerkleProof);
+    }
+    // Each node of the cap covers `2^path_len` leaves.
+    let path_len = num_leaves.trailing_zeros() as usize - cap_height;
+    let proof = Proof {
+        merkle_path: auth_path.to_vec(),
+    };
+    let cap_node = &cap[leaf_idx >> path_len];
+    let idx_below_cap = leaf_idx & ((1 << path_len) - 1);
+    if !proof.verify::<FriBackend>(cap_node, idx_below_cap, &leaf.to_vec()) {
+        return Err(FriError::InvalidMerkleProof);
+    }
+    Ok(())
+}
+
+/// A decommitment for a single query, providing its evaluations for each layer. The Merkle proofs
+/// of all queries are in the proof's [`FriProof::layer_multi_proofs`].
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct QueryDecommitment {
     /// The evaluation at the query index `q` for each layer.
     pub layer_evaluations: Vec<FE>,
-    /// The Merkle authentication path for `layer_evaluations` at each layer.
-    pub layer_auth_paths: Vec<Vec<[u8; 32]>>,
     /// The evaluation at the symmetric index `-q` for each layer.
     pub layer_evaluations_sym: Vec<FE>,
-    /// The Merkle authentication path for `layer_evaluations_sym` at each layer.
-    pub layer_auth_paths_sym: Vec<Vec<[u8; 32]>>,
+}
+
+/// Pairs each evaluation at `x` with the one at `-x`, its neighbour in bit-reversed order, into
+/// the leaves of a layer's Merkle tree.
+fn leaf_pairs(evaluations: &[FE]) -> Vec<Vec<FE>> {
+    evaluations.chunks(2).map(<[FE]>::to_vec).collect()
 }
 
 /// The complete FRI proof sent from the Prover to the Verifier.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FriProof {
-    /// The Merkle root of each FRI layer.
-    pub layer_commitments: Vec<[u8; 32]>,
-    /// The value of the final, constant polynomial.
-    pub last_layer_value: FE,
-    /// The decommitments for each query.
+    /// The Merkle cap of each committed FRI layer, a single root for a cap height of 0. The last
+    /// layer is not committed.
+    pub layer_commitments: Vec<Vec<[u8; 32]>>,
+    /// The coefficients of the last layer's polynomial, of degree at most
+    /// `last_layer_degree_bound`.
+    pub last_layer_coefficients: Vec<FE>,
+    /// The decommitments for each distinct query index, in ascending order of index.
     pub query_decommitments: Vec<QueryDecommitment>,
+    /// The Merkle multi-proof of each committed layer, authenticating the leaves of all queries
+    /// at once. The queries share a leaf wherever they meet, and their paths share every node
+    /// above.
+    pub layer_multi_proofs: Vec<Vec<[u8; 32]>>,
+}
+
+impl FriProof {
+    /// Checks that the proof has the shape `params` give it, so that the Verifier can index it
+    /// without panicking: one cap of the right width and one multi-proof per committed layer, at
+    /// most one decommitment per query, and in each decommitment, one pair of evaluations per
+    /// committed layer.
+    ///
+    /// The length of a multi-proof depends on where the queries land, so the Verifier checks it
+    /// along with the proof.
+    ///
+    /// # Errors
+    /// Returns [`FriError::MalformedProof`] naming the first part out of shape.
+    pub fn validate_shape(&self, params: &FriParameters) -> Result<(), FriError> {
+        let malformed = |reason: String| Err(FriError::MalformedProof { reason });
+        // Without any folding, the first layer is committed and is also the last one.
+        let num_layers = params.num_folding_rounds().max(1);
+        if self.layer_commitments.len() != num_layers {
+            return malformed(format!(
+                "{} layer commitments, expected {}",
+                self.layer_commitments.len(),
+                num_layers
+            ));
+        }
+        for (i, cap) in self.layer_commitments.iter().enumerate() {
+            let width = 1 << params.cap_height(i);
+            if cap.len() != width {
+                return malformed(format!(
+                    "a cap of {} nodes for layer {}, expected {}",
+                    cap.len(),
+                    i,
+                    width
+                ));
+            }
+        }
+        if self.layer_multi_proofs.len() != num_layers {
+            return malformed(format!(
+                "{} layer multi-proofs, expected {}",
+                self.layer_multi_proofs.len(),
+                num_layers
+            ));
+        }
+        if self.query_decommitments.len() > params.num_queries {
+            return malformed(format!(
+                "{} query decommitments for {} queries",
+                self.query_decommitments.len(),
+                params.num_queries
+            ));
+        }
+
+        for (q, decommitment) in self.query_decommitments.iter().enumerate() {
+            let lengths = [
+                ("evaluations", decommitment.layer_evaluations.len()),
+                (
+                    "symmetric evaluations",
+                    decommitment.layer_evaluations_sym.len(),
+                ),
+            ];
+            for (part, len) in lengths {
+                if len != num_layers {
+                    return malformed(format!(
+                        "{} {} in decommitment {}, expected {}",
+                        len, part, q, num_layers
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the size of the proof's payload in bytes; see [`FriProof::size_breakdown`].
+    pub fn size_in_bytes(&self) -> usize {
+        self.size_breakdown().total()
+    }
+
+    /// Returns the size of each component of the proof, counting 32 bytes per hash and the
+    /// canonical `ceil(log2|F| / 8)` bytes per field element. Lengths of the vectors are treated
+    /// as known to the Verifier from the parameters and are not counted.
+    pub fn size_breakdown(&self) -> ProofSize {
+        let hash_bytes = std::mem::size_of::<[u8; 32]>();
+        let element_bytes = F::field_bit_size().div_ceil(8);
+        let decommitments = &self.query_decommitments;
+        ProofSize {
+            commitments: self.layer_commitments.iter().map(Vec::len).sum::<usize>() * hash_bytes,
+            auth_paths: self.layer_multi_proofs.iter().map(Vec::len).sum::<usize>() * hash_bytes,
+            evaluations: decommitments
+                .iter()
+                .map(|d| d.layer_evaluations.len() + d.layer_evaluations_sym.len())
+                .sum::<usize>()
+                * element_bytes,
+            last_layer: self.last_layer_coefficients.len() * element_bytes,
+        }
+    }
+}
+
+/// The size in bytes of each component of a [`FriProof`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofSize {
+    /// The Merkle caps of the committed layers.
+    pub commitments: usize,
+    /// The Merkle multi-proofs of the committed layers.
+    pub auth_paths: usize,
+    /// The opened evaluations of all queries.
+    pub evaluations: usize,
+    /// The coefficients of the last layer's polynomial.
+    pub last_layer: usize,
+}
+
+impl ProofSize {
+    /// Returns the size of the whole proof.
+    pub fn total(&self) -> usize {
+        self.commitments + self.auth_paths + self.evaluations + self.last_layer
+    }
+}
+
+/// A single opening of the committed polynomial `P` at a query point `x` and at `-x`.
+#[derive(Debug, Clone)]
+pub struct PolynomialOpening {
+    /// The evaluation `P(x)` at the query index.
+    pub evaluation: FE,
+    /// The evaluation `P(-x)` at the symmetric index.
+    pub evaluation_sym: FE,
+    /// The Merkle authentication path of the leaf holding both evaluations, up to its node of the
+    /// commitment's cap.
+    pub auth_path: Vec<[u8; 32]>,
+}
+
+/// A proof that the committed polynomial `P` evaluates to `value` at a point `z`.
+#[derive(Debug, Clone)]
+pub struct EvaluationProof {
+    /// The claimed evaluation `y = P(z)`.
+    pub value: FE,
+    /// The FRI proof that the quotient `(P(x) - y) / (x - z)` has low degree.
+    pub quotient_proof: FriProof,
+    /// The openings of `P` at every query of `quotient_proof`, in the same order.
+    pub openings: Vec<PolynomialOpening>,
+}
+
+/// Returns the encoding of the claim `P(z) = y` about the polynomial committed to by `commitment`,
+/// which starts the quotient's transcript after the parameters.
+pub fn evaluation_claim_bytes(commitment: &[[u8; 32]], z: &FE, y: &FE) -> Vec<u8> {
+    let mut bytes: Vec<u8> = commitment.iter().flatten().copied().collect();
+    bytes.extend(z.as_bytes());
+    bytes.extend(y.as_bytes());
+    bytes
+}
+
+/// The labels that keep the transcripts of different protocols and applications apart, so a
+/// proof made for one never verifies, nor draws the same challenges, in another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainSeparator {
+    /// The label the transcript is initialized with, naming the protocol.
+    pub protocol_id: Vec<u8>,
+    /// A string supplied by the application, such as its name and version or the statement the
+    /// proof belongs to.
+    pub context: Vec<u8>,
+    /// An explicit seed absorbed after the context, so that a fixed seed pins every challenge,
+    /// and with it the whole proof, while different seeds give independent ones.
+    pub seed: Option<u64>,
+}
+
+impl DomainSeparator {
+    /// Creates a domain separator for the protocol `protocol_id` within the application context
+    /// `context`.
+    pub fn new(protocol_id: &[u8], context: &[u8]) -> Self {
+        Self {
+            protocol_id: protocol_id.to_vec(),
+            context: context.to_vec(),
+            seed: None,
+        }
+    }
+
+    /// Seeds the transcript with `seed`, for reproducible test vectors and exercises.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns the transcript both parties start from: initialized with the protocol label, then
+    /// bound to the length-prefixed context, the seed if any and the parameters, before any
+    /// commitment.
+    pub fn transcript(&self, params: &FriParameters) -> DefaultTranscript<F> {
+        let mut transcript = DefaultTranscript::new(&self.protocol_id);
+        // The length prefix keeps the context from running into the parameters.
+        transcript.append_bytes(&(self.context.len() as u64).to_be_bytes());
+        transcript.append_bytes(&self.context);
+        // A flag byte tells a seeded transcript from an unseeded one.
+        match self.seed {
+            Some(seed) => {
+                transcript.append_bytes(&[1]);
+                transcript.append_bytes(&seed.to_be_bytes());
+            }
+            None => transcript.append_bytes(&[0]),
+        }
+        transcript.append_bytes(&params.to_transcript_bytes());
+        transcript
+    }
+}
+
+/// The crate's own protocol label, `Educational FRI`, with an empty context.
+impl Default for DomainSeparator {
+    fn default() -> Self {
+        Self::new(PROTOCOL_ID, b"")
+    }
+}
+
+/// Samples the out-of-domain point of DEEP-FRI for the polynomial committed to by `commitment`.
+///
+/// The point is drawn from a transcript of the domain separator, the parameters and the
+/// commitment, so the Prover learns it only once committed. A point that falls in the domain is
+/// drawn again.
+pub fn deep_point(
+    params: &FriParameters,
+    separator: &DomainSeparator,
+    commitment: &[[u8; 32]],
+) -> FE {
+    let mut challenger = FriChallenger::new(separator, params);
+    challenger.absorb_commitment(commitment);
+    challenger.sample_out_of_domain(&[&params.domain])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_security_reaches_the_target_with_the_fewest_queries() {
+        let params = FriParameters::with_security(3, 8, 20).unwrap();
+        assert_eq!(params.num_queries, 7);
+        assert!(params.security_bits() >= 20.0);
+        assert_eq!(params.suggested_grinding_bits(20), 0);
+
+        // One query fewer falls short, by as many bits as grinding would have to make up.
+        let fewer = FriParameters::new(3, 8, 6, 0, FE::from(31)).unwrap();
+        assert!(fewer.security_bits() < 20.0);
+        assert_eq!(fewer.suggested_grinding_bits(20), 2);
+    }
+
+    #[test]
+    fn with_security_rejects_targets_beyond_the_field() {
+        assert_eq!(
+            FriParameters::with_security(3, 8, 100).unwrap_err(),
+            FriError::SecurityTargetTooHigh {
+                target_bits: 100,
+                max_bits: 25,
+            }
+        );
+        assert_eq!(
+            FriParameters::with_security(3, 8, 0).unwrap_err(),
+            FriError::NoQueries
+        );
+    }
+
+    /// A layer of 32 leaves under a cap of 2 nodes, and the leaves at `leaf_indices`.
+    fn opened_layer(leaf_indices: &[usize]) -> (FriLayer, Vec<(usize, Vec<FE>)>) {
+        let evaluations: Vec<FE> = (0..64).map(FE::from).collect();
+        let layer = FriLayer::new(evaluations.clone(), evaluations, 1).unwrap();
+        let leaves = leaf_indices
+            .iter()
+            .map(|&i| (i, layer.evaluations[2 * i..2 * i + 2].to_vec()))
+            .collect();
+        (layer, leaves)
+    }
+
+    #[test]
+    fn multi_proofs_authenticate_leaves_with_fewer_nodes_than_their_paths() {
+        let leaf_indices = [0, 1, 5, 20, 21, 31];
+        let (layer, leaves) = opened_layer(&leaf_indices);
+        let multi_proof = layer.multi_proof(&leaf_indices);
+        assert_eq!(
+            verify_cap_multi_proof(&layer.cap(), 1, 32, &leaves, &multi_proof),
+            Ok(())
+        );
+
+        let paths_len: usize = leaf_indices.iter().map(|&i| layer.auth_path(i).len()).sum();
+        assert!(multi_proof.len() < paths_len);
+        // A single leaf's multi-proof is its authentication path.
+        assert_eq!(layer.multi_proof(&[5]), layer.auth_path(5));
+    }
+
+    #[test]
+    fn multi_proofs_reject_other_leaves_and_other_nodes() {
+        let leaf_indices = [3, 4, 17];
+        let (layer, leaves) = opened_layer(&leaf_indices);
+        let cap = layer.cap();
+        let multi_proof = layer.multi_proof(&leaf_indices);
+
+        let mut wrong_leaf = leaves.clone();
+        wrong_leaf[1].1[0] += FE::one();
+        let mut fewer = multi_proof.clone();
+        fewer.pop();
+        let mut more = multi_proof.clone();
+        more.push([0; 32]);
+        let mut tampered = multi_proof.clone();
+        tampered[0][0] ^= 1;
+        for (leaves, multi_proof) in [
+            (&wrong_leaf, &multi_proof),
+            (&leaves, &fewer),
+            (&leaves, &more),
+            (&leaves, &tampered),
+        ] {
+            assert_eq!(
+                verify_cap_multi_proof(&cap, 1, 32, leaves, multi_proof),
+                Err(FriError::InvalidMerkleProof)
+            );
+        }
+        assert_eq!(
+            verify_cap_multi_proof(&cap[..1], 1, 32, &leaves, &multi_proof),
+            Err(FriError::InvalidMerkleProof)
+        );
+    }
 }
@@ -1,49 +1,222 @@
-use lambdaworks_crypto::fiat_shamir::default_transcript::DefaultTranscript;
-use lambdaworks_crypto::fiat_shamir::is_transcript::IsTranscript;
-use lambdaworks_crypto::merkle_tree::proof::Proof;
+use lambdaworks_math::fft::cpu::bit_reversing::reverse_index;
 use lambdaworks_math::field::traits::IsFFTField;
-use lambdaworks_math::traits::AsBytes;
+use lambdaworks_math::polynomial::Polynomial;
+use tracing::{debug, info, info_span};
 
+use crate::challenges::FriChallenger;
 use crate::error::FriError;
-use crate::types::{FriParameters, FriProof, QueryDecommitment};
-use crate::{FriBackend, F, FE, PROTOCOL_ID};
+use crate::trace::{Observer, ProtocolEvent};
+use crate::types::{
+    deep_point, verify_cap_multi_proof, verify_cap_path, DomainSeparator, EvaluationProof,
+    FriParameters, FriProof, QueryDecommitment,
+};
+use crate::{F, FE};
 
 /// The Verifier entity for the FRI protocol.
-pub struct Verifier {
+pub struct Verifier<O: Observer = ()> {
     params: FriParameters,
-    transcript: DefaultTranscript<F>,
+    separator: DomainSeparator,
+    challenger: FriChallenger,
+    /// The observer notified of every step the Verifier replays from a proof.
+    pub observer: O,
 }
 
 impl Verifier {
-    /// Creates a new Verifier.
+    /// Creates a new Verifier, under the crate's default [`DomainSeparator`].
     pub fn new(params: FriParameters) -> Self {
+        Self::with_domain_separator(params, DomainSeparator::default())
+    }
+
+    /// Creates a new Verifier for the proofs of [`crate::prover::Prover::with_seed`] with the same
+    /// `seed`.
+    pub fn with_seed(params: FriParameters, seed: u64) -> Self {
+        Self::with_domain_separator(params, DomainSeparator::default().with_seed(seed))
+    }
+
+    /// Creates a new Verifier that only accepts proofs made under `separator`.
+    pub fn with_domain_separator(params: FriParameters, separator: DomainSeparator) -> Self {
+        // Bind the labels and the public parameters, as the Prover does, before replaying any
+        // commitment.
+        let challenger = FriChallenger::new(&separator, &params);
         Self {
             params,
-            transcript: DefaultTranscript::new(PROTOCOL_ID),
+            separator,
+            challenger,
+            observer: (),
+        }
+    }
+}
+
+impl<O: Observer> Verifier<O> {
+    /// Notifies `observer` of every step replayed from the proofs this Verifier checks.
+    pub fn with_observer<P: Observer>(self, observer: P) -> Verifier<P> {
+        Verifier {
+            params: self.params,
+            separator: self.separator,
+            challenger: self.challenger,
+            observer,
         }
     }
 
     /// Verifies the FRI proof.
     pub fn verify(&mut self, proof: &FriProof) -> Result<(), FriError> {
-        println!("--- Verifier: Starting verification ---");
+        self.verify_queries(proof).map(|_| ())
+    }
+
+    /// Verifies a proof from [`crate::prover::Prover::open`] that the polynomial committed to by
+    /// `commitment` evaluates to `y` at `z`.
+    ///
+    /// The quotient `q(x) = (P(x) - y) / (x - z)` must pass FRI, and at every queried point `x`
+    /// and `-x`, the opened `P(x)` must be in the commitment and give the quotient's `q(x)`.
+    pub fn verify_eval(
+        &self,
+        commitment: &[[u8; 32]],
+        z: &FE,
+        y: &FE,
+        proof: &EvaluationProof,
+    ) -> Result<(), FriError> {
+        let _span = info_span!("verify_eval").entered();
+        info!(
+            "--- Verifier: Checking the evaluation at z = {} ---",
+            z.representative()
+        );
+        if self.params.domain.contains(z) {
+            return Err(FriError::EvaluationPointInDomain);
+        }
+
+        // Replay the quotient's transcript, which starts from the claim it proves.
+        let mut quotient_verifier = Verifier::with_domain_separator(
+            self.params.quotient_parameters(),
+            self.separator.clone(),
+        );
+        quotient_verifier
+            .challenger
+            .absorb_evaluation_claim(commitment, z, y);
+        let query_indices = quotient_verifier.verify_queries(&proof.quotient_proof)?;
+        if proof.openings.len() != query_indices.len() {
+            return Err(FriError::WrongNumberOfDecommitments {
+                expected: query_indices.len(),
+                got: proof.openings.len(),
+            });
+        }
+
+        let domain_size = self.params.domain.len();
+        let queries = query_indices
+            .iter()
+            .zip(&proof.openings)
+            .zip(&proof.quotient_proof.query_decommitments);
+        for ((&query_idx, opening), decommitment) in queries {
+            let sym_idx = query_idx ^ 1;
+            self.verify_leaf(
+                commitment,
+                0,
+                query_idx,
+                opening.evaluation,
+                opening.evaluation_sym,
+                &opening.auth_path,
+            )?;
+
+            // The quotient's first layer must be (P(x) - y) / (x - z) at both points.
+            let points = [
+                (
+                    query_idx,
+                    opening.evaluation,
+                    decommitment.layer_evaluations[0],
+                ),
+                (
+                    sym_idx,
+                    opening.evaluation_sym,
+                    decommitment.layer_evaluations_sym[0],
+                ),
+            ];
+            for (idx, p_x, q_x) in points {
+                let x = self.params.domain[reverse_index(idx, domain_size as u64)];
+                if (p_x - y) * (x - z).inv().unwrap() != q_x {
+                    return Err(FriError::InconsistentQuotient { index: idx });
+                }
+            }
+            debug!(
+                "  > Opening of P at indices {} and {} matches the quotient",
+                query_idx, sym_idx
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Verifies a proof from [`crate::prover::Prover::prove_deep`] that the function committed to
+    /// by `commitment` is close to a polynomial of the claimed degree.
+    ///
+    /// The out-of-domain point is drawn again from the commitment, and the proof must open the
+    /// commitment there as for [`Verifier::verify_eval`].
+    pub fn verify_deep(
+        &self,
+        commitment: &[[u8; 32]],
+        proof: &EvaluationProof,
+    ) -> Result<(), FriError> {
+        let z = deep_point(&self.params, &self.separator, commitment);
+        self.verify_eval(commitment, &z, &proof.value, proof)
+    }
+
+    /// Verifies the FRI proof, returning the query indices it checked.
+    fn verify_queries(&mut self, proof: &FriProof) -> Result<Vec<usize>, FriError> {
+        let _span = info_span!("verify").entered();
+        info!("--- Verifier: Starting verification ---");
 
         // Reconstruct the challenges (`betas`) and query indices by replaying the transcript.
         let (betas, query_indices) = self.reconstruct_challenges(proof);
+        self.check_queries(proof, &betas, &query_indices)?;
 
-        let root_order = self.params.domain.len().trailing_zeros();
-        let generator = F::get_primitive_root_of_unity(root_order as u64).unwrap();
+        Ok(query_indices)
+    }
+
+    /// Checks `proof` against the folding challenges `betas` and the query indices, however they
+    /// were drawn: replayed from the transcript, or sent over an
+    /// [`crate::interactive::Channel`].
+    pub(crate) fn check_queries(
+        &mut self,
+        proof: &FriProof,
+        betas: &[FE],
+        query_indices: &[usize],
+    ) -> Result<(), FriError> {
+        proof.validate_shape(&self.params)?;
+
+        // The folds halve the degree of an honest polynomial in each round, so the last layer
+        // must be no larger than the claimed degree allows.
+        let bound = self.params.last_layer_degree();
+        let last_poly = Polynomial::new(&proof.last_layer_coefficients);
+        if last_poly.coefficients.len() > bound + 1 {
+            return Err(FriError::LastLayerDegreeTooHigh {
+                degree: last_poly.degree(),
+                bound,
+            });
+        }
+
+        if proof.query_decommitments.len() != query_indices.len() {
+            return Err(FriError::WrongNumberOfDecommitments {
+                expected: query_indices.len(),
+                got: proof.query_decommitments.len(),
+            });
+        }
+
+        let generator = self.generator();
+
+        // Authenticate the opened leaves of each layer, for all queries at once.
+        for i in 0..proof.layer_commitments.len() {
+            self.verify_multi_proof(proof, i, query_indices)?;
+        }
 
         // Verify each query independently.
         for (query_num, &query_idx) in query_indices.iter().enumerate() {
-            println!(
-                "\n[Verifier] Verifying query #{} (for original index {})",
+            debug!(
+                "[Verifier] Verifying query #{} (for original index {})",
                 query_num + 1,
                 query_idx
             );
             self.verify_query(
                 proof,
                 query_idx,
-                &betas,
+                betas,
                 &generator,
                 &proof.query_decommitments[query_num],
             )?;
@@ -56,43 +229,67 @@ impl Verifier {
     /// This ensures the Verifier uses the exact same random values as the Prover.
     fn reconstruct_challenges(&mut self, proof: &FriProof) -> (Vec<FE>, Vec<usize>) {
         // Feed the commitments into the transcript in the same order as the Prover.
-        self.transcript.append_bytes(&proof.layer_commitments[0]);
-        let betas: Vec<FE> = proof
-            .layer_commitments
-            .iter()
-            .skip(1)
-            .map(|commitment| {
-                // Sample the field element *before* appending the next commitment.
-                let beta = self.transcript.sample_field_element();
-                self.transcript.append_bytes(commitment);
+        self.append_layer_commitment(proof, 0);
+        let betas: Vec<FE> = (0..self.params.num_folding_rounds())
+            .map(|i| {
+                // Sample the field element *before* appending the next commitment. The last
+                // round's layer is not committed, so there is none to append after it.
+                let beta = self.challenger.sample_element();
+                self.observer.on_event(ProtocolEvent::ChallengeSampled {
+                    round: i,
+                    beta: ProtocolEvent::element(&beta),
+                });
+                self.append_layer_commitment(proof, i + 1);
                 beta
             })
             .collect();
 
-        // Feed the last layer's value.
-        self.transcript
-            .append_bytes(&proof.last_layer_value.as_bytes());
+        // Feed the last layer's coefficients.
+        self.challenger
+            .absorb_elements(&proof.last_layer_coefficients);
+        self.observer.on_event(ProtocolEvent::LastLayerSent {
+            coefficients: proof
+                .last_layer_coefficients
+                .iter()
+                .map(ProtocolEvent::element)
+                .collect(),
+        });
 
-        // Now, sample the query indices. They will be the same as the Prover's.
-        let query_indices = (0..proof.query_decommitments.len())
-            .map(|_| self.sample_index(self.params.domain.len()))
-            .collect();
+        // Now, sample the query indices. They will be the same as the Prover's, deduplicated and
+        // sorted in the same way.
+        let query_indices = self
+            .challenger
+            .sample_query_indices(self.params.num_queries, self.params.domain.len());
+        self.observer.on_event(ProtocolEvent::QueriesSampled {
+            indices: query_indices.clone(),
+        });
 
-        println!("[Verifier] Reconstructed challenges and query indices from proof commitments.");
+        info!("[Verifier] Reconstructed challenges and query indices from proof commitments.");
         (betas, query_indices)
     }
 
-    /// Verifies a single query decommitment.
+    /// Appends the commitment of layer `layer` to the transcript, if the proof has one.
+    fn append_layer_commitment(&mut self, proof: &FriProof, layer: usize) {
+        if let Some(cap) = proof.layer_commitments.get(layer) {
+            self.challenger.absorb_commitment(cap);
+            self.observer.on_event(ProtocolEvent::LayerCommitted {
+                layer,
+                cap: ProtocolEvent::cap(cap),
+            });
+        }
+    }
+
+    /// Verifies a single query decommitment, whose leaves are already authenticated.
     fn verify_query(
-        &self,
+        &mut self,
         proof: &FriProof,
         query_idx: usize,
         betas: &[FE],
         generator: &FE,
         decommitment: &QueryDecommitment,
     ) -> Result<(), FriError> {
-        // Step 1: Verify the Merkle proofs for each layer's evaluations.
-        self.verify_merkle_paths(proof, query_idx, decommitment)?;
+        // Step 1: Record the evaluations opened at each layer.
+        self.record_openings(query_idx, decommitment);
 
         // Step 2: Verify the folding consistency across all layers.
         self.verify_folding_consistency(proof, query_idx, decommitment, betas, generator)?;
@@ -100,51 +297,94 @@ impl Verifier {
         Ok(())
     }
 
-    /// Verifies that all evaluations in a decommitment are valid against the layer commitments.
-    fn verify_merkle_paths(
+    /// Verifies the Merkle multi-proof of layer `layer` against its commitment, for the leaves
+    /// every query opened.
+    ///
+    /// Queries that meet at the layer open the same leaf, so they must agree on its evaluations.
+    fn verify_multi_proof(
         &self,
         proof: &FriProof,
-        query_idx: usize,
-        decommitment: &QueryDecommitment,
+        layer: usize,
+        query_indices: &[usize],
     ) -> Result<(), FriError> {
-        let mut current_idx = query_idx;
+        let domain_size = self.params.domain.len() >> layer;
+        let mut leaves: Vec<(usize, Vec<FE>)> = query_indices
+            .iter()
+            .zip(&proof.query_decommitments)
+            .map(|(&query_idx, decommitment)| {
+                let (leaf_idx, leaf) = Self::leaf(
+                    query_idx >> layer,
+                    decommitment.layer_evaluations[layer],
+                    decommitment.layer_evaluations_sym[layer],
+                );
+                (leaf_idx, leaf.to_vec())
+            })
+            .collect();
+        leaves.sort_by_key(|(leaf_idx, _)| *leaf_idx);
+        leaves.dedup();
+        if leaves.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(FriError::InvalidMerkleProof);
+        }
 
-        for i in 0..proof.layer_commitments.len() {
-            let domain_size = self.params.domain.len() >> i;
-            let sym_idx = (current_idx + domain_size / 2) % domain_size;
-            let commitment = &proof.layer_commitments[i];
-
-            // Verify proof for f(x)
-            let proof_path = Proof {
-                merkle_path: decommitment.layer_auth_paths[i].clone(),
-            };
-            if !proof_path.verify::<FriBackend>(
-                commitment,
-                current_idx,
-                &decommitment.layer_evaluations[i],
-            ) {
-                return Err(FriError::InvalidMerkleProof);
-            }
+        verify_cap_multi_proof(
+            &proof.layer_commitments[layer],
+            self.params.cap_height(layer),
+            domain_size / 2,
+            &leaves,
+            &proof.layer_multi_proofs[layer],
+        )?;
+        debug!(
+            "  > Layer {}: Merkle multi-proof valid for {} leaves",
+            layer,
+            leaves.len()
+        );
+        Ok(())
+    }
 
-            // Verify proof for f(-x)
-            let proof_path_sym = Proof {
-                merkle_path: decommitment.layer_auth_paths_sym[i].clone(),
-            };
-            if !proof_path_sym.verify::<FriBackend>(
-                commitment,
-                sym_idx,
-                &decommitment.layer_evaluations_sym[i],
-            ) {
-                return Err(FriError::InvalidMerkleProof);
-            }
+    /// Notifies the observer of the evaluations a decommitment opened at each layer.
+    fn record_openings(&mut self, query_idx: usize, decommitment: &QueryDecommitment) {
+        for i in 0..decommitment.layer_evaluations.len() {
+            self.observer.on_event(ProtocolEvent::QueryOpened {
+                query: query_idx,
+                layer: i,
+                index: query_idx >> i,
+                value: ProtocolEvent::element(&decommitment.layer_evaluations[i]),
+                value_sym: ProtocolEvent::element(&decommitment.layer_evaluations_sym[i]),
+            });
+        }
+    }
 
-            println!(
-                "  > Layer {}: Merkle proofs valid for indices {} and {}",
-                i, current_idx, sym_idx
-            );
-            current_idx %= (domain_size / 2).max(1);
+    /// Verifies that the leaf of layer `layer` holding `y` at index `idx` and `y_sym` at its
+    /// symmetric index belongs to the committed cap.
+    fn verify_leaf(
+        &self,
+        cap: &[[u8; 32]],
+        layer: usize,
+        idx: usize,
+        y: FE,
+        y_sym: FE,
+        auth_path: &[[u8; 32]],
+    ) -> Result<(), FriError> {
+        let (leaf_idx, leaf) = Self::leaf(idx, y, y_sym);
+        verify_cap_path(
+            cap,
+            self.params.cap_height(layer),
+            (self.params.domain.len() >> layer) / 2,
+            leaf_idx,
+            &leaf,
+            auth_path,
+        )
+    }
+
+    /// Rebuilds the leaf holding `y` at index `idx` and `y_sym` at its symmetric index, its
+    /// neighbour, in the order the Prover committed them, along with the leaf's index. A single
+    /// leaf holds both evaluations.
+    pub(crate) fn leaf(idx: usize, y: FE, y_sym: FE) -> (usize, [FE; 2]) {
+        if idx % 2 == 0 {
+            (idx / 2, [y, y_sym])
+        } else {
+            (idx / 2, [y_sym, y])
         }
-        Ok(())
     }
 
     /// Checks that the folding from layer `i` to `i+1` was done correctly.
@@ -156,28 +396,33 @@ impl Verifier {
         betas: &[FE],
         generator: &FE,
     ) -> Result<(), FriError> {
-        // Start with the claimed evaluation from the *next* layer and work backwards.
+        // Start with the evaluation of the last layer's polynomial, which the Verifier computes
+        // itself from the coefficients, and work backwards.
         // `claimed_child_evaluation` is the value at layer `i+1` that we are checking.
-        let mut claimed_child_evaluation = proof.last_layer_value.clone();
+        let num_rounds = betas.len();
+        let x_last = self.domain_point(generator, num_rounds, query_idx);
+        let mut claimed_child_evaluation =
+            Polynomial::new(&proof.last_layer_coefficients).evaluate(&x_last);
+
+        // Without any folding, the first layer is itself the last one.
+        if let Some(y) = decommitment.layer_evaluations.get(num_rounds) {
+            if *y != claimed_child_evaluation {
+                return Err(FriError::InconsistentFolding {
+                    layer: num_rounds,
+                    expected: claimed_child_evaluation.representative().to_hex(),
+                    got: y.representative().to_hex(),
+                });
+            }
+        }
 
-        // Iterate backwards from the second-to-last layer down to the first.
-        for i in (0..proof.layer_commitments.len() - 1).rev() {
+        // Iterate backwards from the last folding round down to the first.
+        for i in (0..num_rounds).rev() {
             // Get the evaluations for f(x) and f(-x) at the current layer `i`.
             let y = &decommitment.layer_evaluations[i];
             let y_sym = &decommitment.layer_evaluations_sym[i];
 
-            // Recompute `x` for the specific query index at this layer's domain size.
-            let domain_size = self.params.domain.len() >> i;
-            let current_query_idx_in_layer = query_idx % domain_size;
-            let g_i = generator.pow(1_u64 << i); // Generator for the i-th domain
-            let x = g_i.pow(current_query_idx_in_layer);
-            let x_inv = x.inv().unwrap();
-
             // Re-compute what the folded value should be using the folding formula.
-            let two_inv = FE::from(2).inv().unwrap();
-            let f_even = (y + y_sym) * &two_inv;
-            let f_odd = (y - y_sym) * &two_inv * &x_inv;
-            let expected_child_evaluation = &f_even + &betas[i] * &f_odd;
+            let expected_child_evaluation = self.fold(generator, i, query_idx, y, y_sym, &betas[i]);
 
             // Check if our calculation matches the claimed evaluation from the next layer.
             if claimed_child_evaluation != expected_child_evaluation {
@@ -188,7 +433,7 @@ impl Verifier {
                 });
             }
 
-            println!("  > Layer {}->{}: Folding is consistent.", i, i + 1);
+            debug!("  > Layer {}->{}: Folding is consistent.", i, i + 1);
 
             // For the next iteration, the "child" becomes the current evaluation.
             claimed_child_evaluation = y.clone();
@@ -197,10 +442,170 @@ impl Verifier {
         Ok(())
     }
 
-    /// Samples a random index from the transcript.
-    fn sample_index(&mut self, max_value: usize) -> usize {
-        // Use 8 bytes from the transcript for a u64, then get a value in range.
-        let sample_bytes: [u8; 8] = self.transcript.sample()[..8].try_into().unwrap();
-        (u64::from_be_bytes(sample_bytes) % max_value as u64) as usize
+    /// Folds `y` and `y_sym`, the evaluations of layer `layer` at the point where the query for
+    /// the original index `query_idx` lands and at its negation, into the evaluation of the next
+    /// layer with the challenge `beta`.
+    fn fold(
+        &self,
+        generator: &FE,
+        layer: usize,
+        query_idx: usize,
+        y: &FE,
+        y_sym: &FE,
+        beta: &FE,
+    ) -> FE {
+        // Recompute `x` for the specific query index at this layer's domain size.
+        let x = self.domain_point(generator, layer, query_idx);
+        let x_inv = x.inv().unwrap();
+
+        let two_inv = FE::from(2).inv().unwrap();
+        let f_even = (y + y_sym) * &two_inv;
+        let f_odd = (y - y_sym) * &two_inv * &x_inv;
+        f_even + beta * f_odd
+    }
+
+    /// Returns the generator of the subgroup the first layer's domain is a coset of.
+    fn generator(&self) -> FE {
+        let root_order = self.params.domain.len().trailing_zeros();
+        F::get_primitive_root_of_unity(root_order as u64).unwrap()
+    }
+
+    /// Returns the point of layer `layer`'s domain at which the query for the original index
+    /// `query_idx` lands.
+    ///
+    /// Each fold squares the domain, so layer `i` is the coset `offset^(2^i) * <g^(2^i)>` of size
+    /// `n / 2^i`, stored in bit-reversed order. The query lands on its position `query_idx >> i`,
+    /// which holds the element of natural index the reverse of that position.
+    fn domain_point(&self, generator: &FE, layer: usize, query_idx: usize) -> FE {
+        let domain_size = self.params.domain.len() >> layer;
+        let g_i = generator.pow(1_u64 << layer); // Generator for the i-th domain
+        let natural_idx = reverse_index(query_idx >> layer, domain_size as u64);
+        self.params.offset.pow(1_u64 << layer) * g_i.pow(natural_idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prover::Prover;
+    use crate::test_utils::{degree_seven_poly, demo_params, demo_poly};
+
+    #[test]
+    fn honest_proof_verifies() {
+        let (poly, params) = (demo_poly(), demo_params());
+        let proof = Prover::new(poly, params.clone()).prove().unwrap();
+        assert_eq!(Verifier::new(params).verify(&proof), Ok(()));
+    }
+
+    #[test]
+    fn rejects_last_layer_above_the_folded_degree() {
+        let (poly, params) = (demo_poly(), demo_params());
+        let mut proof = Prover::new(poly, params.clone()).prove().unwrap();
+        // One round folds the 4 coefficients down to 2; a third one exceeds degree 1.
+        proof.last_layer_coefficients.resize(2, FE::zero());
+        proof.last_layer_coefficients.push(FE::one());
+
+        assert_eq!(
+            Verifier::new(params).verify(&proof),
+            Err(FriError::LastLayerDegreeTooHigh {
+                degree: 2,
+                bound: 1
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_polynomial_above_the_claimed_degree() {
+        // A degree-7 polynomial under a claimed degree of 3 still has degree 3 after one fold.
+        let params = demo_params();
+        let proof = Prover::new(degree_seven_poly(), params.clone())
+            .prove()
+            .unwrap();
+
+        assert_eq!(
+            Verifier::new(params).verify(&proof),
+            Err(FriError::LastLayerDegreeTooHigh {
+                degree: 3,
+                bound: 1
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_proofs_without_panicking() {
+        let (poly, params) = (demo_poly(), demo_params());
+        let proof = Prover::new(poly, params.clone()).prove().unwrap();
+        let truncations: [fn(&mut FriProof); 5] = [
+            |p| p.layer_commitments.clear(),
+            |p| p.layer_commitments[0].clear(),
+            |p| p.query_decommitments[0].layer_evaluations_sym.clear(),
+            |p| {
+                p.layer_multi_proofs.pop();
+            },
+            |p| {
+                let extra = p.query_decommitments[0].clone();
+                p.query_decommitments.extend([extra.clone(), extra]);
+            },
+        ];
+        for truncate in truncations {
+            let mut malformed = proof.clone();
+            truncate(&mut malformed);
+            assert!(matches!(
+                Verifier::new(params.clone()).verify(&malformed),
+                Err(FriError::MalformedProof { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_tampered_multi_proofs() {
+        let (poly, params) = (demo_poly(), demo_params());
+        let proof = Prover::new(poly, params.clone()).prove().unwrap();
+        let tamperings: [fn(&mut FriProof); 2] = [
+            |p| p.layer_multi_proofs[0][0][0] ^= 1,
+            |p| p.layer_multi_proofs[0].push([0; 32]),
+        ];
+        for tamper in tamperings {
+            let mut tampered = proof.clone();
+            tamper(&mut tampered);
+            assert_eq!(
+                Verifier::new(params.clone()).verify(&tampered),
+                Err(FriError::InvalidMerkleProof)
+            );
+        }
+    }
+
+    #[test]
+    fn proofs_only_verify_under_their_domain_separator() {
+        let (poly, params) = (demo_poly(), demo_params());
+        let separator = DomainSeparator::new(b"Educational FRI", b"workshop exercise 1");
+        let proof = Prover::with_domain_separator(poly, params.clone(), separator.clone())
+            .prove()
+            .unwrap();
+
+        assert_eq!(
+            Verifier::with_domain_separator(params.clone(), separator).verify(&proof),
+            Ok(())
+        );
+        let other_context = DomainSeparator::new(b"Educational FRI", b"workshop exercise 2");
+        assert!(
+            Verifier::with_domain_separator(params.clone(), other_context)
+                .verify(&proof)
+                .is_err()
+        );
+        assert!(Verifier::new(params).verify(&proof).is_err());
+    }
+
+    #[test]
+    fn deep_proof_verifies_only_with_its_value() {
+        let (poly, params) = (demo_poly(), demo_params());
+        let prover = Prover::new(poly, params.clone());
+        let commitment = prover.commit().unwrap();
+        let mut proof = prover.prove_deep().unwrap();
+        let verifier = Verifier::new(params);
+        assert_eq!(verifier.verify_deep(&commitment, &proof), Ok(()));
+
+        proof.value += FE::one();
+        assert!(verifier.verify_deep(&commitment, &proof).is_err());
     }
 }
@@ -0,0 +1,54 @@
+//! The Verifier's entry point for JavaScript, compiled to `wasm32-unknown-unknown` with the `wasm`
+//! feature and without the default `std` one.
+//!
+//! ```text
+//! cargo rustc -p polynomial_commitment_scheme --lib --crate-type cdylib --release \
+//!     --target wasm32-unknown-unknown --no-default-features --features wasm
+//! wasm-bindgen --target web --out-dir 3_polynomial_commitment_scheme/pkg \
+//!     target/wasm32-unknown-unknown/release/polynomial_commitment_scheme.wasm
+//! ```
+//!
+//! The crate is only built as a `cdylib` on request, so native builds stay a plain library. The
+//! `examples/wasm_verifier` page loads the resulting package and checks the proof committed next to
+//! it against the parameters of its `params.json`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::error::FriError;
+use crate::types::{FriParameters, FriProof};
+use crate::verifier::Verifier;
+use crate::FE;
+
+/// Verifies a proof encoded by [`FriProof::encode`], under the default
+/// [`crate::types::DomainSeparator`]. The parameters are those of [`FriParameters::new`],
+/// followed by the Merkle cap height.
+///
+/// # Errors
+/// Throws the message of the [`FriError`] if the parameters are invalid, the proof does not
+/// decode, or the Verifier rejects it.
+#[wasm_bindgen(js_name = verifyFriProof)]
+pub fn verify_fri_proof(
+    claimed_degree: usize,
+    blowup_factor: usize,
+    num_queries: usize,
+    last_layer_degree_bound: usize,
+    offset: u32,
+    merkle_cap_height: usize,
+    proof: &str,
+) -> Result<(), JsError> {
+    let params = FriParameters::new(
+        claimed_degree,
+        blowup_factor,
+        num_queries,
+        last_layer_degree_bound,
+        FE::from(u64::from(offset)),
+    )
+    .map_err(js_error)?
+    .with_merkle_cap_height(merkle_cap_height);
+    let proof = FriProof::decode(proof).map_err(js_error)?;
+    Verifier::new(params).verify(&proof).map_err(js_error)
+}
+
+fn js_error(e: FriError) -> JsError {
+    JsError::new(&e.to_string())
+}